@@ -0,0 +1,310 @@
+//! Example authentication, all local: a [`CredentialStore`] of argon2-hashed passwords kept in a
+//! ron file, a [`LoginThrottle`] slowing down and then locking out repeated failed logins, and a
+//! [`Session`] that [`AuthPlugin`] mirrors into the [`AuthState`] state, so screens defined as
+//! sub-states of `AuthState::LoggedIn` can't be reached without logging in.
+//!
+//! ```ignore
+//! #[derive(SubStates, Clone, PartialEq, Eq, Hash, Debug, Default)]
+//! #[source(AuthState = AuthState::LoggedIn)]
+//! enum Home { #[default] Dashboard, Account }
+//! ```
+//!
+//! Hashing and verifying take a noticeable moment on purpose, so [`hash_password`] and
+//! [`verify_password`] are meant to run as background tasks (see [`crate::task`]). The throttle
+//! takes the time as a number of seconds, so it runs the same in scripts and without a clock.
+
+use crate::store::RonStore;
+use argon2::Argon2;
+use argon2::password_hash::rand_core::OsRng;
+use argon2::password_hash::{PasswordHash, PasswordHasher, PasswordVerifier, SaltString};
+use bevy::prelude::*;
+use std::collections::{BTreeMap, HashMap};
+use std::fmt;
+use std::path::PathBuf;
+
+/// Shortest password accepted at registration.
+pub const MIN_PASSWORD_LENGTH: usize = 8;
+
+/// What logins of unknown users are checked against, made with [`hash_password`] and the same
+/// argon2 parameters, so they take as long as a wrong password and don't tell the user is unknown.
+const UNKNOWN_USER_HASH: &str = "$argon2id$v=19$m=19456,t=2,p=1$SRidpIsHz0j2PAjJ1A90Nw$DF7AEW0uz29T+tb4wEE4u9bHpCxwdU3FlGEIul8NAlw";
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum AuthError {
+    EmptyUsername,
+    PasswordTooShort,
+    PasswordsDiffer,
+    UsernameTaken,
+    /// Unknown user or wrong password; which one isn't told, so accounts can't be guessed.
+    InvalidCredentials,
+    /// Retried before the delay after a failure was over.
+    TooSoon {
+        seconds: f64,
+    },
+    LockedOut {
+        seconds: f64,
+    },
+    /// The password couldn't be hashed, or the stored hash couldn't be read.
+    Hash(String),
+    /// The credential file couldn't be written.
+    Store(String),
+}
+
+impl fmt::Display for AuthError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            AuthError::EmptyUsername => write!(f, "Enter a username"),
+            AuthError::PasswordTooShort => write!(
+                f,
+                "Passwords need at least {MIN_PASSWORD_LENGTH} characters"
+            ),
+            AuthError::PasswordsDiffer => write!(f, "The passwords don't match"),
+            AuthError::UsernameTaken => write!(f, "That username is taken"),
+            AuthError::InvalidCredentials => write!(f, "Wrong username or password"),
+            AuthError::TooSoon { seconds } => {
+                write!(f, "Wait {}s before trying again", seconds.ceil())
+            }
+            AuthError::LockedOut { seconds } => write!(
+                f,
+                "Too many failed attempts, try again in {}s",
+                seconds.ceil()
+            ),
+            AuthError::Hash(err) => write!(f, "Password hashing failed: {err}"),
+            AuthError::Store(err) => write!(f, "The account couldn't be saved: {err}"),
+        }
+    }
+}
+
+/// Checks a registration form before the password gets hashed.
+pub fn validate_registration(
+    username: &str,
+    password: &str,
+    confirmation: &str,
+) -> Result<(), AuthError> {
+    if username.trim().is_empty() {
+        return Err(AuthError::EmptyUsername);
+    }
+    if password.chars().count() < MIN_PASSWORD_LENGTH {
+        return Err(AuthError::PasswordTooShort);
+    }
+    if password != confirmation {
+        return Err(AuthError::PasswordsDiffer);
+    }
+    Ok(())
+}
+
+/// Hashes `password` with argon2 and a random salt, as a PHC string such as `$argon2id$v=19$...`.
+pub fn hash_password(password: &str) -> Result<String, AuthError> {
+    let salt = SaltString::generate(&mut OsRng);
+    Argon2::default()
+        .hash_password(password.as_bytes(), &salt)
+        .map(|hash| hash.to_string())
+        .map_err(|err| AuthError::Hash(err.to_string()))
+}
+
+/// Checks `password` against a hash from [`CredentialStore::hash`]; `None`, an unknown user,
+/// fails the same way as a wrong password, and just as slowly.
+pub fn verify_password(hash: Option<&str>, password: &str) -> Result<(), AuthError> {
+    let Some(hash) = hash else {
+        let _ = verify_hash(UNKNOWN_USER_HASH, password);
+        return Err(AuthError::InvalidCredentials);
+    };
+    verify_hash(hash, password)
+}
+
+fn verify_hash(hash: &str, password: &str) -> Result<(), AuthError> {
+    let hash = PasswordHash::new(hash).map_err(|err| AuthError::Hash(err.to_string()))?;
+    Argon2::default()
+        .verify_password(password.as_bytes(), &hash)
+        .map_err(|_| AuthError::InvalidCredentials)
+}
+
+/// The registered users and their password hashes.
+#[derive(Resource, Default, Debug)]
+pub struct CredentialStore(RonStore<BTreeMap<String, String>>);
+
+impl CredentialStore {
+    /// A store saved to `path` on every change; see [`RonStore::load`].
+    pub fn load(path: impl Into<PathBuf>) -> Result<Self, String> {
+        RonStore::load(path).map(Self)
+    }
+
+    pub fn contains(&self, username: &str) -> bool {
+        self.0.get().contains_key(username)
+    }
+
+    /// The password hash of `username`, for [`verify_password`].
+    pub fn hash(&self, username: &str) -> Option<&str> {
+        self.0.get().get(username).map(String::as_str)
+    }
+
+    /// Adds a user with a hash from [`hash_password`] and saves the store. A user that can't be
+    /// saved isn't added.
+    pub fn add(&mut self, username: &str, hash: String) -> Result<(), AuthError> {
+        if username.trim().is_empty() {
+            return Err(AuthError::EmptyUsername);
+        }
+        if self.contains(username) {
+            return Err(AuthError::UsernameTaken);
+        }
+        self.0.get_mut().insert(username.to_string(), hash);
+        self.0.save().map_err(|err| {
+            self.0.get_mut().remove(username);
+            AuthError::Store(err)
+        })
+    }
+
+    /// Hashes `password` and adds the user, on the calling thread.
+    pub fn register(&mut self, username: &str, password: &str) -> Result<(), AuthError> {
+        validate_registration(username, password, password)?;
+        if self.contains(username) {
+            return Err(AuthError::UsernameTaken);
+        }
+        let hash = hash_password(password)?;
+        self.add(username, hash)
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default)]
+struct Failures {
+    count: u32,
+    /// When the next attempt is allowed, in seconds.
+    next_attempt: f64,
+}
+
+/// Failed logins per username. After each failure the next attempt has to wait `retry_delay`
+/// seconds, doubled with every further failure, and after `max_attempts` failures in a row the
+/// username is locked out for `lockout` seconds. A successful login clears it.
+#[derive(Resource, Clone, Debug)]
+pub struct LoginThrottle {
+    pub max_attempts: u32,
+    pub retry_delay: f64,
+    pub lockout: f64,
+    failures: HashMap<String, Failures>,
+}
+
+impl Default for LoginThrottle {
+    fn default() -> Self {
+        Self::new(3, 1., 30.)
+    }
+}
+
+impl LoginThrottle {
+    pub fn new(max_attempts: u32, retry_delay: f64, lockout: f64) -> Self {
+        Self {
+            max_attempts,
+            retry_delay,
+            lockout,
+            failures: HashMap::new(),
+        }
+    }
+
+    /// Whether `username` may try logging in at `now`.
+    pub fn check(&self, username: &str, now: f64) -> Result<(), AuthError> {
+        let Some(failures) = self.failures.get(username) else {
+            return Ok(());
+        };
+        let seconds = failures.next_attempt - now;
+        if seconds <= 0. {
+            Ok(())
+        } else if failures.count >= self.max_attempts {
+            Err(AuthError::LockedOut { seconds })
+        } else {
+            Err(AuthError::TooSoon { seconds })
+        }
+    }
+
+    /// Failed logins `username` has left before being locked out.
+    pub fn attempts_left(&self, username: &str) -> u32 {
+        let count = self
+            .failures
+            .get(username)
+            .map_or(0, |failures| failures.count);
+        self.max_attempts.saturating_sub(count)
+    }
+
+    /// Records a failed login at `now`.
+    pub fn fail(&mut self, username: &str, now: f64) {
+        let failures = self.failures.entry(username.to_string()).or_default();
+        // a lockout that's over starts a new round of attempts
+        if failures.count >= self.max_attempts {
+            failures.count = 0;
+        }
+        failures.count += 1;
+        failures.next_attempt = if failures.count >= self.max_attempts {
+            now + self.lockout
+        } else {
+            now + self.retry_delay * 2f64.powi(failures.count as i32 - 1)
+        };
+    }
+
+    /// Records a successful login, forgetting the failures.
+    pub fn succeed(&mut self, username: &str) {
+        self.failures.remove(username);
+    }
+}
+
+/// Who is logged in.
+#[derive(Resource, Default, Debug)]
+pub struct Session {
+    user: Option<String>,
+}
+
+impl Session {
+    pub fn user(&self) -> Option<&str> {
+        self.user.as_deref()
+    }
+
+    pub fn is_authenticated(&self) -> bool {
+        self.user.is_some()
+    }
+
+    pub fn log_in(&mut self, username: impl Into<String>) {
+        self.user = Some(username.into());
+    }
+
+    pub fn log_out(&mut self) {
+        self.user = None;
+    }
+}
+
+/// Follows the [`Session`]; screens that need a logged in user are its sub-states.
+#[derive(States, Clone, Copy, PartialEq, Eq, Hash, Debug, Default)]
+pub enum AuthState {
+    #[default]
+    LoggedOut,
+    LoggedIn,
+}
+
+/// Adds the auth resources and keeps [`AuthState`] following the [`Session`]. The
+/// [`CredentialStore`] it starts with has no file, so its accounts are gone when the app exits;
+/// insert one from [`CredentialStore::load`] to keep them.
+pub struct AuthPlugin;
+
+impl Plugin for AuthPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<CredentialStore>()
+            .init_resource::<LoginThrottle>()
+            .init_resource::<Session>()
+            .init_state::<AuthState>()
+            .add_systems(PreUpdate, follow_session);
+    }
+}
+
+fn follow_session(
+    session: Res<Session>,
+    state: Res<State<AuthState>>,
+    mut next: ResMut<NextState<AuthState>>,
+) {
+    if !session.is_changed() {
+        return;
+    }
+    let wanted = if session.is_authenticated() {
+        AuthState::LoggedIn
+    } else {
+        AuthState::LoggedOut
+    };
+    if *state.get() != wanted {
+        next.set(wanted);
+    }
+}
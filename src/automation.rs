@@ -0,0 +1,980 @@
+//! UI automation: locate widgets by [`Name`], label text, role or marker component and click,
+//! right-click, hover, drag, type into, focus or wait on them, without computing coordinates.
+//!
+//! Tests drive an [`App`] through [`Automation`]:
+//!
+//! ```ignore
+//! let mut ui = Automation::new(&mut app);
+//! ui.click(&Selector::label("Confirm"))?;
+//! ui.wait_until_text(&Selector::name("status_text"), "selected action is ...  Confirm", 10)?;
+//! ```
+//!
+//! Running examples take the same steps from a RON script with `--script <file>`:
+//!
+//! ```sh
+//! cargo run --bin confirmation_example -- --script scripts/confirmation_confirm.ron
+//! cargo run --bin sliders -- --script scripts/slider_drag.ron --headless
+//! ```
+//!
+//! With `--headless` examples started through [`default_plugins`] run without a window or a GPU,
+//! so scripts also run on CI machines without a display.
+
+use crate::record::replay_finished;
+use crate::snapshot::{SnapshotMode, dump_world};
+use crate::widgets::focus::FocusSystem;
+use bevy::app::{PluginGroupBuilder, PluginsState, ScheduleRunnerPlugin};
+use bevy::core::FrameCount;
+use bevy::input::keyboard::{Key, KeyboardInput};
+use bevy::input::mouse::MouseButtonInput;
+use bevy::input::{ButtonState, InputSystem};
+use bevy::log::{DEFAULT_FILTER, LogPlugin};
+use bevy::prelude::*;
+use bevy::render::RenderPlugin;
+use bevy::render::settings::{RenderCreation, WgpuSettings};
+use bevy::ui::UiSystem;
+use bevy::window::PrimaryWindow;
+use bevy::winit::{WinitPlugin, WinitSettings};
+use bevy_simple_text_input::{TextInput, TextInputInactive, TextInputValue};
+use serde::{Deserialize, Serialize};
+use std::collections::VecDeque;
+use std::fmt;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant};
+
+/// Frames a script step waits for its condition before failing.
+pub const DEFAULT_WAIT_FRAMES: u32 = 120;
+/// Cursor moves a drag is split into, one per frame.
+const DRAG_STEPS: u32 = 5;
+/// Time between frames of a headless app.
+const HEADLESS_FRAME_TIME: Duration = Duration::from_micros(16_667);
+
+/// `DefaultPlugins` without a window or a GPU: the primary window is only the entity layout and
+/// input go through, and frames run in a loop. Tests use it with [`Automation`].
+pub fn headless_plugins() -> PluginGroupBuilder {
+    DefaultPlugins
+        .build()
+        .disable::<WinitPlugin>()
+        .set(LogPlugin {
+            // complains that there's no renderer to extract to
+            filter: format!("{DEFAULT_FILTER},bevy_render::extract_resource=off"),
+            ..default()
+        })
+        .set(RenderPlugin {
+            render_creation: RenderCreation::Automatic(WgpuSettings {
+                backends: None,
+                ..default()
+            }),
+            ..default()
+        })
+        .add(ScheduleRunnerPlugin::run_loop(HEADLESS_FRAME_TIME))
+}
+
+/// The plugins examples start with: [`headless_plugins`] when run with `--headless`,
+/// `DefaultPlugins` otherwise.
+pub fn default_plugins() -> PluginGroupBuilder {
+    if std::env::args().any(|arg| arg == "--headless") {
+        headless_plugins()
+    } else {
+        DefaultPlugins.build()
+    }
+}
+
+/// What a widget is, as far as selectors are concerned.
+#[derive(Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Role {
+    Button,
+    TextInput,
+    Label,
+}
+
+#[derive(Serialize, Deserialize, Clone, PartialEq, Eq, Debug)]
+pub enum Selector {
+    /// Entity with this [`Name`].
+    Name(String),
+    /// Text content; text inside a button selects the button.
+    Label(String),
+    Role(Role),
+    /// Entity with a component of this type, e.g. `"ConfirmButton"`.
+    Marker(String),
+    /// The `index`th match of another selector, in hierarchy order.
+    Nth(Box<Selector>, usize),
+}
+
+impl Selector {
+    pub fn name(name: impl Into<String>) -> Self {
+        Selector::Name(name.into())
+    }
+
+    pub fn label(label: impl Into<String>) -> Self {
+        Selector::Label(label.into())
+    }
+
+    pub fn marker<T: Component>() -> Self {
+        Selector::Marker(short_type_name(std::any::type_name::<T>()).to_string())
+    }
+
+    pub fn nth(self, index: usize) -> Self {
+        Selector::Nth(Box::new(self), index)
+    }
+}
+
+impl fmt::Display for Selector {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Selector::Name(name) => write!(f, "name {name:?}"),
+            Selector::Label(label) => write!(f, "label {label:?}"),
+            Selector::Role(role) => write!(f, "role {role:?}"),
+            Selector::Marker(marker) => write!(f, "marker {marker}"),
+            Selector::Nth(selector, index) => write!(f, "{selector} #{index}"),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum AutomationError {
+    NotFound {
+        selector: Selector,
+        hierarchy: String,
+    },
+    Ambiguous {
+        selector: Selector,
+        matches: usize,
+        hierarchy: String,
+    },
+    NoText {
+        selector: Selector,
+    },
+    Timeout {
+        condition: String,
+        frames: u32,
+        hierarchy: String,
+    },
+}
+
+impl fmt::Display for AutomationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AutomationError::NotFound {
+                selector,
+                hierarchy,
+            } => write!(f, "no widget matches {selector}\n{hierarchy}"),
+            AutomationError::Ambiguous {
+                selector,
+                matches,
+                hierarchy,
+            } => write!(f, "{matches} widgets match {selector}\n{hierarchy}"),
+            AutomationError::NoText { selector } => write!(f, "{selector} has no text"),
+            AutomationError::Timeout {
+                condition,
+                frames,
+                hierarchy,
+            } => write!(
+                f,
+                "{condition} still false after {frames} frames\n{hierarchy}"
+            ),
+        }
+    }
+}
+
+impl std::error::Error for AutomationError {}
+
+/// Every entity matching `selector`, in hierarchy order.
+pub fn find_all(world: &mut World, selector: &Selector) -> Vec<Entity> {
+    let nodes = ui_nodes(world);
+    match selector {
+        Selector::Name(name) => nodes
+            .into_iter()
+            .filter(|entity| {
+                world
+                    .get::<Name>(*entity)
+                    .is_some_and(|n| n.as_str() == name)
+            })
+            .collect(),
+        Selector::Label(label) => {
+            let mut found = Vec::new();
+            for entity in nodes {
+                if world
+                    .get::<Text>(entity)
+                    .is_some_and(|text| text.0 == *label)
+                {
+                    let target = interactive_ancestor(world, entity).unwrap_or(entity);
+                    if !found.contains(&target) {
+                        found.push(target);
+                    }
+                }
+            }
+            found
+        }
+        Selector::Role(role) => nodes
+            .into_iter()
+            .filter(|entity| role_of(world, *entity) == Some(*role))
+            .collect(),
+        Selector::Marker(marker) => {
+            let ids: Vec<_> = world
+                .components()
+                .iter()
+                .filter(|info| short_type_name(info.name()) == marker)
+                .map(|info| info.id())
+                .collect();
+            nodes
+                .into_iter()
+                .filter(|entity| {
+                    let entity = world.entity(*entity);
+                    ids.iter().any(|id| entity.contains_id(*id))
+                })
+                .collect()
+        }
+        Selector::Nth(selector, index) => find_all(world, selector)
+            .get(*index)
+            .copied()
+            .into_iter()
+            .collect(),
+    }
+}
+
+/// The single entity matching `selector`.
+pub fn find(world: &mut World, selector: &Selector) -> Result<Entity, AutomationError> {
+    let found = find_all(world, selector);
+    match found.as_slice() {
+        [entity] => Ok(*entity),
+        [] => Err(AutomationError::NotFound {
+            selector: selector.clone(),
+            hierarchy: dump_world(world),
+        }),
+        _ => Err(AutomationError::Ambiguous {
+            selector: selector.clone(),
+            matches: found.len(),
+            hierarchy: dump_world(world),
+        }),
+    }
+}
+
+/// Text shown by `entity`: a text input's value, its own text, or the first text below it.
+pub fn text_of(world: &World, entity: Entity) -> Option<String> {
+    if let Some(value) = world.get::<TextInputValue>(entity) {
+        return Some(value.0.clone());
+    }
+    if let Some(text) = world.get::<Text>(entity) {
+        return Some(text.0.clone());
+    }
+    world
+        .get::<Children>(entity)?
+        .iter()
+        .find_map(|child| text_of(world, *child))
+}
+
+/// Replaces the text shown by `entity`; returns false if it shows none.
+pub fn set_text(world: &mut World, entity: Entity, text: String) -> bool {
+    if let Some(mut value) = world.get_mut::<TextInputValue>(entity) {
+        value.0 = text;
+        return true;
+    }
+    if let Some(mut value) = world.get_mut::<Text>(entity) {
+        value.0 = text;
+        return true;
+    }
+    false
+}
+
+/// Presses `entity` (or the button containing it) on the next frame and releases it on the one
+/// after, like a mouse click would.
+pub fn click(world: &mut World, entity: Entity) {
+    let target = interactive_ancestor(world, entity).unwrap_or(entity);
+    world.resource_mut::<AutomationQueue>().clicks.push(target);
+}
+
+/// Moves the cursor to the center of `entity` and clicks there with real mouse input, so systems
+/// that look at the cursor position see the click too (unlike [`click`]). Returns the number of
+/// frames the click takes.
+pub fn pointer_click(world: &mut World, entity: Entity) -> u32 {
+    let center = node_center(world, entity);
+    let mut queue = world.resource_mut::<AutomationQueue>();
+    queue.pointer.push_back(PointerStep::Move(center));
+    queue
+        .pointer
+        .push_back(PointerStep::Press(MouseButton::Left));
+    queue
+        .pointer
+        .push_back(PointerStep::Release(MouseButton::Left));
+    3
+}
+
+/// Clicks the right mouse button at the center of `entity`, e.g. to open its context menu.
+/// Returns the number of frames the click takes.
+pub fn right_click(world: &mut World, entity: Entity) -> u32 {
+    let center = node_center(world, entity);
+    let mut queue = world.resource_mut::<AutomationQueue>();
+    queue.pointer.push_back(PointerStep::Move(center));
+    queue
+        .pointer
+        .push_back(PointerStep::Press(MouseButton::Right));
+    queue
+        .pointer
+        .push_back(PointerStep::Release(MouseButton::Right));
+    3
+}
+
+/// Moves the cursor to the center of `entity` without pressing, e.g. to show its tooltip.
+/// Returns the number of frames the move takes.
+pub fn hover(world: &mut World, entity: Entity) -> u32 {
+    let center = node_center(world, entity);
+    let mut queue = world.resource_mut::<AutomationQueue>();
+    queue.pointer.push_back(PointerStep::Move(center));
+    1
+}
+
+/// Presses the mouse over the center of `entity`, moves the cursor by `by` logical pixels over
+/// the following frames and releases it, like a real drag would. Returns the number of frames
+/// the drag takes.
+pub fn drag(world: &mut World, entity: Entity, by: Vec2) -> u32 {
+    let start = node_center(world, entity);
+    let mut queue = world.resource_mut::<AutomationQueue>();
+    queue.pointer.push_back(PointerStep::Move(start));
+    queue
+        .pointer
+        .push_back(PointerStep::Press(MouseButton::Left));
+    for step in 1..=DRAG_STEPS {
+        let position = start + by * step as f32 / DRAG_STEPS as f32;
+        queue.pointer.push_back(PointerStep::Move(position));
+    }
+    queue
+        .pointer
+        .push_back(PointerStep::Release(MouseButton::Left));
+    DRAG_STEPS + 3
+}
+
+/// Whether `entity` is drawn, and so can be reached with the pointer.
+fn is_shown(world: &World, entity: Entity) -> bool {
+    world
+        .get::<InheritedVisibility>(entity)
+        .is_none_or(|visibility| visibility.get())
+}
+
+/// Center of a laid out node in logical window coordinates.
+fn node_center(world: &World, entity: Entity) -> Vec2 {
+    world
+        .get::<GlobalTransform>(entity)
+        .zip(world.get::<ComputedNode>(entity))
+        .map(|(transform, node)| transform.translation().truncate() * node.inverse_scale_factor())
+        .unwrap_or_default()
+}
+
+/// Makes `entity` the focused text input.
+pub fn focus(world: &mut World, entity: Entity) {
+    let mut inputs = world.query::<(Entity, &mut TextInputInactive)>();
+    for (input, mut inactive) in inputs.iter_mut(world) {
+        inactive.0 = input != entity;
+    }
+}
+
+/// Sends key presses for `text` to whatever has keyboard focus; `\n` presses Enter.
+pub fn type_text(world: &mut World, text: &str) {
+    let window = primary_window(world);
+    for character in text.chars() {
+        let (key_code, logical_key) = key_for(character);
+        for state in [ButtonState::Pressed, ButtonState::Released] {
+            world.send_event(KeyboardInput {
+                key_code,
+                logical_key: logical_key.clone(),
+                state,
+                repeat: false,
+                window,
+            });
+        }
+    }
+}
+
+/// Presses and releases a key that doesn't type a character, such as Escape or an arrow key.
+pub fn press_key(world: &mut World, key_code: KeyCode) {
+    let window = primary_window(world);
+    let logical_key = match key_code {
+        KeyCode::Escape => Key::Escape,
+        KeyCode::Enter => Key::Enter,
+        KeyCode::Tab => Key::Tab,
+        KeyCode::Space => Key::Space,
+        KeyCode::Backspace => Key::Backspace,
+        KeyCode::Delete => Key::Delete,
+        KeyCode::ArrowUp => Key::ArrowUp,
+        KeyCode::ArrowDown => Key::ArrowDown,
+        KeyCode::ArrowLeft => Key::ArrowLeft,
+        KeyCode::ArrowRight => Key::ArrowRight,
+        KeyCode::Home => Key::Home,
+        KeyCode::End => Key::End,
+        KeyCode::PageUp => Key::PageUp,
+        KeyCode::PageDown => Key::PageDown,
+        _ => Key::Unidentified(bevy::input::keyboard::NativeKey::Unidentified),
+    };
+    for state in [ButtonState::Pressed, ButtonState::Released] {
+        world.send_event(KeyboardInput {
+            key_code,
+            logical_key: logical_key.clone(),
+            state,
+            repeat: false,
+            window,
+        });
+    }
+}
+
+fn primary_window(world: &mut World) -> Entity {
+    world
+        .query_filtered::<Entity, With<PrimaryWindow>>()
+        .iter(world)
+        .next()
+        .unwrap_or(Entity::PLACEHOLDER)
+}
+
+fn key_for(character: char) -> (KeyCode, Key) {
+    let key_code = match character.to_ascii_lowercase() {
+        'a' => KeyCode::KeyA,
+        'b' => KeyCode::KeyB,
+        'c' => KeyCode::KeyC,
+        'd' => KeyCode::KeyD,
+        'e' => KeyCode::KeyE,
+        'f' => KeyCode::KeyF,
+        'g' => KeyCode::KeyG,
+        'h' => KeyCode::KeyH,
+        'i' => KeyCode::KeyI,
+        'j' => KeyCode::KeyJ,
+        'k' => KeyCode::KeyK,
+        'l' => KeyCode::KeyL,
+        'm' => KeyCode::KeyM,
+        'n' => KeyCode::KeyN,
+        'o' => KeyCode::KeyO,
+        'p' => KeyCode::KeyP,
+        'q' => KeyCode::KeyQ,
+        'r' => KeyCode::KeyR,
+        's' => KeyCode::KeyS,
+        't' => KeyCode::KeyT,
+        'u' => KeyCode::KeyU,
+        'v' => KeyCode::KeyV,
+        'w' => KeyCode::KeyW,
+        'x' => KeyCode::KeyX,
+        'y' => KeyCode::KeyY,
+        'z' => KeyCode::KeyZ,
+        '0' => KeyCode::Digit0,
+        '1' => KeyCode::Digit1,
+        '2' => KeyCode::Digit2,
+        '3' => KeyCode::Digit3,
+        '4' => KeyCode::Digit4,
+        '5' => KeyCode::Digit5,
+        '6' => KeyCode::Digit6,
+        '7' => KeyCode::Digit7,
+        '8' => KeyCode::Digit8,
+        '9' => KeyCode::Digit9,
+        ' ' => return (KeyCode::Space, Key::Space),
+        '\n' => return (KeyCode::Enter, Key::Enter),
+        _ => KeyCode::Unidentified(bevy::input::keyboard::NativeKeyCode::Unidentified),
+    };
+    (key_code, Key::Character(character.to_string().into()))
+}
+
+/// Every UI node, in hierarchy order.
+pub fn ui_nodes(world: &mut World) -> Vec<Entity> {
+    let mut roots: Vec<Entity> = world
+        .query_filtered::<Entity, (With<Node>, Without<Parent>)>()
+        .iter(world)
+        .collect();
+    roots.sort();
+    let mut nodes = Vec::new();
+    let mut stack: Vec<Entity> = roots.into_iter().rev().collect();
+    while let Some(entity) = stack.pop() {
+        if world.get::<Node>(entity).is_none() {
+            continue;
+        }
+        nodes.push(entity);
+        if let Some(children) = world.get::<Children>(entity) {
+            stack.extend(children.iter().rev().copied());
+        }
+    }
+    nodes
+}
+
+fn interactive_ancestor(world: &World, entity: Entity) -> Option<Entity> {
+    let mut current = Some(entity);
+    while let Some(entity) = current {
+        if world.get::<Interaction>(entity).is_some() {
+            return Some(entity);
+        }
+        current = world.get::<Parent>(entity).map(|parent| parent.get());
+    }
+    None
+}
+
+pub fn role_of(world: &World, entity: Entity) -> Option<Role> {
+    if world.get::<Button>(entity).is_some() {
+        Some(Role::Button)
+    } else if world.get::<TextInput>(entity).is_some() {
+        Some(Role::TextInput)
+    } else if world.get::<Text>(entity).is_some() {
+        Some(Role::Label)
+    } else {
+        None
+    }
+}
+
+fn short_type_name(name: &str) -> &str {
+    let name = name.split('<').next().unwrap_or(name);
+    name.rsplit("::").next().unwrap_or(name)
+}
+
+/// Drives an [`App`] frame by frame; the app needs the [`AutomationPlugin`].
+pub struct Automation<'a> {
+    app: &'a mut App,
+    last_frame: Instant,
+}
+
+impl<'a> Automation<'a> {
+    /// Wraps `app`. If it hasn't run yet its plugins are finished and its first frame is run, so
+    /// the UI spawned on startup is there and laid out.
+    pub fn new(app: &'a mut App) -> Self {
+        let started = app.plugins_state() == PluginsState::Cleaned;
+        if !started {
+            while app.plugins_state() == PluginsState::Adding {
+                bevy::tasks::tick_global_task_pools_on_main_thread();
+            }
+            app.finish();
+            app.cleanup();
+        }
+        let mut automation = Self {
+            app,
+            last_frame: Instant::now(),
+        };
+        if !started {
+            automation.update(1);
+        }
+        automation
+    }
+
+    pub fn world(&mut self) -> &mut World {
+        self.app.world_mut()
+    }
+
+    /// Runs `frames` frames, paced like a headless app, so timers and background tasks get as
+    /// much time per frame as in a running example.
+    pub fn update(&mut self, frames: u32) {
+        for _ in 0..frames {
+            if let Some(left) = HEADLESS_FRAME_TIME.checked_sub(self.last_frame.elapsed()) {
+                std::thread::sleep(left);
+            }
+            self.last_frame = Instant::now();
+            self.app.update();
+        }
+    }
+
+    pub fn find(&mut self, selector: &Selector) -> Result<Entity, AutomationError> {
+        find(self.world(), selector)
+    }
+
+    pub fn text(&mut self, selector: &Selector) -> Result<String, AutomationError> {
+        let entity = self.find(selector)?;
+        text_of(self.world(), entity).ok_or_else(|| AutomationError::NoText {
+            selector: selector.clone(),
+        })
+    }
+
+    /// Clicks the widget and runs the press and release frames.
+    pub fn click(&mut self, selector: &Selector) -> Result<(), AutomationError> {
+        let entity = self.find(selector)?;
+        click(self.world(), entity);
+        self.update(2);
+        Ok(())
+    }
+
+    /// Clicks the widget with the mouse and runs the frames until the release has been handled.
+    pub fn pointer_click(&mut self, selector: &Selector) -> Result<(), AutomationError> {
+        let entity = self.find(selector)?;
+        let frames = pointer_click(self.world(), entity);
+        self.update(frames + 1);
+        Ok(())
+    }
+
+    /// Right-clicks the widget and runs the frames until the release has been handled.
+    pub fn right_click(&mut self, selector: &Selector) -> Result<(), AutomationError> {
+        let entity = self.find(selector)?;
+        let frames = right_click(self.world(), entity);
+        self.update(frames + 1);
+        Ok(())
+    }
+
+    /// Moves the cursor over the widget and runs the frames until the move has been handled.
+    pub fn hover(&mut self, selector: &Selector) -> Result<(), AutomationError> {
+        let entity = self.find(selector)?;
+        let frames = hover(self.world(), entity);
+        self.update(frames + 1);
+        Ok(())
+    }
+
+    /// Drags the widget by `by` logical pixels and runs the frames until the mouse is released
+    /// and the release has been handled.
+    pub fn drag(&mut self, selector: &Selector, by: Vec2) -> Result<(), AutomationError> {
+        let entity = self.find(selector)?;
+        let frames = drag(self.world(), entity, by);
+        self.update(frames + 1);
+        Ok(())
+    }
+
+    pub fn focus(&mut self, selector: &Selector) -> Result<(), AutomationError> {
+        let entity = self.find(selector)?;
+        focus(self.world(), entity);
+        self.update(1);
+        Ok(())
+    }
+
+    pub fn type_text(&mut self, text: &str) {
+        type_text(self.world(), text);
+        self.update(1);
+    }
+
+    pub fn press_key(&mut self, key_code: KeyCode) {
+        press_key(self.world(), key_code);
+        self.update(1);
+    }
+
+    /// Runs frames until `condition` holds, at most `frames` of them.
+    pub fn wait_until(
+        &mut self,
+        description: &str,
+        frames: u32,
+        mut condition: impl FnMut(&mut World) -> bool,
+    ) -> Result<(), AutomationError> {
+        for _ in 0..frames {
+            if condition(self.world()) {
+                return Ok(());
+            }
+            self.update(1);
+        }
+        if condition(self.world()) {
+            return Ok(());
+        }
+        Err(AutomationError::Timeout {
+            condition: description.to_string(),
+            frames,
+            hierarchy: dump_world(self.world()),
+        })
+    }
+
+    /// Runs script steps the way `--script` does, until they all passed or one failed.
+    pub fn run_script(&mut self, steps: Vec<Step>) -> Result<(), AutomationError> {
+        self.world().insert_resource(ScriptRun::new(steps));
+        while self
+            .world()
+            .get_resource::<ScriptRun>()
+            .is_some_and(|script| !script.finished)
+        {
+            self.update(1);
+        }
+        match self.world().remove_resource::<ScriptRun>() {
+            Some(ScriptRun {
+                failure: Some(err), ..
+            }) => Err(err),
+            _ => Ok(()),
+        }
+    }
+
+    pub fn wait_until_text(
+        &mut self,
+        selector: &Selector,
+        expected: &str,
+        frames: u32,
+    ) -> Result<(), AutomationError> {
+        let description = format!("text of {selector} == {expected:?}");
+        self.wait_until(&description, frames, |world| {
+            find(world, selector)
+                .ok()
+                .and_then(|entity| text_of(world, entity))
+                .is_some_and(|text| text == expected)
+        })
+    }
+}
+
+/// One step of an automation script.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub enum Step {
+    Click(Selector),
+    /// Clicks with the mouse at the widget's center; see [`pointer_click`].
+    PointerClick(Selector),
+    /// Clicks the right mouse button at the widget's center.
+    RightClick(Selector),
+    /// Moves the cursor over the widget's center.
+    Hover(Selector),
+    /// Drags the widget by `(x, y)` logical pixels.
+    Drag(Selector, Vec2),
+    Focus(Selector),
+    TypeText(String),
+    /// Presses a key such as `Escape` or `ArrowDown`.
+    PressKey(KeyCode),
+    WaitUntil(Condition),
+    /// Lets `n` frames pass.
+    Wait(u32),
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub enum Condition {
+    Exists(Selector),
+    Missing(Selector),
+    TextEquals(Selector, String),
+    TextContains(Selector, String),
+}
+
+impl Condition {
+    fn holds(&self, world: &mut World) -> bool {
+        let text = |world: &mut World, selector: &Selector| {
+            find(world, selector)
+                .ok()
+                .and_then(|entity| text_of(world, entity))
+        };
+        match self {
+            Condition::Exists(selector) => !find_all(world, selector).is_empty(),
+            Condition::Missing(selector) => find_all(world, selector).is_empty(),
+            Condition::TextEquals(selector, expected) => {
+                text(world, selector).is_some_and(|text| text == *expected)
+            }
+            Condition::TextContains(selector, expected) => {
+                text(world, selector).is_some_and(|text| text.contains(expected.as_str()))
+            }
+        }
+    }
+}
+
+pub fn load_script(path: &Path) -> Result<Vec<Step>, String> {
+    let contents =
+        std::fs::read_to_string(path).map_err(|err| format!("{}: {err}", path.display()))?;
+    ron::from_str(&contents).map_err(|err| format!("{}: {err}", path.display()))
+}
+
+/// Clicks and pointer moves waiting to be applied; see [`click`] and [`drag`].
+#[derive(Resource, Default)]
+pub struct AutomationQueue {
+    clicks: Vec<Entity>,
+    releases: Vec<Entity>,
+    pointer: VecDeque<PointerStep>,
+}
+
+impl AutomationQueue {
+    /// True while a pointer click or drag is still being played.
+    pub fn is_dragging(&self) -> bool {
+        !self.pointer.is_empty()
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+enum PointerStep {
+    Move(Vec2),
+    Press(MouseButton),
+    Release(MouseButton),
+}
+
+pub struct AutomationPlugin;
+
+impl Plugin for AutomationPlugin {
+    fn build(&self, app: &mut App) {
+        // clicks are applied after the UI focus system, which would otherwise reset them, and
+        // before click-to-focus looks at them
+        // pointer steps go in as mouse input, before the input and focus systems read it
+        app.init_resource::<AutomationQueue>()
+            .add_systems(PreUpdate, apply_pointer.before(InputSystem))
+            .add_systems(
+                PreUpdate,
+                apply_clicks.after(UiSystem::Focus).before(FocusSystem),
+            )
+            .add_systems(
+                Update,
+                run_script
+                    .run_if(resource_exists::<ScriptRun>)
+                    .run_if(replay_finished),
+            );
+
+        let args: Vec<String> = std::env::args().collect();
+        let Some(path) = args
+            .iter()
+            .position(|arg| arg == "--script")
+            .and_then(|index| args.get(index + 1))
+            .map(PathBuf::from)
+        else {
+            return;
+        };
+        match load_script(&path) {
+            Ok(steps) => {
+                println!("running {} steps from {}", steps.len(), path.display());
+                app.insert_resource(ScriptRun::new(steps));
+            }
+            Err(err) => {
+                // running the app without its script would look like a pass
+                println!("cannot run script {err}");
+                app.add_systems(Startup, |mut exit: EventWriter<AppExit>| {
+                    exit.send(AppExit::error());
+                });
+            }
+        }
+    }
+
+    fn finish(&self, app: &mut App) {
+        if app.world().contains_resource::<ScriptRun>() {
+            app.insert_resource(WinitSettings::game());
+        }
+    }
+}
+
+/// True unless a `--script` is still running.
+pub fn script_finished(script: Option<Res<ScriptRun>>) -> bool {
+    script.is_none_or(|script| script.finished)
+}
+
+fn apply_pointer(
+    mut queue: ResMut<AutomationQueue>,
+    mut windows: Query<(Entity, &mut Window), With<PrimaryWindow>>,
+    mut buttons: EventWriter<MouseButtonInput>,
+) {
+    let Ok((window_entity, mut window)) = windows.get_single_mut() else {
+        return;
+    };
+    let Some(step) = queue.pointer.pop_front() else {
+        return;
+    };
+    let (button, state) = match step {
+        PointerStep::Move(position) => {
+            window.set_cursor_position(Some(position));
+            return;
+        }
+        PointerStep::Press(button) => (button, ButtonState::Pressed),
+        PointerStep::Release(button) => (button, ButtonState::Released),
+    };
+    buttons.send(MouseButtonInput {
+        button,
+        state,
+        window: window_entity,
+    });
+}
+
+fn apply_clicks(mut queue: ResMut<AutomationQueue>, mut interactions: Query<&mut Interaction>) {
+    let queue = &mut *queue;
+    for entity in queue.releases.drain(..) {
+        if let Ok(mut interaction) = interactions.get_mut(entity) {
+            interaction.set_if_neq(Interaction::None);
+        }
+    }
+    for entity in queue.clicks.drain(..) {
+        if let Ok(mut interaction) = interactions.get_mut(entity) {
+            *interaction = Interaction::Pressed;
+            queue.releases.push(entity);
+        }
+    }
+}
+
+#[derive(Resource)]
+pub struct ScriptRun {
+    steps: Vec<Step>,
+    current: usize,
+    frames: u32,
+    finished: bool,
+    failure: Option<AutomationError>,
+}
+
+impl ScriptRun {
+    fn new(steps: Vec<Step>) -> Self {
+        Self {
+            steps,
+            current: 0,
+            frames: 0,
+            finished: false,
+            failure: None,
+        }
+    }
+}
+
+fn run_script(world: &mut World) {
+    let (step, frames) = {
+        let script = world.resource::<ScriptRun>();
+        if script.finished {
+            return;
+        }
+        (script.steps.get(script.current).cloned(), script.frames)
+    };
+    // pointer input has to finish before the next step looks at its effects, and pointer steps
+    // aim at node centers, which are only laid out once the first frame is over
+    if world.resource::<AutomationQueue>().is_dragging() || world.resource::<FrameCount>().0 == 0 {
+        return;
+    }
+
+    let Some(step) = step else {
+        world.resource_mut::<ScriptRun>().finished = true;
+        println!("script passed");
+        if SnapshotMode::from_args().is_none() {
+            world.send_event(AppExit::Success);
+        }
+        return;
+    };
+
+    // overlays stay hidden until they're placed, and clicking where they were spawned would miss
+    let target = match &step {
+        Step::Click(selector)
+        | Step::PointerClick(selector)
+        | Step::RightClick(selector)
+        | Step::Hover(selector)
+        | Step::Drag(selector, _) => Some(selector),
+        _ => None,
+    };
+    if let Some(selector) = target
+        && let [entity] = find_all(world, selector).as_slice()
+        && !is_shown(world, *entity)
+        && frames < DEFAULT_WAIT_FRAMES
+    {
+        world.resource_mut::<ScriptRun>().frames += 1;
+        return;
+    }
+
+    let result = match &step {
+        Step::Click(selector) => find(world, selector).map(|entity| click(world, entity)),
+        Step::PointerClick(selector) => find(world, selector).map(|entity| {
+            pointer_click(world, entity);
+        }),
+        Step::RightClick(selector) => find(world, selector).map(|entity| {
+            right_click(world, entity);
+        }),
+        Step::Hover(selector) => find(world, selector).map(|entity| {
+            hover(world, entity);
+        }),
+        Step::Drag(selector, by) => find(world, selector).map(|entity| {
+            drag(world, entity, *by);
+        }),
+        Step::PressKey(key_code) => {
+            press_key(world, *key_code);
+            Ok(())
+        }
+        Step::Focus(selector) => find(world, selector).map(|entity| focus(world, entity)),
+        Step::TypeText(text) => {
+            type_text(world, text);
+            Ok(())
+        }
+        Step::Wait(n) if frames < *n => {
+            world.resource_mut::<ScriptRun>().frames += 1;
+            return;
+        }
+        Step::Wait(_) => Ok(()),
+        Step::WaitUntil(condition) if condition.holds(world) => Ok(()),
+        Step::WaitUntil(_) if frames < DEFAULT_WAIT_FRAMES => {
+            world.resource_mut::<ScriptRun>().frames += 1;
+            return;
+        }
+        Step::WaitUntil(condition) => Err(AutomationError::Timeout {
+            condition: format!("{condition:?}"),
+            frames,
+            hierarchy: dump_world(world),
+        }),
+    };
+
+    match result {
+        Ok(()) => {
+            let mut script = world.resource_mut::<ScriptRun>();
+            script.current += 1;
+            script.frames = 0;
+        }
+        Err(err) => {
+            println!("script failed at step {step:?}: {err}");
+            let mut script = world.resource_mut::<ScriptRun>();
+            script.finished = true;
+            script.failure = Some(err);
+            world.send_event(AppExit::error());
+        }
+    }
+}
@@ -0,0 +1,35 @@
+//! This example groups a long settings form into collapsible sections. The left accordion keeps a
+//! single section open and remembers which one in `accordion_state.ron`, so it opens the same way
+//! on the next run; "Rebuild" respawns the form to show the state coming back. The right accordion
+//! lets several sections be open at once. Click a header, or Tab to it and press Space or Enter, to
+//! open or close it. The open sections are shown at the top.
+//!
+//! `cargo run --bin accordion -- --script scripts/accordion.ron --headless` checks the grouping;
+//! scripted runs start from a fresh state instead of the file.
+//!
+//! The demo itself lives in [`bevy_container::demos::accordion`], so `cargo test` runs the script
+//! too.
+use bevy::prelude::*;
+use bevy_container::automation::{AutomationPlugin, default_plugins};
+use bevy_container::demos::accordion::AccordionDemoPlugin;
+use bevy_container::widgets::collapsible::CollapsibleStore;
+
+const STATE_PATH: &str = "accordion_state.ron";
+
+fn main() -> AppExit {
+    let scripted = std::env::args().any(|arg| arg == "--script");
+    let store = if scripted {
+        CollapsibleStore::default()
+    } else {
+        CollapsibleStore::load(STATE_PATH).unwrap_or_else(|err| {
+            println!("cannot load {err}, the open sections won't be remembered");
+            CollapsibleStore::default()
+        })
+    };
+    App::new()
+        .add_plugins(default_plugins())
+        .add_plugins(AutomationPlugin)
+        .add_plugins(AccordionDemoPlugin)
+        .insert_resource(store)
+        .run()
+}
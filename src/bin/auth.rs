@@ -0,0 +1,43 @@
+//! This example is a login flow on the auth module. Create an account on the registration screen;
+//! its password is hashed with argon2 and kept in `credentials.ron`. Logging in checks the
+//! password in the background while the button shows a spinner. After a failed login the next
+//! try has to wait a moment, longer after each failure, and three failures in a row lock the
+//! username out for 30 seconds. The dashboard and account screens only exist while someone is
+//! logged in: F2 opens the account screen, or says to log in first.
+//!
+//! `cargo run --bin auth -- --script scripts/auth.ron --headless` checks the flow; scripted runs
+//! keep their credentials in a file of their own, emptied when they start.
+//!
+//! The demo itself lives in [`bevy_container::demos::auth`], so `cargo test` runs the script
+//! too.
+use bevy::prelude::*;
+use bevy_container::auth::CredentialStore;
+use bevy_container::automation::{AutomationPlugin, default_plugins};
+use bevy_container::demos::auth::AuthDemoPlugin;
+
+const CREDENTIALS_PATH: &str = "credentials.ron";
+
+fn main() -> AppExit {
+    let scripted = std::env::args().any(|arg| arg == "--script");
+    let path = if scripted {
+        let path = std::env::temp_dir().join("auth_script_credentials.ron");
+        let _ = std::fs::remove_file(&path);
+        path
+    } else {
+        CREDENTIALS_PATH.into()
+    };
+    let store = match CredentialStore::load(path) {
+        Ok(store) => store,
+        Err(err) => {
+            // starting empty would overwrite the accounts on the first registration
+            println!("cannot load {err}");
+            return AppExit::error();
+        }
+    };
+    App::new()
+        .add_plugins(default_plugins())
+        .add_plugins(AutomationPlugin)
+        .add_plugins(AuthDemoPlugin)
+        .insert_resource(store)
+        .run()
+}
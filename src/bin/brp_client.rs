@@ -0,0 +1,60 @@
+//! This example is a small command line client for the BRP methods served by examples started with
+//! `--remote`, e.g.
+//!
+//! ```sh
+//! cargo run --bin brp_client -- ui/list_widgets
+//! cargo run --bin brp_client -- ui/click '{"selector": {"Name": "increment_button"}}'
+//! cargo run --bin brp_client -- counter/get
+//! cargo run --bin brp_client -- --port 15703 counter/get
+//! ```
+//!
+//! `--port` talks to an example started with the same `--remote-port`. The result is printed as
+//! JSON; BRP errors are printed to stderr and exit with status 1.
+use bevy::remote::http::DEFAULT_PORT;
+use bevy_container::remote::call_on;
+use serde_json::Value;
+use std::process::ExitCode;
+
+fn main() -> ExitCode {
+    let mut args: Vec<String> = std::env::args().skip(1).collect();
+    let mut port = DEFAULT_PORT;
+    if args.first().is_some_and(|arg| arg == "--port") {
+        match args.get(1).map(|port| port.parse()) {
+            Some(Ok(given)) => port = given,
+            _ => {
+                eprintln!("--port needs a port number");
+                return ExitCode::FAILURE;
+            }
+        }
+        args.drain(..2);
+    }
+    let Some(method) = args.first() else {
+        eprintln!("usage: brp_client [--port <port>] <method> [params as json]");
+        return ExitCode::FAILURE;
+    };
+    let params = match args
+        .get(1)
+        .map(|params| serde_json::from_str::<Value>(params))
+    {
+        Some(Ok(params)) => Some(params),
+        Some(Err(err)) => {
+            eprintln!("params are not valid json: {err}");
+            return ExitCode::FAILURE;
+        }
+        None => None,
+    };
+
+    match call_on(port, method, params) {
+        Ok(result) => {
+            println!(
+                "{}",
+                serde_json::to_string_pretty(&result).unwrap_or_default()
+            );
+            ExitCode::SUCCESS
+        }
+        Err(err) => {
+            eprintln!("{err}");
+            ExitCode::FAILURE
+        }
+    }
+}
@@ -3,13 +3,16 @@
 use bevy::app::App;
 use bevy::color::palettes::css::RED;
 use bevy::prelude::*;
+use bevy_container::automation::default_plugins;
+use bevy_container::snapshot::SnapshotPlugin;
 
-fn main() {
+fn main() -> AppExit {
     App::new()
-        .add_plugins(DefaultPlugins)
+        .add_plugins(default_plugins())
+        .add_plugins(SnapshotPlugin::new("button_example"))
         .add_systems(Startup, setup)
         .add_systems(Update, button_system)
-        .run();
+        .run()
 }
 
 // const NORMAL_BUTTON: Color = Color::srgb(255., 0., 0.);
@@ -30,6 +33,7 @@ pub fn setup(mut commands: Commands) {
                 align_items: AlignItems::Center,
                 ..default()
             },
+            Name::new("root"),
         ))
         .with_children(|parent| {
             // CHILD - button
@@ -45,6 +49,7 @@ pub fn setup(mut commands: Commands) {
                     },
                     BackgroundColor(NORMAL_BUTTON),
                     BorderColor(Color::BLACK),
+                    Name::new("button"),
                 ))
                 .with_children(|parent| {
                     parent.spawn((
@@ -59,6 +64,7 @@ pub fn setup(mut commands: Commands) {
         });
 }
 
+#[allow(clippy::type_complexity)]
 pub fn button_system(
     mut interaction_query: Query<
         (
@@ -1,11 +1,22 @@
 // this example uses bevy_cosmic_edit crate for text input support, it has two inputs with submit button
 // TODO: solve issue: CosmicEditBuffer is not being updated when text is changed for password input
-use bevy::{color::palettes::css::BLUE, prelude::*};
+use bevy::{
+    color::palettes::css::BLUE,
+    prelude::*,
+    remote::{BrpError, BrpResult, error_codes},
+};
 use bevy_cosmic_edit::{
     cosmic_text::{Attrs, AttrsOwned, Family, Metrics},
     placeholder::Placeholder,
     prelude::*,
 };
+use bevy_container::record::InputRecordPlugin;
+use bevy_container::remote::{RemoteUiPlugin, TargetParams, parse_params, resolve};
+use bevy_container::snapshot::SnapshotPlugin;
+use bevy_container::task::{Loading, SpawnTaskExt, TaskAppExt, TaskFinished, TaskPlugin, delay};
+use serde::Deserialize;
+use serde_json::{Value, json};
+use std::time::Duration;
 
 #[derive(Component)]
 struct NameInput;
@@ -22,7 +33,7 @@ struct PasswordDisplay;
 #[derive(Component)]
 struct SubmitButton;
 
-fn main() {
+fn main() -> AppExit {
     let font_bytes: &[u8] = include_bytes!("../../assets/fonts/FiraMono-Medium.ttf");
     let font_config = CosmicFontConfig {
         fonts_dir_path: None,
@@ -32,10 +43,19 @@ fn main() {
 
     App::new()
         .add_plugins(DefaultPlugins)
+        .add_plugins(SnapshotPlugin::new("cosmic_input_text"))
+        .add_plugins(InputRecordPlugin)
+        .add_plugins(
+            RemoteUiPlugin::new()
+                .with_method("cosmic/get_text", get_cosmic_text)
+                .with_method("cosmic/set_text", set_cosmic_text),
+        )
         .add_plugins(CosmicEditPlugin { font_config })
+        .add_plugins(TaskPlugin)
+        .add_task_result::<String>()
         .add_systems(Startup, setup)
-        .add_systems(Update, submit_inputs)
-        .run();
+        .add_systems(Update, (submit_inputs, show_submissions))
+        .run()
 }
 
 fn setup(mut commands: Commands, mut font_system: ResMut<CosmicFontSystem>) {
@@ -86,6 +106,7 @@ fn setup(mut commands: Commands, mut font_system: ResMut<CosmicFontSystem>) {
                     ..default()
                 },
                 NameInput,
+                Name::new("name_input"),
             ))
             .observe(focus_on_click);
     });
@@ -110,6 +131,7 @@ fn setup(mut commands: Commands, mut font_system: ResMut<CosmicFontSystem>) {
                     ..default()
                 },
                 PasswordInput,
+                Name::new("password_input"),
             ))
             .observe(focus_on_click);
     });
@@ -130,6 +152,7 @@ fn setup(mut commands: Commands, mut font_system: ResMut<CosmicFontSystem>) {
                 button_node.clone(),
                 BackgroundColor(BLUE.into()),
                 SubmitButton,
+                Name::new("submit_button"),
             ))
             .with_child((
                 Text::new("Submit"),
@@ -144,44 +167,98 @@ fn setup(mut commands: Commands, mut font_system: ResMut<CosmicFontSystem>) {
 }
 
 fn submit_inputs(
+    mut commands: Commands,
     mut interaction_query: Query<
-        (&Interaction, &mut BackgroundColor),
-        (Changed<Interaction>, With<SubmitButton>),
+        (Entity, &Interaction, &mut BackgroundColor),
+        (Changed<Interaction>, With<SubmitButton>, Without<Loading>),
     >,
     name_query: Query<&CosmicEditBuffer, With<NameInput>>,
     password_query: Query<&CosmicEditBuffer, With<PasswordInput>>,
 ) {
 
-    for (interaction, mut background_color) in interaction_query.iter_mut() {
+    for (button, interaction, mut background_color) in interaction_query.iter_mut() {
         if *interaction == Interaction::Pressed {
 
             // *background_color = bevy::color::palettes::css::GREY.into();
 
-            // Handle name input
-            if let Ok(name_buffer) = name_query.get_single() {
-                let name = name_buffer.get_text_spans(AttrsOwned::new(Attrs::new()))
-                    .iter()
-                    .flat_map(|line| line.iter().map(|(text, _)| text.as_str()))
-                    .collect::<String>();
-                if name.starts_with("Enter name") {
-                    println!("Name: No input provided");
-                } else {
-                    println!("Name: {}", name);
-                }
-            }
-
-            // Handle password input
-            if let Ok(password_buffer) = password_query.get_single() {
-                let password = password_buffer.get_text_spans(AttrsOwned::new(Attrs::new()))
-                    .iter()
-                    .flat_map(|line| line.iter().map(|(text, _)| text.as_str()))
-                    .collect::<String>();
-                if password.starts_with("Enter password") {
-                    println!("Password: No input provided");
-                } else {
-                    println!("Password: {}", password);
-                }
-            }
+            // the buffers are read here, the submission itself runs in the background
+            let name = name_query.get_single().map(buffer_text).unwrap_or_default();
+            let password = password_query
+                .get_single()
+                .map(buffer_text)
+                .unwrap_or_default();
+            commands.spawn_task(button, submit(name, password));
         }
     }
 }
+
+/// Stands in for slow work done with the inputs, e.g. sending them to a server.
+async fn submit(name: String, password: String) -> Result<String, String> {
+    delay(Duration::from_millis(600)).await;
+    let mut lines = Vec::new();
+    // Handle name input
+    if name.is_empty() || name.starts_with("Enter name") {
+        lines.push("Name: No input provided".to_string());
+    } else {
+        lines.push(format!("Name: {}", name));
+    }
+    // Handle password input
+    if password.is_empty() || password.starts_with("Enter password") {
+        lines.push("Password: No input provided".to_string());
+    } else {
+        lines.push(format!("Password: {}", password));
+    }
+    Ok(lines.join("\n"))
+}
+
+fn show_submissions(mut finished: EventReader<TaskFinished<String>>) {
+    for event in finished.read() {
+        if let Ok(summary) = &event.result {
+            println!("{summary}");
+        }
+    }
+}
+
+fn buffer_text(buffer: &CosmicEditBuffer) -> String {
+    buffer
+        .get_text_spans(AttrsOwned::new(Attrs::new()))
+        .iter()
+        .flat_map(|line| line.iter().map(|(text, _)| text.as_str()))
+        .collect::<String>()
+}
+
+#[derive(Deserialize)]
+struct SetCosmicTextParams {
+    #[serde(flatten)]
+    target: TargetParams,
+    text: String,
+}
+
+fn no_buffer(entity: Entity) -> BrpError {
+    BrpError {
+        code: error_codes::COMPONENT_NOT_PRESENT,
+        message: format!("entity {entity} has no cosmic buffer"),
+        data: None,
+    }
+}
+
+fn get_cosmic_text(In(params): In<Option<Value>>, world: &mut World) -> BrpResult {
+    let target: TargetParams = parse_params(params)?;
+    let entity = resolve(world, &target)?;
+    let buffer = world
+        .get::<CosmicEditBuffer>(entity)
+        .ok_or_else(|| no_buffer(entity))?;
+    Ok(json!(buffer_text(buffer)))
+}
+
+fn set_cosmic_text(In(params): In<Option<Value>>, world: &mut World) -> BrpResult {
+    let params: SetCosmicTextParams = parse_params(params)?;
+    let entity = resolve(world, &params.target)?;
+    world.resource_scope(|world, mut font_system: Mut<CosmicFontSystem>| {
+        let mut buffer = world
+            .get_mut::<CosmicEditBuffer>(entity)
+            .ok_or_else(|| no_buffer(entity))?;
+        buffer.set_text(&mut font_system, &params.text, Attrs::new());
+        Ok(Value::Null)
+    })
+}
@@ -1,187 +1,29 @@
 //! This example illustrates how to create increment & decrement button that changes counter value &
 //! interaction state.
-
-use bevy::{color::palettes::basic::*, prelude::*, winit::WinitSettings};
-fn main() {
+//!
+//! The demo itself lives in [`bevy_container::demos::counter`], so `cargo test` runs it too.
+use bevy::remote::BrpResult;
+use bevy::{prelude::*, winit::WinitSettings};
+use bevy_container::automation::{AutomationPlugin, default_plugins};
+use bevy_container::demos::counter::{Counter, CounterDemoPlugin};
+use bevy_container::record::InputRecordPlugin;
+use bevy_container::remote::RemoteUiPlugin;
+use bevy_container::snapshot::SnapshotPlugin;
+use serde_json::{Value, json};
+
+fn main() -> AppExit {
     App::new()
-        .add_plugins(DefaultPlugins)
+        .add_plugins(default_plugins())
+        .add_plugins(SnapshotPlugin::new("counter"))
+        .add_plugins(InputRecordPlugin)
+        .add_plugins(AutomationPlugin)
+        .add_plugins(RemoteUiPlugin::new().with_method("counter/get", get_counter))
         // Only run the app when there is user input. This will significantly reduce CPU/GPU use.
         .insert_resource(WinitSettings::desktop_app())
-        .init_resource::<Counter>()
-        .insert_resource(Counter(0))
-        .add_systems(Startup, setup)
-        .add_systems(Update, button_system)
-        .run();
-}
-
-#[derive(Resource, Component)]
-struct Counter(i32);
-
-impl Default for Counter {
-    fn default() -> Self {
-        Self(0)
-    }
-}
-
-#[derive(Clone, Copy, Component)]
-struct CounterText;
-
-#[derive(Component)]
-enum ButtonAction {
-    Increment,
-    Decrement,
-}
-
-const NORMAL_BUTTON: Color = Color::srgb(0.15, 0.15, 0.15);
-fn setup(mut commands: Commands, counter: Res<Counter>, assets: Res<AssetServer>) {
-    println!("counter in setup: {}", counter.0);
-    // ui camera
-    commands.spawn(Camera2d);
-    // Text with one section
-
-    create_counter_text(&mut commands, &counter, &assets);
-
-    commands
-        .spawn((
-            Button,
-            Node {
-                width: Val::Px(100.0),
-                height: Val::Px(65.0),
-                border: UiRect::all(Val::Px(5.0)),
-                // horizontally center child text
-                justify_content: JustifyContent::Center,
-                // vertically center child text
-                align_items: AlignItems::Center,
-                position_type: PositionType::Absolute,
-                top: Val::Px(150.0),
-                left: Val::Px(25.0),
-                ..default()
-            },
-            BorderColor(Color::BLACK),
-            BorderRadius::MAX,
-            BackgroundColor(NORMAL_BUTTON),
-            ButtonAction::Decrement, 
-        ))
-        .with_child((
-            Text::new("-"),
-            TextFont {
-                font: assets.load("fonts/FiraSans-Bold.ttf"),
-                font_size: 33.0,
-                ..default()
-            },
-            TextColor(Color::srgb(0.9, 0.9, 0.9)),
-        ));
-    commands
-        .spawn((
-            Button,
-            Node {
-                width: Val::Px(100.0),
-                height: Val::Px(65.0),
-                border: UiRect::all(Val::Px(5.0)),
-                // horizontally center child text
-                justify_content: JustifyContent::Center,
-                // vertically center child text
-                align_items: AlignItems::Center,
-                position_type: PositionType::Absolute,
-                top: Val::Px(150.0),
-                left: Val::Px(120.0),
-                ..default()
-            },
-            BorderColor(Color::BLACK),
-            BorderRadius::MAX,
-            BackgroundColor(NORMAL_BUTTON),
-            ButtonAction::Increment, 
-        ))
-        .with_child((
-            Text::new("+"),
-            TextFont {
-                font: assets.load("fonts/FiraSans-Bold.ttf"),
-                font_size: 33.0,
-                ..default()
-            },
-            TextColor(Color::srgb(0.9, 0.9, 0.9)),
-        ));
-}
-
-fn create_counter_text(commands: &mut Commands, counter_value: &Counter, assets: &AssetServer) {
-    commands
-        .spawn((
-            Button,
-            Node {
-                width: Val::Px(100.0),
-                height: Val::Px(65.0),
-                border: UiRect::all(Val::Px(5.0)),
-                // horizontally center child text
-                justify_content: JustifyContent::Center,
-                // vertically center child text
-                align_items: AlignItems::Center,
-                position_type: PositionType::Absolute,
-                top: Val::Px(45.0),
-                left: Val::Px(70.0),
-                ..default()
-            },
-            BorderColor(Color::BLACK),
-            BorderRadius::MAX,
-            BackgroundColor(NORMAL_BUTTON),
-        ))
-        .with_child((
-            Text::new(&counter_value.0.to_string()),
-            TextFont {
-                font: assets.load("fonts/FiraSans-Bold.ttf"),
-                font_size: 33.0,
-                ..default()
-            },
-            TextColor(Color::srgb(0.9, 0.9, 0.9)),
-            CounterText, // Mark the text component
-        ));
+        .add_plugins(CounterDemoPlugin)
+        .run()
 }
-fn button_system(
-    mut queries: ParamSet<(
-        Query<
-            (
-                &Interaction,
-                &mut BackgroundColor,
-                &mut BorderColor,
-                &Children,
-                Option<&ButtonAction>, // Added ButtonAction component
-            ),
-            (Changed<Interaction>, With<Button>),
-        >,
-        Query<&mut Text, With<CounterText>>,
-        Query<&mut Text>,
-    )>,
-    mut counter: ResMut<Counter>,
-) {
-    
-    for (interaction, _, mut border_color, _, actions) in queries.p0().iter_mut() {
-        // println!("button text: {}", text.0);
-        match *interaction {
-            Interaction::Pressed => {
-                println!("pressed");
 
-                match actions {
-                    Some(ButtonAction::Increment) => {
-                        counter.0 += 1;
-                    }
-                    Some(ButtonAction::Decrement) => {
-                        counter.0 -= 1;
-                    }
-                    _ => {
-                        println!("no action");
-                    }
-                }
-                border_color.0 = RED.into();
-            }
-            Interaction::Hovered => {
-                border_color.0 = Color::WHITE;
-            }
-            Interaction::None => {
-                border_color.0 = Color::BLACK;
-            }
-        }
-    }
-    for entity in queries.p1().iter_mut() {
-        let mut text = entity;
-        text.0 = counter.0.to_string();
-    }
+fn get_counter(In(_): In<Option<Value>>, world: &mut World) -> BrpResult {
+    Ok(json!(world.resource::<Counter>().0))
 }
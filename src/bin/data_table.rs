@@ -0,0 +1,194 @@
+//! This example shows the data table widget with a few hundred generated people. Click a header to
+//! sort, drag a header's right edge to resize the column, select rows with click, shift-click and
+//! ctrl-click, and double-click a name, age, score or "active" cell to edit it. "Export CSV" writes
+//! the table as currently sorted to `data_table_export.csv`.
+use bevy::prelude::*;
+use bevy_container::theme::Theme;
+use bevy_container::widgets::data_table::{
+    CellEdited, CellValue, Column, ColumnKind, DataTable, DataTablePlugin, spawn_data_table,
+};
+use bevy_container::widgets::virtual_list::{SelectionChanged, VirtualList};
+
+const CSV_EXPORT_PATH: &str = "data_table_export.csv";
+const PEOPLE: usize = 500;
+const FIRST_NAMES: [&str; 8] = [
+    "Ada", "Alan", "Grace", "Edsger", "Barbara", "Donald", "Frances", "Ken",
+];
+const CITIES: [&str; 5] = ["Berlin", "Lisbon", "Oslo", "Toronto", "Kyoto"];
+
+fn main() -> AppExit {
+    App::new()
+        .add_plugins(DefaultPlugins)
+        .add_plugins(DataTablePlugin)
+        .add_systems(Startup, setup)
+        .add_systems(Update, (export_button, print_changes))
+        .run()
+}
+
+#[derive(Component)]
+struct People;
+
+#[derive(Component)]
+struct ExportButton;
+
+#[derive(Component)]
+struct StatusText;
+
+fn people() -> Vec<Vec<CellValue>> {
+    (0..PEOPLE)
+        .map(|index| {
+            let name = format!("{} {}", FIRST_NAMES[index % FIRST_NAMES.len()], index);
+            vec![
+                name.into(),
+                (20 + (index * 7 % 50) as i64).into(),
+                ((index * 37 % 1000) as f64 / 10.).into(),
+                (index % 3 != 0).into(),
+                CITIES[index * 3 % CITIES.len()].into(),
+            ]
+        })
+        .collect()
+}
+
+fn setup(mut commands: Commands, theme: Res<Theme>) {
+    commands.spawn(Camera2d);
+    let table = DataTable::new(
+        vec![
+            Column::new("Name", ColumnKind::Text)
+                .with_width(180.)
+                .editable(),
+            Column::new("Age", ColumnKind::Int)
+                .with_width(70.)
+                .editable(),
+            Column::new("Score", ColumnKind::Float)
+                .with_width(90.)
+                .editable(),
+            Column::new("Active", ColumnKind::Bool)
+                .with_width(80.)
+                .editable(),
+            Column::new("City", ColumnKind::Text),
+        ],
+        people(),
+    );
+    commands
+        .spawn((
+            Node {
+                width: Val::Percent(100.),
+                height: Val::Percent(100.),
+                flex_direction: FlexDirection::Column,
+                padding: UiRect::all(Val::Px(20.)),
+                row_gap: Val::Px(10.),
+                ..default()
+            },
+            BackgroundColor(theme.background),
+            Name::new("root"),
+        ))
+        .with_children(|parent| {
+            parent
+                .spawn(Node {
+                    column_gap: Val::Px(10.),
+                    align_items: AlignItems::Center,
+                    ..default()
+                })
+                .with_children(|toolbar| {
+                    toolbar
+                        .spawn((
+                            Button,
+                            Node {
+                                padding: UiRect::axes(Val::Px(12.), Val::Px(6.)),
+                                ..default()
+                            },
+                            BackgroundColor(theme.surface),
+                            ExportButton,
+                            Name::new("export_button"),
+                        ))
+                        .with_child((
+                            Text::new("Export CSV"),
+                            theme.text_font(),
+                            TextColor(theme.text),
+                        ));
+                    toolbar.spawn((
+                        Text::new("nothing selected"),
+                        theme.text_font(),
+                        TextColor(theme.text_muted),
+                        StatusText,
+                        Name::new("status_text"),
+                    ));
+                });
+
+            let table = spawn_data_table(
+                parent,
+                table,
+                (
+                    Node {
+                        width: Val::Percent(100.),
+                        flex_grow: 1.,
+                        min_height: Val::Px(0.),
+                        ..default()
+                    },
+                    Name::new("people_table"),
+                ),
+            );
+            parent.enqueue_command(move |world: &mut World| {
+                world.entity_mut(table).insert(People);
+            });
+        });
+}
+
+#[allow(clippy::type_complexity)]
+fn export_button(
+    theme: Res<Theme>,
+    mut buttons: Query<
+        (&Interaction, &mut BackgroundColor),
+        (Changed<Interaction>, With<ExportButton>),
+    >,
+    tables: Query<&DataTable, With<People>>,
+) {
+    for (interaction, mut color) in &mut buttons {
+        color.0 = theme.surface_color(*interaction);
+        if *interaction != Interaction::Pressed {
+            continue;
+        }
+        let Ok(table) = tables.get_single() else {
+            continue;
+        };
+        match table.export_csv(CSV_EXPORT_PATH) {
+            Ok(()) => println!("exported {} rows to {CSV_EXPORT_PATH}", table.rows().len()),
+            Err(err) => println!("failed to export table to {CSV_EXPORT_PATH}: {err}"),
+        }
+    }
+}
+
+fn print_changes(
+    mut selections: EventReader<SelectionChanged>,
+    mut edits: EventReader<CellEdited>,
+    tables: Query<&DataTable, With<People>>,
+    lists: Query<&VirtualList>,
+    mut status: Query<&mut Text, With<StatusText>>,
+) {
+    let Ok(table) = tables.get_single() else {
+        return;
+    };
+    for edit in edits.read() {
+        println!(
+            "row {} {} is now {:?}",
+            edit.row,
+            table.columns()[edit.column].title,
+            edit.value
+        );
+    }
+    if selections.read().last().is_none() {
+        return;
+    }
+    let Ok(list) = lists.get(table.list()) else {
+        return;
+    };
+    let selected = table.selected_rows(list);
+    println!("selected rows {selected:?}");
+    if let Ok(mut text) = status.get_single_mut() {
+        text.0 = match selected.len() {
+            0 => "nothing selected".to_string(),
+            1 => format!("{} selected", table.rows()[selected[0]][0]),
+            count => format!("{count} rows selected"),
+        };
+    }
+}
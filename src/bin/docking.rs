@@ -0,0 +1,40 @@
+//! This example is an IDE-like dock: an inspector showing the dock layout, a counter, notes and a
+//! log console, in tab groups. Drag a tab over a group and drop it on the highlighted half to
+//! split the group, or on its center to add it as a tab; drop it anywhere else to float it, and
+//! drag the floating panel by its title bar to move it or dock it again. The counter keeps its
+//! count wherever it goes. The layout is saved to `dock_layout.ron` on every change and restored
+//! from it on the next run; "Reset layout" goes back to the default one.
+//!
+//! `cargo run --bin docking -- --script scripts/docking.ron --headless` checks the docking;
+//! scripted runs start from the default layout and don't save it.
+//!
+//! The demo itself lives in [`bevy_container::demos::docking`], so `cargo test` runs the script
+//! too.
+use bevy::prelude::*;
+use bevy_container::automation::{AutomationPlugin, default_plugins};
+use bevy_container::demos::docking::DockingDemoPlugin;
+use bevy_container::widgets::dock::DockLayoutStore;
+
+const LAYOUT_PATH: &str = "dock_layout.ron";
+
+fn main() -> AppExit {
+    let scripted = std::env::args().any(|arg| arg == "--script");
+    let store = if scripted {
+        DockLayoutStore::default()
+    } else {
+        match DockLayoutStore::load(LAYOUT_PATH) {
+            Ok(store) => store,
+            Err(err) => {
+                // the default layout would be saved over it on the first change
+                println!("cannot load {err}");
+                return AppExit::error();
+            }
+        }
+    };
+    App::new()
+        .add_plugins(default_plugins())
+        .add_plugins(AutomationPlugin)
+        .add_plugins(DockingDemoPlugin)
+        .insert_resource(store)
+        .run()
+}
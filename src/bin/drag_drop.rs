@@ -0,0 +1,22 @@
+//! This example is a small kanban board built on drag and drop. The cards are items of reorderable
+//! lists: drag one within its column to reorder it, into another column to move it, or onto the
+//! trash to delete it. The color swatches carry a different payload, with a custom preview; only
+//! the canvas accepts them and takes their color. Targets accepting what's dragged are outlined.
+//! Releasing anywhere else, or pressing Escape, cancels the drag. Every drag event is printed and
+//! the last one is shown under the canvas.
+//!
+//! `cargo run --bin drag_drop -- --script scripts/drag_drop.ron --headless` checks the drags.
+//!
+//! The demo itself lives in [`bevy_container::demos::drag_drop`], so `cargo test` runs the script
+//! too.
+use bevy::prelude::*;
+use bevy_container::automation::{AutomationPlugin, default_plugins};
+use bevy_container::demos::drag_drop::DragDropDemoPlugin;
+
+fn main() -> AppExit {
+    App::new()
+        .add_plugins(default_plugins())
+        .add_plugins(AutomationPlugin)
+        .add_plugins(DragDropDemoPlugin)
+        .run()
+}
@@ -0,0 +1,20 @@
+//! This example shows the dropdown and combo box widgets. "Country" has enough options to scroll
+//! and jumps to the first match as letters are typed; "Shipping" sits at the bottom of the window,
+//! so its popup opens upwards; "Fruit" suggests the fruits containing what's typed. Every pick is
+//! printed and shown at the top.
+//!
+//! `cargo run --bin dropdowns -- --script scripts/dropdown_select.ron --headless` checks them.
+//!
+//! The demo itself lives in [`bevy_container::demos::dropdowns`], so `cargo test` runs the script
+//! too.
+use bevy::prelude::*;
+use bevy_container::automation::{AutomationPlugin, default_plugins};
+use bevy_container::demos::dropdowns::DropdownsDemoPlugin;
+
+fn main() -> AppExit {
+    App::new()
+        .add_plugins(default_plugins())
+        .add_plugins(AutomationPlugin)
+        .add_plugins(DropdownsDemoPlugin)
+        .run()
+}
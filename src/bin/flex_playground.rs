@@ -0,0 +1,926 @@
+//! This example is an interactive flexbox/grid playground: the control panel on the left edits the
+//! container's and the selected child's layout properties live, children can be added/removed, and
+//! the resulting configuration can be exported as Rust code or as a RON layout file.
+//!
+use bevy::app::App;
+use bevy::color::palettes::css::{GREY, WHITE};
+use bevy::color::palettes::tailwind::{
+    AMBER_400, CYAN_400, EMERALD_400, FUCHSIA_400, LIME_400, ORANGE_400, ROSE_400, SKY_400,
+};
+use bevy::prelude::*;
+use bevy_container::automation::default_plugins;
+use bevy_container::snapshot::SnapshotPlugin;
+use serde::{Deserialize, Serialize};
+use std::fmt::Write as _;
+
+const NORMAL_BUTTON: Color = Color::srgb(0.15, 0.15, 0.15);
+const HOVERED_BUTTON: Color = Color::srgb(0.25, 0.25, 0.25);
+const PRESSED_BUTTON: Color = Color::srgb(0.35, 0.75, 0.35);
+const PANEL_COLOR: Color = Color::srgb(0.1, 0.1, 0.1);
+const TEXT_COLOR: Color = Color::srgb(0.9, 0.9, 0.9);
+
+const RUST_EXPORT_PATH: &str = "flex_playground_export.rs";
+const RON_EXPORT_PATH: &str = "flex_playground_layout.ron";
+
+const CHILD_COLORS: [Srgba; 8] = [
+    SKY_400,
+    ROSE_400,
+    LIME_400,
+    AMBER_400,
+    FUCHSIA_400,
+    EMERALD_400,
+    ORANGE_400,
+    CYAN_400,
+];
+
+const FLEX_DIRECTIONS: [FlexDirection; 4] = [
+    FlexDirection::Row,
+    FlexDirection::Column,
+    FlexDirection::RowReverse,
+    FlexDirection::ColumnReverse,
+];
+
+const JUSTIFY_CONTENTS: [JustifyContent; 7] = [
+    JustifyContent::FlexStart,
+    JustifyContent::FlexEnd,
+    JustifyContent::Center,
+    JustifyContent::SpaceBetween,
+    JustifyContent::SpaceAround,
+    JustifyContent::SpaceEvenly,
+    JustifyContent::Stretch,
+];
+
+const ALIGN_ITEMS: [AlignItems; 6] = [
+    AlignItems::Default,
+    AlignItems::FlexStart,
+    AlignItems::FlexEnd,
+    AlignItems::Center,
+    AlignItems::Baseline,
+    AlignItems::Stretch,
+];
+
+const ALIGN_CONTENTS: [AlignContent; 7] = [
+    AlignContent::Default,
+    AlignContent::FlexStart,
+    AlignContent::FlexEnd,
+    AlignContent::Center,
+    AlignContent::Stretch,
+    AlignContent::SpaceBetween,
+    AlignContent::SpaceAround,
+];
+
+const ALIGN_SELVES: [AlignSelf; 6] = [
+    AlignSelf::Auto,
+    AlignSelf::FlexStart,
+    AlignSelf::FlexEnd,
+    AlignSelf::Center,
+    AlignSelf::Baseline,
+    AlignSelf::Stretch,
+];
+
+/// Every property the control panel can edit.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+enum Property {
+    Grid,
+    FlexDirection,
+    FlexWrap,
+    JustifyContent,
+    AlignItems,
+    AlignContent,
+    RowGap,
+    ColumnGap,
+    Padding,
+    GridColumns,
+    GridRows,
+    ChildWidth,
+    ChildHeight,
+    ChildGrow,
+    ChildShrink,
+    ChildAlignSelf,
+    ChildMargin,
+}
+
+impl Property {
+    fn label(self) -> &'static str {
+        match self {
+            Property::Grid => "grid",
+            Property::FlexDirection => "flex_direction",
+            Property::FlexWrap => "flex_wrap",
+            Property::JustifyContent => "justify_content",
+            Property::AlignItems => "align_items",
+            Property::AlignContent => "align_content",
+            Property::RowGap => "row_gap",
+            Property::ColumnGap => "column_gap",
+            Property::Padding => "padding",
+            Property::GridColumns => "grid columns",
+            Property::GridRows => "grid rows",
+            Property::ChildWidth => "width",
+            Property::ChildHeight => "height",
+            Property::ChildGrow => "flex_grow",
+            Property::ChildShrink => "flex_shrink",
+            Property::ChildAlignSelf => "align_self",
+            Property::ChildMargin => "margin",
+        }
+    }
+
+    /// Option labels for the dropdown properties.
+    fn options(self) -> Vec<String> {
+        match self {
+            Property::FlexDirection => FLEX_DIRECTIONS.iter().map(|v| format!("{v:?}")).collect(),
+            Property::JustifyContent => JUSTIFY_CONTENTS.iter().map(|v| format!("{v:?}")).collect(),
+            Property::AlignItems => ALIGN_ITEMS.iter().map(|v| format!("{v:?}")).collect(),
+            Property::AlignContent => ALIGN_CONTENTS.iter().map(|v| format!("{v:?}")).collect(),
+            Property::ChildAlignSelf => ALIGN_SELVES.iter().map(|v| format!("{v:?}")).collect(),
+            _ => Vec::new(),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Serialize, Deserialize)]
+struct ContainerProps {
+    grid: bool,
+    flex_direction: FlexDirection,
+    flex_wrap: bool,
+    justify_content: JustifyContent,
+    align_items: AlignItems,
+    align_content: AlignContent,
+    row_gap: f32,
+    column_gap: f32,
+    padding: f32,
+    grid_columns: u16,
+    grid_rows: u16,
+}
+
+#[derive(Clone, Copy, Serialize, Deserialize)]
+struct ChildProps {
+    width: f32,
+    height: f32,
+    flex_grow: f32,
+    flex_shrink: f32,
+    align_self: AlignSelf,
+    margin: f32,
+}
+
+impl Default for ChildProps {
+    fn default() -> Self {
+        Self {
+            width: 80.,
+            height: 80.,
+            flex_grow: 0.,
+            flex_shrink: 1.,
+            align_self: AlignSelf::Auto,
+            margin: 0.,
+        }
+    }
+}
+
+/// The layout being edited; the preview is rebuilt whenever it changes.
+#[derive(Resource, Serialize, Deserialize)]
+struct PlaygroundLayout {
+    container: ContainerProps,
+    children: Vec<ChildProps>,
+    /// Which child the panel edits, not part of the exported layout.
+    #[serde(skip)]
+    selected: usize,
+}
+
+impl Default for PlaygroundLayout {
+    fn default() -> Self {
+        Self {
+            container: ContainerProps {
+                grid: false,
+                flex_direction: FlexDirection::Row,
+                flex_wrap: false,
+                justify_content: JustifyContent::FlexStart,
+                align_items: AlignItems::Default,
+                align_content: AlignContent::Default,
+                row_gap: 0.,
+                column_gap: 0.,
+                padding: 10.,
+                grid_columns: 3,
+                grid_rows: 2,
+            },
+            children: vec![ChildProps::default(); 3],
+            selected: 0,
+        }
+    }
+}
+
+impl PlaygroundLayout {
+    fn selected_child(&mut self) -> Option<&mut ChildProps> {
+        self.children.get_mut(self.selected)
+    }
+
+    fn choice_index(&self, property: Property) -> usize {
+        let container = &self.container;
+        let child = self.children.get(self.selected);
+        match property {
+            Property::FlexDirection => index_of(&FLEX_DIRECTIONS, container.flex_direction),
+            Property::JustifyContent => index_of(&JUSTIFY_CONTENTS, container.justify_content),
+            Property::AlignItems => index_of(&ALIGN_ITEMS, container.align_items),
+            Property::AlignContent => index_of(&ALIGN_CONTENTS, container.align_content),
+            Property::ChildAlignSelf => child.map_or(0, |c| index_of(&ALIGN_SELVES, c.align_self)),
+            _ => 0,
+        }
+    }
+
+    fn set_choice(&mut self, property: Property, index: usize) {
+        match property {
+            Property::FlexDirection => self.container.flex_direction = FLEX_DIRECTIONS[index],
+            Property::JustifyContent => self.container.justify_content = JUSTIFY_CONTENTS[index],
+            Property::AlignItems => self.container.align_items = ALIGN_ITEMS[index],
+            Property::AlignContent => self.container.align_content = ALIGN_CONTENTS[index],
+            Property::ChildAlignSelf => {
+                if let Some(child) = self.selected_child() {
+                    child.align_self = ALIGN_SELVES[index];
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn toggle(&mut self, property: Property) {
+        match property {
+            Property::Grid => self.container.grid = !self.container.grid,
+            Property::FlexWrap => self.container.flex_wrap = !self.container.flex_wrap,
+            _ => {}
+        }
+    }
+
+    fn step(&mut self, property: Property, delta: f32) {
+        let container = &mut self.container;
+        match property {
+            Property::RowGap => container.row_gap = (container.row_gap + delta).max(0.),
+            Property::ColumnGap => container.column_gap = (container.column_gap + delta).max(0.),
+            Property::Padding => container.padding = (container.padding + delta).max(0.),
+            Property::GridColumns => {
+                container.grid_columns = (container.grid_columns as f32 + delta).max(1.) as u16
+            }
+            Property::GridRows => {
+                container.grid_rows = (container.grid_rows as f32 + delta).max(1.) as u16
+            }
+            _ => {
+                let Some(child) = self.selected_child() else {
+                    return;
+                };
+                match property {
+                    Property::ChildWidth => child.width = (child.width + delta).max(0.),
+                    Property::ChildHeight => child.height = (child.height + delta).max(0.),
+                    Property::ChildGrow => child.flex_grow = (child.flex_grow + delta).max(0.),
+                    Property::ChildShrink => {
+                        child.flex_shrink = (child.flex_shrink + delta).max(0.)
+                    }
+                    Property::ChildMargin => child.margin = (child.margin + delta).max(0.),
+                    _ => {}
+                }
+            }
+        }
+    }
+
+    fn value_text(&self, property: Property) -> String {
+        let container = &self.container;
+        let child = self.children.get(self.selected);
+        match property {
+            Property::Grid => on_off(container.grid),
+            Property::FlexWrap => on_off(container.flex_wrap),
+            Property::FlexDirection
+            | Property::JustifyContent
+            | Property::AlignItems
+            | Property::AlignContent
+            | Property::ChildAlignSelf => {
+                if property == Property::ChildAlignSelf && child.is_none() {
+                    return "-".to_string();
+                }
+                property.options()[self.choice_index(property)].clone()
+            }
+            Property::RowGap => format!("{}px", container.row_gap),
+            Property::ColumnGap => format!("{}px", container.column_gap),
+            Property::Padding => format!("{}px", container.padding),
+            Property::GridColumns => container.grid_columns.to_string(),
+            Property::GridRows => container.grid_rows.to_string(),
+            Property::ChildWidth => child.map_or("-".into(), |c| format!("{}px", c.width)),
+            Property::ChildHeight => child.map_or("-".into(), |c| format!("{}px", c.height)),
+            Property::ChildGrow => child.map_or("-".into(), |c| format!("{}", c.flex_grow)),
+            Property::ChildShrink => child.map_or("-".into(), |c| format!("{}", c.flex_shrink)),
+            Property::ChildMargin => child.map_or("-".into(), |c| format!("{}px", c.margin)),
+        }
+    }
+
+    fn container_node(&self) -> Node {
+        let c = &self.container;
+        Node {
+            width: Val::Percent(100.),
+            height: Val::Percent(100.),
+            display: if c.grid { Display::Grid } else { Display::Flex },
+            flex_direction: c.flex_direction,
+            flex_wrap: if c.flex_wrap {
+                FlexWrap::Wrap
+            } else {
+                FlexWrap::NoWrap
+            },
+            justify_content: c.justify_content,
+            align_items: c.align_items,
+            align_content: c.align_content,
+            row_gap: Val::Px(c.row_gap),
+            column_gap: Val::Px(c.column_gap),
+            padding: UiRect::all(Val::Px(c.padding)),
+            grid_template_columns: RepeatedGridTrack::flex(c.grid_columns, 1.),
+            grid_template_rows: RepeatedGridTrack::flex(c.grid_rows, 1.),
+            ..default()
+        }
+    }
+
+    fn child_node(child: &ChildProps) -> Node {
+        Node {
+            width: Val::Px(child.width),
+            height: Val::Px(child.height),
+            flex_grow: child.flex_grow,
+            flex_shrink: child.flex_shrink,
+            align_self: child.align_self,
+            margin: UiRect::all(Val::Px(child.margin)),
+            justify_content: JustifyContent::Center,
+            align_items: AlignItems::Center,
+            border: UiRect::all(Val::Px(3.)),
+            ..default()
+        }
+    }
+
+    /// Rust code spawning the current layout, in the style of the other examples.
+    fn to_rust(&self) -> String {
+        let c = &self.container;
+        let mut out = String::new();
+        let _ = writeln!(out, "commands");
+        let _ = writeln!(out, "    .spawn(Node {{");
+        let _ = writeln!(out, "        width: Val::Percent(100.),");
+        let _ = writeln!(out, "        height: Val::Percent(100.),");
+        if c.grid {
+            let _ = writeln!(out, "        display: Display::Grid,");
+            let _ = writeln!(
+                out,
+                "        grid_template_columns: RepeatedGridTrack::flex({}, 1.),",
+                c.grid_columns
+            );
+            let _ = writeln!(
+                out,
+                "        grid_template_rows: RepeatedGridTrack::flex({}, 1.),",
+                c.grid_rows
+            );
+        }
+        let _ = writeln!(
+            out,
+            "        flex_direction: FlexDirection::{:?},",
+            c.flex_direction
+        );
+        if c.flex_wrap {
+            let _ = writeln!(out, "        flex_wrap: FlexWrap::Wrap,");
+        }
+        let _ = writeln!(
+            out,
+            "        justify_content: JustifyContent::{:?},",
+            c.justify_content
+        );
+        let _ = writeln!(out, "        align_items: AlignItems::{:?},", c.align_items);
+        let _ = writeln!(
+            out,
+            "        align_content: AlignContent::{:?},",
+            c.align_content
+        );
+        let _ = writeln!(out, "        row_gap: Val::Px({:?}),", c.row_gap);
+        let _ = writeln!(out, "        column_gap: Val::Px({:?}),", c.column_gap);
+        let _ = writeln!(
+            out,
+            "        padding: UiRect::all(Val::Px({:?})),",
+            c.padding
+        );
+        let _ = writeln!(out, "        ..default()");
+        let _ = writeln!(out, "    }})");
+        let _ = writeln!(out, "    .with_children(|parent| {{");
+        for child in &self.children {
+            let _ = writeln!(out, "        parent.spawn(Node {{");
+            let _ = writeln!(out, "            width: Val::Px({:?}),", child.width);
+            let _ = writeln!(out, "            height: Val::Px({:?}),", child.height);
+            let _ = writeln!(out, "            flex_grow: {:?},", child.flex_grow);
+            let _ = writeln!(out, "            flex_shrink: {:?},", child.flex_shrink);
+            let _ = writeln!(
+                out,
+                "            align_self: AlignSelf::{:?},",
+                child.align_self
+            );
+            let _ = writeln!(
+                out,
+                "            margin: UiRect::all(Val::Px({:?})),",
+                child.margin
+            );
+            let _ = writeln!(out, "            ..default()");
+            let _ = writeln!(out, "        }});");
+        }
+        let _ = writeln!(out, "    }});");
+        out
+    }
+
+    /// RON description of the current layout, which deserializes back into a `PlaygroundLayout`.
+    fn to_ron(&self) -> Result<String, String> {
+        ron::ser::to_string_pretty(self, ron::ser::PrettyConfig::default())
+            .map_err(|err| err.to_string())
+    }
+}
+
+fn index_of<T: PartialEq>(values: &[T], value: T) -> usize {
+    values.iter().position(|v| *v == value).unwrap_or(0)
+}
+
+fn on_off(value: bool) -> String {
+    if value { "on" } else { "off" }.to_string()
+}
+
+#[derive(Component)]
+struct PreviewContainer;
+
+#[derive(Component)]
+struct PreviewChild(usize);
+
+#[derive(Component)]
+struct ValueText(Property);
+
+#[derive(Component)]
+struct ChoiceButton(Property);
+
+#[derive(Component)]
+struct ChoiceList(Property);
+
+#[derive(Component)]
+struct ChoiceOption(Property, usize);
+
+#[derive(Component)]
+struct StepButton(Property, f32);
+
+#[derive(Component)]
+struct ToggleButton(Property);
+
+#[derive(Component, Clone, Copy)]
+enum PlaygroundAction {
+    AddChild,
+    RemoveChild,
+    ExportRust,
+    ExportRon,
+}
+
+fn main() -> AppExit {
+    App::new()
+        .add_plugins(default_plugins())
+        .add_plugins(SnapshotPlugin::new("flex_playground"))
+        .init_resource::<PlaygroundLayout>()
+        .add_systems(Startup, setup)
+        .add_systems(
+            Update,
+            (
+                button_colors,
+                choice_buttons,
+                choice_options,
+                step_buttons,
+                toggle_buttons,
+                action_buttons,
+                select_child,
+                apply_layout.run_if(resource_changed::<PlaygroundLayout>),
+            )
+                .chain(),
+        )
+        .run()
+}
+
+fn text_font() -> TextFont {
+    TextFont {
+        font_size: 14.,
+        ..default()
+    }
+}
+
+fn small_button() -> Node {
+    Node {
+        min_width: Val::Px(24.),
+        height: Val::Px(22.),
+        padding: UiRect::horizontal(Val::Px(6.)),
+        justify_content: JustifyContent::Center,
+        align_items: AlignItems::Center,
+        ..default()
+    }
+}
+
+fn setup(mut commands: Commands) {
+    commands.spawn(Camera2d);
+
+    let root = commands
+        .spawn((
+            Node {
+                width: Val::Percent(100.),
+                height: Val::Percent(100.),
+                flex_direction: FlexDirection::Row,
+                ..default()
+            },
+            BackgroundColor(Color::WHITE),
+        ))
+        .id();
+
+    // control panel
+    let panel = commands
+        .spawn((
+            Node {
+                width: Val::Px(320.),
+                height: Val::Percent(100.),
+                flex_direction: FlexDirection::Column,
+                padding: UiRect::all(Val::Px(10.)),
+                row_gap: Val::Px(4.),
+                ..default()
+            },
+            BackgroundColor(PANEL_COLOR),
+        ))
+        .id();
+
+    // preview area
+    let preview = commands
+        .spawn((
+            Node {
+                flex_grow: 1.,
+                height: Val::Percent(100.),
+                padding: UiRect::all(Val::Px(20.)),
+                ..default()
+            },
+            BackgroundColor(GREY.into()),
+        ))
+        .with_child((
+            Node {
+                width: Val::Percent(100.),
+                height: Val::Percent(100.),
+                ..default()
+            },
+            BackgroundColor(WHITE.into()),
+            PreviewContainer,
+            Name::new("preview_container"),
+        ))
+        .id();
+
+    commands.entity(root).add_children(&[panel, preview]);
+
+    commands.entity(panel).with_children(|parent| {
+        section_title(parent, "Container");
+        toggle_row(parent, Property::Grid);
+        choice_row(parent, Property::FlexDirection);
+        toggle_row(parent, Property::FlexWrap);
+        choice_row(parent, Property::JustifyContent);
+        choice_row(parent, Property::AlignItems);
+        choice_row(parent, Property::AlignContent);
+        stepper_row(parent, Property::RowGap, 5.);
+        stepper_row(parent, Property::ColumnGap, 5.);
+        stepper_row(parent, Property::Padding, 5.);
+        stepper_row(parent, Property::GridColumns, 1.);
+        stepper_row(parent, Property::GridRows, 1.);
+
+        section_title(parent, "Selected child (click a child to select)");
+        stepper_row(parent, Property::ChildWidth, 10.);
+        stepper_row(parent, Property::ChildHeight, 10.);
+        stepper_row(parent, Property::ChildGrow, 1.);
+        stepper_row(parent, Property::ChildShrink, 1.);
+        choice_row(parent, Property::ChildAlignSelf);
+        stepper_row(parent, Property::ChildMargin, 5.);
+
+        section_title(parent, "Children");
+        parent
+            .spawn(Node {
+                column_gap: Val::Px(6.),
+                ..default()
+            })
+            .with_children(|parent| {
+                action_button(parent, "Add", PlaygroundAction::AddChild);
+                action_button(parent, "Remove", PlaygroundAction::RemoveChild);
+            });
+
+        section_title(parent, "Export");
+        parent
+            .spawn(Node {
+                column_gap: Val::Px(6.),
+                ..default()
+            })
+            .with_children(|parent| {
+                action_button(parent, "Rust", PlaygroundAction::ExportRust);
+                action_button(parent, "RON", PlaygroundAction::ExportRon);
+            });
+    });
+}
+
+fn section_title(parent: &mut ChildBuilder, title: &str) {
+    parent.spawn((
+        Text::new(title),
+        TextFont {
+            font_size: 16.,
+            ..default()
+        },
+        TextColor(CYAN_400.into()),
+        Node {
+            margin: UiRect::top(Val::Px(8.)),
+            ..default()
+        },
+    ));
+}
+
+fn row_node() -> Node {
+    Node {
+        width: Val::Percent(100.),
+        justify_content: JustifyContent::SpaceBetween,
+        align_items: AlignItems::Center,
+        ..default()
+    }
+}
+
+fn row_label(parent: &mut ChildBuilder, property: Property) {
+    parent.spawn((
+        Text::new(property.label()),
+        text_font(),
+        TextColor(TEXT_COLOR),
+    ));
+}
+
+fn value_text(property: Property) -> impl Bundle {
+    (
+        Text::default(),
+        text_font(),
+        TextColor(TEXT_COLOR),
+        ValueText(property),
+    )
+}
+
+fn toggle_row(parent: &mut ChildBuilder, property: Property) {
+    parent.spawn(row_node()).with_children(|parent| {
+        row_label(parent, property);
+        parent
+            .spawn((
+                Button,
+                small_button(),
+                BackgroundColor(NORMAL_BUTTON),
+                ToggleButton(property),
+            ))
+            .with_child(value_text(property));
+    });
+}
+
+fn stepper_row(parent: &mut ChildBuilder, property: Property, step: f32) {
+    parent.spawn(row_node()).with_children(|parent| {
+        row_label(parent, property);
+        parent
+            .spawn(Node {
+                column_gap: Val::Px(6.),
+                align_items: AlignItems::Center,
+                ..default()
+            })
+            .with_children(|parent| {
+                parent
+                    .spawn((
+                        Button,
+                        small_button(),
+                        BackgroundColor(NORMAL_BUTTON),
+                        StepButton(property, -step),
+                    ))
+                    .with_child((Text::new("-"), text_font(), TextColor(TEXT_COLOR)));
+                parent.spawn(value_text(property));
+                parent
+                    .spawn((
+                        Button,
+                        small_button(),
+                        BackgroundColor(NORMAL_BUTTON),
+                        StepButton(property, step),
+                    ))
+                    .with_child((Text::new("+"), text_font(), TextColor(TEXT_COLOR)));
+            });
+    });
+}
+
+fn choice_row(parent: &mut ChildBuilder, property: Property) {
+    parent.spawn(row_node()).with_children(|parent| {
+        row_label(parent, property);
+        parent
+            .spawn(Node {
+                flex_direction: FlexDirection::Column,
+                ..default()
+            })
+            .with_children(|parent| {
+                parent
+                    .spawn((
+                        Button,
+                        small_button(),
+                        BackgroundColor(NORMAL_BUTTON),
+                        ChoiceButton(property),
+                    ))
+                    .with_child(value_text(property));
+                // the option list is hidden until the dropdown button is pressed
+                parent
+                    .spawn((
+                        Node {
+                            display: Display::None,
+                            flex_direction: FlexDirection::Column,
+                            position_type: PositionType::Absolute,
+                            top: Val::Px(22.),
+                            right: Val::Px(0.),
+                            ..default()
+                        },
+                        BackgroundColor(Color::BLACK),
+                        GlobalZIndex(1),
+                        ChoiceList(property),
+                    ))
+                    .with_children(|parent| {
+                        for (index, option) in property.options().into_iter().enumerate() {
+                            parent
+                                .spawn((
+                                    Button,
+                                    small_button(),
+                                    BackgroundColor(NORMAL_BUTTON),
+                                    ChoiceOption(property, index),
+                                ))
+                                .with_child((
+                                    Text::new(option),
+                                    text_font(),
+                                    TextColor(TEXT_COLOR),
+                                ));
+                        }
+                    });
+            });
+    });
+}
+
+fn action_button(parent: &mut ChildBuilder, label: &str, action: PlaygroundAction) {
+    parent
+        .spawn((
+            Button,
+            Node {
+                width: Val::Px(80.),
+                height: Val::Px(28.),
+                justify_content: JustifyContent::Center,
+                align_items: AlignItems::Center,
+                ..default()
+            },
+            BackgroundColor(NORMAL_BUTTON),
+            action,
+        ))
+        .with_child((Text::new(label), text_font(), TextColor(TEXT_COLOR)));
+}
+
+#[allow(clippy::type_complexity)]
+fn button_colors(
+    mut interaction_query: Query<
+        (&Interaction, &mut BackgroundColor),
+        (Changed<Interaction>, With<Button>, Without<PreviewChild>),
+    >,
+) {
+    for (interaction, mut color) in &mut interaction_query {
+        *color = match *interaction {
+            Interaction::Pressed => PRESSED_BUTTON.into(),
+            Interaction::Hovered => HOVERED_BUTTON.into(),
+            Interaction::None => NORMAL_BUTTON.into(),
+        };
+    }
+}
+
+fn choice_buttons(
+    interaction_query: Query<(&Interaction, &ChoiceButton), Changed<Interaction>>,
+    mut list_query: Query<(&mut Node, &ChoiceList)>,
+) {
+    for (interaction, choice) in &interaction_query {
+        if *interaction == Interaction::Pressed {
+            for (mut node, list) in &mut list_query {
+                node.display = if list.0 == choice.0 && node.display == Display::None {
+                    Display::Flex
+                } else {
+                    Display::None
+                };
+            }
+        }
+    }
+}
+
+fn choice_options(
+    interaction_query: Query<(&Interaction, &ChoiceOption), Changed<Interaction>>,
+    mut list_query: Query<&mut Node, With<ChoiceList>>,
+    mut layout: ResMut<PlaygroundLayout>,
+) {
+    for (interaction, option) in &interaction_query {
+        if *interaction == Interaction::Pressed {
+            layout.set_choice(option.0, option.1);
+            for mut node in &mut list_query {
+                node.display = Display::None;
+            }
+        }
+    }
+}
+
+fn step_buttons(
+    interaction_query: Query<(&Interaction, &StepButton), Changed<Interaction>>,
+    mut layout: ResMut<PlaygroundLayout>,
+) {
+    for (interaction, step) in &interaction_query {
+        if *interaction == Interaction::Pressed {
+            layout.step(step.0, step.1);
+        }
+    }
+}
+
+fn toggle_buttons(
+    interaction_query: Query<(&Interaction, &ToggleButton), Changed<Interaction>>,
+    mut layout: ResMut<PlaygroundLayout>,
+) {
+    for (interaction, toggle) in &interaction_query {
+        if *interaction == Interaction::Pressed {
+            layout.toggle(toggle.0);
+        }
+    }
+}
+
+fn action_buttons(
+    interaction_query: Query<(&Interaction, &PlaygroundAction), Changed<Interaction>>,
+    mut layout: ResMut<PlaygroundLayout>,
+) {
+    for (interaction, action) in &interaction_query {
+        if *interaction != Interaction::Pressed {
+            continue;
+        }
+        match action {
+            PlaygroundAction::AddChild => {
+                layout.children.push(ChildProps::default());
+                layout.selected = layout.children.len() - 1;
+            }
+            PlaygroundAction::RemoveChild => {
+                if !layout.children.is_empty() {
+                    let selected = layout.selected.min(layout.children.len() - 1);
+                    layout.children.remove(selected);
+                    layout.selected = selected.saturating_sub(1);
+                }
+            }
+            PlaygroundAction::ExportRust => export(RUST_EXPORT_PATH, &layout.to_rust()),
+            PlaygroundAction::ExportRon => match layout.to_ron() {
+                Ok(ron) => export(RON_EXPORT_PATH, &ron),
+                Err(err) => println!("failed to export layout to {RON_EXPORT_PATH}: {err}"),
+            },
+        }
+    }
+}
+
+fn export(path: &str, contents: &str) {
+    match std::fs::write(path, contents) {
+        Ok(()) => println!("exported layout to {path}"),
+        Err(err) => println!("failed to export layout to {path}: {err}"),
+    }
+}
+
+fn select_child(
+    interaction_query: Query<(&Interaction, &PreviewChild), Changed<Interaction>>,
+    mut layout: ResMut<PlaygroundLayout>,
+) {
+    for (interaction, child) in &interaction_query {
+        if *interaction == Interaction::Pressed && layout.selected != child.0 {
+            layout.selected = child.0;
+        }
+    }
+}
+
+fn apply_layout(
+    mut commands: Commands,
+    layout: Res<PlaygroundLayout>,
+    mut container_query: Query<(Entity, &mut Node), With<PreviewContainer>>,
+    mut text_query: Query<(&mut Text, &ValueText)>,
+) {
+    let Ok((container, mut node)) = container_query.get_single_mut() else {
+        return;
+    };
+    *node = layout.container_node();
+
+    // children are cheap to rebuild, so respawn them instead of diffing
+    commands
+        .entity(container)
+        .despawn_descendants()
+        .with_children(|parent| {
+            for (index, child) in layout.children.iter().enumerate() {
+                let border = if index == layout.selected {
+                    Color::BLACK
+                } else {
+                    Color::NONE
+                };
+                parent
+                    .spawn((
+                        Button,
+                        PlaygroundLayout::child_node(child),
+                        BackgroundColor(CHILD_COLORS[index % CHILD_COLORS.len()].into()),
+                        BorderColor(border),
+                        PreviewChild(index),
+                    ))
+                    .with_child((
+                        Text::new((index + 1).to_string()),
+                        TextFont {
+                            font_size: 20.,
+                            ..default()
+                        },
+                        TextColor(Color::BLACK),
+                    ));
+            }
+        });
+
+    for (mut text, value) in &mut text_query {
+        text.0 = layout.value_text(value.0);
+    }
+}
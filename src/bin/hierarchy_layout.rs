@@ -3,12 +3,15 @@
 use bevy::app::App;
 use bevy::color::palettes::css::{BLUE, GREEN, GREY};
 use bevy::prelude::*;
+use bevy_container::automation::default_plugins;
+use bevy_container::snapshot::SnapshotPlugin;
 
-fn main() {
+fn main() -> AppExit {
     App::new()
-        .add_plugins(DefaultPlugins)
+        .add_plugins(default_plugins())
+        .add_plugins(SnapshotPlugin::new("hierarchy_layout"))
         .add_systems(Startup, setup)
-        .run();
+        .run()
 }
 
 pub fn setup(mut commands: Commands, asset_server: Res<AssetServer>) {
@@ -36,6 +39,7 @@ pub fn setup(mut commands: Commands, asset_server: Res<AssetServer>) {
                 ..default()
             },
             BackgroundColor(Color::WHITE),
+            Name::new("root"),
         ))
         .with_children(
             // Child text node
@@ -48,6 +52,7 @@ pub fn setup(mut commands: Commands, asset_server: Res<AssetServer>) {
                 },
                 BackgroundColor(GREY.into()),
                 Text::new("Inside container 1"),
+                Name::new("container_1"),
                 text_font.clone()
             ));
         })
@@ -63,6 +68,7 @@ pub fn setup(mut commands: Commands, asset_server: Res<AssetServer>) {
                 },
                 BackgroundColor(GREEN.into()),
                 Text::new("Inside container 2"),
+                Name::new("container_2"),
                 text_font.clone()
             ))
             .with_child((
@@ -74,6 +80,7 @@ pub fn setup(mut commands: Commands, asset_server: Res<AssetServer>) {
                 },
                 BackgroundColor(BLUE.into()),
                 Text::new("SINGLE CHILD"),
+                Name::new("single_child"),
                 text_font.clone()
             ));
         });
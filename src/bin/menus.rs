@@ -0,0 +1,21 @@
+//! This example is a small drawing tool's menus: a menu bar with nested submenus, separators,
+//! disabled items, a checkable "Show grid" item and shortcut hints taken from the keymap, plus
+//! context menus on the canvas and on the shape inside it (right click; the shape's wins). The
+//! shortcuts work without opening the menus. Every picked action is printed and shown at the
+//! bottom.
+//!
+//! `cargo run --bin menus -- --script scripts/menus.ron --headless` checks the menus.
+//!
+//! The demo itself lives in [`bevy_container::demos::menus`], so `cargo test` runs the script
+//! too.
+use bevy::prelude::*;
+use bevy_container::automation::{AutomationPlugin, default_plugins};
+use bevy_container::demos::menus::MenusDemoPlugin;
+
+fn main() -> AppExit {
+    App::new()
+        .add_plugins(default_plugins())
+        .add_plugins(AutomationPlugin)
+        .add_plugins(MenusDemoPlugin)
+        .run()
+}
@@ -0,0 +1,21 @@
+//! This example shows modal dialogs. "Close document" asks what to do with the unsaved changes:
+//! Enter saves, Escape cancels, and Tab only cycles through the dialog's buttons. "What changed?"
+//! opens a second modal on top of the first; closing it goes back to the first one, and closing
+//! that gives the focus back to "Close document". The picked action is printed and shown at the
+//! top.
+//!
+//! `cargo run --bin modals -- --script scripts/modals.ron --headless` checks the stacking.
+//!
+//! The demo itself lives in [`bevy_container::demos::modals`], so `cargo test` runs the script
+//! too.
+use bevy::prelude::*;
+use bevy_container::automation::{AutomationPlugin, default_plugins};
+use bevy_container::demos::modals::ModalsDemoPlugin;
+
+fn main() -> AppExit {
+    App::new()
+        .add_plugins(default_plugins())
+        .add_plugins(AutomationPlugin)
+        .add_plugins(ModalsDemoPlugin)
+        .run()
+}
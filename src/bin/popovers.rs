@@ -0,0 +1,21 @@
+//! This example shows the overlay layer with popovers anchored to buttons near each edge of the
+//! window. Each asks to open towards the edge it's next to, so it flips to the other side of its
+//! button, and is then kept inside the window. The "Nested" popover opens a second one from inside
+//! it; "Sticky" only closes with its own button. A press outside a popover or Escape closes it,
+//! and the reason is shown in the middle.
+//!
+//! `cargo run --bin popovers -- --script scripts/popovers.ron --headless` checks the dismissal.
+//!
+//! The demo itself lives in [`bevy_container::demos::popovers`], so `cargo test` runs the script
+//! too.
+use bevy::prelude::*;
+use bevy_container::automation::{AutomationPlugin, default_plugins};
+use bevy_container::demos::popovers::PopoversDemoPlugin;
+
+fn main() -> AppExit {
+    App::new()
+        .add_plugins(default_plugins())
+        .add_plugins(AutomationPlugin)
+        .add_plugins(PopoversDemoPlugin)
+        .run()
+}
@@ -0,0 +1,20 @@
+//! This example shows the progress widgets: determinate bars with a percentage or a custom label,
+//! which "-" and "+" move by 10%, an indeterminate bar and spinners of a few sizes and colors.
+//! "Submit" stands for a slow action: while it runs the button shows a spinner next to its label
+//! and ignores clicks, and the bar under it follows the fake work until it's done.
+//!
+//! `cargo run --bin progress -- --script scripts/progress.ron --headless` checks the progress.
+//!
+//! The demo itself lives in [`bevy_container::demos::progress`], so `cargo test` runs the script
+//! too.
+use bevy::prelude::*;
+use bevy_container::automation::{AutomationPlugin, default_plugins};
+use bevy_container::demos::progress::ProgressDemoPlugin;
+
+fn main() -> AppExit {
+    App::new()
+        .add_plugins(default_plugins())
+        .add_plugins(AutomationPlugin)
+        .add_plugins(ProgressDemoPlugin)
+        .run()
+}
@@ -2,12 +2,15 @@
 
 use bevy::app::App;
 use bevy::prelude::*;
+use bevy_container::automation::default_plugins;
+use bevy_container::snapshot::SnapshotPlugin;
 
-fn main() {
+fn main() -> AppExit {
     App::new()
-        .add_plugins(DefaultPlugins)
+        .add_plugins(default_plugins())
+        .add_plugins(SnapshotPlugin::new("root_container"))
         .add_systems(Startup, setup)
-        .run();
+        .run()
 }
 
 pub fn setup(mut commands: Commands) {
@@ -25,6 +28,7 @@ pub fn setup(mut commands: Commands) {
                 ..default()
             },
             BackgroundColor(Color::srgb(0.0, 255., 0.)),
+            Name::new("root"),
         ));
 
     
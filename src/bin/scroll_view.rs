@@ -0,0 +1,161 @@
+//! This example shows the scroll view widget: a kinetic vertical list with a text input at the
+//! bottom (focusing it scrolls it into view) next to a grid that scrolls both ways. Scroll with the
+//! wheel (shift for horizontal), by dragging the content or the scrollbars, or with the arrow,
+//! page, home and end keys while hovering a view.
+use bevy::prelude::*;
+use bevy_container::automation::default_plugins;
+use bevy_container::snapshot::SnapshotPlugin;
+use bevy_container::theme::Theme;
+use bevy_container::widgets::scroll_view::{
+    ScrollIntoView, ScrollPositionChanged, ScrollView, ScrollViewPlugin, spawn_scroll_view,
+};
+use bevy_simple_text_input::{TextInput, TextInputPlugin, TextInputTextFont};
+
+const ROWS: usize = 60;
+const GRID: usize = 20;
+
+fn main() -> AppExit {
+    App::new()
+        .add_plugins(default_plugins())
+        .add_plugins((TextInputPlugin, ScrollViewPlugin))
+        .add_plugins(SnapshotPlugin::new("scroll_view"))
+        .add_systems(Startup, setup)
+        .add_systems(Update, (row_buttons, print_scroll_changes))
+        .run()
+}
+
+#[derive(Component)]
+struct Row(usize);
+
+fn setup(mut commands: Commands, theme: Res<Theme>) {
+    commands.spawn(Camera2d);
+    commands
+        .spawn((
+            Node {
+                width: Val::Percent(100.),
+                height: Val::Percent(100.),
+                padding: UiRect::all(Val::Px(20.)),
+                column_gap: Val::Px(20.),
+                ..default()
+            },
+            BackgroundColor(theme.background),
+            Name::new("root"),
+        ))
+        .with_children(|parent| {
+            spawn_scroll_view(
+                parent,
+                ScrollView::vertical().with_kinetic(true),
+                (
+                    Node {
+                        width: Val::Px(300.),
+                        height: Val::Percent(100.),
+                        ..default()
+                    },
+                    Name::new("list_view"),
+                ),
+                |content| {
+                    for index in 0..ROWS {
+                        content
+                            .spawn((
+                                Button,
+                                Node {
+                                    width: Val::Percent(100.),
+                                    padding: UiRect::axes(Val::Px(10.), Val::Px(6.)),
+                                    margin: UiRect::bottom(Val::Px(2.)),
+                                    ..default()
+                                },
+                                BackgroundColor(theme.surface),
+                                Row(index),
+                            ))
+                            .with_child((
+                                Text::new(format!("Row {index}")),
+                                theme.text_font(),
+                                TextColor(theme.text),
+                            ));
+                    }
+                    content.spawn((
+                        Node {
+                            width: Val::Percent(100.),
+                            border: UiRect::all(Val::Px(2.)),
+                            padding: UiRect::all(Val::Px(5.)),
+                            ..default()
+                        },
+                        BorderColor(theme.border),
+                        BackgroundColor(theme.surface),
+                        TextInput,
+                        TextInputTextFont(theme.text_font()),
+                        Name::new("bottom_input"),
+                    ));
+                },
+            );
+
+            spawn_scroll_view(
+                parent,
+                ScrollView::both(),
+                (
+                    Node {
+                        flex_grow: 1.,
+                        height: Val::Percent(100.),
+                        ..default()
+                    },
+                    Name::new("grid_view"),
+                ),
+                |content| {
+                    for row in 0..GRID {
+                        content
+                            .spawn(Node {
+                                column_gap: Val::Px(4.),
+                                margin: UiRect::bottom(Val::Px(4.)),
+                                ..default()
+                            })
+                            .with_children(|cells| {
+                                for column in 0..GRID {
+                                    cells
+                                        .spawn((
+                                            Node {
+                                                width: Val::Px(80.),
+                                                height: Val::Px(50.),
+                                                justify_content: JustifyContent::Center,
+                                                align_items: AlignItems::Center,
+                                                ..default()
+                                            },
+                                            BackgroundColor(theme.surface),
+                                        ))
+                                        .with_child((
+                                            Text::new(format!("{row},{column}")),
+                                            theme.text_font(),
+                                            TextColor(theme.text_muted),
+                                        ));
+                                }
+                            });
+                    }
+                },
+            );
+        });
+}
+
+/// Clicking a row scrolls the row ten further down into view.
+fn row_buttons(
+    mut commands: Commands,
+    theme: Res<Theme>,
+    mut rows: Query<(&Interaction, &Row, &mut BackgroundColor), Changed<Interaction>>,
+    all_rows: Query<(Entity, &Row)>,
+) {
+    for (interaction, row, mut color) in &mut rows {
+        color.0 = theme.surface_color(*interaction);
+        if *interaction != Interaction::Pressed {
+            continue;
+        }
+        let target = (row.0 + 10).min(ROWS - 1);
+        if let Some((entity, _)) = all_rows.iter().find(|(_, row)| row.0 == target) {
+            println!("scrolling row {target} into view");
+            commands.entity(entity).insert(ScrollIntoView);
+        }
+    }
+}
+
+fn print_scroll_changes(mut changes: EventReader<ScrollPositionChanged>) {
+    for change in changes.read() {
+        println!("{} scrolled to {}", change.view, change.offset);
+    }
+}
@@ -0,0 +1,251 @@
+//! This example is a settings form built from the checkbox, radio group and toggle switch widgets.
+//! "All notifications" shows the state of the three boxes below it (indeterminate when they
+//! differ) and sets all of them when clicked; "Share crash reports" is a tri-state box where the
+//! indeterminate state means "ask each time". Tab moves the focus, Space toggles and the arrow keys
+//! change the theme. "Save" prints the settings.
+use bevy::prelude::*;
+use bevy_container::automation::default_plugins;
+use bevy_container::snapshot::SnapshotPlugin;
+use bevy_container::theme::Theme;
+use bevy_container::widgets::checkbox::{
+    CheckState, Checkbox, CheckboxChanged, CheckboxPlugin, spawn_checkbox,
+};
+use bevy_container::widgets::radio_group::{
+    RadioChanged, RadioGroup, RadioGroupPlugin, spawn_radio_group,
+};
+use bevy_container::widgets::toggle_switch::{
+    ToggleChanged, ToggleSwitch, ToggleSwitchPlugin, spawn_toggle_switch,
+};
+
+fn main() -> AppExit {
+    App::new()
+        .add_plugins(default_plugins())
+        .add_plugins((CheckboxPlugin, RadioGroupPlugin, ToggleSwitchPlugin))
+        .add_plugins(SnapshotPlugin::new("settings_form"))
+        .add_systems(Startup, setup)
+        .add_systems(Update, (sync_all_notifications, print_changes, save_button))
+        .run()
+}
+
+#[derive(Component)]
+struct AllNotifications;
+
+#[derive(Component)]
+struct Notification;
+
+#[derive(Component)]
+struct SaveButton;
+
+fn setup(mut commands: Commands, theme: Res<Theme>) {
+    commands.spawn(Camera2d);
+    let theme = theme.clone();
+    commands
+        .spawn((
+            Node {
+                width: Val::Percent(100.),
+                height: Val::Percent(100.),
+                justify_content: JustifyContent::Center,
+                align_items: AlignItems::Center,
+                ..default()
+            },
+            BackgroundColor(theme.background),
+            Name::new("root"),
+        ))
+        .with_children(|parent| {
+            parent
+                .spawn((
+                    Node {
+                        flex_direction: FlexDirection::Column,
+                        row_gap: Val::Px(8.),
+                        padding: UiRect::all(Val::Px(20.)),
+                        min_width: Val::Px(320.),
+                        ..default()
+                    },
+                    BackgroundColor(theme.surface),
+                    BorderRadius::all(Val::Px(8.)),
+                    Name::new("form"),
+                ))
+                .with_children(|form| {
+                    section_title(form, "Notifications", &theme);
+                    let all =
+                        spawn_checkbox(form, Checkbox::new(false), "All notifications", &theme);
+                    form.enqueue_command(move |world: &mut World| {
+                        world
+                            .entity_mut(all)
+                            .insert((AllNotifications, Name::new("all_notifications")));
+                    });
+                    form.spawn(Node {
+                        flex_direction: FlexDirection::Column,
+                        row_gap: Val::Px(4.),
+                        margin: UiRect::left(Val::Px(26.)),
+                        ..default()
+                    })
+                    .with_children(|list| {
+                        for (label, checked) in [("Email", true), ("Push", false), ("SMS", false)] {
+                            let entity =
+                                spawn_checkbox(list, Checkbox::new(checked), label, &theme);
+                            let name = format!("notify_{}", label.to_lowercase());
+                            list.enqueue_command(move |world: &mut World| {
+                                world
+                                    .entity_mut(entity)
+                                    .insert((Notification, Name::new(name)));
+                            });
+                        }
+                    });
+                    let crash_reports = spawn_checkbox(
+                        form,
+                        Checkbox::new(true).tri_state(),
+                        "Share crash reports",
+                        &theme,
+                    );
+                    name(form, crash_reports, "crash_reports");
+
+                    section_title(form, "Theme", &theme);
+                    let group = spawn_radio_group(
+                        form,
+                        RadioGroup::new(["Light", "Dark", "System"]).with_selected(2),
+                        Node {
+                            flex_direction: FlexDirection::Column,
+                            row_gap: Val::Px(4.),
+                            ..default()
+                        },
+                        &theme,
+                    );
+                    name(form, group, "theme_choice");
+
+                    section_title(form, "Editor", &theme);
+                    let auto_save =
+                        spawn_toggle_switch(form, ToggleSwitch::new(true), "Auto-save", &theme);
+                    name(form, auto_save, "auto_save");
+                    let spell_check =
+                        spawn_toggle_switch(form, ToggleSwitch::new(false), "Spell check", &theme);
+                    name(form, spell_check, "spell_check");
+
+                    form.spawn((
+                        Button,
+                        Node {
+                            margin: UiRect::top(Val::Px(12.)),
+                            padding: UiRect::axes(Val::Px(16.), Val::Px(8.)),
+                            align_self: AlignSelf::FlexEnd,
+                            ..default()
+                        },
+                        BackgroundColor(theme.surface_hovered),
+                        BorderRadius::all(Val::Px(4.)),
+                        SaveButton,
+                        Name::new("save_button"),
+                    ))
+                    .with_child((
+                        Text::new("Save"),
+                        theme.text_font(),
+                        TextColor(theme.text),
+                    ));
+                });
+        });
+}
+
+fn section_title(parent: &mut ChildBuilder, title: &str, theme: &Theme) {
+    parent.spawn((
+        Text::new(title),
+        TextFont {
+            font_size: theme.font_size * 1.2,
+            ..default()
+        },
+        TextColor(theme.text_muted),
+        Node {
+            margin: UiRect::top(Val::Px(8.)),
+            ..default()
+        },
+    ));
+}
+
+fn name(parent: &mut ChildBuilder, entity: Entity, name: &'static str) {
+    parent.enqueue_command(move |world: &mut World| {
+        world.entity_mut(entity).insert(Name::new(name));
+    });
+}
+
+/// Keeps "All notifications" in line with the individual boxes, and the other way round.
+#[allow(clippy::type_complexity)]
+fn sync_all_notifications(
+    mut changes: EventReader<CheckboxChanged>,
+    mut all: Query<(Entity, &mut Checkbox), (With<AllNotifications>, Without<Notification>)>,
+    mut notifications: Query<&mut Checkbox, With<Notification>>,
+) {
+    let Ok((all_entity, mut all)) = all.get_single_mut() else {
+        return;
+    };
+    for change in changes.read() {
+        if change.checkbox == all_entity {
+            for mut notification in &mut notifications {
+                notification.state = change.state;
+            }
+        }
+    }
+    let checked = notifications
+        .iter()
+        .filter(|checkbox| checkbox.is_checked())
+        .count();
+    let state = match checked {
+        0 => CheckState::Unchecked,
+        count if count == notifications.iter().count() => CheckState::Checked,
+        _ => CheckState::Indeterminate,
+    };
+    if all.state != state {
+        all.state = state;
+    }
+}
+
+fn print_changes(
+    mut checkboxes: EventReader<CheckboxChanged>,
+    mut radios: EventReader<RadioChanged>,
+    mut toggles: EventReader<ToggleChanged>,
+    names: Query<&Name>,
+    groups: Query<&RadioGroup>,
+) {
+    let name = |entity: Entity| names.get(entity).map(Name::as_str).unwrap_or("?");
+    for change in checkboxes.read() {
+        println!("{}: {:?}", name(change.checkbox), change.state);
+    }
+    for change in radios.read() {
+        let option = groups
+            .get(change.group)
+            .ok()
+            .and_then(RadioGroup::selected_option);
+        println!("{}: {option:?}", name(change.group));
+    }
+    for change in toggles.read() {
+        println!("{}: {}", name(change.switch), change.on);
+    }
+}
+
+#[allow(clippy::type_complexity)]
+fn save_button(
+    theme: Res<Theme>,
+    mut buttons: Query<
+        (&Interaction, &mut BackgroundColor),
+        (Changed<Interaction>, With<SaveButton>),
+    >,
+    checkboxes: Query<(&Name, &Checkbox)>,
+    groups: Query<(&Name, &RadioGroup)>,
+    switches: Query<(&Name, &ToggleSwitch)>,
+) {
+    for (interaction, mut color) in &mut buttons {
+        color.0 = match interaction {
+            Interaction::None => theme.surface_hovered,
+            _ => theme.surface_color(*interaction),
+        };
+        if *interaction != Interaction::Pressed {
+            continue;
+        }
+        println!("saving settings:");
+        for (name, checkbox) in &checkboxes {
+            println!("  {name}: {:?}", checkbox.state);
+        }
+        for (name, group) in &groups {
+            println!("  {name}: {}", group.selected_option().unwrap_or("none"));
+        }
+        for (name, switch) in &switches {
+            println!("  {name}: {}", switch.on);
+        }
+    }
+}
@@ -0,0 +1,20 @@
+//! This example shows the slider widgets: a stepped volume slider with ticks, a continuous
+//! brightness slider, a price range slider and three vertical equalizer bands. Dragging a thumb or
+//! pressing the track moves it, Tab focuses a thumb and the arrow keys, Page Up/Down and Home/End
+//! move the focused one. Every committed change is printed and shown at the bottom.
+//!
+//! `cargo run --bin sliders -- --script scripts/slider_drag.ron --headless` checks the drags.
+//!
+//! The demo itself lives in [`bevy_container::demos::sliders`], so `cargo test` runs the script
+//! too.
+use bevy::prelude::*;
+use bevy_container::automation::{AutomationPlugin, default_plugins};
+use bevy_container::demos::sliders::SlidersDemoPlugin;
+
+fn main() -> AppExit {
+    App::new()
+        .add_plugins(default_plugins())
+        .add_plugins(AutomationPlugin)
+        .add_plugins(SlidersDemoPlugin)
+        .run()
+}
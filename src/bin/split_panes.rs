@@ -0,0 +1,36 @@
+//! This example is an editor-style layout: a file sidebar next to an editor with a console under
+//! it, made of two nested split panes. Drag a divider to resize the panes; the sidebar stays
+//! between 120 and 400 pixels wide and the console at least 60 pixels high. Double-click a divider
+//! to collapse the sidebar or the console and double-click it again to bring it back. The splits
+//! are remembered in `split_state.ron`, so the layout comes back the same on the next run. The
+//! pane sizes are shown in the status bar.
+//!
+//! `cargo run --bin split_panes -- --script scripts/split_panes.ron --headless` checks the
+//! resizing; scripted runs start from a fresh state instead of the file.
+//!
+//! The demo itself lives in [`bevy_container::demos::split_panes`], so `cargo test` runs the script
+//! too.
+use bevy::prelude::*;
+use bevy_container::automation::{AutomationPlugin, default_plugins};
+use bevy_container::demos::split_panes::SplitPanesDemoPlugin;
+use bevy_container::widgets::split_pane::SplitStore;
+
+const STATE_PATH: &str = "split_state.ron";
+
+fn main() -> AppExit {
+    let scripted = std::env::args().any(|arg| arg == "--script");
+    let store = if scripted {
+        SplitStore::default()
+    } else {
+        SplitStore::load(STATE_PATH).unwrap_or_else(|err| {
+            println!("cannot load {err}, the layout won't be remembered");
+            SplitStore::default()
+        })
+    };
+    App::new()
+        .add_plugins(default_plugins())
+        .add_plugins(AutomationPlugin)
+        .add_plugins(SplitPanesDemoPlugin)
+        .insert_resource(store)
+        .run()
+}
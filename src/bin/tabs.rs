@@ -0,0 +1,21 @@
+//! This example shows the tabs widget. "Kept" keeps its panel while hidden, so its click count
+//! survives switching tabs; "Rebuilt" despawns its panel and starts over at zero. Panels are only
+//! built the first time their tab is shown, which is printed. The log tabs can be closed, tabs can
+//! be dragged to reorder them, the strip scrolls with the wheel once they don't fit, and the arrow
+//! keys, Home and End move between tabs once one has the focus.
+//!
+//! `cargo run --bin tabs -- --script scripts/tabs.ron --headless` checks the lazy panels.
+//!
+//! The demo itself lives in [`bevy_container::demos::tabs`], so `cargo test` runs the script
+//! too.
+use bevy::prelude::*;
+use bevy_container::automation::{AutomationPlugin, default_plugins};
+use bevy_container::demos::tabs::TabsDemoPlugin;
+
+fn main() -> AppExit {
+    App::new()
+        .add_plugins(default_plugins())
+        .add_plugins(AutomationPlugin)
+        .add_plugins(TabsDemoPlugin)
+        .run()
+}
@@ -0,0 +1,21 @@
+//! This example shows the tree view widget. On the left, a project's files: "assets" loads its
+//! children the first time it's expanded, which is printed. On the right, a live tree of this
+//! app's own entity hierarchy, refreshed as it changes; the Name of an entity is used where it has
+//! one. Click a row or Tab to a tree, then use the arrow keys, Home and End to move around and
+//! expand or collapse items. The selection is printed and shown at the top.
+//!
+//! `cargo run --bin tree_view -- --script scripts/tree_view.ron --headless` checks the navigation.
+//!
+//! The demo itself lives in [`bevy_container::demos::tree_view`], so `cargo test` runs the script
+//! too.
+use bevy::prelude::*;
+use bevy_container::automation::{AutomationPlugin, default_plugins};
+use bevy_container::demos::tree_view::TreeViewDemoPlugin;
+
+fn main() -> AppExit {
+    App::new()
+        .add_plugins(default_plugins())
+        .add_plugins(AutomationPlugin)
+        .add_plugins(TreeViewDemoPlugin)
+        .run()
+}
@@ -0,0 +1,208 @@
+//! This example shows the virtual list widget over 100 000 rows of varying height. Click, shift-click
+//! and ctrl-click rows to select them, or move the selection with the arrow and page keys.
+//!
+//! With `--bench` it scrolls through the whole list on its own (first in large jumps, which rebuild
+//! every visible row each frame, then smoothly), prints frame time statistics and exits. With
+//! `--headless` as well it runs frames back to back without a window or a GPU, so the times cover
+//! the list, layout and text but not rendering:
+//!
+//! ```sh
+//! cargo run --release --bin virtual_list -- --bench
+//! cargo run --release --bin virtual_list -- --bench --headless
+//! ```
+use bevy::app::ScheduleRunnerPlugin;
+use bevy::prelude::*;
+use bevy::window::PresentMode;
+use bevy_container::automation::default_plugins;
+use bevy_container::theme::Theme;
+use bevy_container::widgets::scroll_view::ScrollMetrics;
+use bevy_container::widgets::virtual_list::{
+    ListSource, SelectionChanged, SelectionMode, VirtualList, VirtualListPlugin, spawn_virtual_list,
+};
+use std::time::Duration;
+
+const ITEMS: usize = 100_000;
+const WARMUP_FRAMES: usize = 30;
+/// Frames spent jumping from the top to the bottom of the list.
+const JUMP_FRAMES: usize = 600;
+/// Frames spent scrolling smoothly afterwards, at `SMOOTH_SPEED` pixels per frame.
+const SMOOTH_FRAMES: usize = 600;
+const SMOOTH_SPEED: f32 = 15.;
+
+fn main() -> AppExit {
+    let bench = std::env::args().any(|arg| arg == "--bench");
+    let headless = std::env::args().any(|arg| arg == "--headless");
+    let mut plugins = default_plugins().set(WindowPlugin {
+        primary_window: Some(Window {
+            title: "virtual list".into(),
+            // measure frame times, not the display's refresh rate
+            present_mode: if bench {
+                PresentMode::AutoNoVsync
+            } else {
+                PresentMode::AutoVsync
+            },
+            ..default()
+        }),
+        ..default()
+    });
+    if bench && headless {
+        plugins = plugins.set(ScheduleRunnerPlugin::run_loop(Duration::ZERO));
+    }
+    let mut app = App::new();
+    app.add_plugins(plugins)
+        .add_plugins(VirtualListPlugin)
+        .add_systems(Startup, setup)
+        .add_systems(Update, print_selection);
+    if bench {
+        app.init_resource::<Benchmark>()
+            .add_systems(Update, run_benchmark);
+    }
+    app.run()
+}
+
+struct Items {
+    count: usize,
+    font: TextFont,
+    color: TextColor,
+    muted: TextColor,
+}
+
+impl Items {
+    fn has_details(index: usize) -> bool {
+        index % 4 == 3
+    }
+}
+
+impl ListSource for Items {
+    fn len(&self) -> usize {
+        self.count
+    }
+
+    fn row_height(&self, index: usize) -> f32 {
+        if Self::has_details(index) { 48. } else { 28. }
+    }
+
+    fn build_row(&self, index: usize, row: &mut ChildBuilder) {
+        row.spawn((
+            Text::new(format!("Item {index}")),
+            self.font.clone(),
+            self.color,
+        ));
+        if Self::has_details(index) {
+            row.spawn((
+                Text::new(format!(
+                    "every fourth row is taller; this one is {}",
+                    index / 4
+                )),
+                self.font.clone().with_font_size(self.font.font_size * 0.8),
+                self.muted,
+            ));
+        }
+    }
+}
+
+#[derive(Component)]
+struct ItemList;
+
+fn setup(mut commands: Commands, theme: Res<Theme>) {
+    commands.spawn(Camera2d);
+    let items = Items {
+        count: ITEMS,
+        font: theme.text_font(),
+        color: TextColor(theme.text),
+        muted: TextColor(theme.text_muted),
+    };
+    commands
+        .spawn((
+            Node {
+                width: Val::Percent(100.),
+                height: Val::Percent(100.),
+                padding: UiRect::all(Val::Px(20.)),
+                ..default()
+            },
+            BackgroundColor(theme.background),
+            Name::new("root"),
+        ))
+        .with_children(|parent| {
+            let list = spawn_virtual_list(
+                parent,
+                VirtualList::new(items).with_selection_mode(SelectionMode::Multiple),
+                (
+                    Node {
+                        width: Val::Px(400.),
+                        height: Val::Percent(100.),
+                        ..default()
+                    },
+                    Name::new("item_list"),
+                ),
+            );
+            parent.enqueue_command(move |world: &mut World| {
+                world.entity_mut(list).insert(ItemList);
+            });
+        });
+}
+
+fn print_selection(mut changes: EventReader<SelectionChanged>, lists: Query<&VirtualList>) {
+    for change in changes.read() {
+        let Ok(list) = lists.get(change.list) else {
+            continue;
+        };
+        let selected: Vec<usize> = list.selection.iter().collect();
+        println!("selected {selected:?} (current {:?})", change.current);
+    }
+}
+
+#[derive(Resource, Default)]
+struct Benchmark {
+    frame: usize,
+    frame_times: Vec<f32>,
+    max_row_entities: usize,
+}
+
+fn run_benchmark(
+    time: Res<Time>,
+    mut benchmark: ResMut<Benchmark>,
+    mut lists: Query<(&VirtualList, &ScrollMetrics, &mut ScrollPosition), With<ItemList>>,
+    mut exit: EventWriter<AppExit>,
+) {
+    let Ok((list, metrics, mut position)) = lists.get_single_mut() else {
+        return;
+    };
+    benchmark.frame += 1;
+    let frame = benchmark.frame;
+    if frame <= WARMUP_FRAMES {
+        return;
+    }
+    benchmark.frame_times.push(time.delta_secs() * 1000.);
+    benchmark.max_row_entities = benchmark.max_row_entities.max(list.row_entities());
+
+    let max = metrics.max_offset().y;
+    let step = frame - WARMUP_FRAMES;
+    if step <= JUMP_FRAMES {
+        position.offset_y = max * step as f32 / JUMP_FRAMES as f32;
+    } else if step <= JUMP_FRAMES + SMOOTH_FRAMES {
+        position.offset_y = (max / 2. + (step - JUMP_FRAMES) as f32 * SMOOTH_SPEED).min(max);
+    } else {
+        benchmark.report();
+        exit.send(AppExit::Success);
+    }
+}
+
+impl Benchmark {
+    fn report(&self) {
+        let mut times = self.frame_times.clone();
+        times.sort_by(f32::total_cmp);
+        let average = times.iter().sum::<f32>() / times.len() as f32;
+        let percentile = |p: f32| times[((times.len() - 1) as f32 * p) as usize];
+        println!("{} frames over {ITEMS} items", times.len());
+        println!(
+            "frame time: average {average:.2} ms, median {:.2} ms, p95 {:.2} ms, p99 {:.2} ms, max {:.2} ms",
+            percentile(0.5),
+            percentile(0.95),
+            percentile(0.99),
+            times.last().copied().unwrap_or_default(),
+        );
+        println!("average fps: {:.0}", 1000. / average);
+        println!("row entities: {} at most", self.max_row_entities);
+    }
+}
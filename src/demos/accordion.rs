@@ -0,0 +1,201 @@
+//! The accordion demo: a long settings form grouped into collapsible sections, one accordion
+//! keeping a single section open and one letting several be open. The open sections are kept in
+//! [`CollapsibleStore`](crate::widgets::collapsible::CollapsibleStore), so inserting one loaded
+//! from a file brings them back. `accordion` runs it, tests drive it through
+//! [`Automation`](crate::automation::Automation).
+
+use crate::theme::Theme;
+use crate::widgets::checkbox::{Checkbox, CheckboxPlugin, spawn_checkbox};
+use crate::widgets::collapsible::{
+    Accordion, Collapsible, CollapsiblePlugin, CollapsibleToggled, spawn_accordion,
+    spawn_collapsible,
+};
+use crate::widgets::toggle_switch::{ToggleSwitch, ToggleSwitchPlugin, spawn_toggle_switch};
+use bevy::prelude::*;
+
+pub struct AccordionDemoPlugin;
+
+impl Plugin for AccordionDemoPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_plugins((CollapsiblePlugin, CheckboxPlugin, ToggleSwitchPlugin))
+            .add_systems(Startup, setup)
+            .add_systems(Update, (rebuild_form, print_toggles, show_open_sections));
+    }
+}
+
+#[derive(Component)]
+struct Form;
+
+#[derive(Component)]
+struct RebuildButton;
+
+#[derive(Component)]
+struct Status;
+
+fn setup(mut commands: Commands, theme: Res<Theme>) {
+    commands.spawn(Camera2d);
+    commands
+        .spawn((
+            Node {
+                width: Val::Percent(100.),
+                height: Val::Percent(100.),
+                flex_direction: FlexDirection::Column,
+                row_gap: Val::Px(20.),
+                padding: UiRect::all(Val::Px(20.)),
+                ..default()
+            },
+            BackgroundColor(theme.background),
+            Name::new("root"),
+        ))
+        .with_children(|parent| {
+            parent
+                .spawn(Node {
+                    align_items: AlignItems::Center,
+                    column_gap: Val::Px(20.),
+                    ..default()
+                })
+                .with_children(|parent| {
+                    parent
+                        .spawn((
+                            Button,
+                            Node {
+                                padding: UiRect::axes(Val::Px(14.), Val::Px(8.)),
+                                ..default()
+                            },
+                            BackgroundColor(theme.surface),
+                            BorderRadius::all(Val::Px(4.)),
+                            RebuildButton,
+                            Name::new("rebuild"),
+                        ))
+                        .with_child((
+                            Text::new("Rebuild"),
+                            theme.text_font(),
+                            TextColor(theme.text),
+                        ));
+                    parent.spawn((
+                        Text::default(),
+                        theme.text_font(),
+                        TextColor(theme.text_muted),
+                        Status,
+                        Name::new("status"),
+                    ));
+                });
+            parent.spawn((
+                Node {
+                    column_gap: Val::Px(20.),
+                    align_items: AlignItems::Start,
+                    ..default()
+                },
+                Form,
+                Name::new("form"),
+            ));
+        });
+}
+
+fn build_form(form: &mut ChildBuilder, theme: &Theme) {
+    let column = || Node {
+        width: Val::Px(360.),
+        ..default()
+    };
+    let section = |parent: &mut ChildBuilder, title: &str, open: bool, name: &str| {
+        spawn_collapsible(
+            parent,
+            Collapsible::new(title, open),
+            Name::new(name.to_string()),
+            theme,
+            |body| match title {
+                "Profile" => {
+                    for line in ["Name: Alice", "Email: alice@example.com"] {
+                        body.spawn((Text::new(line), theme.text_font(), TextColor(theme.text)));
+                    }
+                    spawn_toggle_switch(body, ToggleSwitch::new(true), "Public profile", theme);
+                }
+                "Notifications" => {
+                    for label in ["Email", "Push", "SMS"] {
+                        spawn_checkbox(body, Checkbox::new(label != "SMS"), label, theme);
+                    }
+                }
+                "Privacy" => {
+                    spawn_checkbox(body, Checkbox::new(false), "Share usage data", theme);
+                    spawn_toggle_switch(body, ToggleSwitch::new(true), "Private messages", theme);
+                }
+                _ => {
+                    body.spawn((
+                        Text::new(format!("{title} settings")),
+                        theme.text_font(),
+                        TextColor(theme.text_muted),
+                    ));
+                }
+            },
+        );
+    };
+    spawn_accordion(
+        form,
+        Accordion::single().with_persist_key("account"),
+        (column(), Name::new("account")),
+        |parent| {
+            section(parent, "Profile", true, "profile");
+            section(parent, "Notifications", false, "notifications");
+            section(parent, "Privacy", false, "privacy");
+        },
+    );
+    spawn_accordion(
+        form,
+        Accordion::multiple(),
+        (column(), Name::new("advanced")),
+        |parent| {
+            section(parent, "Display", true, "display");
+            section(parent, "Network", false, "network");
+            section(parent, "Storage", false, "storage");
+        },
+    );
+}
+
+/// Builds the form at startup and again when "Rebuild" is pressed.
+fn rebuild_form(
+    mut commands: Commands,
+    theme: Res<Theme>,
+    forms: Query<Entity, With<Form>>,
+    buttons: Query<&Interaction, (Changed<Interaction>, With<RebuildButton>)>,
+    mut built: Local<bool>,
+) {
+    let pressed = buttons
+        .iter()
+        .any(|interaction| *interaction == Interaction::Pressed);
+    if *built && !pressed {
+        return;
+    }
+    *built = true;
+    for form in &forms {
+        commands
+            .entity(form)
+            .despawn_descendants()
+            .with_children(|form| build_form(form, &theme));
+    }
+}
+
+fn print_toggles(mut toggled: EventReader<CollapsibleToggled>, names: Query<&Name>) {
+    for event in toggled.read() {
+        let name = names.get(event.section).map_or("?", Name::as_str);
+        let state = if event.open { "opened" } else { "closed" };
+        println!("{state} {name}");
+    }
+}
+
+fn show_open_sections(
+    sections: Query<(&Collapsible, &Name)>,
+    mut status: Query<&mut Text, With<Status>>,
+) {
+    let mut open: Vec<&str> = sections
+        .iter()
+        .filter(|(section, _)| section.open)
+        .map(|(_, name)| name.as_str())
+        .collect();
+    open.sort();
+    let message = format!("open: {}", open.join(", "));
+    for mut text in &mut status {
+        if text.0 != message {
+            text.0 = message.clone();
+        }
+    }
+}
@@ -0,0 +1,440 @@
+//! The auth demo: registration and login screens on the [`auth`](crate::auth) module, with
+//! dashboard and account screens that only exist while someone is logged in. The accounts are kept
+//! in the [`CredentialStore`], which only lasts the run unless one loaded from a file is inserted.
+//! `auth` runs it, tests drive it through [`Automation`](crate::automation::Automation).
+
+use crate::auth::{
+    AuthError, AuthPlugin, AuthState, CredentialStore, LoginThrottle, Session, hash_password,
+    validate_registration, verify_password,
+};
+use crate::task::{Loading, SpawnTaskExt, TaskAppExt, TaskFinished, TaskPlugin};
+use crate::theme::Theme;
+use bevy::prelude::*;
+use bevy_simple_text_input::{
+    TextInput, TextInputInactive, TextInputPlaceholder, TextInputPlugin, TextInputSettings,
+    TextInputSystem, TextInputTextColor, TextInputTextFont, TextInputValue,
+};
+
+pub struct AuthDemoPlugin;
+
+impl Plugin for AuthDemoPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_plugins(TextInputPlugin)
+            .add_plugins(AuthPlugin)
+            .add_plugins(TaskPlugin)
+            .add_task_result::<LoginAttempt>()
+            .add_task_result::<Registration>()
+            .add_sub_state::<Gate>()
+            .add_sub_state::<Home>()
+            .enable_state_scoped_entities::<Gate>()
+            .enable_state_scoped_entities::<Home>()
+            .add_systems(Startup, setup)
+            .add_systems(OnEnter(Gate::Login), spawn_login)
+            .add_systems(OnEnter(Gate::Register), spawn_register)
+            .add_systems(OnEnter(Home::Dashboard), spawn_dashboard)
+            .add_systems(OnEnter(Home::Account), spawn_account)
+            .add_systems(Update, focus_inputs.before(TextInputSystem))
+            .add_systems(
+                Update,
+                (
+                    press_buttons,
+                    open_account,
+                    finish_logins,
+                    finish_registrations,
+                )
+                    .chain(),
+            );
+    }
+}
+
+/// Screens reachable while logged out.
+#[derive(SubStates, Clone, Copy, PartialEq, Eq, Hash, Debug, Default)]
+#[source(AuthState = AuthState::LoggedOut)]
+enum Gate {
+    #[default]
+    Login,
+    Register,
+}
+
+/// Screens reachable while logged in.
+#[derive(SubStates, Clone, Copy, PartialEq, Eq, Hash, Debug, Default)]
+#[source(AuthState = AuthState::LoggedIn)]
+enum Home {
+    #[default]
+    Dashboard,
+    Account,
+}
+
+#[derive(Component, Clone, Copy, PartialEq, Eq, Debug)]
+enum Field {
+    Username,
+    Password,
+    Confirmation,
+}
+
+#[derive(Component, Clone, Copy, PartialEq, Eq, Debug)]
+enum Action {
+    LogIn,
+    Register,
+    ShowLogin,
+    ShowRegister,
+    ShowDashboard,
+    ShowAccount,
+    LogOut,
+}
+
+#[derive(Component)]
+struct Message;
+
+/// What a login task found out about the password.
+struct LoginAttempt {
+    username: String,
+    result: Result<(), AuthError>,
+}
+
+/// A new user with their hashed password, to add to the store.
+struct Registration {
+    username: String,
+    hash: String,
+}
+
+fn setup(mut commands: Commands) {
+    commands.spawn(Camera2d);
+}
+
+fn screen(theme: &Theme, name: &str) -> impl Bundle {
+    (
+        Node {
+            width: Val::Percent(100.),
+            height: Val::Percent(100.),
+            flex_direction: FlexDirection::Column,
+            justify_content: JustifyContent::Center,
+            align_items: AlignItems::Center,
+            row_gap: Val::Px(12.),
+            ..default()
+        },
+        BackgroundColor(theme.background),
+        // pressing outside the inputs takes the focus away from them
+        Interaction::None,
+        Name::new(name.to_string()),
+    )
+}
+
+fn title(parent: &mut ChildBuilder, theme: &Theme, text: impl Into<String>, name: &str) {
+    parent.spawn((
+        Text::new(text),
+        TextFont {
+            font_size: theme.font_size * 1.4,
+            ..default()
+        },
+        TextColor(theme.text),
+        Name::new(name.to_string()),
+    ));
+}
+
+fn input(parent: &mut ChildBuilder, theme: &Theme, field: Field) {
+    let (placeholder, name) = match field {
+        Field::Username => ("Username", "username"),
+        Field::Password => ("Password", "password"),
+        Field::Confirmation => ("Confirm password", "confirmation"),
+    };
+    parent.spawn((
+        Node {
+            width: Val::Px(260.),
+            border: UiRect::all(Val::Px(2.)),
+            padding: UiRect::all(Val::Px(6.)),
+            ..default()
+        },
+        BorderColor(if field == Field::Username {
+            theme.accent
+        } else {
+            theme.border
+        }),
+        BackgroundColor(theme.surface),
+        BorderRadius::all(Val::Px(4.)),
+        TextInput,
+        TextInputValue(String::new()),
+        TextInputTextFont(theme.text_font()),
+        TextInputTextColor(TextColor(theme.text)),
+        TextInputPlaceholder {
+            value: placeholder.to_string(),
+            ..default()
+        },
+        TextInputSettings {
+            mask_character: (field != Field::Username).then_some('*'),
+            retain_on_submit: true,
+        },
+        TextInputInactive(field != Field::Username),
+        field,
+        Name::new(name),
+    ));
+}
+
+fn button(parent: &mut ChildBuilder, theme: &Theme, label: &str, action: Action) {
+    parent
+        .spawn((
+            Button,
+            Node {
+                min_width: Val::Px(120.),
+                padding: UiRect::axes(Val::Px(16.), Val::Px(6.)),
+                justify_content: JustifyContent::Center,
+                align_items: AlignItems::Center,
+                ..default()
+            },
+            BackgroundColor(if matches!(action, Action::LogIn | Action::Register) {
+                theme.accent
+            } else {
+                theme.surface
+            }),
+            BorderRadius::all(Val::Px(4.)),
+            action,
+        ))
+        .with_child((Text::new(label), theme.text_font(), TextColor(theme.text)));
+}
+
+fn message(parent: &mut ChildBuilder, theme: &Theme) {
+    parent.spawn((
+        Text::default(),
+        theme.text_font(),
+        TextColor(theme.error),
+        Message,
+        Name::new("message"),
+    ));
+}
+
+fn spawn_login(mut commands: Commands, theme: Res<Theme>) {
+    commands
+        .spawn((screen(&theme, "login_screen"), StateScoped(Gate::Login)))
+        .with_children(|parent| {
+            title(parent, &theme, "Log in to your account", "title");
+            input(parent, &theme, Field::Username);
+            input(parent, &theme, Field::Password);
+            button(parent, &theme, "Log in", Action::LogIn);
+            button(parent, &theme, "Create account", Action::ShowRegister);
+            message(parent, &theme);
+        });
+}
+
+fn spawn_register(mut commands: Commands, theme: Res<Theme>) {
+    commands
+        .spawn((
+            screen(&theme, "register_screen"),
+            StateScoped(Gate::Register),
+        ))
+        .with_children(|parent| {
+            title(parent, &theme, "Create an account", "title");
+            input(parent, &theme, Field::Username);
+            input(parent, &theme, Field::Password);
+            input(parent, &theme, Field::Confirmation);
+            button(parent, &theme, "Register", Action::Register);
+            button(parent, &theme, "Back to login", Action::ShowLogin);
+            message(parent, &theme);
+        });
+}
+
+fn spawn_dashboard(mut commands: Commands, theme: Res<Theme>, session: Res<Session>) {
+    let user = session.user().unwrap_or_default().to_string();
+    commands
+        .spawn((
+            screen(&theme, "dashboard_screen"),
+            StateScoped(Home::Dashboard),
+        ))
+        .with_children(|parent| {
+            title(parent, &theme, format!("Welcome, {user}"), "welcome");
+            button(parent, &theme, "Account", Action::ShowAccount);
+            button(parent, &theme, "Log out", Action::LogOut);
+        });
+}
+
+fn spawn_account(mut commands: Commands, theme: Res<Theme>, session: Res<Session>) {
+    let user = session.user().unwrap_or_default().to_string();
+    commands
+        .spawn((screen(&theme, "account_screen"), StateScoped(Home::Account)))
+        .with_children(|parent| {
+            title(parent, &theme, "Account", "title");
+            parent.spawn((
+                Text::new(format!("Logged in as {user}")),
+                theme.text_font(),
+                TextColor(theme.text_muted),
+                Name::new("account_user"),
+            ));
+            button(parent, &theme, "Back", Action::ShowDashboard);
+            button(parent, &theme, "Log out", Action::LogOut);
+        });
+}
+
+/// Gives the keyboard focus to the input that was pressed, or takes it away.
+fn focus_inputs(
+    theme: Res<Theme>,
+    pressed: Query<(Entity, &Interaction), Changed<Interaction>>,
+    mut inputs: Query<(Entity, &mut TextInputInactive, &mut BorderColor)>,
+) {
+    for (pressed, interaction) in &pressed {
+        if *interaction != Interaction::Pressed {
+            continue;
+        }
+        for (input, mut inactive, mut border) in &mut inputs {
+            inactive.0 = input != pressed;
+            border.0 = if inactive.0 {
+                theme.border
+            } else {
+                theme.accent
+            };
+        }
+    }
+}
+
+fn show(messages: &mut Query<&mut Text, With<Message>>, text: impl Into<String>) {
+    let text = text.into();
+    println!("{text}");
+    for mut message in messages {
+        message.0 = text.clone();
+    }
+}
+
+fn field_value(fields: &Query<(&Field, &TextInputValue)>, wanted: Field) -> String {
+    fields
+        .iter()
+        .find(|(field, _)| **field == wanted)
+        .map(|(_, value)| value.0.clone())
+        .unwrap_or_default()
+}
+
+#[allow(clippy::type_complexity, clippy::too_many_arguments)]
+fn press_buttons(
+    mut commands: Commands,
+    time: Res<Time<Real>>,
+    buttons: Query<(Entity, &Interaction, &Action), (Changed<Interaction>, Without<Loading>)>,
+    fields: Query<(&Field, &TextInputValue)>,
+    store: Res<CredentialStore>,
+    throttle: Res<LoginThrottle>,
+    mut session: ResMut<Session>,
+    mut gate: ResMut<NextState<Gate>>,
+    mut home: ResMut<NextState<Home>>,
+    mut messages: Query<&mut Text, With<Message>>,
+) {
+    for (button, interaction, action) in &buttons {
+        if *interaction != Interaction::Pressed {
+            continue;
+        }
+        match action {
+            Action::LogIn => {
+                let username = field_value(&fields, Field::Username);
+                let password = field_value(&fields, Field::Password);
+                if let Err(err) = throttle.check(&username, time.elapsed_secs_f64()) {
+                    show(&mut messages, err.to_string());
+                    continue;
+                }
+                show(&mut messages, "Checking…");
+                let hash = store.hash(&username).map(str::to_owned);
+                commands.spawn_task(button, async move {
+                    let result = verify_password(hash.as_deref(), &password);
+                    Ok(LoginAttempt { username, result })
+                });
+            }
+            Action::Register => {
+                let username = field_value(&fields, Field::Username);
+                let password = field_value(&fields, Field::Password);
+                let confirmation = field_value(&fields, Field::Confirmation);
+                let checked =
+                    validate_registration(&username, &password, &confirmation).and_then(|()| {
+                        if store.contains(&username) {
+                            Err(AuthError::UsernameTaken)
+                        } else {
+                            Ok(())
+                        }
+                    });
+                if let Err(err) = checked {
+                    show(&mut messages, err.to_string());
+                    continue;
+                }
+                show(&mut messages, "Creating the account…");
+                commands.spawn_task(button, async move {
+                    let hash = hash_password(&password).map_err(|err| err.to_string())?;
+                    Ok(Registration { username, hash })
+                });
+            }
+            Action::ShowLogin => gate.set(Gate::Login),
+            Action::ShowRegister => gate.set(Gate::Register),
+            Action::ShowDashboard => home.set(Home::Dashboard),
+            Action::ShowAccount => home.set(Home::Account),
+            Action::LogOut => {
+                println!("logged out");
+                session.log_out();
+            }
+        }
+    }
+}
+
+/// F2 asks for the account screen, which only exists while logged in.
+fn open_account(
+    keys: Res<ButtonInput<KeyCode>>,
+    session: Res<Session>,
+    mut home: ResMut<NextState<Home>>,
+    mut messages: Query<&mut Text, With<Message>>,
+) {
+    if !keys.just_pressed(KeyCode::F2) {
+        return;
+    }
+    if session.is_authenticated() {
+        home.set(Home::Account);
+    } else {
+        show(&mut messages, "Log in to open your account");
+    }
+}
+
+fn finish_logins(
+    time: Res<Time<Real>>,
+    mut finished: EventReader<TaskFinished<LoginAttempt>>,
+    mut throttle: ResMut<LoginThrottle>,
+    mut session: ResMut<Session>,
+    mut messages: Query<&mut Text, With<Message>>,
+) {
+    for event in finished.read() {
+        // task errors are reported by TaskPlugin
+        let Ok(attempt) = &event.result else {
+            continue;
+        };
+        match &attempt.result {
+            Ok(()) => {
+                println!("logged in as {}", attempt.username);
+                throttle.succeed(&attempt.username);
+                session.log_in(attempt.username.clone());
+            }
+            Err(err) => {
+                let now = time.elapsed_secs_f64();
+                throttle.fail(&attempt.username, now);
+                let message = match throttle.check(&attempt.username, now) {
+                    Err(locked @ AuthError::LockedOut { .. }) => locked.to_string(),
+                    _ => match throttle.attempts_left(&attempt.username) {
+                        1 => format!("{err}, 1 attempt left"),
+                        left => format!("{err}, {left} attempts left"),
+                    },
+                };
+                show(&mut messages, message);
+            }
+        }
+    }
+}
+
+fn finish_registrations(
+    mut finished: EventReader<TaskFinished<Registration>>,
+    mut store: ResMut<CredentialStore>,
+    mut throttle: ResMut<LoginThrottle>,
+    mut session: ResMut<Session>,
+    mut messages: Query<&mut Text, With<Message>>,
+) {
+    for event in finished.read() {
+        let Ok(registration) = &event.result else {
+            continue;
+        };
+        match store.add(&registration.username, registration.hash.clone()) {
+            Ok(()) => {
+                println!("registered {}", registration.username);
+                throttle.succeed(&registration.username);
+                session.log_in(registration.username.clone());
+            }
+            Err(err) => show(&mut messages, err.to_string()),
+        }
+    }
+}
@@ -0,0 +1,201 @@
+//! The confirmation demo: a "Delete file" button and a modal dialog confirming it, with the
+//! outcome in a status text and a toast that can undo the deletion. `confirmation_example` runs it
+//! with the dev tools, tests drive it through [`Automation`](crate::automation::Automation).
+
+use crate::theme::Theme;
+use crate::widgets::modal::{Modal, ModalAction, ModalClosed, ModalPlugin, spawn_modal};
+use crate::widgets::toast::{Toast, ToastActionPressed, ToastId, ToastPlugin, Toasts};
+use bevy::color::palettes::css::{BLUE, GREY, WHITE};
+use bevy::color::palettes::tailwind::CYAN_400;
+use bevy::prelude::*;
+
+pub struct ConfirmationDemoPlugin;
+
+impl Plugin for ConfirmationDemoPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_plugins(ModalPlugin)
+            .add_plugins(ToastPlugin)
+            .insert_resource(SelectedButton::None)
+            .add_systems(Startup, setup_ui)
+            .add_systems(
+                Update,
+                (tag_dialog_buttons, open_dialog_button, dialog_closed),
+            );
+    }
+}
+
+#[derive(Component)]
+pub struct ConfirmButton;
+
+#[derive(Component)]
+pub struct CancelButton;
+
+#[derive(Component)]
+struct OpenDialogButton;
+
+/// The button the dialog was last closed with.
+#[derive(Resource, Default, Debug)]
+pub enum SelectedButton {
+    Confirm,
+    Cancel,
+    #[default]
+    None,
+}
+
+#[derive(Component, Clone, Copy)]
+struct StatusText;
+
+fn setup_ui(mut commands: Commands, selected_button: Res<SelectedButton>, theme: Res<Theme>) {
+    commands.spawn(Camera2d);
+
+    // Define colors
+    let root_color = Color::srgb(0.1, 0.1, 0.1);
+
+    // Root node (acts like a full-screen container)
+    let main_container = commands
+        .spawn((
+            Node {
+                width: Val::Percent(100.),
+                height: Val::Percent(100.),
+                justify_content: JustifyContent::Center,
+                align_items: AlignItems::Center,
+                flex_direction: FlexDirection::Column,
+                row_gap: Val::Px(20.),
+                ..default()
+            },
+            BackgroundColor(root_color),
+            Name::new("root"),
+        ))
+        .id();
+
+    commands.entity(main_container).with_children(|parent| {
+        parent
+            .spawn((
+                Button,
+                Node {
+                    padding: UiRect::axes(Val::Px(14.), Val::Px(8.)),
+                    ..default()
+                },
+                BackgroundColor(BLUE.into()),
+                OpenDialogButton,
+                Name::new("open_dialog"),
+            ))
+            .with_child((
+                Text::new("Delete file"),
+                TextFont {
+                    font_size: 17.,
+                    ..default()
+                },
+                TextColor(WHITE.into()),
+            ));
+    });
+
+    selected_button_text_view(&mut commands, main_container, &selected_button);
+    open_dialog(&mut commands, &theme);
+}
+
+/// Opens the confirmation as a modal: Enter confirms, Escape cancels.
+fn open_dialog(commands: &mut Commands, theme: &Theme) {
+    spawn_modal(
+        commands,
+        Modal::new("Confirm action!", ["Cancel", "Confirm"])
+            .with_default(1)
+            .with_cancel(0),
+        Name::new("dialog"),
+        theme,
+        |parent| {
+            parent.spawn((
+                Text::new("The file will be deleted for good."),
+                TextFont {
+                    font_size: 17.,
+                    ..default()
+                },
+                TextColor(GREY.into()),
+                Name::new("message"),
+            ));
+        },
+    );
+}
+
+/// Marks the dialog's buttons so scripts and remote clients can find them by marker or name.
+fn tag_dialog_buttons(
+    mut commands: Commands,
+    actions: Query<(Entity, &ModalAction), Added<ModalAction>>,
+) {
+    for (entity, action) in &actions {
+        if action.index == 0 {
+            commands
+                .entity(entity)
+                .insert((CancelButton, Name::new("cancel_button")));
+        } else {
+            commands
+                .entity(entity)
+                .insert((ConfirmButton, Name::new("confirm_button")));
+        }
+    }
+}
+
+fn selected_button_text_view(
+    commands: &mut Commands,
+    main_container: Entity,
+    selected_button: &SelectedButton,
+) {
+    commands.entity(main_container).with_child((
+        Text::new(get_text_view(selected_button)),
+        TextFont {
+            font_size: 30.,
+            ..default()
+        },
+        TextColor(CYAN_400.into()),
+        StatusText,
+        Name::new("status_text"),
+    ));
+}
+
+fn get_text_view(currently_selected_option: &SelectedButton) -> String {
+    format!("selected action is ...  {:?}", currently_selected_option)
+}
+
+fn open_dialog_button(
+    mut commands: Commands,
+    theme: Res<Theme>,
+    buttons: Query<&Interaction, (Changed<Interaction>, With<OpenDialogButton>)>,
+) {
+    for interaction in &buttons {
+        if *interaction == Interaction::Pressed {
+            open_dialog(&mut commands, &theme);
+        }
+    }
+}
+
+fn dialog_closed(
+    mut closed: EventReader<ModalClosed>,
+    mut undo: EventReader<ToastActionPressed>,
+    mut toasts: ResMut<Toasts>,
+    mut undo_toast: Local<Option<ToastId>>,
+    mut text_query: Query<&mut Text, With<StatusText>>,
+    mut selected_button: ResMut<SelectedButton>,
+) {
+    let mut status_text = text_query.get_single_mut().unwrap();
+
+    for event in closed.read() {
+        if event.action == Some(1) {
+            println!("CONFIRM CLICKED!");
+            *selected_button = SelectedButton::Confirm;
+            *undo_toast = Some(toasts.push(Toast::success("File deleted").with_action("Undo")));
+        } else {
+            println!("CANCEL CLICKED!");
+            *selected_button = SelectedButton::Cancel;
+            toasts.push(Toast::info("Nothing was deleted"));
+        }
+        status_text.0 = get_text_view(&selected_button);
+    }
+    for event in undo.read() {
+        if *undo_toast == Some(event.toast) {
+            println!("UNDO CLICKED!");
+            *selected_button = SelectedButton::None;
+            toasts.push(Toast::info("File restored"));
+            status_text.0 = get_text_view(&selected_button);
+        }
+    }
+}
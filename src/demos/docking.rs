@@ -0,0 +1,383 @@
+//! The docking demo: an IDE-like dock of an inspector, a counter, notes and a log console in tab
+//! groups, saving the layout to the [`DockLayoutStore`] on every change and restoring it from there
+//! on startup. The store is path-less unless one loaded from a file is inserted. `docking` runs
+//! it, tests drive it through [`Automation`](crate::automation::Automation).
+
+use crate::theme::Theme;
+use crate::widgets::dock::{
+    DockArea, DockLayout, DockLayoutChanged, DockLayoutStore, DockNode, DockPanel, DockPlugin,
+    DockZone, PanelMoved, PanelPlace, spawn_dock_area,
+};
+use crate::widgets::split_pane::SplitDirection;
+use bevy::prelude::*;
+
+pub struct DockingDemoPlugin;
+
+impl Plugin for DockingDemoPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_plugins(DockPlugin)
+            .init_resource::<DockLayoutStore>()
+            .init_resource::<Count>()
+            .init_resource::<Log>()
+            .add_systems(Startup, setup)
+            .add_systems(
+                Update,
+                (
+                    count,
+                    reset_layout,
+                    log_moves,
+                    save_layout,
+                    show_layout,
+                    show_count,
+                    show_log,
+                )
+                    .chain(),
+            );
+    }
+}
+
+/// Lines kept in the log console.
+const LOG_LINES: usize = 8;
+
+#[derive(Resource, Default)]
+struct Count(i32);
+
+#[derive(Resource, Default)]
+struct Log(Vec<String>);
+
+impl Log {
+    fn push(&mut self, line: String) {
+        println!("{line}");
+        self.0.push(line);
+        if self.0.len() > LOG_LINES {
+            self.0.remove(0);
+        }
+    }
+}
+
+#[derive(Component)]
+struct CountButton(i32);
+
+#[derive(Component)]
+struct ResetButton;
+
+#[derive(Component)]
+struct LayoutText;
+
+#[derive(Component)]
+struct CountText;
+
+#[derive(Component)]
+struct LogText;
+
+fn default_layout() -> DockLayout {
+    DockLayout::new(DockNode::split(
+        SplitDirection::Horizontal,
+        0.25,
+        DockNode::tabs(["inspector"]),
+        DockNode::split(
+            SplitDirection::Vertical,
+            0.65,
+            DockNode::tabs(["counter", "notes"]),
+            DockNode::tabs(["console"]),
+        ),
+    ))
+}
+
+fn panels(theme: &Theme) -> Vec<DockPanel> {
+    let padded = || Node {
+        flex_direction: FlexDirection::Column,
+        row_gap: Val::Px(6.),
+        padding: UiRect::all(Val::Px(12.)),
+        ..default()
+    };
+    let inspector = {
+        let theme = theme.clone();
+        move |panel: &mut ChildBuilder| {
+            panel.spawn(padded()).with_child((
+                Text::default(),
+                theme.text_font(),
+                TextColor(theme.text_muted),
+                LayoutText,
+                Name::new("layout"),
+            ));
+        }
+    };
+    let counter = {
+        let theme = theme.clone();
+        move |panel: &mut ChildBuilder| {
+            panel
+                .spawn(Node {
+                    flex_grow: 1.,
+                    justify_content: JustifyContent::Center,
+                    align_items: AlignItems::Center,
+                    column_gap: Val::Px(16.),
+                    ..default()
+                })
+                .with_children(|parent| {
+                    for (label, step) in [("-", -1), ("+", 1)] {
+                        parent
+                            .spawn((
+                                Button,
+                                Node {
+                                    width: Val::Px(40.),
+                                    height: Val::Px(40.),
+                                    justify_content: JustifyContent::Center,
+                                    align_items: AlignItems::Center,
+                                    ..default()
+                                },
+                                BackgroundColor(theme.surface_hovered),
+                                BorderRadius::all(Val::Px(4.)),
+                                CountButton(step),
+                            ))
+                            .with_child((
+                                Text::new(label),
+                                theme.text_font(),
+                                TextColor(theme.text),
+                            ));
+                        if step < 0 {
+                            parent.spawn((
+                                Text::new("0"),
+                                theme.text_font(),
+                                TextColor(theme.text),
+                                CountText,
+                                Name::new("count"),
+                            ));
+                        }
+                    }
+                });
+        }
+    };
+    let notes = {
+        let theme = theme.clone();
+        move |panel: &mut ChildBuilder| {
+            panel.spawn(padded()).with_children(|parent| {
+                for line in [
+                    "Drag a tab onto a group to dock it.",
+                    "Drop it elsewhere to float it.",
+                ] {
+                    parent.spawn((Text::new(line), theme.text_font(), TextColor(theme.text)));
+                }
+            });
+        }
+    };
+    let console = {
+        let theme = theme.clone();
+        move |panel: &mut ChildBuilder| {
+            panel.spawn(padded()).with_child((
+                Text::default(),
+                theme.text_font(),
+                TextColor(theme.text_muted),
+                LogText,
+                Name::new("log"),
+            ));
+        }
+    };
+    vec![
+        DockPanel::new("inspector", "Inspector", inspector),
+        DockPanel::new("counter", "Counter", counter),
+        DockPanel::new("notes", "Notes", notes),
+        DockPanel::new("console", "Console", console),
+    ]
+}
+
+fn setup(
+    mut commands: Commands,
+    theme: Res<Theme>,
+    store: Res<DockLayoutStore>,
+    mut log: ResMut<Log>,
+) {
+    commands.spawn(Camera2d);
+    let layout = match store.layout() {
+        Some(layout) => {
+            log.push("restored the saved layout".to_string());
+            layout.clone()
+        }
+        None => default_layout(),
+    };
+    let dock = DockArea::new(panels(&theme), layout);
+    commands
+        .spawn((
+            Node {
+                width: Val::Percent(100.),
+                height: Val::Percent(100.),
+                flex_direction: FlexDirection::Column,
+                ..default()
+            },
+            BackgroundColor(theme.background),
+            Name::new("root"),
+        ))
+        .with_children(|parent| {
+            parent
+                .spawn(Node {
+                    flex_shrink: 0.,
+                    padding: UiRect::all(Val::Px(6.)),
+                    ..default()
+                })
+                .with_children(|parent| {
+                    parent
+                        .spawn((
+                            Button,
+                            Node {
+                                padding: UiRect::axes(Val::Px(12.), Val::Px(4.)),
+                                ..default()
+                            },
+                            BackgroundColor(theme.surface),
+                            BorderRadius::all(Val::Px(4.)),
+                            ResetButton,
+                            Name::new("reset"),
+                        ))
+                        .with_child((
+                            Text::new("Reset layout"),
+                            theme.text_font(),
+                            TextColor(theme.text),
+                        ));
+                });
+            spawn_dock_area(
+                parent,
+                dock,
+                (
+                    Node {
+                        flex_grow: 1.,
+                        min_height: Val::Px(0.),
+                        ..default()
+                    },
+                    Name::new("dock"),
+                ),
+            );
+        });
+}
+
+fn count(
+    buttons: Query<(&Interaction, &CountButton), Changed<Interaction>>,
+    mut count: ResMut<Count>,
+) {
+    for (interaction, button) in &buttons {
+        if *interaction == Interaction::Pressed {
+            count.0 += button.0;
+        }
+    }
+}
+
+fn reset_layout(
+    buttons: Query<&Interaction, (Changed<Interaction>, With<ResetButton>)>,
+    mut docks: Query<(Entity, &mut DockArea)>,
+    mut changed: EventWriter<DockLayoutChanged>,
+    mut log: ResMut<Log>,
+) {
+    if !buttons
+        .iter()
+        .any(|interaction| *interaction == Interaction::Pressed)
+    {
+        return;
+    }
+    for (entity, mut dock) in &mut docks {
+        dock.set_layout(default_layout());
+        changed.send(DockLayoutChanged { dock: entity });
+    }
+    log.push("layout reset".to_string());
+}
+
+fn log_moves(mut moved: EventReader<PanelMoved>, mut log: ResMut<Log>) {
+    for event in moved.read() {
+        let line = match &event.place {
+            PanelPlace::Floating => format!("{} floating", event.panel),
+            PanelPlace::Docked { target: None, zone } => {
+                format!("{} docked {}", event.panel, zone_name(*zone))
+            }
+            PanelPlace::Docked {
+                target: Some(target),
+                zone: DockZone::Center,
+            } => format!("{} docked with {target}", event.panel),
+            PanelPlace::Docked {
+                target: Some(target),
+                zone,
+            } => format!("{} docked {} of {target}", event.panel, zone_name(*zone)),
+        };
+        log.push(line);
+    }
+}
+
+fn zone_name(zone: DockZone) -> String {
+    format!("{zone:?}").to_lowercase()
+}
+
+fn save_layout(
+    mut store: ResMut<DockLayoutStore>,
+    mut changed: EventReader<DockLayoutChanged>,
+    docks: Query<&DockArea>,
+) {
+    for event in changed.read() {
+        if let Ok(dock) = docks.get(event.dock) {
+            store.save(dock.layout());
+        }
+    }
+}
+
+/// Shows the layout as an indented tree in the inspector.
+fn show_layout(
+    docks: Query<&DockArea, Changed<DockArea>>,
+    mut texts: Query<&mut Text, With<LayoutText>>,
+) {
+    fn describe(node: &DockNode, depth: usize, lines: &mut Vec<String>) {
+        let indent = "  ".repeat(depth);
+        match node {
+            DockNode::Tabs { panels, active } => {
+                let tabs: Vec<String> = panels
+                    .iter()
+                    .enumerate()
+                    .map(|(index, panel)| {
+                        if index == *active {
+                            format!("[{panel}]")
+                        } else {
+                            panel.clone()
+                        }
+                    })
+                    .collect();
+                lines.push(format!("{indent}tabs {}", tabs.join(" ")));
+            }
+            DockNode::Split {
+                direction,
+                ratio,
+                first,
+                second,
+            } => {
+                lines.push(
+                    format!("{indent}{direction:?} split {:.0}%", ratio * 100.).to_lowercase(),
+                );
+                describe(first, depth + 1, lines);
+                describe(second, depth + 1, lines);
+            }
+        }
+    }
+    for dock in &docks {
+        let mut lines = Vec::new();
+        if let Some(root) = &dock.layout().root {
+            describe(root, 0, &mut lines);
+        }
+        for floating in &dock.layout().floating {
+            lines.push(format!("floating {}", floating.panel));
+        }
+        for mut text in &mut texts {
+            text.0 = lines.join("\n");
+        }
+    }
+}
+
+fn show_count(count: Res<Count>, mut texts: Query<&mut Text, With<CountText>>) {
+    if !count.is_changed() {
+        return;
+    }
+    for mut text in &mut texts {
+        text.0 = count.0.to_string();
+    }
+}
+
+fn show_log(log: Res<Log>, mut texts: Query<&mut Text, With<LogText>>) {
+    for mut text in &mut texts {
+        let contents = log.0.join("\n");
+        if text.0 != contents {
+            text.0 = contents;
+        }
+    }
+}
@@ -0,0 +1,282 @@
+//! The drag and drop demo: a small kanban board whose cards are items of reorderable lists, with a
+//! trash accepting them, and color swatches only a canvas accepts. `drag_drop` runs it, tests drive
+//! it through [`Automation`](crate::automation::Automation).
+
+use crate::theme::Theme;
+use crate::widgets::drag_drop::{
+    DragCancel, DragDropPlugin, DragStart, Draggable, Drop, DropTarget, ListItem, ListItemMoved,
+    ReorderableList,
+};
+use bevy::prelude::*;
+
+pub struct DragDropDemoPlugin;
+
+impl Plugin for DragDropDemoPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_plugins(DragDropPlugin)
+            .add_systems(Startup, setup)
+            .add_systems(Update, show_moves)
+            .add_observer(show_drag_starts)
+            .add_observer(show_cancels);
+    }
+}
+
+#[derive(Component)]
+struct Status;
+
+/// The payload of the color swatches.
+#[derive(Clone, Copy)]
+struct Swatch {
+    name: &'static str,
+    color: Color,
+}
+
+const SWATCHES: [Swatch; 3] = [
+    Swatch {
+        name: "red",
+        color: Color::srgb(0.85, 0.3, 0.3),
+    },
+    Swatch {
+        name: "green",
+        color: Color::srgb(0.3, 0.7, 0.4),
+    },
+    Swatch {
+        name: "blue",
+        color: Color::srgb(0.3, 0.45, 0.85),
+    },
+];
+
+fn setup(mut commands: Commands, theme: Res<Theme>) {
+    commands.spawn(Camera2d);
+    let theme = theme.clone();
+    let columns = [
+        (
+            "todo",
+            "To do",
+            vec!["Write docs", "Fix login", "Review PR"],
+        ),
+        ("doing", "Doing", vec!["Release notes"]),
+        ("done", "Done", vec![]),
+    ];
+    let mut card_number = 0;
+    commands
+        .spawn((
+            Node {
+                width: Val::Percent(100.),
+                height: Val::Percent(100.),
+                padding: UiRect::all(Val::Px(20.)),
+                column_gap: Val::Px(20.),
+                align_items: AlignItems::Start,
+                ..default()
+            },
+            BackgroundColor(theme.background),
+            Name::new("root"),
+        ))
+        .with_children(|parent| {
+            for (name, title, cards) in columns {
+                parent
+                    .spawn((
+                        Node {
+                            width: Val::Px(220.),
+                            flex_direction: FlexDirection::Column,
+                            row_gap: Val::Px(8.),
+                            padding: UiRect::all(Val::Px(10.)),
+                            ..default()
+                        },
+                        BackgroundColor(theme.surface),
+                        BorderRadius::all(Val::Px(6.)),
+                    ))
+                    .with_children(|parent| {
+                        parent.spawn((Text::new(title), theme.text_font(), TextColor(theme.text)));
+                        parent
+                            .spawn((
+                                Node {
+                                    flex_direction: FlexDirection::Column,
+                                    row_gap: Val::Px(8.),
+                                    min_height: Val::Px(300.),
+                                    ..default()
+                                },
+                                ReorderableList::new("tasks"),
+                                Name::new(name),
+                            ))
+                            .with_children(|parent| {
+                                for card in cards {
+                                    card_number += 1;
+                                    parent
+                                        .spawn((
+                                            Node {
+                                                height: Val::Px(40.),
+                                                padding: UiRect::horizontal(Val::Px(10.)),
+                                                align_items: AlignItems::Center,
+                                                ..default()
+                                            },
+                                            BackgroundColor(theme.background),
+                                            BorderRadius::all(Val::Px(4.)),
+                                            Name::new(format!("card_{card_number}")),
+                                        ))
+                                        .with_child((
+                                            Text::new(card),
+                                            theme.text_font(),
+                                            TextColor(theme.text),
+                                        ));
+                                }
+                            });
+                    });
+            }
+            parent
+                .spawn(Node {
+                    width: Val::Px(220.),
+                    flex_direction: FlexDirection::Column,
+                    row_gap: Val::Px(20.),
+                    ..default()
+                })
+                .with_children(|parent| {
+                    parent
+                        .spawn((
+                            Node {
+                                height: Val::Px(80.),
+                                justify_content: JustifyContent::Center,
+                                align_items: AlignItems::Center,
+                                border: UiRect::all(Val::Px(1.)),
+                                ..default()
+                            },
+                            BorderColor(theme.error),
+                            BorderRadius::all(Val::Px(6.)),
+                            DropTarget::accepting::<ListItem>(),
+                            Name::new("trash"),
+                        ))
+                        .with_child((
+                            Text::new("Trash"),
+                            theme.text_font(),
+                            TextColor(theme.error),
+                        ))
+                        .observe(delete_cards);
+                    parent
+                        .spawn(Node {
+                            height: Val::Px(40.),
+                            column_gap: Val::Px(10.),
+                            ..default()
+                        })
+                        .with_children(|parent| {
+                            for swatch in SWATCHES {
+                                let preview = move |preview: &mut ChildBuilder| {
+                                    preview.spawn((
+                                        Node {
+                                            width: Val::Px(24.),
+                                            height: Val::Px(24.),
+                                            ..default()
+                                        },
+                                        BackgroundColor(swatch.color),
+                                        BorderRadius::all(Val::Percent(50.)),
+                                    ));
+                                };
+                                parent.spawn((
+                                    Node {
+                                        width: Val::Px(40.),
+                                        height: Val::Px(40.),
+                                        ..default()
+                                    },
+                                    BackgroundColor(swatch.color),
+                                    BorderRadius::all(Val::Px(4.)),
+                                    Draggable::new(swatch).with_preview(preview),
+                                    Name::new(format!("swatch_{}", swatch.name)),
+                                ));
+                            }
+                        });
+                    parent
+                        .spawn((
+                            Node {
+                                height: Val::Px(120.),
+                                justify_content: JustifyContent::Center,
+                                align_items: AlignItems::Center,
+                                ..default()
+                            },
+                            BackgroundColor(theme.surface),
+                            BorderRadius::all(Val::Px(6.)),
+                            DropTarget::accepting::<Swatch>(),
+                            Name::new("canvas"),
+                        ))
+                        .with_child((
+                            Text::new("Drop a color here"),
+                            theme.text_font(),
+                            TextColor(theme.text_muted),
+                        ))
+                        .observe(paint_canvas);
+                    parent.spawn((
+                        Text::default(),
+                        theme.text_font(),
+                        TextColor(theme.text_muted),
+                        Status,
+                        Name::new("status"),
+                    ));
+                });
+        });
+}
+
+fn show(status: &mut Query<&mut Text, With<Status>>, message: String) {
+    println!("{message}");
+    for mut text in status {
+        text.0 = message.clone();
+    }
+}
+
+fn name_of(names: &Query<&Name>, entity: Entity) -> String {
+    names
+        .get(entity)
+        .map_or_else(|_| entity.to_string(), |name| name.to_string())
+}
+
+fn show_drag_starts(
+    trigger: Trigger<DragStart>,
+    names: Query<&Name>,
+    mut status: Query<&mut Text, With<Status>>,
+) {
+    let name = name_of(&names, trigger.entity());
+    show(&mut status, format!("picked up {name}"));
+}
+
+fn show_cancels(
+    trigger: Trigger<DragCancel>,
+    names: Query<&Name>,
+    mut status: Query<&mut Text, With<Status>>,
+) {
+    let name = name_of(&names, trigger.entity());
+    show(&mut status, format!("{name} drag cancelled"));
+}
+
+fn show_moves(
+    mut moved: EventReader<ListItemMoved>,
+    names: Query<&Name>,
+    mut status: Query<&mut Text, With<Status>>,
+) {
+    for event in moved.read() {
+        let item = name_of(&names, event.item);
+        let list = name_of(&names, event.to_list);
+        show(&mut status, format!("{item} moved to {list} #{}", event.to));
+    }
+}
+
+fn delete_cards(
+    trigger: Trigger<Drop>,
+    mut commands: Commands,
+    names: Query<&Name>,
+    mut status: Query<&mut Text, With<Status>>,
+) {
+    let card = trigger.source;
+    show(&mut status, format!("{} deleted", name_of(&names, card)));
+    commands.entity(card).despawn_recursive();
+}
+
+fn paint_canvas(
+    trigger: Trigger<Drop>,
+    mut canvases: Query<&mut BackgroundColor>,
+    mut status: Query<&mut Text, With<Status>>,
+) {
+    let Some(swatch) = trigger.payload.get::<Swatch>() else {
+        return;
+    };
+    if let Ok(mut background) = canvases.get_mut(trigger.entity()) {
+        background.0 = swatch.color;
+    }
+    show(&mut status, format!("canvas painted {}", swatch.name));
+}
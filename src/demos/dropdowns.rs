@@ -0,0 +1,192 @@
+//! The dropdowns demo: dropdowns for a country and a shipping method, and a combo box suggesting
+//! fruits. `dropdowns` runs it, tests drive it through
+//! [`Automation`](crate::automation::Automation).
+
+use crate::theme::Theme;
+use crate::widgets::dropdown::{
+    ComboBox, ComboBoxChanged, Dropdown, DropdownChanged, DropdownPlugin, spawn_combo_box,
+    spawn_dropdown,
+};
+use bevy::prelude::*;
+
+pub struct DropdownsDemoPlugin;
+
+impl Plugin for DropdownsDemoPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_plugins(DropdownPlugin)
+            .add_systems(Startup, setup)
+            .add_systems(Update, show_changes);
+    }
+}
+
+const COUNTRIES: [&str; 24] = [
+    "Argentina",
+    "Australia",
+    "Austria",
+    "Belgium",
+    "Brazil",
+    "Canada",
+    "Chile",
+    "Denmark",
+    "Finland",
+    "France",
+    "Germany",
+    "Greece",
+    "India",
+    "Ireland",
+    "Italy",
+    "Japan",
+    "Mexico",
+    "Netherlands",
+    "Norway",
+    "Poland",
+    "Portugal",
+    "Spain",
+    "Sweden",
+    "Switzerland",
+];
+
+const FRUITS: [&str; 12] = [
+    "Apple",
+    "Apricot",
+    "Banana",
+    "Blackberry",
+    "Blueberry",
+    "Cherry",
+    "Grape",
+    "Mango",
+    "Orange",
+    "Peach",
+    "Pear",
+    "Pineapple",
+];
+
+#[derive(Component)]
+struct LastChange;
+
+fn setup(mut commands: Commands, theme: Res<Theme>) {
+    commands.spawn(Camera2d);
+    let theme = theme.clone();
+    commands
+        .spawn((
+            Node {
+                width: Val::Percent(100.),
+                height: Val::Percent(100.),
+                flex_direction: FlexDirection::Column,
+                align_items: AlignItems::Center,
+                justify_content: JustifyContent::SpaceBetween,
+                padding: UiRect::all(Val::Px(20.)),
+                ..default()
+            },
+            BackgroundColor(theme.background),
+            Name::new("root"),
+        ))
+        .with_children(|parent| {
+            parent.spawn((
+                Text::new("nothing picked yet"),
+                theme.text_font(),
+                TextColor(theme.text_muted),
+                LastChange,
+                Name::new("last_change"),
+            ));
+            parent
+                .spawn((
+                    Node {
+                        flex_direction: FlexDirection::Column,
+                        row_gap: Val::Px(8.),
+                        ..default()
+                    },
+                    Name::new("form"),
+                ))
+                .with_children(|form| {
+                    caption(form, "Country", &theme);
+                    spawn_dropdown(
+                        form,
+                        Dropdown::new(COUNTRIES).with_placeholder("Choose a country"),
+                        (
+                            Node {
+                                width: Val::Px(240.),
+                                ..default()
+                            },
+                            Name::new("country"),
+                        ),
+                        &theme,
+                    );
+                    caption(form, "Fruit", &theme);
+                    spawn_combo_box(
+                        form,
+                        ComboBox::new(FRUITS),
+                        (
+                            Node {
+                                width: Val::Px(240.),
+                                ..default()
+                            },
+                            Name::new("fruit"),
+                        ),
+                        "Type a fruit",
+                        &theme,
+                    );
+                });
+            parent
+                .spawn(Node {
+                    column_gap: Val::Px(12.),
+                    align_items: AlignItems::Center,
+                    ..default()
+                })
+                .with_children(|footer| {
+                    caption(footer, "Shipping", &theme);
+                    spawn_dropdown(
+                        footer,
+                        Dropdown::new(["Standard", "Express", "Overnight", "Pick up in store"])
+                            .with_selected(0),
+                        Name::new("shipping"),
+                        &theme,
+                    );
+                });
+        });
+}
+
+fn caption(parent: &mut ChildBuilder, title: &str, theme: &Theme) {
+    parent.spawn((
+        Text::new(title),
+        theme.text_font(),
+        TextColor(theme.text_muted),
+    ));
+}
+
+fn show_changes(
+    mut dropdowns: EventReader<DropdownChanged>,
+    mut combo_boxes: EventReader<ComboBoxChanged>,
+    names: Query<&Name>,
+    options: Query<&Dropdown>,
+    mut last: Query<&mut Text, With<LastChange>>,
+) {
+    let name = |entity: Entity| names.get(entity).map(Name::as_str).unwrap_or("?");
+    let mut changes = Vec::new();
+    for change in dropdowns.read() {
+        let option = options
+            .get(change.dropdown)
+            .ok()
+            .and_then(Dropdown::selected_option)
+            .unwrap_or("?");
+        changes.push(format!("{}: {option}", name(change.dropdown)));
+    }
+    for change in combo_boxes.read() {
+        let known = if change.option.is_some() {
+            ""
+        } else {
+            " (custom)"
+        };
+        changes.push(format!(
+            "{}: {}{known}",
+            name(change.combo_box),
+            change.value
+        ));
+    }
+    for change in changes {
+        println!("{change}");
+        for mut text in &mut last {
+            text.0 = change.clone();
+        }
+    }
+}
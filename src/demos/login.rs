@@ -0,0 +1,228 @@
+//! The login demo: username and password inputs from `bevy_simple_text_input` and a submit button
+//! that checks the login in the background, with the outcome as a toast. `simple_text_input`
+//! runs it with the dev tools, tests drive it through
+//! [`Automation`](crate::automation::Automation).
+
+use crate::task::{Loading, SpawnTaskExt, TaskAppExt, TaskFinished, TaskPlugin, delay};
+use crate::widgets::toast::{Toast, ToastPlugin, Toasts};
+use bevy::{
+    color::palettes::{css::BLUE, tailwind::BLUE_400},
+    prelude::*,
+    ui::FocusPolicy,
+};
+use bevy_simple_text_input::{
+    TextInput, TextInputInactive, TextInputPlaceholder, TextInputPlugin, TextInputSettings,
+    TextInputSystem, TextInputTextColor, TextInputTextFont, TextInputValue,
+};
+use std::time::Duration;
+
+pub struct LoginDemoPlugin;
+
+impl Plugin for LoginDemoPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_plugins(TextInputPlugin)
+            .add_plugins(ToastPlugin)
+            .add_plugins(TaskPlugin)
+            .add_task_result::<String>()
+            .add_systems(Startup, setup)
+            .add_systems(Update, focus.before(TextInputSystem))
+            .add_systems(Update, (handle_submit, show_login));
+    }
+}
+
+const BORDER_COLOR_ACTIVE: Color = Color::srgb(0.75, 0.52, 0.99);
+const BORDER_COLOR_INACTIVE: Color = Color::srgb(0.25, 0.25, 0.25);
+const TEXT_COLOR: Color = Color::srgb(0.9, 0.9, 0.9);
+const BACKGROUND_COLOR: Color = Color::srgb(0.15, 0.15, 0.15);
+
+#[derive(Component, Clone, Copy)]
+struct Username;
+
+#[derive(Component, Clone, Copy)]
+struct Password;
+
+#[derive(Component, Clone, Copy)]
+struct SubmitButton;
+
+fn setup(mut commands: Commands) {
+    commands.spawn(Camera2d);
+
+    commands
+        .spawn((
+            Node {
+                width: Val::Percent(100.0),
+                height: Val::Percent(100.0),
+                align_items: AlignItems::Center,
+                justify_content: JustifyContent::Center,
+                flex_direction: FlexDirection::Column,
+                row_gap: Val::Px(10.),
+                ..default()
+            },
+            // Make this container node interactive so that clicking on it removes
+            // focus from the text input.
+            Interaction::None,
+        ))
+        .with_children(|parent| {
+            parent.spawn(text_input());
+            parent.spawn((
+                Node {
+                    width: Val::Px(200.0),
+                    border: UiRect::all(Val::Px(5.0)),
+                    padding: UiRect::all(Val::Px(5.0)),
+                    ..default()
+                },
+                BorderColor(BORDER_COLOR_ACTIVE),
+                BackgroundColor(BACKGROUND_COLOR),
+                TextInput,
+                TextInputValue("".to_string()),
+                TextInputTextFont(TextFont {
+                    font_size: 34.,
+                    ..default()
+                }),
+                TextInputTextColor(TextColor(TEXT_COLOR)),
+                TextInputPlaceholder {
+                    value: "Password".to_string(),
+                    ..default()
+                },
+                TextInputSettings {
+                    mask_character: Some('*'),
+                    retain_on_submit: true,
+                },
+                Password,
+                Name::new("password"),
+            ));
+            parent
+                .spawn((
+                    Button,
+                    Node {
+                        width: Val::Px(100.0),
+                        height: Val::Px(40.0),
+                        margin: UiRect::all(Val::Px(20.0)),
+                        justify_content: JustifyContent::Center,
+                        align_items: AlignItems::Center,
+                        ..default()
+                    },
+                    BorderColor(BLUE.into()),
+                    BackgroundColor(BLUE_400.into()),
+                    SubmitButton,
+                    Name::new("submit_button"),
+                ))
+                .with_child((
+                    Text::new("Submit"),
+                    TextFont {
+                        font_size: 17.,
+                        ..default()
+                    },
+                    TextColor(Color::srgb(255., 255., 255.)),
+                ));
+        });
+}
+
+fn text_input() -> impl Bundle {
+    (
+        Node {
+            width: Val::Px(200.0),
+            border: UiRect::all(Val::Px(5.0)),
+            padding: UiRect::all(Val::Px(5.0)),
+            ..default()
+        },
+        BorderColor(BORDER_COLOR_INACTIVE),
+        BackgroundColor(BACKGROUND_COLOR),
+        // Prevent clicks on the input from also bubbling down to the container
+        // behind it
+        FocusPolicy::Block,
+        TextInput,
+        TextInputTextFont(TextFont {
+            font_size: 34.,
+            ..default()
+        }),
+        TextInputTextColor(TextColor(TEXT_COLOR)),
+        TextInputPlaceholder {
+            value: "Name".to_string(),
+            ..default()
+        },
+        TextInputInactive(true),
+        Username,
+        Name::new("username"),
+    )
+}
+
+fn focus(
+    query: Query<(Entity, &Interaction), Changed<Interaction>>,
+    mut text_input_query: Query<(Entity, &mut TextInputInactive, &mut BorderColor)>,
+) {
+    for (interaction_entity, interaction) in &query {
+        if *interaction == Interaction::Pressed {
+            for (entity, mut inactive, mut border_color) in &mut text_input_query {
+                if entity == interaction_entity {
+                    inactive.0 = false;
+                    *border_color = BORDER_COLOR_ACTIVE.into();
+                } else {
+                    inactive.0 = true;
+                    *border_color = BORDER_COLOR_INACTIVE.into();
+                }
+            }
+        }
+    }
+}
+
+#[allow(clippy::type_complexity)]
+fn handle_submit(
+    mut commands: Commands,
+    query: Query<
+        (Entity, &Interaction),
+        (Changed<Interaction>, With<SubmitButton>, Without<Loading>),
+    >,
+    mut input_queries: ParamSet<(
+        Query<&mut TextInputValue, With<Username>>,
+        Query<&mut TextInputValue, With<Password>>,
+    )>,
+    mut toasts: ResMut<Toasts>,
+) {
+    for (interaction_entity, interaction) in &query {
+        if *interaction == Interaction::Pressed {
+            let Ok(username_value) = input_queries
+                .p0()
+                .get_single_mut()
+                .map(|value| value.0.clone())
+            else {
+                continue;
+            };
+            if username_value.is_empty() {
+                toasts.push(Toast::warning("Enter a name to log in"));
+                continue;
+            }
+
+            let password_value = input_queries
+                .p1()
+                .get_single_mut()
+                .map(|value| value.0.clone())
+                .unwrap_or_default();
+
+            // the check runs in the background while the button shows a spinner
+            commands.spawn_task(
+                interaction_entity,
+                check_login(username_value, password_value),
+            );
+        }
+    }
+}
+
+/// Stands in for a slow login check, e.g. a request to a server.
+async fn check_login(username: String, password: String) -> Result<String, String> {
+    delay(Duration::from_millis(600)).await;
+    if password.is_empty() {
+        Err(format!("Wrong password for {username}"))
+    } else {
+        Ok(username)
+    }
+}
+
+fn show_login(mut finished: EventReader<TaskFinished<String>>, mut toasts: ResMut<Toasts>) {
+    for event in finished.read() {
+        // failures are reported by TaskPlugin
+        if let Ok(username) = &event.result {
+            toasts.push(Toast::success(format!("Logged in as {username}")));
+        }
+    }
+}
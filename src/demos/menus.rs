@@ -0,0 +1,200 @@
+//! The menus demo: a small drawing tool's menu bar and context menus, with shortcut hints from the
+//! keymap. `menus` runs it, tests drive it through [`Automation`](crate::automation::Automation).
+
+use crate::keymap::{ActionTriggered, KeyChord, Keymap};
+use crate::theme::Theme;
+use crate::widgets::menu::{
+    ContextMenu, Menu, MenuBar, MenuItem, MenuItemSelected, MenuPlugin, spawn_menu_bar,
+};
+use bevy::prelude::*;
+
+pub struct MenusDemoPlugin;
+
+impl Plugin for MenusDemoPlugin {
+    fn build(&self, app: &mut App) {
+        let keymap = Keymap::default()
+            .with("file.new", KeyChord::new(KeyCode::KeyN).ctrl())
+            .with("file.open", KeyChord::new(KeyCode::KeyO).ctrl())
+            .with("file.save", KeyChord::new(KeyCode::KeyS).ctrl())
+            .with("file.save_as", KeyChord::new(KeyCode::KeyS).ctrl().shift())
+            .with("edit.undo", KeyChord::new(KeyCode::KeyZ).ctrl())
+            .with("edit.redo", KeyChord::new(KeyCode::KeyZ).ctrl().shift())
+            .with("view.grid", KeyChord::new(KeyCode::KeyG))
+            .with("shape.delete", KeyChord::new(KeyCode::Delete));
+        app.add_plugins(MenuPlugin)
+            .insert_resource(keymap)
+            .add_systems(Startup, setup)
+            .add_systems(Update, (menu_actions, shortcut_actions));
+    }
+}
+
+#[derive(Component)]
+struct Status;
+
+fn menu_bar() -> MenuBar {
+    let recent = Menu::new("Open Recent")
+        .item(MenuItem::new("notes.txt", "file.recent.notes"))
+        .item(MenuItem::new("todo.txt", "file.recent.todo"))
+        .separator()
+        .submenu(Menu::new("Older").item(MenuItem::new("archive.txt", "file.recent.archive")));
+    let file = Menu::new("File")
+        .item(MenuItem::new("New", "file.new"))
+        .item(MenuItem::new("Open", "file.open"))
+        .submenu(recent)
+        .separator()
+        .item(MenuItem::new("Save", "file.save"))
+        .item(MenuItem::new("Save As", "file.save_as"))
+        .separator()
+        .submenu(
+            Menu::new("Export")
+                .item(MenuItem::new("PNG", "file.export.png"))
+                .item(MenuItem::new("SVG", "file.export.svg"))
+                .item(MenuItem::new("PDF", "file.export.pdf").disabled()),
+        );
+    let edit = Menu::new("Edit")
+        .item(MenuItem::new("Undo", "edit.undo"))
+        .item(MenuItem::new("Redo", "edit.redo").disabled())
+        .separator()
+        .item(MenuItem::new("Cut", "edit.cut"))
+        .item(MenuItem::new("Copy", "edit.copy"))
+        .item(MenuItem::new("Paste", "edit.paste"));
+    let view = Menu::new("View")
+        .item(MenuItem::new("Show grid", "view.grid").checkable(false))
+        .item(MenuItem::new("Show rulers", "view.rulers").checkable(true))
+        .separator()
+        .submenu(
+            Menu::new("Zoom")
+                .item(MenuItem::new("Zoom in", "view.zoom_in"))
+                .item(MenuItem::new("Zoom out", "view.zoom_out"))
+                .item(MenuItem::new("Actual size", "view.zoom_reset")),
+        );
+    MenuBar::new([file, edit, view])
+}
+
+fn setup(mut commands: Commands, theme: Res<Theme>) {
+    commands.spawn(Camera2d);
+    let theme = theme.clone();
+    commands
+        .spawn((
+            Node {
+                width: Val::Percent(100.),
+                height: Val::Percent(100.),
+                flex_direction: FlexDirection::Column,
+                ..default()
+            },
+            BackgroundColor(theme.background),
+            Name::new("root"),
+        ))
+        .with_children(|parent| {
+            spawn_menu_bar(parent, menu_bar(), Name::new("menu_bar"), &theme);
+            parent
+                .spawn((
+                    // the shape sits in a corner, so the canvas' own center can be right-clicked
+                    Node {
+                        flex_grow: 1.,
+                        margin: UiRect::all(Val::Px(20.)),
+                        padding: UiRect::all(Val::Px(40.)),
+                        align_items: AlignItems::FlexStart,
+                        border: UiRect::all(Val::Px(1.)),
+                        ..default()
+                    },
+                    BackgroundColor(theme.surface),
+                    BorderColor(theme.border),
+                    ContextMenu::new(
+                        Menu::new("Canvas")
+                            .item(MenuItem::new("Add shape", "canvas.add_shape"))
+                            .item(MenuItem::new("Paste", "edit.paste"))
+                            .separator()
+                            .item(MenuItem::new("Show grid", "view.grid")),
+                    ),
+                    Name::new("canvas"),
+                ))
+                .with_children(|parent| {
+                    parent.spawn((
+                        Node {
+                            width: Val::Px(160.),
+                            height: Val::Px(100.),
+                            ..default()
+                        },
+                        BackgroundColor(theme.accent),
+                        BorderRadius::all(Val::Px(8.)),
+                        ContextMenu::new(
+                            Menu::new("Shape")
+                                .item(MenuItem::new("Bring to front", "shape.front"))
+                                .item(MenuItem::new("Send to back", "shape.back"))
+                                .submenu(
+                                    Menu::new("Fill")
+                                        .item(MenuItem::new("Purple", "shape.fill.purple"))
+                                        .item(MenuItem::new("Gray", "shape.fill.gray")),
+                                )
+                                .separator()
+                                .item(MenuItem::new("Delete shape", "shape.delete")),
+                        ),
+                        Name::new("shape"),
+                    ));
+                });
+            parent.spawn((
+                Node {
+                    margin: UiRect::new(Val::Px(20.), Val::Px(20.), Val::Px(0.), Val::Px(20.)),
+                    ..default()
+                },
+                Text::new("right click the canvas or the shape"),
+                theme.text_font(),
+                TextColor(theme.text_muted),
+                Status,
+                Name::new("status"),
+            ));
+        });
+}
+
+fn show(status: &mut Query<&mut Text, With<Status>>, message: String) {
+    println!("{message}");
+    for mut text in status {
+        text.0 = message.clone();
+    }
+}
+
+fn menu_actions(
+    mut selected: EventReader<MenuItemSelected>,
+    mut status: Query<&mut Text, With<Status>>,
+    mut bars: Query<&mut MenuBar>,
+) {
+    for event in selected.read() {
+        let message = match (event.action.as_str(), event.checked) {
+            (action, Some(checked)) => format!("{action}: {}", if checked { "on" } else { "off" }),
+            // the canvas menu's "Show grid" isn't checkable, so it flips the bar's item
+            ("view.grid", None) => toggle_grid(&mut bars),
+            (action, None) => action.to_string(),
+        };
+        show(&mut status, message);
+    }
+}
+
+/// Shortcuts of checkable items flip the check in the menu bar too.
+fn shortcut_actions(
+    mut triggered: EventReader<ActionTriggered>,
+    mut status: Query<&mut Text, With<Status>>,
+    mut bars: Query<&mut MenuBar>,
+) {
+    for event in triggered.read() {
+        let message = match event.action.as_str() {
+            "view.grid" => toggle_grid(&mut bars),
+            action => format!("{action} (shortcut)"),
+        };
+        show(&mut status, message);
+    }
+}
+
+fn toggle_grid(bars: &mut Query<&mut MenuBar>) -> String {
+    let mut on = false;
+    for mut bar in bars {
+        if let Some(checked) = bar
+            .item_mut("view.grid")
+            .and_then(|item| item.checked.as_mut())
+        {
+            *checked = !*checked;
+            on = *checked;
+        }
+    }
+    format!("view.grid: {}", if on { "on" } else { "off" })
+}
@@ -0,0 +1,19 @@
+//! The demos that integration tests drive, as plugins; the example binaries of the same name add
+//! them next to the snapshot, recording, automation and remote plugins.
+
+pub mod accordion;
+pub mod auth;
+pub mod confirmation;
+pub mod counter;
+pub mod docking;
+pub mod drag_drop;
+pub mod dropdowns;
+pub mod login;
+pub mod menus;
+pub mod modals;
+pub mod popovers;
+pub mod progress;
+pub mod sliders;
+pub mod split_panes;
+pub mod tabs;
+pub mod tree_view;
@@ -0,0 +1,196 @@
+//! The modals demo: a "Close document" dialog with a second modal stacked on top of it.
+//! `modals` runs it, tests drive it through [`Automation`](crate::automation::Automation).
+
+use crate::theme::Theme;
+use crate::widgets::focus::Focusable;
+use crate::widgets::modal::{Modal, ModalClosed, ModalPlugin, spawn_modal};
+use bevy::prelude::*;
+
+pub struct ModalsDemoPlugin;
+
+impl Plugin for ModalsDemoPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_plugins(ModalPlugin)
+            .init_resource::<Dialogs>()
+            .add_systems(Startup, setup)
+            .add_systems(Update, (open_dialogs, show_results, button_colors).chain());
+    }
+}
+
+const UNSAVED_ACTIONS: [&str; 3] = ["Discard", "Cancel", "Save"];
+const CHANGES_ACTIONS: [&str; 1] = ["Close"];
+
+/// The open dialogs, kept to name the result after the dialog is gone.
+#[derive(Resource, Default)]
+struct Dialogs {
+    unsaved: Option<Entity>,
+    changes: Option<Entity>,
+}
+
+#[derive(Component, Clone, Copy, PartialEq, Eq)]
+enum OpenButton {
+    CloseDocument,
+    ShowChanges,
+}
+
+#[derive(Component)]
+struct Status;
+
+fn setup(mut commands: Commands, theme: Res<Theme>) {
+    commands.spawn(Camera2d);
+    commands
+        .spawn((
+            Node {
+                width: Val::Percent(100.),
+                height: Val::Percent(100.),
+                flex_direction: FlexDirection::Column,
+                row_gap: Val::Px(20.),
+                padding: UiRect::all(Val::Px(20.)),
+                ..default()
+            },
+            BackgroundColor(theme.background),
+            Name::new("root"),
+        ))
+        .with_children(|parent| {
+            parent.spawn((
+                Text::new("nothing picked yet"),
+                theme.text_font(),
+                TextColor(theme.text_muted),
+                Status,
+                Name::new("status"),
+            ));
+            button(
+                parent,
+                "Close document",
+                OpenButton::CloseDocument,
+                "close_document",
+                &theme,
+            );
+        });
+}
+
+fn button(
+    parent: &mut ChildBuilder,
+    title: &str,
+    open: OpenButton,
+    name: &'static str,
+    theme: &Theme,
+) {
+    let mut button = parent.spawn((
+        Button,
+        Node {
+            align_self: AlignSelf::Start,
+            padding: UiRect::axes(Val::Px(14.), Val::Px(8.)),
+            ..default()
+        },
+        BackgroundColor(theme.surface),
+        BorderRadius::all(Val::Px(4.)),
+        open,
+        Name::new(name),
+    ));
+    if open == OpenButton::CloseDocument {
+        // shows the focus coming back to it once the dialogs close
+        button.insert(Focusable);
+    }
+    button.with_child((Text::new(title), theme.text_font(), TextColor(theme.text)));
+}
+
+fn open_dialogs(
+    mut commands: Commands,
+    theme: Res<Theme>,
+    mut dialogs: ResMut<Dialogs>,
+    buttons: Query<(&Interaction, &OpenButton), Changed<Interaction>>,
+) {
+    for (interaction, open) in &buttons {
+        if *interaction != Interaction::Pressed {
+            continue;
+        }
+        match open {
+            OpenButton::CloseDocument => {
+                let modal = Modal::new("Unsaved changes", UNSAVED_ACTIONS)
+                    .with_default(2)
+                    .with_cancel(1);
+                dialogs.unsaved = Some(spawn_modal(
+                    &mut commands,
+                    modal,
+                    Name::new("unsaved_changes"),
+                    &theme,
+                    |parent| {
+                        parent.spawn((
+                            Text::new("Save the changes to \"notes.txt\" before closing?"),
+                            theme.text_font(),
+                            TextColor(theme.text_muted),
+                        ));
+                        button(
+                            parent,
+                            "What changed?",
+                            OpenButton::ShowChanges,
+                            "show_changes",
+                            &theme,
+                        );
+                    },
+                ));
+            }
+            OpenButton::ShowChanges => {
+                let modal = Modal::new("Changes", CHANGES_ACTIONS)
+                    .with_default(0)
+                    .with_cancel(0);
+                dialogs.changes = Some(spawn_modal(
+                    &mut commands,
+                    modal,
+                    Name::new("changes"),
+                    &theme,
+                    |parent| {
+                        for line in ["+ buy milk", "- call the bank", "+ book flights"] {
+                            parent.spawn((
+                                Text::new(line),
+                                theme.text_font(),
+                                TextColor(theme.text_muted),
+                            ));
+                        }
+                    },
+                ));
+            }
+        }
+    }
+}
+
+fn show_results(
+    mut closed: EventReader<ModalClosed>,
+    mut dialogs: ResMut<Dialogs>,
+    mut status: Query<&mut Text, With<Status>>,
+) {
+    for event in closed.read() {
+        let (name, actions) = if dialogs.unsaved == Some(event.modal) {
+            dialogs.unsaved = None;
+            ("unsaved_changes", &UNSAVED_ACTIONS[..])
+        } else if dialogs.changes == Some(event.modal) {
+            dialogs.changes = None;
+            ("changes", &CHANGES_ACTIONS[..])
+        } else {
+            continue;
+        };
+        let action = event
+            .action
+            .and_then(|index| actions.get(index))
+            .unwrap_or(&"closed");
+        let message = format!("{name}: {action}");
+        println!("{message}");
+        for mut text in &mut status {
+            text.0 = message.clone();
+        }
+    }
+}
+
+#[allow(clippy::type_complexity)]
+fn button_colors(
+    theme: Res<Theme>,
+    mut buttons: Query<
+        (&Interaction, &mut BackgroundColor),
+        (Changed<Interaction>, With<OpenButton>),
+    >,
+) {
+    for (interaction, mut color) in &mut buttons {
+        color.0 = theme.surface_color(*interaction);
+    }
+}
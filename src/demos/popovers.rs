@@ -0,0 +1,227 @@
+//! The popovers demo: popovers anchored to buttons near each edge of the window, a nested one and
+//! a sticky one. `popovers` runs it, tests drive it through
+//! [`Automation`](crate::automation::Automation).
+
+use crate::theme::Theme;
+use crate::widgets::overlay::{
+    Overlay, OverlayAlign, OverlayDismissed, OverlayLayer, OverlayPlugin, Placement, spawn_overlay,
+};
+use bevy::prelude::*;
+
+pub struct PopoversDemoPlugin;
+
+impl Plugin for PopoversDemoPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_plugins(OverlayPlugin)
+            .init_resource::<Theme>()
+            .add_systems(Startup, setup)
+            .add_systems(Update, (toggle_popovers, show_dismissals, button_colors));
+    }
+}
+
+/// A button opening a popover on `placement`, with `sticky` popovers ignoring outside presses and
+/// Escape.
+#[derive(Component)]
+struct PopoverButton {
+    name: &'static str,
+    placement: Placement,
+    sticky: bool,
+    popover: Option<Entity>,
+}
+
+#[derive(Component)]
+struct Status;
+
+fn setup(mut commands: Commands, theme: Res<Theme>) {
+    commands.spawn(Camera2d);
+    let theme = theme.clone();
+    commands
+        .spawn((
+            Node {
+                width: Val::Percent(100.),
+                height: Val::Percent(100.),
+                justify_content: JustifyContent::Center,
+                align_items: AlignItems::Center,
+                ..default()
+            },
+            BackgroundColor(theme.background),
+            Name::new("background"),
+        ))
+        .with_children(|parent| {
+            parent.spawn((
+                Text::new("open a popover"),
+                theme.text_font(),
+                TextColor(theme.text_muted),
+                Status,
+                Name::new("status"),
+            ));
+            // (top, bottom, left, right) insets of each corner button
+            let corners = [
+                ("top", Placement::Top, (Some(8.), None, Some(8.), None)),
+                (
+                    "bottom",
+                    Placement::Bottom,
+                    (None, Some(8.), None, Some(8.)),
+                ),
+                ("left", Placement::Left, (None, Some(8.), Some(8.), None)),
+                ("right", Placement::Right, (Some(8.), None, None, Some(8.))),
+            ];
+            for (name, placement, (top, bottom, left, right)) in corners {
+                let position = Node {
+                    position_type: PositionType::Absolute,
+                    top: top.map_or(Val::Auto, Val::Px),
+                    bottom: bottom.map_or(Val::Auto, Val::Px),
+                    left: left.map_or(Val::Auto, Val::Px),
+                    right: right.map_or(Val::Auto, Val::Px),
+                    ..default()
+                };
+                popover_button(parent, name, placement, false, position, &theme);
+            }
+            let centered = |top: f32| Node {
+                position_type: PositionType::Absolute,
+                top: Val::Percent(top),
+                ..default()
+            };
+            popover_button(
+                parent,
+                "nested",
+                Placement::Bottom,
+                false,
+                centered(20.),
+                &theme,
+            );
+            popover_button(
+                parent,
+                "sticky",
+                Placement::Top,
+                true,
+                centered(70.),
+                &theme,
+            );
+        });
+}
+
+fn popover_button(
+    parent: &mut ChildBuilder,
+    name: &'static str,
+    placement: Placement,
+    sticky: bool,
+    position: Node,
+    theme: &Theme,
+) {
+    let mut title = name.to_string();
+    title[..1].make_ascii_uppercase();
+    parent
+        .spawn((
+            Button,
+            Node {
+                padding: UiRect::axes(Val::Px(14.), Val::Px(8.)),
+                ..position
+            },
+            BackgroundColor(theme.surface),
+            BorderRadius::all(Val::Px(4.)),
+            PopoverButton {
+                name,
+                placement,
+                sticky,
+                popover: None,
+            },
+            Name::new(format!("open_{name}")),
+        ))
+        .with_child((Text::new(title), theme.text_font(), TextColor(theme.text)));
+}
+
+fn toggle_popovers(
+    mut commands: Commands,
+    theme: Res<Theme>,
+    mut buttons: Query<(Entity, &Interaction, &mut PopoverButton), Changed<Interaction>>,
+    exists: Query<()>,
+) {
+    for (entity, interaction, mut button) in &mut buttons {
+        if *interaction != Interaction::Pressed {
+            continue;
+        }
+        if let Some(popover) = button
+            .popover
+            .take()
+            .filter(|popover| exists.contains(*popover))
+        {
+            commands.entity(popover).despawn_recursive();
+            continue;
+        }
+        let overlay = Overlay::new(OverlayLayer::Popup)
+            .anchored(entity, button.placement)
+            .with_align(OverlayAlign::Center)
+            .with_gap(6.)
+            .with_dismiss(!button.sticky, !button.sticky);
+        let name = button.name;
+        let theme = theme.clone();
+        let popover = spawn_overlay(
+            &mut commands,
+            overlay,
+            (
+                Node {
+                    flex_direction: FlexDirection::Column,
+                    row_gap: Val::Px(8.),
+                    padding: UiRect::all(Val::Px(12.)),
+                    border: UiRect::all(Val::Px(1.)),
+                    ..default()
+                },
+                BackgroundColor(theme.surface_hovered),
+                BorderColor(theme.border),
+                BorderRadius::all(Val::Px(6.)),
+                Name::new(format!("popover_{name}")),
+            ),
+            |parent| {
+                parent.spawn((
+                    Text::new(format!("{name} popover")),
+                    theme.text_font(),
+                    TextColor(theme.text),
+                ));
+                if name == "nested" {
+                    popover_button(
+                        parent,
+                        "inner",
+                        Placement::Right,
+                        false,
+                        Node::default(),
+                        &theme,
+                    );
+                }
+            },
+        );
+        button.popover = Some(popover);
+    }
+}
+
+fn show_dismissals(
+    mut dismissed: EventReader<OverlayDismissed>,
+    buttons: Query<&PopoverButton>,
+    mut status: Query<&mut Text, With<Status>>,
+) {
+    for event in dismissed.read() {
+        // the popover itself may be gone already, but its button still remembers it
+        let name = buttons
+            .iter()
+            .find(|button| button.popover == Some(event.overlay))
+            .map_or("?", |button| button.name);
+        let message = format!("popover_{name} dismissed by {:?}", event.reason);
+        println!("{message}");
+        for mut text in &mut status {
+            text.0 = message.clone();
+        }
+    }
+}
+
+#[allow(clippy::type_complexity)]
+fn button_colors(
+    theme: Res<Theme>,
+    mut buttons: Query<
+        (&Interaction, &mut BackgroundColor),
+        (Changed<Interaction>, With<PopoverButton>),
+    >,
+) {
+    for (interaction, mut color) in &mut buttons {
+        color.0 = theme.surface_color(*interaction);
+    }
+}
@@ -0,0 +1,268 @@
+//! The progress demo: progress bars, spinners and a "Submit" button standing for a slow action.
+//! `progress` runs it, tests drive it through [`Automation`](crate::automation::Automation).
+
+use crate::theme::Theme;
+use crate::widgets::progress::{
+    ProgressBar, ProgressLabel, ProgressPlugin, Spinner, spawn_progress_bar, spawn_spinner,
+};
+use bevy::prelude::*;
+
+pub struct ProgressDemoPlugin;
+
+impl Plugin for ProgressDemoPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_plugins(ProgressPlugin)
+            .init_resource::<Submission>()
+            .add_systems(Startup, setup)
+            .add_systems(
+                Update,
+                (step_bars, start_submission, run_submission).chain(),
+            );
+    }
+}
+
+/// Seconds the fake submission takes.
+const SUBMIT_TIME: f32 = 1.5;
+/// Files "uploaded" by the second bar.
+const FILES: usize = 8;
+
+/// Seconds the submission has been running, if it is.
+#[derive(Resource, Default)]
+struct Submission(Option<f32>);
+
+#[derive(Component)]
+struct StepButton(f32);
+
+#[derive(Component)]
+struct SubmitButton;
+
+#[derive(Component)]
+struct SubmitLabel;
+
+#[derive(Component)]
+struct SubmitSpinner;
+
+#[derive(Component)]
+struct SubmitBar;
+
+#[derive(Component)]
+struct Status;
+
+fn setup(mut commands: Commands, theme: Res<Theme>) {
+    commands.spawn(Camera2d);
+    let theme = theme.clone();
+    let bar_node = |height: f32| Node {
+        width: Val::Px(320.),
+        height: Val::Px(height),
+        ..default()
+    };
+    let heading = |parent: &mut ChildBuilder, text: &str| {
+        parent.spawn((
+            Text::new(text.to_string()),
+            theme.text_font(),
+            TextColor(theme.text_muted),
+        ));
+    };
+    let button = |label: &str| {
+        (
+            Button,
+            Node {
+                padding: UiRect::axes(Val::Px(12.), Val::Px(4.)),
+                column_gap: Val::Px(8.),
+                align_items: AlignItems::Center,
+                ..default()
+            },
+            BackgroundColor(theme.surface),
+            BorderRadius::all(Val::Px(4.)),
+            Name::new(label.to_string()),
+        )
+    };
+    commands
+        .spawn((
+            Node {
+                width: Val::Percent(100.),
+                height: Val::Percent(100.),
+                flex_direction: FlexDirection::Column,
+                row_gap: Val::Px(14.),
+                padding: UiRect::all(Val::Px(24.)),
+                ..default()
+            },
+            BackgroundColor(theme.background),
+            Name::new("root"),
+        ))
+        .with_children(|parent| {
+            heading(parent, "Determinate");
+            parent
+                .spawn(Node {
+                    column_gap: Val::Px(10.),
+                    align_items: AlignItems::Center,
+                    ..default()
+                })
+                .with_children(|parent| {
+                    spawn_progress_bar(
+                        parent,
+                        ProgressBar::new(0.5).with_label(ProgressLabel::Percent),
+                        (bar_node(18.), Name::new("percent")),
+                        &theme,
+                    );
+                    for (label, step) in [("-", -0.1), ("+", 0.1)] {
+                        parent.spawn((button(label), StepButton(step))).with_child((
+                            Text::new(label),
+                            theme.text_font(),
+                            TextColor(theme.text),
+                        ));
+                    }
+                });
+            spawn_progress_bar(
+                parent,
+                ProgressBar::new(3. / FILES as f32)
+                    .with_label(ProgressLabel::Text(format!("3 of {FILES} files")))
+                    .with_color(theme.success),
+                (bar_node(18.), Name::new("files")),
+                &theme,
+            );
+            heading(parent, "Indeterminate");
+            spawn_progress_bar(
+                parent,
+                ProgressBar::indeterminate(),
+                (bar_node(6.), Name::new("loading")),
+                &theme,
+            );
+            parent
+                .spawn(Node {
+                    column_gap: Val::Px(16.),
+                    align_items: AlignItems::Center,
+                    ..default()
+                })
+                .with_children(|parent| {
+                    for (size, spinner) in [
+                        (16., Spinner::new()),
+                        (24., Spinner::new().with_color(theme.warning)),
+                        (48., Spinner::new().with_speed(0.5)),
+                    ] {
+                        spawn_spinner(
+                            parent,
+                            spinner,
+                            Node {
+                                width: Val::Px(size),
+                                height: Val::Px(size),
+                                ..default()
+                            },
+                            &theme,
+                        );
+                    }
+                });
+            heading(parent, "In a button");
+            parent.spawn((button("submit"), SubmitButton)).with_child((
+                Text::new("Submit"),
+                theme.text_font(),
+                TextColor(theme.text),
+                SubmitLabel,
+            ));
+            spawn_progress_bar(
+                parent,
+                ProgressBar::new(0.),
+                (bar_node(4.), SubmitBar, Name::new("submit_progress")),
+                &theme,
+            );
+            parent.spawn((
+                Text::default(),
+                theme.text_font(),
+                TextColor(theme.text_muted),
+                Status,
+                Name::new("status"),
+            ));
+        });
+}
+
+fn step_bars(
+    buttons: Query<(&Interaction, &StepButton), Changed<Interaction>>,
+    mut bars: Query<(&mut ProgressBar, &Name)>,
+) {
+    for (interaction, step) in &buttons {
+        if *interaction != Interaction::Pressed {
+            continue;
+        }
+        for (mut bar, name) in &mut bars {
+            if name.as_str() == "percent" {
+                let value = bar.value().unwrap_or(0.);
+                bar.set_value(value + step.0);
+                println!("{name} at {:.0}%", bar.value().unwrap_or(0.) * 100.);
+            }
+        }
+    }
+}
+
+#[allow(clippy::type_complexity)]
+fn start_submission(
+    mut commands: Commands,
+    theme: Res<Theme>,
+    buttons: Query<(Entity, &Interaction), (Changed<Interaction>, With<SubmitButton>)>,
+    mut labels: Query<&mut Text, (With<SubmitLabel>, Without<Status>)>,
+    mut status: Query<&mut Text, With<Status>>,
+    mut submission: ResMut<Submission>,
+) {
+    for (button, interaction) in &buttons {
+        if *interaction != Interaction::Pressed || submission.0.is_some() {
+            continue;
+        }
+        submission.0 = Some(0.);
+        println!("submitting");
+        for mut text in &mut labels {
+            text.0 = "Submitting…".to_string();
+        }
+        for mut text in &mut status {
+            text.0 = "submitting".to_string();
+        }
+        let theme = theme.clone();
+        commands.entity(button).with_children(|parent| {
+            spawn_spinner(
+                parent,
+                Spinner::new().with_color(theme.text),
+                (
+                    Node {
+                        width: Val::Px(14.),
+                        height: Val::Px(14.),
+                        ..default()
+                    },
+                    SubmitSpinner,
+                    Name::new("submit_spinner"),
+                ),
+                &theme,
+            );
+        });
+    }
+}
+
+fn run_submission(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut submission: ResMut<Submission>,
+    mut bars: Query<&mut ProgressBar, With<SubmitBar>>,
+    mut labels: Query<&mut Text, (With<SubmitLabel>, Without<Status>)>,
+    mut status: Query<&mut Text, With<Status>>,
+    spinners: Query<Entity, With<SubmitSpinner>>,
+) {
+    let Some(elapsed) = &mut submission.0 else {
+        return;
+    };
+    *elapsed += time.delta_secs();
+    let done = *elapsed >= SUBMIT_TIME;
+    for mut bar in &mut bars {
+        bar.set_value(*elapsed / SUBMIT_TIME);
+    }
+    if !done {
+        return;
+    }
+    submission.0 = None;
+    println!("submitted");
+    for mut text in &mut labels {
+        text.0 = "Submit".to_string();
+    }
+    for mut text in &mut status {
+        text.0 = "submitted".to_string();
+    }
+    for spinner in &spinners {
+        commands.entity(spinner).despawn_recursive();
+    }
+}
@@ -0,0 +1,167 @@
+//! The sliders demo: a stepped volume slider, a continuous brightness slider, a price range slider
+//! and three vertical equalizer bands, with the last committed change shown at the bottom.
+//! `sliders` runs it, tests drive it through [`Automation`](crate::automation::Automation).
+
+use crate::theme::Theme;
+use crate::widgets::slider::{
+    RangeSlider, Slider, SliderCommitted, SliderPlugin, SliderSettings, spawn_range_slider,
+    spawn_slider,
+};
+use bevy::prelude::*;
+
+pub struct SlidersDemoPlugin;
+
+impl Plugin for SlidersDemoPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_plugins(SliderPlugin)
+            .add_systems(Startup, setup)
+            .add_systems(Update, show_commits);
+    }
+}
+
+#[derive(Component)]
+struct LastCommit;
+
+fn setup(mut commands: Commands, theme: Res<Theme>) {
+    commands.spawn(Camera2d);
+    let theme = theme.clone();
+    commands
+        .spawn((
+            Node {
+                width: Val::Percent(100.),
+                height: Val::Percent(100.),
+                justify_content: JustifyContent::Center,
+                align_items: AlignItems::Center,
+                ..default()
+            },
+            BackgroundColor(theme.background),
+            Name::new("root"),
+        ))
+        .with_children(|parent| {
+            parent
+                .spawn((
+                    Node {
+                        flex_direction: FlexDirection::Column,
+                        row_gap: Val::Px(10.),
+                        padding: UiRect::all(Val::Px(20.)),
+                        width: Val::Px(420.),
+                        ..default()
+                    },
+                    BackgroundColor(theme.surface),
+                    BorderRadius::all(Val::Px(8.)),
+                    Name::new("panel"),
+                ))
+                .with_children(|panel| {
+                    let row = || Node {
+                        width: Val::Percent(100.),
+                        height: Val::Px(28.),
+                        ..default()
+                    };
+                    caption(panel, "Volume", &theme);
+                    spawn_slider(
+                        panel,
+                        Slider::new(
+                            SliderSettings::new(0., 100.)
+                                .with_step(1.)
+                                .with_ticks(10.)
+                                .with_value_label(),
+                            50.,
+                        ),
+                        (row(), Name::new("volume")),
+                        &theme,
+                    );
+                    caption(panel, "Brightness", &theme);
+                    spawn_slider(
+                        panel,
+                        Slider::new(SliderSettings::new(0., 1.).with_value_label(), 0.8),
+                        (row(), Name::new("brightness")),
+                        &theme,
+                    );
+                    caption(panel, "Price", &theme);
+                    spawn_range_slider(
+                        panel,
+                        RangeSlider::new(
+                            SliderSettings::new(0., 1000.)
+                                .with_step(10.)
+                                .with_value_label(),
+                            200.,
+                            600.,
+                        ),
+                        (row(), Name::new("price")),
+                        &theme,
+                    );
+                    caption(panel, "Equalizer", &theme);
+                    panel
+                        .spawn(Node {
+                            column_gap: Val::Px(24.),
+                            justify_content: JustifyContent::Center,
+                            ..default()
+                        })
+                        .with_children(|bands| {
+                            for band in ["bass", "mid", "treble"] {
+                                spawn_slider(
+                                    bands,
+                                    Slider::new(
+                                        SliderSettings::new(-12., 12.)
+                                            .with_step(1.)
+                                            .with_ticks(6.)
+                                            .with_value_label()
+                                            .vertical(),
+                                        0.,
+                                    ),
+                                    (
+                                        Node {
+                                            height: Val::Px(160.),
+                                            ..default()
+                                        },
+                                        Name::new(band),
+                                    ),
+                                    &theme,
+                                );
+                            }
+                        });
+                    panel.spawn((
+                        Text::new("nothing committed yet"),
+                        theme.text_font(),
+                        TextColor(theme.text_muted),
+                        LastCommit,
+                        Name::new("last_commit"),
+                    ));
+                });
+        });
+}
+
+fn caption(parent: &mut ChildBuilder, title: &str, theme: &Theme) {
+    parent.spawn((
+        Text::new(title),
+        theme.text_font(),
+        TextColor(theme.text_muted),
+    ));
+}
+
+fn show_commits(
+    mut commits: EventReader<SliderCommitted>,
+    names: Query<&Name>,
+    sliders: Query<&Slider>,
+    ranges: Query<&RangeSlider>,
+    mut last: Query<&mut Text, With<LastCommit>>,
+) {
+    for commit in commits.read() {
+        let name = names.get(commit.slider).map(Name::as_str).unwrap_or("?");
+        let value = if let Ok(slider) = sliders.get(commit.slider) {
+            slider.settings.format(commit.value)
+        } else if let Ok(range) = ranges.get(commit.slider) {
+            format!(
+                "{} – {}",
+                range.settings.format(range.low),
+                range.settings.format(range.high)
+            )
+        } else {
+            continue;
+        };
+        println!("{name}: {value}");
+        for mut text in &mut last {
+            text.0 = format!("{name}: {value}");
+        }
+    }
+}
@@ -0,0 +1,181 @@
+//! The split panes demo: an editor-style layout of two nested split panes, a file sidebar next to
+//! an editor with a console under it. The splits are kept in
+//! [`SplitStore`](crate::widgets::split_pane::SplitStore), so inserting one loaded from a file
+//! brings the layout back. `split_panes` runs it, tests drive it through
+//! [`Automation`](crate::automation::Automation).
+
+use crate::theme::Theme;
+use crate::widgets::split_pane::{
+    PaneSize, SplitChanged, SplitDirection, SplitPane, SplitPanePlugin, SplitPanel, SplitSide,
+    spawn_split_pane,
+};
+use bevy::prelude::*;
+
+pub struct SplitPanesDemoPlugin;
+
+impl Plugin for SplitPanesDemoPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_plugins(SplitPanePlugin)
+            .add_systems(Startup, setup)
+            .add_systems(Update, (print_changes, show_sizes));
+    }
+}
+
+#[derive(Component)]
+struct Status;
+
+fn setup(mut commands: Commands, theme: Res<Theme>) {
+    commands.spawn(Camera2d);
+    let theme = theme.clone();
+    let label = |parent: &mut ChildBuilder, text: &str, color: Color| {
+        parent.spawn((
+            Text::new(text.to_string()),
+            theme.text_font(),
+            TextColor(color),
+        ));
+    };
+    let pane = |color: Color| {
+        (
+            Node {
+                flex_grow: 1.,
+                flex_direction: FlexDirection::Column,
+                row_gap: Val::Px(6.),
+                padding: UiRect::all(Val::Px(12.)),
+                ..default()
+            },
+            BackgroundColor(color),
+        )
+    };
+    let sidebar = SplitPane::horizontal(0.25)
+        .with_first_size(PaneSize::new(120., 400.))
+        .with_persist_key("sidebar");
+    let console = SplitPane::vertical(0.7)
+        .with_second_size(PaneSize::new(60., f32::INFINITY))
+        .with_collapsible(SplitSide::Second)
+        .with_persist_key("console");
+    commands
+        .spawn((
+            Node {
+                width: Val::Percent(100.),
+                height: Val::Percent(100.),
+                flex_direction: FlexDirection::Column,
+                ..default()
+            },
+            BackgroundColor(theme.background),
+            Name::new("root"),
+        ))
+        .with_children(|parent| {
+            spawn_split_pane(
+                parent,
+                sidebar,
+                (
+                    Node {
+                        flex_grow: 1.,
+                        min_height: Val::Px(0.),
+                        ..default()
+                    },
+                    Name::new("sidebar_split"),
+                ),
+                &theme,
+                |parent| {
+                    parent
+                        .spawn((pane(theme.surface), Name::new("sidebar")))
+                        .with_children(|parent| {
+                            label(parent, "Files", theme.text);
+                            for file in ["main.rs", "lib.rs", "theme.rs", "widgets/"] {
+                                label(parent, file, theme.text_muted);
+                            }
+                        });
+                },
+                |parent| {
+                    spawn_split_pane(
+                        parent,
+                        console,
+                        Name::new("console_split"),
+                        &theme,
+                        |parent| {
+                            parent
+                                .spawn((pane(theme.background), Name::new("editor")))
+                                .with_children(|parent| {
+                                    label(parent, "fn main() {", theme.text);
+                                    label(parent, "    println!(\"hello\");", theme.text);
+                                    label(parent, "}", theme.text);
+                                });
+                        },
+                        |parent| {
+                            parent
+                                .spawn((pane(theme.surface), Name::new("console")))
+                                .with_children(|parent| {
+                                    label(parent, "Console", theme.text);
+                                    label(parent, "$ cargo run", theme.text_muted);
+                                });
+                        },
+                    );
+                },
+            );
+            parent.spawn((
+                Node {
+                    padding: UiRect::axes(Val::Px(12.), Val::Px(6.)),
+                    ..default()
+                },
+                Text::default(),
+                theme.text_font(),
+                TextColor(theme.text_muted),
+                Status,
+                Name::new("status"),
+            ));
+        });
+}
+
+fn print_changes(mut changed: EventReader<SplitChanged>, names: Query<&Name>) {
+    for event in changed.read() {
+        let name = names.get(event.split).map_or("?", Name::as_str);
+        if event.collapsed {
+            println!("{name} collapsed");
+        } else {
+            println!("{name} at {:.0}%", event.ratio * 100.);
+        }
+    }
+}
+
+/// Shows the laid out size of the sidebar and the console, or that they're hidden.
+fn show_sizes(
+    splits: Query<(Entity, &SplitPane, &Name)>,
+    panels: Query<(&SplitPanel, &ComputedNode)>,
+    mut status: Query<&mut Text, With<Status>>,
+) {
+    let mut parts = Vec::new();
+    for (label, split_name, side) in [
+        ("sidebar", "sidebar_split", SplitSide::First),
+        ("console", "console_split", SplitSide::Second),
+    ] {
+        let Some((entity, split, _)) = splits
+            .iter()
+            .find(|(_, _, name)| name.as_str() == split_name)
+        else {
+            continue;
+        };
+        if split.is_collapsed() {
+            parts.push(format!("{label} hidden"));
+            continue;
+        }
+        let Some((_, node)) = panels
+            .iter()
+            .find(|(panel, _)| panel.split == entity && panel.side == side)
+        else {
+            continue;
+        };
+        let size = node.size() * node.inverse_scale_factor();
+        let length = match split.direction {
+            SplitDirection::Horizontal => size.x,
+            SplitDirection::Vertical => size.y,
+        };
+        parts.push(format!("{label} {length:.0}px"));
+    }
+    let message = parts.join(", ");
+    for mut text in &mut status {
+        if text.0 != message {
+            text.0 = message.clone();
+        }
+    }
+}
@@ -0,0 +1,164 @@
+//! The tabs demo: a strip of tabs whose panels are built the first time they're shown, one kept
+//! while hidden and one rebuilt, plus closable log tabs. `tabs` runs it, tests drive it through
+//! [`Automation`](crate::automation::Automation).
+
+use crate::theme::Theme;
+use crate::widgets::tabs::{Tab, TabChanged, TabClosed, TabMoved, Tabs, TabsPlugin, spawn_tabs};
+use bevy::prelude::*;
+
+pub struct TabsDemoPlugin;
+
+impl Plugin for TabsDemoPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_plugins(TabsPlugin)
+            .add_systems(Startup, setup)
+            .add_systems(Update, (count_clicks, show_tab_events));
+    }
+}
+
+#[derive(Component, Default)]
+struct ClickCounter(u32);
+
+#[derive(Component)]
+struct Status;
+
+fn setup(mut commands: Commands, theme: Res<Theme>) {
+    commands.spawn(Camera2d);
+    let theme = theme.clone();
+    let mut tabs = vec![
+        Tab::new("Kept", {
+            let theme = theme.clone();
+            move |panel: &mut ChildBuilder| {
+                println!("building the Kept panel");
+                counter(panel, "kept_counter", &theme);
+            }
+        }),
+        Tab::new("Rebuilt", {
+            let theme = theme.clone();
+            move |panel: &mut ChildBuilder| {
+                println!("building the Rebuilt panel");
+                counter(panel, "rebuilt_counter", &theme);
+            }
+        })
+        .despawn_when_hidden(),
+    ];
+    for number in 1..=6 {
+        let theme = theme.clone();
+        let tab = Tab::new(format!("Log {number}"), move |panel: &mut ChildBuilder| {
+            println!("building the Log {number} panel");
+            for line in 1..=3 {
+                panel.spawn((
+                    Text::new(format!("log {number}, line {line}")),
+                    theme.text_font(),
+                    TextColor(theme.text_muted),
+                ));
+            }
+        });
+        tabs.push(tab.closable());
+    }
+    commands
+        .spawn((
+            Node {
+                width: Val::Percent(100.),
+                height: Val::Percent(100.),
+                flex_direction: FlexDirection::Column,
+                align_items: AlignItems::Center,
+                row_gap: Val::Px(20.),
+                padding: UiRect::all(Val::Px(20.)),
+                ..default()
+            },
+            BackgroundColor(theme.background),
+            Name::new("root"),
+        ))
+        .with_children(|parent| {
+            parent.spawn((
+                Text::new("showing Kept"),
+                theme.text_font(),
+                TextColor(theme.text_muted),
+                Status,
+                Name::new("status"),
+            ));
+            spawn_tabs(
+                parent,
+                Tabs::new(tabs),
+                (
+                    Node {
+                        width: Val::Px(480.),
+                        height: Val::Px(300.),
+                        ..default()
+                    },
+                    Name::new("tabs"),
+                ),
+                &theme,
+            );
+        });
+}
+
+fn counter(panel: &mut ChildBuilder, name: &'static str, theme: &Theme) {
+    panel
+        .spawn((
+            Button,
+            Node {
+                align_self: AlignSelf::Start,
+                padding: UiRect::axes(Val::Px(14.), Val::Px(8.)),
+                ..default()
+            },
+            BackgroundColor(theme.surface_hovered),
+            BorderRadius::all(Val::Px(4.)),
+            ClickCounter::default(),
+            Name::new(name),
+        ))
+        .with_child((
+            Text::new("Clicked 0 times"),
+            theme.text_font(),
+            TextColor(theme.text),
+        ));
+}
+
+fn count_clicks(
+    mut counters: Query<(&Interaction, &mut ClickCounter, &Children), Changed<Interaction>>,
+    mut texts: Query<&mut Text>,
+) {
+    for (interaction, mut counter, children) in &mut counters {
+        if *interaction != Interaction::Pressed {
+            continue;
+        }
+        counter.0 += 1;
+        for child in children {
+            if let Ok(mut text) = texts.get_mut(*child) {
+                text.0 = format!("Clicked {} times", counter.0);
+            }
+        }
+    }
+}
+
+fn show_tab_events(
+    mut changed: EventReader<TabChanged>,
+    mut closed: EventReader<TabClosed>,
+    mut moved: EventReader<TabMoved>,
+    tabs_query: Query<&Tabs>,
+    mut status: Query<&mut Text, With<Status>>,
+) {
+    let mut messages = Vec::new();
+    // closing the shown tab shows another one in the same frame
+    for event in closed.read() {
+        messages.push(format!("closed {}", event.title));
+    }
+    for event in changed.read() {
+        if let Ok(tabs) = tabs_query.get(event.tabs) {
+            messages.push(format!(
+                "showing {}",
+                tabs.title(event.index).unwrap_or("?")
+            ));
+        }
+    }
+    for event in moved.read() {
+        messages.push(format!("moved tab {} to {}", event.from + 1, event.to + 1));
+    }
+    for message in messages {
+        println!("{message}");
+        for mut text in &mut status {
+            text.0 = message.clone();
+        }
+    }
+}
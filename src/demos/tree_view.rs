@@ -0,0 +1,138 @@
+//! The tree view demo: a project's files, whose "assets" folder loads its children when it's
+//! first expanded, next to a live tree of the app's own entities. `tree_view` runs it, tests drive
+//! it through [`Automation`](crate::automation::Automation).
+
+use crate::theme::Theme;
+use crate::widgets::tree_view::{
+    TreeItem, TreeSelectionChanged, TreeView, TreeViewPlugin, spawn_tree_view,
+};
+use bevy::prelude::*;
+
+pub struct TreeViewDemoPlugin;
+
+impl Plugin for TreeViewDemoPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_plugins(TreeViewPlugin)
+            .add_systems(Startup, setup)
+            .add_systems(Update, show_selection);
+    }
+}
+
+#[derive(Component)]
+struct Status;
+
+fn project_files() -> Vec<TreeItem> {
+    vec![
+        TreeItem::new(1, "src").with_children([
+            TreeItem::new(2, "main.rs"),
+            TreeItem::new(3, "theme.rs"),
+            TreeItem::new(4, "widgets").with_children([
+                TreeItem::new(5, "mod.rs"),
+                TreeItem::new(6, "tabs.rs"),
+                TreeItem::new(7, "tree_view.rs"),
+            ]),
+        ]),
+        TreeItem::new(10, "assets").with_lazy_children(|| {
+            println!("loading the children of assets");
+            vec![TreeItem::new(11, "fonts").with_children([
+                TreeItem::new(12, "FiraMono-Medium.ttf"),
+                TreeItem::new(13, "FiraSans-Bold.ttf"),
+            ])]
+        }),
+        TreeItem::new(20, "Cargo.toml"),
+        TreeItem::new(21, "README.md"),
+    ]
+}
+
+fn setup(mut commands: Commands, theme: Res<Theme>) {
+    commands.spawn(Camera2d);
+    let theme = theme.clone();
+    commands
+        .spawn((
+            Node {
+                width: Val::Percent(100.),
+                height: Val::Percent(100.),
+                flex_direction: FlexDirection::Column,
+                row_gap: Val::Px(20.),
+                padding: UiRect::all(Val::Px(20.)),
+                ..default()
+            },
+            BackgroundColor(theme.background),
+            Name::new("root"),
+        ))
+        .with_children(|parent| {
+            parent.spawn((
+                Text::new("nothing selected"),
+                theme.text_font(),
+                TextColor(theme.text_muted),
+                Status,
+                Name::new("status"),
+            ));
+            parent
+                .spawn((
+                    Node {
+                        flex_grow: 1.,
+                        column_gap: Val::Px(20.),
+                        ..default()
+                    },
+                    Name::new("panes"),
+                ))
+                .with_children(|parent| {
+                    let pane = || Node {
+                        width: Val::Percent(50.),
+                        height: Val::Percent(100.),
+                        ..default()
+                    };
+                    spawn_tree_view(
+                        parent,
+                        TreeView::new(project_files()).with_expanded([4]),
+                        (pane(), Name::new("files")),
+                        &theme,
+                    );
+                    spawn_tree_view(
+                        parent,
+                        TreeView::live(),
+                        (pane(), Name::new("entities")),
+                        &theme,
+                    );
+                });
+        });
+}
+
+fn show_selection(
+    mut changes: EventReader<TreeSelectionChanged>,
+    trees: Query<&TreeView>,
+    names: Query<&Name>,
+    mut status: Query<&mut Text, With<Status>>,
+) {
+    for event in changes.read() {
+        let Ok(tree) = trees.get(event.tree) else {
+            continue;
+        };
+        let message = match (event.id, tree.selected_entity()) {
+            (None, _) => "nothing selected".to_string(),
+            (_, Some(entity)) => match names.get(entity) {
+                Ok(name) => format!("selected entity {name}"),
+                Err(_) => format!("selected entity {entity}"),
+            },
+            (Some(id), None) => {
+                let label = find_label(tree.roots(), id).unwrap_or("?");
+                format!("selected {label}")
+            }
+        };
+        println!("{message}");
+        for mut text in &mut status {
+            text.0 = message.clone();
+        }
+    }
+}
+
+fn find_label(items: &[TreeItem], id: u64) -> Option<&str> {
+    items.iter().find_map(|item| {
+        if item.id == id {
+            Some(item.label.as_str())
+        } else {
+            find_label(item.children(), id)
+        }
+    })
+}
@@ -0,0 +1,152 @@
+//! Keyboard shortcuts: a [`Keymap`] binds named actions such as `"file.save"` to key chords, and
+//! [`KeymapPlugin`] sends [`ActionTriggered`] when one is pressed. Menus show the chords bound to
+//! their items' actions as shortcut hints, so the two stay in sync.
+//!
+//! ```ignore
+//! let keymap = Keymap::default().with("file.save", KeyChord::new(KeyCode::KeyS).ctrl());
+//! app.insert_resource(keymap);
+//! ```
+//!
+//! Shortcuts don't fire while a text input has the focus.
+
+use bevy::prelude::*;
+use bevy_simple_text_input::TextInputInactive;
+use std::fmt;
+
+/// A key with the modifiers held down with it. Ctrl also matches the Command key, for macOS.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct KeyChord {
+    pub key: KeyCode,
+    pub ctrl: bool,
+    pub shift: bool,
+    pub alt: bool,
+}
+
+impl KeyChord {
+    pub fn new(key: KeyCode) -> Self {
+        Self {
+            key,
+            ctrl: false,
+            shift: false,
+            alt: false,
+        }
+    }
+
+    pub fn ctrl(mut self) -> Self {
+        self.ctrl = true;
+        self
+    }
+
+    pub fn shift(mut self) -> Self {
+        self.shift = true;
+        self
+    }
+
+    pub fn alt(mut self) -> Self {
+        self.alt = true;
+        self
+    }
+
+    /// Whether the key was just pressed with exactly these modifiers.
+    pub fn just_pressed(&self, keys: &ButtonInput<KeyCode>) -> bool {
+        let ctrl = keys.any_pressed([
+            KeyCode::ControlLeft,
+            KeyCode::ControlRight,
+            KeyCode::SuperLeft,
+            KeyCode::SuperRight,
+        ]);
+        let shift = keys.any_pressed([KeyCode::ShiftLeft, KeyCode::ShiftRight]);
+        let alt = keys.any_pressed([KeyCode::AltLeft, KeyCode::AltRight]);
+        keys.just_pressed(self.key) && ctrl == self.ctrl && shift == self.shift && alt == self.alt
+    }
+}
+
+impl fmt::Display for KeyChord {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (held, name) in [
+            (self.ctrl, "Ctrl"),
+            (self.shift, "Shift"),
+            (self.alt, "Alt"),
+        ] {
+            if held {
+                write!(f, "{name}+")?;
+            }
+        }
+        let key = format!("{:?}", self.key);
+        let key = key
+            .strip_prefix("Key")
+            .or_else(|| key.strip_prefix("Digit"))
+            .unwrap_or(&key);
+        write!(f, "{key}")
+    }
+}
+
+/// Actions and the chords bound to them, in the order they were bound.
+#[derive(Resource, Clone, Default, Debug)]
+pub struct Keymap {
+    bindings: Vec<(String, KeyChord)>,
+}
+
+impl Keymap {
+    pub fn with(mut self, action: impl Into<String>, chord: KeyChord) -> Self {
+        self.bind(action, chord);
+        self
+    }
+
+    /// Binds `chord` to `action`, replacing the action's previous chord.
+    pub fn bind(&mut self, action: impl Into<String>, chord: KeyChord) {
+        let action = action.into();
+        self.bindings.retain(|(bound, _)| *bound != action);
+        self.bindings.push((action, chord));
+    }
+
+    pub fn unbind(&mut self, action: &str) {
+        self.bindings.retain(|(bound, _)| bound != action);
+    }
+
+    pub fn chord(&self, action: &str) -> Option<KeyChord> {
+        self.bindings
+            .iter()
+            .find(|(bound, _)| bound == action)
+            .map(|(_, chord)| *chord)
+    }
+
+    /// The chord of `action` as shown next to a menu item, e.g. "Ctrl+S".
+    pub fn hint(&self, action: &str) -> Option<String> {
+        self.chord(action).map(|chord| chord.to_string())
+    }
+}
+
+/// Sent when the chord of an action is pressed.
+#[derive(Event, Clone, Debug)]
+pub struct ActionTriggered {
+    pub action: String,
+}
+
+pub struct KeymapPlugin;
+
+impl Plugin for KeymapPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<Keymap>()
+            .add_event::<ActionTriggered>()
+            .add_systems(Update, trigger_actions);
+    }
+}
+
+fn trigger_actions(
+    keys: Res<ButtonInput<KeyCode>>,
+    keymap: Res<Keymap>,
+    text_inputs: Query<&TextInputInactive>,
+    mut triggered: EventWriter<ActionTriggered>,
+) {
+    if keys.get_just_pressed().next().is_none() || text_inputs.iter().any(|inactive| !inactive.0) {
+        return;
+    }
+    for (action, chord) in &keymap.bindings {
+        if chord.just_pressed(&keys) {
+            triggered.send(ActionTriggered {
+                action: action.clone(),
+            });
+        }
+    }
+}
@@ -0,0 +1,13 @@
+//! Shared plugins used by the example binaries.
+
+pub mod auth;
+pub mod automation;
+pub mod demos;
+pub mod keymap;
+pub mod record;
+pub mod remote;
+pub mod snapshot;
+pub mod store;
+pub mod task;
+pub mod theme;
+pub mod widgets;
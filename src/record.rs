@@ -0,0 +1,337 @@
+//! Input recording and deterministic replay.
+//!
+//! - `--record <file>`: appends every keyboard, mouse and window event, tagged with its frame
+//!   number, to `file` (one RON value per line)
+//! - `--replay <file>`: ignores live input and feeds the recorded events back on the same frames,
+//!   then exits; combined with `--snapshot` the layout is checked once the replay is over
+//! - `--headless`: replays without a window, in examples started with
+//!   [`default_plugins`](crate::automation::default_plugins)
+//!
+//! ```sh
+//! cargo run --bin counter -- --record counter_session.ron
+//! cargo run --bin counter -- --replay recordings/counter_session.ron --headless --snapshot
+//! ```
+
+use crate::snapshot::SnapshotMode;
+use bevy::core::FrameCount;
+use bevy::input::keyboard::{Key, KeyboardInput};
+use bevy::input::mouse::{MouseButtonInput, MouseScrollUnit, MouseWheel};
+use bevy::input::{ButtonState, InputSystem};
+use bevy::prelude::*;
+use bevy::window::{CursorLeft, CursorMoved, PrimaryWindow, WindowResized};
+use bevy::winit::WinitSettings;
+use serde::{Deserialize, Serialize};
+use std::collections::VecDeque;
+use std::fs::File;
+use std::io::{BufWriter, Write};
+use std::path::{Path, PathBuf};
+
+/// Frames to keep running after the last replayed event, so its effects are laid out.
+const REPLAY_SETTLE_FRAMES: u32 = 5;
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub enum RecordedInput {
+    Keyboard {
+        key_code: KeyCode,
+        logical_key: Key,
+        state: ButtonState,
+        repeat: bool,
+    },
+    MouseButton {
+        button: MouseButton,
+        state: ButtonState,
+    },
+    CursorMoved {
+        position: Vec2,
+    },
+    CursorLeft,
+    MouseWheel {
+        unit: MouseScrollUnit,
+        x: f32,
+        y: f32,
+    },
+    WindowResized {
+        width: f32,
+        height: f32,
+    },
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct RecordedEvent {
+    pub frame: u32,
+    pub input: RecordedInput,
+}
+
+/// Reads a recording written by `--record`.
+pub fn load_recording(path: &Path) -> Result<Vec<RecordedEvent>, String> {
+    let contents =
+        std::fs::read_to_string(path).map_err(|err| format!("{}: {err}", path.display()))?;
+    contents
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty())
+        .map(|(index, line)| {
+            ron::from_str(line).map_err(|err| format!("{}:{}: {err}", path.display(), index + 1))
+        })
+        .collect()
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum RecordMode {
+    Record(PathBuf),
+    Replay(PathBuf),
+}
+
+impl RecordMode {
+    /// Reads the mode from the command line arguments.
+    pub fn from_args() -> Option<Self> {
+        let args: Vec<String> = std::env::args().collect();
+        let value_of = |flag: &str| {
+            args.iter()
+                .position(|arg| arg == flag)
+                .and_then(|index| args.get(index + 1))
+                .map(PathBuf::from)
+        };
+        value_of("--replay")
+            .map(RecordMode::Replay)
+            .or_else(|| value_of("--record").map(RecordMode::Record))
+    }
+}
+
+pub struct InputRecordPlugin;
+
+impl Plugin for InputRecordPlugin {
+    fn build(&self, app: &mut App) {
+        match RecordMode::from_args() {
+            Some(RecordMode::Record(path)) => {
+                let file = match File::create(&path) {
+                    Ok(file) => file,
+                    Err(err) => {
+                        println!("cannot record to {}: {err}", path.display());
+                        app.add_systems(Startup, exit_with_error);
+                        return;
+                    }
+                };
+                println!("recording input to {}", path.display());
+                app.insert_resource(Recorder {
+                    writer: BufWriter::new(file),
+                })
+                .add_systems(PreUpdate, record_input.before(InputSystem));
+            }
+            Some(RecordMode::Replay(path)) => {
+                let events = match load_recording(&path) {
+                    Ok(events) => events,
+                    Err(err) => {
+                        println!("cannot replay {err}");
+                        app.add_systems(Startup, exit_with_error);
+                        return;
+                    }
+                };
+                println!("replaying {} events from {}", events.len(), path.display());
+                app.insert_resource(Replay::new(events));
+            }
+            None => {}
+        }
+        app.add_systems(
+            PreUpdate,
+            replay_input
+                .before(InputSystem)
+                .run_if(resource_exists::<Replay>),
+        );
+    }
+
+    fn finish(&self, app: &mut App) {
+        // recorded frame numbers only line up if every frame is run
+        let world = app.world();
+        if world.contains_resource::<Replay>() || world.contains_resource::<Recorder>() {
+            app.insert_resource(WinitSettings::game());
+        }
+    }
+}
+
+/// Ends an app asked to record or replay that can't, instead of letting it run as if it did.
+fn exit_with_error(mut exit: EventWriter<AppExit>) {
+    exit.send(AppExit::error());
+}
+
+/// True unless a replay is still feeding events.
+pub fn replay_finished(replay: Option<Res<Replay>>) -> bool {
+    replay.is_none_or(|replay| replay.finished)
+}
+
+#[derive(Resource)]
+struct Recorder {
+    writer: BufWriter<File>,
+}
+
+#[derive(Resource)]
+pub struct Replay {
+    events: VecDeque<RecordedEvent>,
+    cursor: Option<Vec2>,
+    idle_frames: u32,
+    finished: bool,
+}
+
+impl Replay {
+    /// Replays `events`, as loaded by [`load_recording`]; inserting it into an app with the
+    /// [`InputRecordPlugin`] starts the replay without `--replay`.
+    pub fn new(events: impl IntoIterator<Item = RecordedEvent>) -> Self {
+        Self {
+            events: events.into_iter().collect(),
+            cursor: None,
+            idle_frames: 0,
+            finished: false,
+        }
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+fn record_input(
+    mut recorder: ResMut<Recorder>,
+    frame: Res<FrameCount>,
+    mut keyboard: EventReader<KeyboardInput>,
+    mut mouse_buttons: EventReader<MouseButtonInput>,
+    mut cursor_moved: EventReader<CursorMoved>,
+    mut cursor_left: EventReader<CursorLeft>,
+    mut wheel: EventReader<MouseWheel>,
+    mut resized: EventReader<WindowResized>,
+) {
+    let mut inputs = Vec::new();
+    inputs.extend(keyboard.read().map(|event| RecordedInput::Keyboard {
+        key_code: event.key_code,
+        logical_key: event.logical_key.clone(),
+        state: event.state,
+        repeat: event.repeat,
+    }));
+    inputs.extend(
+        mouse_buttons
+            .read()
+            .map(|event| RecordedInput::MouseButton {
+                button: event.button,
+                state: event.state,
+            }),
+    );
+    inputs.extend(cursor_moved.read().map(|event| RecordedInput::CursorMoved {
+        position: event.position,
+    }));
+    inputs.extend(cursor_left.read().map(|_| RecordedInput::CursorLeft));
+    inputs.extend(wheel.read().map(|event| RecordedInput::MouseWheel {
+        unit: event.unit,
+        x: event.x,
+        y: event.y,
+    }));
+    inputs.extend(resized.read().map(|event| RecordedInput::WindowResized {
+        width: event.width,
+        height: event.height,
+    }));
+    if inputs.is_empty() {
+        return;
+    }
+
+    for input in inputs {
+        let event = RecordedEvent {
+            frame: frame.0,
+            input,
+        };
+        match ron::to_string(&event) {
+            Ok(line) => {
+                let _ = writeln!(recorder.writer, "{line}");
+            }
+            Err(err) => println!("cannot record {event:?}: {err}"),
+        }
+    }
+    // flush every frame so a crash still leaves a usable recording
+    let _ = recorder.writer.flush();
+}
+
+#[allow(clippy::too_many_arguments)]
+fn replay_input(
+    mut replay: ResMut<Replay>,
+    frame: Res<FrameCount>,
+    mut window_query: Query<(Entity, &mut Window), With<PrimaryWindow>>,
+    mut keyboard: ResMut<Events<KeyboardInput>>,
+    mut mouse_buttons: ResMut<Events<MouseButtonInput>>,
+    mut cursor_moved: ResMut<Events<CursorMoved>>,
+    mut wheel: ResMut<Events<MouseWheel>>,
+    mut exit: EventWriter<AppExit>,
+) {
+    let Ok((window_entity, mut window)) = window_query.get_single_mut() else {
+        return;
+    };
+    // live input would make the replay diverge from the recording
+    keyboard.clear();
+    mouse_buttons.clear();
+    cursor_moved.clear();
+    wheel.clear();
+
+    while replay
+        .events
+        .front()
+        .is_some_and(|event| event.frame <= frame.0)
+    {
+        let Some(event) = replay.events.pop_front() else {
+            break;
+        };
+        match event.input {
+            RecordedInput::Keyboard {
+                key_code,
+                logical_key,
+                state,
+                repeat,
+            } => {
+                keyboard.send(KeyboardInput {
+                    key_code,
+                    logical_key,
+                    state,
+                    repeat,
+                    window: window_entity,
+                });
+            }
+            RecordedInput::MouseButton { button, state } => {
+                mouse_buttons.send(MouseButtonInput {
+                    button,
+                    state,
+                    window: window_entity,
+                });
+            }
+            RecordedInput::CursorMoved { position } => {
+                replay.cursor = Some(position);
+                cursor_moved.send(CursorMoved {
+                    window: window_entity,
+                    position,
+                    delta: None,
+                });
+            }
+            RecordedInput::CursorLeft => replay.cursor = None,
+            RecordedInput::MouseWheel { unit, x, y } => {
+                wheel.send(MouseWheel {
+                    unit,
+                    x,
+                    y,
+                    window: window_entity,
+                });
+            }
+            RecordedInput::WindowResized { width, height } => {
+                window.resolution.set(width, height);
+            }
+        }
+    }
+
+    // the UI reads the cursor from the window, which winit keeps overwriting with the real one
+    if window.cursor_position() != replay.cursor {
+        window.set_cursor_position(replay.cursor);
+    }
+
+    if replay.finished || !replay.events.is_empty() {
+        return;
+    }
+    replay.idle_frames += 1;
+    if replay.idle_frames < REPLAY_SETTLE_FRAMES {
+        return;
+    }
+    replay.finished = true;
+    println!("replay finished at frame {}", frame.0);
+    if SnapshotMode::from_args().is_none() {
+        exit.send(AppExit::Success);
+    }
+}
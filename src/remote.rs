@@ -0,0 +1,241 @@
+//! Bevy Remote Protocol methods for driving and inspecting the UI from external tools.
+//!
+//! Started with `--remote`, the app serves JSON-RPC over HTTP on `127.0.0.1:15702`, or on the port
+//! given with `--remote-port <port>`:
+//! - `ui/list_widgets`: every UI node with its entity, name, role and text
+//! - `ui/get_text`, `ui/set_text`: read/write a text input's value or a text node
+//! - `ui/click`: click a widget
+//! - `ui/dump`: the layout tree, as written by the snapshot plugin
+//!
+//! Widgets are addressed with `{"entity": <bits>}` or an automation selector such as
+//! `{"selector": {"Name": "confirm_button"}}`. Examples add their own state methods with
+//! [`RemoteUiPlugin::with_method`]; [`call`] and [`call_on`] send a request to a running app, as
+//! `cargo run --bin brp_client` does.
+
+use crate::automation::{self, AutomationPlugin, Selector};
+use crate::snapshot::dump_world;
+use bevy::prelude::*;
+use bevy::remote::http::{DEFAULT_ADDR, DEFAULT_PORT, RemoteHttpPlugin};
+use bevy::remote::{BrpError, BrpResult, RemotePlugin, error_codes};
+use bevy::winit::WinitSettings;
+use serde::Deserialize;
+use serde_json::{Value, json};
+use std::io::{Read, Write};
+use std::net::TcpStream;
+
+pub const LIST_WIDGETS_METHOD: &str = "ui/list_widgets";
+pub const GET_TEXT_METHOD: &str = "ui/get_text";
+pub const SET_TEXT_METHOD: &str = "ui/set_text";
+pub const CLICK_METHOD: &str = "ui/click";
+pub const DUMP_METHOD: &str = "ui/dump";
+
+/// An exclusive system answering one remote method.
+pub type RemoteHandler = fn(In<Option<Value>>, &mut World) -> BrpResult;
+
+#[derive(Default)]
+pub struct RemoteUiPlugin {
+    methods: Vec<(&'static str, RemoteHandler)>,
+}
+
+impl RemoteUiPlugin {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Serves an example specific method next to the `ui/*` ones.
+    pub fn with_method(mut self, name: &'static str, handler: RemoteHandler) -> Self {
+        self.methods.push((name, handler));
+        self
+    }
+}
+
+/// The port given with `--remote-port`, or the default one.
+pub fn port_from_args() -> Result<u16, String> {
+    let args: Vec<String> = std::env::args().collect();
+    match args.iter().position(|arg| arg == "--remote-port") {
+        Some(index) => {
+            let port = args.get(index + 1).map_or("", String::as_str);
+            port.parse()
+                .map_err(|err| format!("--remote-port {port:?}: {err}"))
+        }
+        None => Ok(DEFAULT_PORT),
+    }
+}
+
+impl Plugin for RemoteUiPlugin {
+    fn build(&self, app: &mut App) {
+        if !std::env::args().any(|arg| arg == "--remote") {
+            return;
+        }
+        let port = match port_from_args() {
+            Ok(port) => port,
+            Err(err) => {
+                println!("cannot serve remote methods, {err}");
+                app.add_systems(Startup, |mut exit: EventWriter<AppExit>| {
+                    exit.send(AppExit::error());
+                });
+                return;
+            }
+        };
+        if !app.is_plugin_added::<AutomationPlugin>() {
+            app.add_plugins(AutomationPlugin);
+        }
+
+        let mut remote = RemotePlugin::default()
+            .with_method(LIST_WIDGETS_METHOD, list_widgets)
+            .with_method(GET_TEXT_METHOD, get_text)
+            .with_method(SET_TEXT_METHOD, set_text)
+            .with_method(CLICK_METHOD, click)
+            .with_method(DUMP_METHOD, dump);
+        for (name, handler) in &self.methods {
+            remote = remote.with_method(*name, *handler);
+        }
+        app.add_plugins((remote, RemoteHttpPlugin::default().with_port(port)));
+    }
+
+    fn finish(&self, app: &mut App) {
+        // requests are answered from a system, so don't wait for input before running one
+        if app.is_plugin_added::<RemotePlugin>() {
+            app.insert_resource(WinitSettings::game());
+        }
+    }
+}
+
+/// Params addressing one widget.
+#[derive(Deserialize)]
+pub struct TargetParams {
+    pub entity: Option<u64>,
+    pub selector: Option<Selector>,
+}
+
+#[derive(Deserialize)]
+struct SetTextParams {
+    #[serde(flatten)]
+    target: TargetParams,
+    text: String,
+}
+
+/// Parses the method params, reporting malformed ones as `INVALID_PARAMS`.
+pub fn parse_params<T: for<'de> Deserialize<'de>>(params: Option<Value>) -> Result<T, BrpError> {
+    let params = params.ok_or_else(|| invalid_params("missing params".to_string()))?;
+    serde_json::from_value(params).map_err(|err| invalid_params(err.to_string()))
+}
+
+fn invalid_params(message: String) -> BrpError {
+    BrpError {
+        code: error_codes::INVALID_PARAMS,
+        message,
+        data: None,
+    }
+}
+
+/// The entity addressed by `target`.
+pub fn resolve(world: &mut World, target: &TargetParams) -> Result<Entity, BrpError> {
+    if let Some(bits) = target.entity {
+        let entity = Entity::try_from_bits(bits)
+            .map_err(|_| invalid_params(format!("{bits} is not an entity")))?;
+        if world.get_entity(entity).is_err() {
+            return Err(BrpError {
+                code: error_codes::ENTITY_NOT_FOUND,
+                message: format!("entity {entity} does not exist"),
+                data: None,
+            });
+        }
+        return Ok(entity);
+    }
+    let Some(selector) = &target.selector else {
+        return Err(invalid_params(
+            "expected `entity` or `selector`".to_string(),
+        ));
+    };
+    automation::find(world, selector).map_err(|err| BrpError {
+        code: error_codes::ENTITY_NOT_FOUND,
+        message: err.to_string(),
+        data: None,
+    })
+}
+
+fn list_widgets(In(_): In<Option<Value>>, world: &mut World) -> BrpResult {
+    let widgets: Vec<Value> = automation::ui_nodes(world)
+        .into_iter()
+        .map(|entity| {
+            json!({
+                "entity": entity.to_bits(),
+                "name": world.get::<Name>(entity).map(|name| name.as_str().to_string()),
+                "role": automation::role_of(world, entity),
+                "text": automation::text_of(world, entity),
+            })
+        })
+        .collect();
+    Ok(Value::Array(widgets))
+}
+
+fn get_text(In(params): In<Option<Value>>, world: &mut World) -> BrpResult {
+    let target: TargetParams = parse_params(params)?;
+    let entity = resolve(world, &target)?;
+    Ok(json!(automation::text_of(world, entity)))
+}
+
+fn set_text(In(params): In<Option<Value>>, world: &mut World) -> BrpResult {
+    let params: SetTextParams = parse_params(params)?;
+    let entity = resolve(world, &params.target)?;
+    if automation::set_text(world, entity, params.text) {
+        Ok(Value::Null)
+    } else {
+        Err(invalid_params(format!("entity {entity} has no text")))
+    }
+}
+
+fn click(In(params): In<Option<Value>>, world: &mut World) -> BrpResult {
+    let target: TargetParams = parse_params(params)?;
+    let entity = resolve(world, &target)?;
+    automation::click(world, entity);
+    Ok(Value::Null)
+}
+
+fn dump(In(_): In<Option<Value>>, world: &mut World) -> BrpResult {
+    Ok(Value::String(dump_world(world)))
+}
+
+/// Calls `method` on an app started with `--remote` on this machine, returning its result, or the
+/// BRP error as a string.
+pub fn call(method: &str, params: Option<Value>) -> Result<Value, String> {
+    call_on(DEFAULT_PORT, method, params)
+}
+
+/// [`call`] for an app started with `--remote-port <port>`.
+pub fn call_on(port: u16, method: &str, params: Option<Value>) -> Result<Value, String> {
+    let request = json!({
+        "jsonrpc": "2.0",
+        "id": 1,
+        "method": method,
+        "params": params,
+    })
+    .to_string();
+
+    let address = format!("{DEFAULT_ADDR}:{port}");
+    let mut stream = TcpStream::connect(&address).map_err(|err| {
+        format!("cannot connect to {address} (is the example running with --remote?): {err}")
+    })?;
+    write!(
+        stream,
+        "POST / HTTP/1.1\r\nHost: {address}\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{request}",
+        request.len()
+    )
+    .map_err(|err| err.to_string())?;
+
+    let mut response = String::new();
+    stream
+        .read_to_string(&mut response)
+        .map_err(|err| err.to_string())?;
+    let body = response
+        .split_once("\r\n\r\n")
+        .map(|(_, body)| body)
+        .ok_or("malformed http response")?;
+    let mut reply: Value = serde_json::from_str(body).map_err(|err| err.to_string())?;
+
+    if let Some(error) = reply.get("error") {
+        return Err(format!("{method} failed: {error}"));
+    }
+    Ok(reply["result"].take())
+}
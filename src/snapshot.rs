@@ -0,0 +1,350 @@
+//! Layout snapshots: walks the UI tree after layout, dumps it as deterministic RON text (name,
+//! computed rect, text content, colors) and compares it against the files committed under
+//! `snapshots/<example>/<width>x<height>.ron`.
+//!
+//! The plugin does nothing unless the example is started with one of
+//! - `--snapshot`: compare against the committed snapshots and exit with an error on mismatch
+//! - `--update-snapshots`: (re)write the committed snapshots
+//!
+//! ```sh
+//! cargo run --bin counter -- --snapshot
+//! ```
+
+use crate::automation::script_finished;
+use crate::record::replay_finished;
+use bevy::ecs::query::QueryData;
+use bevy::ecs::system::{SystemParam, SystemState};
+use bevy::prelude::*;
+use bevy::window::{PrimaryWindow, WindowResized, WindowResolution};
+use bevy::winit::WinitSettings;
+use std::fmt::Write as _;
+use std::path::PathBuf;
+
+/// Window sizes every example is snapshotted at.
+pub const DEFAULT_SIZES: [UVec2; 3] = [
+    UVec2::new(800, 600),
+    UVec2::new(1280, 720),
+    UVec2::new(480, 800),
+];
+
+/// Frames to wait after resizing before the tree is dumped, so layout and text have settled.
+const SETTLE_FRAMES: u32 = 5;
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum SnapshotMode {
+    Compare,
+    Update,
+}
+
+impl SnapshotMode {
+    /// Reads the mode from the command line arguments.
+    pub fn from_args() -> Option<Self> {
+        std::env::args().find_map(|arg| match arg.as_str() {
+            "--snapshot" => Some(SnapshotMode::Compare),
+            "--update-snapshots" => Some(SnapshotMode::Update),
+            _ => None,
+        })
+    }
+}
+
+pub struct SnapshotPlugin {
+    name: &'static str,
+    sizes: Vec<UVec2>,
+}
+
+impl SnapshotPlugin {
+    /// `name` is the snapshot directory, usually the binary name.
+    pub fn new(name: &'static str) -> Self {
+        Self {
+            name,
+            sizes: DEFAULT_SIZES.to_vec(),
+        }
+    }
+
+    pub fn with_sizes(mut self, sizes: impl IntoIterator<Item = UVec2>) -> Self {
+        self.sizes = sizes.into_iter().collect();
+        self
+    }
+}
+
+impl Plugin for SnapshotPlugin {
+    fn build(&self, app: &mut App) {
+        let Some(mode) = SnapshotMode::from_args() else {
+            return;
+        };
+        app.insert_resource(SnapshotRun {
+            dir: snapshot_dir(self.name),
+            sizes: self.sizes.clone(),
+            mode,
+            current: 0,
+            frames: 0,
+            failures: Vec::new(),
+        })
+        .add_systems(
+            Last,
+            run_snapshots.run_if(replay_finished.and(script_finished)),
+        );
+    }
+
+    fn finish(&self, app: &mut App) {
+        // examples like `counter` only update on input, snapshots need every frame
+        if app.world().contains_resource::<SnapshotRun>() {
+            app.insert_resource(WinitSettings::game());
+        }
+    }
+}
+
+pub fn snapshot_dir(name: &str) -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("snapshots")
+        .join(name)
+}
+
+#[derive(Resource)]
+struct SnapshotRun {
+    dir: PathBuf,
+    sizes: Vec<UVec2>,
+    mode: SnapshotMode,
+    current: usize,
+    frames: u32,
+    failures: Vec<String>,
+}
+
+fn run_snapshots(
+    mut run: ResMut<SnapshotRun>,
+    mut window_query: Query<(Entity, &mut Window), With<PrimaryWindow>>,
+    font_query: Query<&TextFont>,
+    fonts: Res<Assets<Font>>,
+    tree: UiTree,
+    mut resized: EventWriter<WindowResized>,
+    mut exit: EventWriter<AppExit>,
+) {
+    let Ok((window_entity, mut window)) = window_query.get_single_mut() else {
+        return;
+    };
+    let Some(size) = run.sizes.get(run.current).copied() else {
+        return;
+    };
+
+    if run.frames == 0 {
+        window.resolution =
+            WindowResolution::new(size.x as f32, size.y as f32).with_scale_factor_override(1.0);
+        // winit reports the resize, without it (headless) the cameras would keep the old size
+        resized.send(WindowResized {
+            window: window_entity,
+            width: size.x as f32,
+            height: size.y as f32,
+        });
+    }
+    run.frames += 1;
+
+    // the default font is added to the assets directly, so the asset server doesn't track it
+    let fonts_loaded = font_query.iter().all(|font| fonts.contains(&font.font));
+    if run.frames < SETTLE_FRAMES || !fonts_loaded {
+        return;
+    }
+
+    let dump = tree.dump(size);
+    let path = run.dir.join(format!("{}x{}.ron", size.x, size.y));
+    match run.mode {
+        SnapshotMode::Update => {
+            let result =
+                std::fs::create_dir_all(&run.dir).and_then(|_| std::fs::write(&path, &dump));
+            match result {
+                Ok(()) => println!("snapshot written: {}", path.display()),
+                Err(err) => run.failures.push(format!("{}: {err}", path.display())),
+            }
+        }
+        SnapshotMode::Compare => match std::fs::read_to_string(&path) {
+            Ok(expected) if expected == dump => println!("snapshot ok: {}", path.display()),
+            Ok(expected) => {
+                let new_path = path.with_extension("ron.new");
+                let _ = std::fs::write(&new_path, &dump);
+                run.failures.push(format!(
+                    "{} differs, {}; actual output written to {}",
+                    path.display(),
+                    first_difference(&expected, &dump),
+                    new_path.display()
+                ));
+            }
+            Err(err) => run.failures.push(format!(
+                "{}: {err} (run with --update-snapshots to create it)",
+                path.display()
+            )),
+        },
+    }
+
+    run.current += 1;
+    run.frames = 0;
+    if run.current < run.sizes.len() {
+        return;
+    }
+
+    if run.failures.is_empty() {
+        exit.send(AppExit::Success);
+    } else {
+        for failure in &run.failures {
+            println!("snapshot failed: {failure}");
+        }
+        exit.send(AppExit::error());
+    }
+}
+
+fn first_difference(expected: &str, actual: &str) -> String {
+    let mut expected_lines = expected.lines();
+    let mut actual_lines = actual.lines();
+    let mut line = 1;
+    loop {
+        match (expected_lines.next(), actual_lines.next()) {
+            (Some(e), Some(a)) if e == a => line += 1,
+            (None, None) => return "only in line endings".to_string(),
+            (e, a) => {
+                return format!(
+                    "line {line}: expected `{}`, got `{}`",
+                    e.unwrap_or("<end>").trim(),
+                    a.unwrap_or("<end>").trim()
+                );
+            }
+        }
+    }
+}
+
+#[derive(QueryData)]
+struct NodeInfo {
+    entity: Entity,
+    name: Option<&'static Name>,
+    node: &'static ComputedNode,
+    transform: &'static GlobalTransform,
+    text: Option<&'static Text>,
+    text_color: Option<&'static TextColor>,
+    background: Option<&'static BackgroundColor>,
+    border: Option<&'static BorderColor>,
+    children: Option<&'static Children>,
+    button: Has<Button>,
+}
+
+/// Read-only access to the laid out UI tree.
+#[derive(SystemParam)]
+pub struct UiTree<'w, 's> {
+    roots: Query<'w, 's, Entity, (With<Node>, Without<Parent>)>,
+    nodes: Query<'w, 's, NodeInfo>,
+}
+
+impl UiTree<'_, '_> {
+    /// Dumps every UI root; roots are ordered by entity so the output is stable across runs.
+    pub fn dump(&self, window: UVec2) -> String {
+        let mut roots: Vec<Entity> = self.roots.iter().collect();
+        roots.sort();
+
+        let mut out = String::new();
+        let _ = writeln!(out, "(");
+        let _ = writeln!(out, "    window: ({}, {}),", window.x, window.y);
+        let _ = writeln!(out, "    nodes: [");
+        for root in roots {
+            self.write_node(&mut out, root, 2);
+        }
+        let _ = writeln!(out, "    ],");
+        let _ = writeln!(out, ")");
+        out
+    }
+
+    /// Dumps the subtree below `entity`.
+    pub fn dump_entity(&self, entity: Entity) -> String {
+        let mut out = String::new();
+        self.write_node(&mut out, entity, 0);
+        out
+    }
+
+    /// Display name of a node: its [`Name`], or the kind of node it is.
+    pub fn name(&self, entity: Entity) -> String {
+        match self.nodes.get(entity) {
+            Ok(info) => node_name(&info),
+            Err(_) => format!("{entity}"),
+        }
+    }
+
+    fn write_node(&self, out: &mut String, entity: Entity, depth: usize) {
+        let Ok(info) = self.nodes.get(entity) else {
+            return;
+        };
+        let indent = "    ".repeat(depth);
+        let scale = info.node.inverse_scale_factor();
+        let size = info.node.size() * scale;
+        let min = info.transform.translation().truncate() * scale - size / 2.;
+
+        let _ = write!(
+            out,
+            "{indent}(name: {:?}, rect: ({}, {}, {}, {})",
+            node_name(&info),
+            round(min.x),
+            round(min.y),
+            round(size.x),
+            round(size.y)
+        );
+        if let Some(text) = info.text {
+            let _ = write!(out, ", text: {:?}", text.0);
+        }
+        if let Some(color) = info.text_color {
+            let _ = write!(out, ", text_color: {:?}", hex(color.0));
+        }
+        if let Some(color) = info.background {
+            let _ = write!(out, ", background: {:?}", hex(color.0));
+        }
+        if let Some(color) = info.border {
+            let _ = write!(out, ", border: {:?}", hex(color.0));
+        }
+
+        let children: Vec<Entity> = info
+            .children
+            .map(|children| {
+                children
+                    .iter()
+                    .copied()
+                    .filter(|child| self.nodes.contains(*child))
+                    .collect()
+            })
+            .unwrap_or_default();
+        if children.is_empty() {
+            let _ = writeln!(out, "),");
+            return;
+        }
+        let _ = writeln!(out, ", children: [");
+        for child in children {
+            self.write_node(out, child, depth + 1);
+        }
+        let _ = writeln!(out, "{indent}]),");
+    }
+}
+
+/// Dumps the UI tree of `world`, e.g. for error messages outside of systems.
+pub fn dump_world(world: &mut World) -> String {
+    let window = world
+        .query_filtered::<&Window, With<PrimaryWindow>>()
+        .iter(world)
+        .next()
+        .map(|window| UVec2::new(window.width() as u32, window.height() as u32))
+        .unwrap_or_default();
+    let mut state = SystemState::<UiTree>::new(world);
+    state.get(world).dump(window)
+}
+
+fn node_name(info: &NodeInfoItem) -> String {
+    if let Some(name) = info.name {
+        name.as_str().to_string()
+    } else if info.button {
+        "Button".to_string()
+    } else if info.text.is_some() {
+        "Text".to_string()
+    } else {
+        "Node".to_string()
+    }
+}
+
+fn round(value: f32) -> String {
+    // adding zero turns -0.0 into 0.0
+    format!("{:.1}", value + 0.)
+}
+
+fn hex(color: Color) -> String {
+    color.to_srgba().to_hex()
+}
@@ -0,0 +1,68 @@
+//! State kept across runs in a RON file, such as which accordion sections are open or where split
+//! dividers were left. A [`RonStore`] holds the value and writes it back on [`RonStore::save`];
+//! one created with `default()` has no file and only lives as long as the app.
+//!
+//! ```ignore
+//! let store = RonStore::<BTreeMap<String, bool>>::load("state.ron").unwrap_or_else(|err| {
+//!     println!("cannot load {err}, changes won't be saved");
+//!     RonStore::default()
+//! });
+//! ```
+
+use serde::Serialize;
+use serde::de::DeserializeOwned;
+use std::path::{Path, PathBuf};
+
+#[derive(Clone, Debug, Default)]
+pub struct RonStore<T> {
+    path: Option<PathBuf>,
+    value: T,
+}
+
+impl<T: Serialize + DeserializeOwned + Default> RonStore<T> {
+    /// A store saved to `path`, starting from what's there. Only a missing file starts from the
+    /// default value: a file that can't be read or parsed is an error rather than being
+    /// overwritten by the next save.
+    pub fn load(path: impl Into<PathBuf>) -> Result<Self, String> {
+        let path = path.into();
+        let value = match std::fs::read_to_string(&path) {
+            Ok(contents) => {
+                ron::from_str(&contents).map_err(|err| format!("{}: {err}", path.display()))?
+            }
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => T::default(),
+            Err(err) => return Err(format!("{}: {err}", path.display())),
+        };
+        Ok(Self {
+            path: Some(path),
+            value,
+        })
+    }
+
+    /// The file the store is saved to, if it has one.
+    pub fn path(&self) -> Option<&Path> {
+        self.path.as_deref()
+    }
+
+    pub fn get(&self) -> &T {
+        &self.value
+    }
+
+    /// The value, to change before saving.
+    pub fn get_mut(&mut self) -> &mut T {
+        &mut self.value
+    }
+
+    /// Writes the value to the file, if there is one. It's written next to it first and then
+    /// moved over it, so a failed save leaves the previous file whole.
+    pub fn save(&self) -> Result<(), String> {
+        let Some(path) = &self.path else {
+            return Ok(());
+        };
+        let contents = ron::ser::to_string_pretty(&self.value, ron::ser::PrettyConfig::default())
+            .map_err(|err| format!("{}: {err}", path.display()))?;
+        let partial = path.with_extension("partial");
+        std::fs::write(&partial, contents)
+            .and_then(|()| std::fs::rename(&partial, path))
+            .map_err(|err| format!("{}: {err}", path.display()))
+    }
+}
@@ -0,0 +1,268 @@
+//! Background work for widgets: [`SpawnTaskExt::spawn_task`] runs a future on the
+//! `AsyncComputeTaskPool` on behalf of an owning entity, usually the button that started it, and
+//! [`TaskFinished`] brings its result back to the main thread as an event.
+//!
+//! ```ignore
+//! app.add_plugins(TaskPlugin).add_task_result::<Session>();
+//!
+//! fn submit(mut commands: Commands, buttons: Query<(Entity, &Interaction), Without<Loading>>) {
+//!     // on a press
+//!     commands.spawn_task(button, async move { log_in(name, password) });
+//! }
+//!
+//! fn logged_in(mut finished: EventReader<TaskFinished<Session>>) { ... }
+//! ```
+//!
+//! While an entity has tasks running it has the [`Loading`] component, and a spinner is shown
+//! inside it if it's a UI node. The task is kept on the owner, so despawning the owner cancels it,
+//! and so does starting another task with the same result type. Failed tasks are also reported
+//! with [`TaskFailed`] and an error toast. Tasks that need to wait use [`delay`], which doesn't
+//! hold up a pool thread.
+
+use crate::theme::Theme;
+use crate::widgets::progress::{ProgressPlugin, Spinner, spawn_spinner};
+use crate::widgets::toast::{Toast, ToastPlugin, Toasts};
+use bevy::ecs::component::ComponentId;
+use bevy::ecs::world::DeferredWorld;
+use bevy::prelude::*;
+use bevy::tasks::{AsyncComputeTaskPool, Task, block_on, poll_once};
+use bevy::window::RequestRedraw;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::{Arc, Mutex};
+use std::task::{Context, Poll, Waker};
+use std::time::{Duration, Instant};
+
+/// On an entity while tasks it owns are running.
+#[derive(Component, Debug, Default)]
+pub struct Loading {
+    tasks: usize,
+    spinner: Option<Entity>,
+}
+
+impl Loading {
+    /// Number of tasks the entity is waiting for.
+    pub fn tasks(&self) -> usize {
+        self.tasks
+    }
+}
+
+/// A running task, on its owner. Dropping it cancels the task.
+#[derive(Component)]
+#[component(on_add = task_added, on_remove = task_removed)]
+#[require(Loading)]
+struct PendingTask<T: Send + Sync + 'static>(Task<Result<T, String>>);
+
+/// Sent when a task finishes, with what its future returned.
+#[derive(Event, Debug)]
+pub struct TaskFinished<T: Send + Sync + 'static> {
+    pub owner: Entity,
+    pub result: Result<T, String>,
+}
+
+/// Sent when a task fails, whatever its result type, for reporting the error.
+#[derive(Event, Clone, Debug)]
+pub struct TaskFailed {
+    pub owner: Entity,
+    pub error: String,
+}
+
+pub struct TaskPlugin;
+
+impl Plugin for TaskPlugin {
+    fn build(&self, app: &mut App) {
+        if !app.is_plugin_added::<ProgressPlugin>() {
+            app.add_plugins(ProgressPlugin);
+        }
+        if !app.is_plugin_added::<ToastPlugin>() {
+            app.add_plugins(ToastPlugin);
+        }
+        app.init_resource::<Theme>()
+            .add_event::<TaskFailed>()
+            .add_systems(Update, (show_loading, report_failures));
+    }
+}
+
+pub trait TaskAppExt {
+    /// Polls the tasks returning `T` and sends their [`TaskFinished<T>`] events; needed once per
+    /// result type.
+    fn add_task_result<T: Send + Sync + 'static>(&mut self) -> &mut Self;
+}
+
+impl TaskAppExt for App {
+    fn add_task_result<T: Send + Sync + 'static>(&mut self) -> &mut Self {
+        self.add_event::<TaskFinished<T>>()
+            .add_systems(Update, poll_tasks::<T>.before(show_loading))
+    }
+}
+
+pub trait SpawnTaskExt {
+    /// Runs `future` in the background for `owner`, replacing (and cancelling) the task returning
+    /// `T` it already had. Nothing runs if `owner` is despawned first.
+    fn spawn_task<T, F>(&mut self, owner: Entity, future: F)
+    where
+        T: Send + Sync + 'static,
+        F: Future<Output = Result<T, String>> + Send + 'static;
+
+    /// Cancels the task returning `T` that `owner` is running, if any.
+    fn cancel_task<T: Send + Sync + 'static>(&mut self, owner: Entity);
+}
+
+impl SpawnTaskExt for Commands<'_, '_> {
+    fn spawn_task<T, F>(&mut self, owner: Entity, future: F)
+    where
+        T: Send + Sync + 'static,
+        F: Future<Output = Result<T, String>> + Send + 'static,
+    {
+        // started when the command runs, so a despawned owner never starts it
+        self.queue(move |world: &mut World| {
+            if let Ok(mut entity) = world.get_entity_mut(owner) {
+                entity.insert(PendingTask(AsyncComputeTaskPool::get().spawn(future)));
+            }
+        });
+    }
+
+    fn cancel_task<T: Send + Sync + 'static>(&mut self, owner: Entity) {
+        if let Some(mut entity) = self.get_entity(owner) {
+            entity.remove::<PendingTask<T>>();
+        }
+    }
+}
+
+/// A future that finishes after `duration`. The waiting is done by a timer thread, so the task
+/// awaiting it leaves its pool thread free and can be cancelled meanwhile.
+pub fn delay(duration: Duration) -> Delay {
+    Delay {
+        deadline: Instant::now() + duration,
+        waker: None,
+    }
+}
+
+/// See [`delay`].
+pub struct Delay {
+    deadline: Instant,
+    /// Woken by the timer thread, once it's started.
+    waker: Option<Arc<Mutex<Waker>>>,
+}
+
+impl Future for Delay {
+    type Output = ();
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<()> {
+        let left = self.deadline.saturating_duration_since(Instant::now());
+        if left.is_zero() {
+            return Poll::Ready(());
+        }
+        match &self.waker {
+            Some(waker) => waker.lock().unwrap().clone_from(cx.waker()),
+            None => {
+                let waker = Arc::new(Mutex::new(cx.waker().clone()));
+                self.waker = Some(waker.clone());
+                std::thread::spawn(move || {
+                    std::thread::sleep(left);
+                    waker.lock().unwrap().wake_by_ref();
+                });
+            }
+        }
+        Poll::Pending
+    }
+}
+
+fn task_added(mut world: DeferredWorld, owner: Entity, _: ComponentId) {
+    if let Some(mut loading) = world.get_mut::<Loading>(owner) {
+        loading.tasks += 1;
+    }
+}
+
+/// Leaves the loading state once the last task is gone, unless another one started since.
+fn task_removed(mut world: DeferredWorld, owner: Entity, _: ComponentId) {
+    let Some(mut loading) = world.get_mut::<Loading>(owner) else {
+        return;
+    };
+    loading.tasks = loading.tasks.saturating_sub(1);
+    if loading.tasks > 0 {
+        return;
+    }
+    world.commands().queue(move |world: &mut World| {
+        let Ok(mut entity) = world.get_entity_mut(owner) else {
+            return;
+        };
+        if entity
+            .get::<Loading>()
+            .is_none_or(|loading| loading.tasks > 0)
+        {
+            return;
+        }
+        let spinner = entity.take::<Loading>().and_then(|loading| loading.spinner);
+        if let Some(spinner) = spinner
+            && let Ok(spinner) = world.get_entity_mut(spinner)
+        {
+            spinner.despawn_recursive();
+        }
+    });
+}
+
+fn poll_tasks<T: Send + Sync + 'static>(
+    mut commands: Commands,
+    mut tasks: Query<(Entity, &mut PendingTask<T>)>,
+    mut finished: EventWriter<TaskFinished<T>>,
+    mut failed: EventWriter<TaskFailed>,
+    mut redraw: EventWriter<RequestRedraw>,
+) {
+    for (owner, mut task) in &mut tasks {
+        let Some(result) = block_on(poll_once(&mut task.0)) else {
+            // keep reactive apps updating until the task is done
+            redraw.send(RequestRedraw);
+            continue;
+        };
+        commands.entity(owner).remove::<PendingTask<T>>();
+        if let Err(error) = &result {
+            failed.send(TaskFailed {
+                owner,
+                error: error.clone(),
+            });
+        }
+        finished.send(TaskFinished { owner, result });
+    }
+}
+
+/// Puts a spinner at the start of loading UI nodes, in the color of their text.
+#[allow(clippy::type_complexity)]
+fn show_loading(
+    mut commands: Commands,
+    theme: Res<Theme>,
+    mut owners: Query<(Entity, &mut Loading), (Added<Loading>, With<Node>)>,
+    children: Query<&Children>,
+    colors: Query<&TextColor>,
+) {
+    for (owner, mut loading) in &mut owners {
+        let color = children
+            .iter_descendants(owner)
+            .find_map(|child| colors.get(child).ok())
+            .map_or(theme.text, |color| color.0);
+        let size = theme.font_size;
+        let mut spinner = Entity::PLACEHOLDER;
+        commands.entity(owner).with_children(|parent| {
+            spinner = spawn_spinner(
+                parent,
+                Spinner::new().with_color(color),
+                Node {
+                    width: Val::Px(size),
+                    height: Val::Px(size),
+                    margin: UiRect::right(Val::Px(size / 2.)),
+                    ..default()
+                },
+                &theme,
+            );
+        });
+        commands.entity(owner).insert_children(0, &[spinner]);
+        loading.spinner = Some(spinner);
+    }
+}
+
+fn report_failures(mut failed: EventReader<TaskFailed>, mut toasts: ResMut<Toasts>) {
+    for event in failed.read() {
+        println!("task failed: {}", event.error);
+        toasts.push(Toast::error(event.error.clone()));
+    }
+}
@@ -0,0 +1,71 @@
+//! Colors and sizes shared by the widgets, taken from the palette the examples already use.
+
+use bevy::prelude::*;
+
+#[derive(Resource, Clone, Debug)]
+pub struct Theme {
+    pub text: Color,
+    pub text_muted: Color,
+    pub background: Color,
+    pub surface: Color,
+    pub surface_hovered: Color,
+    pub surface_pressed: Color,
+    pub accent: Color,
+    pub border: Color,
+    /// Background of selected rows and items.
+    pub selection: Color,
+    /// Dims the UI behind a modal dialog.
+    pub backdrop: Color,
+    /// Status colors, e.g. for notifications.
+    pub success: Color,
+    pub warning: Color,
+    pub error: Color,
+    pub font_size: f32,
+    pub scrollbar_size: f32,
+    pub scrollbar_track: Color,
+    pub scrollbar_thumb: Color,
+    pub scrollbar_thumb_hovered: Color,
+}
+
+impl Default for Theme {
+    fn default() -> Self {
+        Self {
+            text: Color::srgb(0.9, 0.9, 0.9),
+            text_muted: Color::srgb(0.6, 0.6, 0.6),
+            background: Color::srgb(0.1, 0.1, 0.1),
+            surface: Color::srgb(0.15, 0.15, 0.15),
+            surface_hovered: Color::srgb(0.25, 0.25, 0.25),
+            surface_pressed: Color::srgb(0.35, 0.75, 0.35),
+            accent: Color::srgb(0.75, 0.52, 0.99),
+            border: Color::srgb(0.25, 0.25, 0.25),
+            selection: Color::srgb(0.22, 0.32, 0.55),
+            backdrop: Color::srgba(0., 0., 0., 0.55),
+            success: Color::srgb(0.35, 0.75, 0.35),
+            warning: Color::srgb(0.95, 0.7, 0.25),
+            error: Color::srgb(0.9, 0.35, 0.35),
+            font_size: 17.,
+            scrollbar_size: 10.,
+            scrollbar_track: Color::srgba(1., 1., 1., 0.05),
+            scrollbar_thumb: Color::srgba(1., 1., 1., 0.3),
+            scrollbar_thumb_hovered: Color::srgba(1., 1., 1., 0.5),
+        }
+    }
+}
+
+impl Theme {
+    pub fn text_font(&self) -> TextFont {
+        TextFont {
+            font_size: self.font_size,
+            ..default()
+        }
+    }
+
+    /// Background for an interactive surface in the given state.
+    pub fn surface_color(&self, interaction: Interaction) -> Color {
+        match interaction {
+            Interaction::Pressed => self.surface_pressed,
+            Interaction::Hovered => self.surface_hovered,
+            Interaction::None => self.surface,
+        }
+    }
+}
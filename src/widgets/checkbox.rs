@@ -0,0 +1,184 @@
+//! Checkbox with an optional indeterminate state. Clicking it, or pressing Space while it's
+//! focused, toggles it; `tri_state` checkboxes cycle through indeterminate as well. Setting the
+//! state from code (e.g. a "select all" box reflecting its children) works for any checkbox.
+
+use crate::theme::Theme;
+use crate::widgets::focus::{Focus, FocusPlugin, Focusable};
+use bevy::prelude::*;
+
+const BOX_SIZE: f32 = 18.;
+const MARK_SIZE: f32 = 10.;
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub enum CheckState {
+    #[default]
+    Unchecked,
+    Checked,
+    Indeterminate,
+}
+
+#[derive(Component, Clone, Copy, Debug, Default)]
+#[require(Button, Focusable)]
+pub struct Checkbox {
+    pub state: CheckState,
+    /// Whether clicking cycles through [`CheckState::Indeterminate`].
+    pub tri_state: bool,
+}
+
+impl Checkbox {
+    pub fn new(checked: bool) -> Self {
+        Self {
+            state: if checked {
+                CheckState::Checked
+            } else {
+                CheckState::Unchecked
+            },
+            tri_state: false,
+        }
+    }
+
+    pub fn tri_state(mut self) -> Self {
+        self.tri_state = true;
+        self
+    }
+
+    pub fn is_checked(&self) -> bool {
+        self.state == CheckState::Checked
+    }
+
+    fn next(&self) -> CheckState {
+        match self.state {
+            CheckState::Unchecked => CheckState::Checked,
+            CheckState::Checked if self.tri_state => CheckState::Indeterminate,
+            CheckState::Checked => CheckState::Unchecked,
+            CheckState::Indeterminate if self.tri_state => CheckState::Unchecked,
+            CheckState::Indeterminate => CheckState::Checked,
+        }
+    }
+}
+
+/// Sent when the user toggles a checkbox.
+#[derive(Event, Clone, Copy, Debug)]
+pub struct CheckboxChanged {
+    pub checkbox: Entity,
+    pub state: CheckState,
+}
+
+#[derive(Component)]
+struct CheckboxParts {
+    check_box: Entity,
+    mark: Entity,
+}
+
+pub struct CheckboxPlugin;
+
+impl Plugin for CheckboxPlugin {
+    fn build(&self, app: &mut App) {
+        if !app.is_plugin_added::<FocusPlugin>() {
+            app.add_plugins(FocusPlugin);
+        }
+        app.add_event::<CheckboxChanged>()
+            .add_systems(Update, (toggle_checkboxes, draw_checkboxes).chain());
+    }
+}
+
+/// Spawns a checkbox followed by its label and returns the checkbox entity.
+pub fn spawn_checkbox(
+    parent: &mut ChildBuilder,
+    checkbox: Checkbox,
+    label: impl Into<String>,
+    theme: &Theme,
+) -> Entity {
+    let mut check_box = Entity::PLACEHOLDER;
+    let mut mark = Entity::PLACEHOLDER;
+    let mut checkbox_commands = parent.spawn((
+        checkbox,
+        Node {
+            align_items: AlignItems::Center,
+            column_gap: Val::Px(8.),
+            padding: UiRect::all(Val::Px(2.)),
+            ..default()
+        },
+        BorderRadius::all(Val::Px(4.)),
+    ));
+    checkbox_commands.with_children(|parent| {
+        check_box = parent
+            .spawn((
+                Node {
+                    width: Val::Px(BOX_SIZE),
+                    height: Val::Px(BOX_SIZE),
+                    border: UiRect::all(Val::Px(2.)),
+                    justify_content: JustifyContent::Center,
+                    align_items: AlignItems::Center,
+                    ..default()
+                },
+                BorderColor(theme.border),
+                BorderRadius::all(Val::Px(3.)),
+                BackgroundColor(theme.surface),
+            ))
+            .with_children(|parent| {
+                mark = parent
+                    .spawn((
+                        Node::default(),
+                        BackgroundColor(theme.text),
+                        BorderRadius::all(Val::Px(1.)),
+                    ))
+                    .id();
+            })
+            .id();
+        parent.spawn((Text::new(label), theme.text_font(), TextColor(theme.text)));
+    });
+    checkbox_commands.insert(CheckboxParts { check_box, mark });
+    checkbox_commands.id()
+}
+
+fn toggle_checkboxes(
+    keys: Res<ButtonInput<KeyCode>>,
+    focus: Res<Focus>,
+    mut checkboxes: Query<(Entity, Ref<Interaction>, &mut Checkbox)>,
+    mut changes: EventWriter<CheckboxChanged>,
+) {
+    for (entity, interaction, mut checkbox) in &mut checkboxes {
+        let clicked = interaction.is_changed() && *interaction == Interaction::Pressed;
+        let pressed_key = focus.is(entity) && keys.just_pressed(KeyCode::Space);
+        if clicked || pressed_key {
+            checkbox.state = checkbox.next();
+            changes.send(CheckboxChanged {
+                checkbox: entity,
+                state: checkbox.state,
+            });
+        }
+    }
+}
+
+#[allow(clippy::type_complexity)]
+fn draw_checkboxes(
+    theme: Res<Theme>,
+    checkboxes: Query<
+        (&Checkbox, &Interaction, &CheckboxParts),
+        Or<(Changed<Checkbox>, Changed<Interaction>)>,
+    >,
+    mut backgrounds: Query<(&mut BackgroundColor, &mut BorderColor)>,
+    mut nodes: Query<&mut Node>,
+) {
+    for (checkbox, interaction, parts) in &checkboxes {
+        if let Ok((mut background, mut border)) = backgrounds.get_mut(parts.check_box) {
+            let (fill, edge) = match checkbox.state {
+                CheckState::Unchecked => (theme.surface_color(*interaction), theme.border),
+                _ => (theme.accent, theme.accent),
+            };
+            background.0 = fill;
+            border.0 = edge;
+        }
+        if let Ok(mut mark) = nodes.get_mut(parts.mark) {
+            let (display, height) = match checkbox.state {
+                CheckState::Unchecked => (Display::None, MARK_SIZE),
+                CheckState::Checked => (Display::Flex, MARK_SIZE),
+                CheckState::Indeterminate => (Display::Flex, 3.),
+            };
+            mark.display = display;
+            mark.width = Val::Px(MARK_SIZE);
+            mark.height = Val::Px(height);
+        }
+    }
+}
@@ -0,0 +1,466 @@
+//! Collapsible sections: a header button over a body that slides open and closed. Clicking the
+//! header, or Space or Enter while it's focused, toggles the section, and the header's
+//! accessibility node reports it as expanded or collapsed.
+//!
+//! Sections spawned inside an [`Accordion`] are grouped: a [`AccordionMode::Single`] accordion
+//! closes its other sections when one opens. An accordion with a persist key records which of its
+//! sections are open in [`CollapsibleStore`], so they come back the same when it's spawned again,
+//! and across runs when the store was loaded from a file.
+//!
+//! ```ignore
+//! let accordion = Accordion::single().with_persist_key("settings");
+//! spawn_accordion(parent, accordion, Node::default(), |parent| {
+//!     let section = Collapsible::new("Account", true);
+//!     spawn_collapsible(parent, section, Node::default(), &theme, |body| {
+//!         body.spawn(Text::new("..."));
+//!     });
+//! });
+//! ```
+
+use crate::store::RonStore;
+use crate::theme::Theme;
+use crate::widgets::focus::{Focus, FocusPlugin, Focusable};
+use accesskit::Role;
+use bevy::a11y::AccessibilityNode;
+use bevy::prelude::*;
+use bevy::window::RequestRedraw;
+use std::collections::BTreeMap;
+use std::f32::consts::FRAC_PI_4;
+use std::path::PathBuf;
+
+/// Seconds a section takes to open or close.
+const SLIDE_TIME: f32 = 0.18;
+
+#[derive(Component, Clone, Debug)]
+#[require(SectionSlide)]
+pub struct Collapsible {
+    pub title: String,
+    pub open: bool,
+}
+
+impl Collapsible {
+    pub fn new(title: impl Into<String>, open: bool) -> Self {
+        Self {
+            title: title.into(),
+            open,
+        }
+    }
+}
+
+/// Sent when a section opens or closes, including sections an accordion closes.
+#[derive(Event, Clone, Copy, Debug)]
+pub struct CollapsibleToggled {
+    pub section: Entity,
+    pub open: bool,
+}
+
+#[derive(Component, Clone, Copy, Debug)]
+#[require(Button, Focusable)]
+pub struct CollapsibleHeader {
+    pub section: Entity,
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum AccordionMode {
+    /// Opening a section closes the others.
+    #[default]
+    Single,
+    Multiple,
+}
+
+#[derive(Component, Clone, Debug, Default)]
+pub struct Accordion {
+    pub mode: AccordionMode,
+    /// Where the open sections are recorded in [`CollapsibleStore`].
+    pub persist_key: Option<String>,
+}
+
+impl Accordion {
+    pub fn single() -> Self {
+        Self::default()
+    }
+
+    pub fn multiple() -> Self {
+        Self {
+            mode: AccordionMode::Multiple,
+            persist_key: None,
+        }
+    }
+
+    pub fn with_persist_key(mut self, key: impl Into<String>) -> Self {
+        self.persist_key = Some(key.into());
+        self
+    }
+}
+
+/// The open state of the sections of persisted accordions, keyed by the accordion's persist key
+/// and the section's title.
+#[derive(Resource, Default, Debug)]
+pub struct CollapsibleStore(RonStore<BTreeMap<String, bool>>);
+
+impl CollapsibleStore {
+    /// A store saved to `path` on every change; see [`RonStore::load`].
+    pub fn load(path: impl Into<PathBuf>) -> Result<Self, String> {
+        RonStore::load(path).map(Self)
+    }
+
+    pub fn is_open(&self, accordion: &str, section: &str) -> Option<bool> {
+        self.0.get().get(&store_key(accordion, section)).copied()
+    }
+
+    fn set_open(&mut self, accordion: &str, section: &str, open: bool) {
+        self.0.get_mut().insert(store_key(accordion, section), open);
+    }
+
+    fn save(&self) {
+        if let Err(err) = self.0.save() {
+            println!("failed to save {err}");
+        }
+    }
+}
+
+fn store_key(accordion: &str, section: &str) -> String {
+    format!("{accordion}/{section}")
+}
+
+/// How far a section is open, from 0 to 1.
+#[derive(Component, Default)]
+struct SectionSlide(f32);
+
+#[derive(Component)]
+struct SectionParts {
+    header: Entity,
+    chevron: Entity,
+    body: Entity,
+    content: Entity,
+}
+
+pub struct CollapsiblePlugin;
+
+impl Plugin for CollapsiblePlugin {
+    fn build(&self, app: &mut App) {
+        if !app.is_plugin_added::<FocusPlugin>() {
+            app.add_plugins(FocusPlugin);
+        }
+        app.init_resource::<CollapsibleStore>()
+            .add_event::<CollapsibleToggled>()
+            .add_systems(
+                Update,
+                (
+                    restore_sections,
+                    toggle_sections,
+                    slide_sections,
+                    describe_headers,
+                    draw_headers,
+                )
+                    .chain(),
+            );
+    }
+}
+
+/// Spawns an accordion laid out by `root`, with the sections spawned by `content`, and returns
+/// its entity.
+pub fn spawn_accordion(
+    parent: &mut ChildBuilder,
+    accordion: Accordion,
+    root: impl Bundle,
+    content: impl FnOnce(&mut ChildBuilder),
+) -> Entity {
+    let mut accordion_commands = parent.spawn(Node {
+        flex_direction: FlexDirection::Column,
+        row_gap: Val::Px(6.),
+        ..default()
+    });
+    accordion_commands.insert(root);
+    accordion_commands.insert(accordion).with_children(content);
+    accordion_commands.id()
+}
+
+/// Spawns a section laid out by `root`, with the children spawned by `content` in its body, and
+/// returns its entity.
+pub fn spawn_collapsible(
+    parent: &mut ChildBuilder,
+    collapsible: Collapsible,
+    root: impl Bundle,
+    theme: &Theme,
+    content: impl FnOnce(&mut ChildBuilder),
+) -> Entity {
+    let open = collapsible.open;
+    let mut section_commands = parent.spawn((
+        Node {
+            flex_direction: FlexDirection::Column,
+            border: UiRect::all(Val::Px(1.)),
+            ..default()
+        },
+        BackgroundColor(theme.surface),
+        BorderColor(theme.border),
+        BorderRadius::all(Val::Px(4.)),
+    ));
+    section_commands.insert(root);
+    let section = section_commands.id();
+    let mut header = Entity::PLACEHOLDER;
+    let mut chevron = Entity::PLACEHOLDER;
+    let mut body = Entity::PLACEHOLDER;
+    let mut content_node = Entity::PLACEHOLDER;
+    section_commands.with_children(|parent| {
+        let mut accessible = accesskit::Node::new(Role::Button);
+        accessible.set_expanded(open);
+        header = parent
+            .spawn((
+                Node {
+                    align_items: AlignItems::Center,
+                    column_gap: Val::Px(10.),
+                    padding: UiRect::axes(Val::Px(12.), Val::Px(8.)),
+                    ..default()
+                },
+                BackgroundColor(theme.surface),
+                AccessibilityNode::from(accessible),
+                CollapsibleHeader { section },
+            ))
+            .with_children(|parent| {
+                // a chevron: the bottom right corner of a square, pointing right or down
+                chevron =
+                    parent
+                        .spawn((
+                            Node {
+                                width: Val::Px(7.),
+                                height: Val::Px(7.),
+                                border: UiRect {
+                                    right: Val::Px(2.),
+                                    bottom: Val::Px(2.),
+                                    ..default()
+                                },
+                                ..default()
+                            },
+                            BorderColor(theme.text_muted),
+                            Transform::from_rotation(Quat::from_rotation_z(chevron_angle(
+                                if open { 1. } else { 0. },
+                            ))),
+                        ))
+                        .id();
+                parent.spawn((
+                    Text::new(collapsible.title.clone()),
+                    theme.text_font(),
+                    TextColor(theme.text),
+                ));
+            })
+            .id();
+        body = parent
+            .spawn(Node {
+                flex_direction: FlexDirection::Column,
+                overflow: Overflow::clip(),
+                height: if open { Val::Auto } else { Val::Px(0.) },
+                display: if open { Display::Flex } else { Display::None },
+                ..default()
+            })
+            .with_children(|parent| {
+                // the content keeps its natural height while the body around it slides
+                content_node = parent
+                    .spawn(Node {
+                        flex_direction: FlexDirection::Column,
+                        flex_shrink: 0.,
+                        row_gap: Val::Px(8.),
+                        padding: UiRect::all(Val::Px(12.)),
+                        ..default()
+                    })
+                    .with_children(content)
+                    .id();
+            })
+            .id();
+    });
+    section_commands.insert((
+        collapsible,
+        SectionSlide(if open { 1. } else { 0. }),
+        SectionParts {
+            header,
+            chevron,
+            body,
+            content: content_node,
+        },
+    ));
+    section
+}
+
+/// Pointing right when closed, down when open.
+fn chevron_angle(slide: f32) -> f32 {
+    -FRAC_PI_4 + 2. * FRAC_PI_4 * slide
+}
+
+/// The accordion `section` belongs to, if any.
+fn accordion_of<'a>(
+    section: Entity,
+    parents: &Query<&Parent>,
+    accordions: &'a Query<&Accordion>,
+) -> Option<(Entity, &'a Accordion)> {
+    parents.iter_ancestors(section).find_map(|ancestor| {
+        accordions
+            .get(ancestor)
+            .ok()
+            .map(|accordion| (ancestor, accordion))
+    })
+}
+
+/// New sections of a persisted accordion open as they were last left.
+fn restore_sections(
+    store: Res<CollapsibleStore>,
+    parents: Query<&Parent>,
+    accordions: Query<&Accordion>,
+    mut sections: Query<(Entity, &mut Collapsible, &mut SectionSlide), Added<Collapsible>>,
+) {
+    for (entity, mut section, mut slide) in &mut sections {
+        let Some(key) = accordion_of(entity, &parents, &accordions)
+            .and_then(|(_, accordion)| accordion.persist_key.as_deref())
+        else {
+            continue;
+        };
+        if let Some(open) = store.is_open(key, &section.title) {
+            section.open = open;
+            slide.0 = if open { 1. } else { 0. };
+        }
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+fn toggle_sections(
+    keys: Res<ButtonInput<KeyCode>>,
+    focus: Res<Focus>,
+    mut store: ResMut<CollapsibleStore>,
+    headers: Query<(Entity, Ref<Interaction>, &CollapsibleHeader)>,
+    parents: Query<&Parent>,
+    accordions: Query<&Accordion>,
+    mut sections: Query<(Entity, &mut Collapsible)>,
+    mut toggled: EventWriter<CollapsibleToggled>,
+) {
+    let mut changes = Vec::new();
+    for (entity, interaction, header) in &headers {
+        let clicked = interaction.is_changed() && *interaction == Interaction::Pressed;
+        let pressed_key =
+            focus.is(entity) && keys.any_just_pressed([KeyCode::Space, KeyCode::Enter]);
+        if !clicked && !pressed_key {
+            continue;
+        }
+        let Ok((_, section)) = sections.get(header.section) else {
+            continue;
+        };
+        let open = !section.open;
+        changes.push((header.section, open));
+        // a single accordion closes the other open sections
+        if let Some((accordion, _)) = accordion_of(header.section, &parents, &accordions)
+            .filter(|(_, accordion)| open && accordion.mode == AccordionMode::Single)
+        {
+            for (other, other_section) in &sections {
+                let same_accordion = accordion_of(other, &parents, &accordions)
+                    .is_some_and(|(other_accordion, _)| other_accordion == accordion);
+                if other != header.section && other_section.open && same_accordion {
+                    changes.push((other, false));
+                }
+            }
+        }
+    }
+    let mut changed_store = false;
+    for (entity, open) in changes {
+        let Ok((_, mut section)) = sections.get_mut(entity) else {
+            continue;
+        };
+        if section.open == open {
+            continue;
+        }
+        section.open = open;
+        toggled.send(CollapsibleToggled {
+            section: entity,
+            open,
+        });
+        if let Some(key) = accordion_of(entity, &parents, &accordions)
+            .and_then(|(_, accordion)| accordion.persist_key.as_deref())
+        {
+            store.set_open(key, &section.title, open);
+            changed_store = true;
+        }
+    }
+    if changed_store {
+        store.save();
+    }
+}
+
+/// Slides bodies towards their section's state. A fully open body takes its content's height, so
+/// the content can still change size.
+fn slide_sections(
+    time: Res<Time>,
+    mut sections: Query<(&Collapsible, &mut SectionSlide, &SectionParts)>,
+    content: Query<&ComputedNode>,
+    mut nodes: Query<&mut Node>,
+    mut transforms: Query<&mut Transform>,
+    mut redraw: EventWriter<RequestRedraw>,
+) {
+    let step = time.delta_secs() / SLIDE_TIME;
+    for (section, mut slide, parts) in &mut sections {
+        let target = if section.open { 1. } else { 0. };
+        let Ok(mut body) = nodes.get_mut(parts.body) else {
+            continue;
+        };
+        if slide.0 == target {
+            // a section restored or set from code jumps to its state
+            let (height, display) = if section.open {
+                (Val::Auto, Display::Flex)
+            } else {
+                (Val::Px(0.), Display::None)
+            };
+            if body.height != height || body.display != display {
+                body.height = height;
+                body.display = display;
+                if let Ok(mut transform) = transforms.get_mut(parts.chevron) {
+                    transform.rotation = Quat::from_rotation_z(chevron_angle(target));
+                }
+            }
+            continue;
+        }
+        slide.0 = if slide.0 < target {
+            (slide.0 + step).min(target)
+        } else {
+            (slide.0 - step).max(target)
+        };
+        // ease out, so the body slows down as it settles
+        let eased = 1. - (1. - slide.0).powi(2);
+        let height = content.get(parts.content).map_or(0., |computed| {
+            computed.size().y * computed.inverse_scale_factor()
+        });
+        body.display = Display::Flex;
+        body.height = Val::Px(height * eased);
+        if let Ok(mut transform) = transforms.get_mut(parts.chevron) {
+            transform.rotation = Quat::from_rotation_z(chevron_angle(eased));
+        }
+        // reactive apps only update on input
+        redraw.send(RequestRedraw);
+    }
+}
+
+/// Keeps the headers' expanded state up to date for assistive technology, which announces it.
+fn describe_headers(
+    sections: Query<(&Collapsible, &SectionParts), Changed<Collapsible>>,
+    mut headers: Query<&mut AccessibilityNode, With<CollapsibleHeader>>,
+) {
+    for (section, parts) in &sections {
+        let Ok(mut accessible) = headers.get_mut(parts.header) else {
+            continue;
+        };
+        if accessible.is_expanded() != Some(section.open) {
+            accessible.set_expanded(section.open);
+        }
+    }
+}
+
+#[allow(clippy::type_complexity)]
+fn draw_headers(
+    theme: Res<Theme>,
+    mut headers: Query<
+        (&Interaction, &mut BackgroundColor),
+        (Changed<Interaction>, With<CollapsibleHeader>),
+    >,
+) {
+    for (interaction, mut color) in &mut headers {
+        color.0 = match interaction {
+            Interaction::None => theme.surface,
+            _ => theme.surface_hovered,
+        };
+    }
+}
@@ -0,0 +1,863 @@
+//! Data table on top of the virtual list. Columns have a title, a value type and a width; clicking
+//! a header sorts by that column (again to reverse), dragging the right edge of a header resizes
+//! the column, rows are selected like in the virtual list (shift/ctrl for ranges and toggles) and
+//! double-clicking a cell of an editable column edits it in a text input (Enter commits, Escape
+//! cancels).
+//!
+//! ```ignore
+//! let table = DataTable::new(
+//!     vec![Column::new("Name", ColumnKind::Text), Column::new("Age", ColumnKind::Int).editable()],
+//!     vec![vec!["Ada".into(), CellValue::Int(36)]],
+//! );
+//! spawn_data_table(parent, table, Node::default());
+//! ```
+
+use crate::theme::Theme;
+use crate::widgets::DOUBLE_CLICK_TIME;
+use crate::widgets::virtual_list::{
+    ListSource, SelectionMode, VirtualList, VirtualListPlugin, VirtualListSystem,
+    spawn_virtual_list,
+};
+use bevy::prelude::*;
+use bevy::ui::FocusPolicy;
+use bevy::utils::HashMap;
+use bevy::window::PrimaryWindow;
+use bevy_simple_text_input::{
+    TextInput, TextInputInactive, TextInputPlugin, TextInputSettings, TextInputSubmitEvent,
+    TextInputTextColor, TextInputTextFont, TextInputValue,
+};
+use std::cmp::Ordering;
+use std::fmt;
+use std::path::Path;
+use std::sync::Arc;
+
+const ROW_HEIGHT: f32 = 26.;
+const HEADER_HEIGHT: f32 = 30.;
+const RESIZE_HANDLE_WIDTH: f32 = 6.;
+const INVALID_EDIT: Color = Color::srgb(0.8, 0.2, 0.2);
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum ColumnKind {
+    Text,
+    Int,
+    Float,
+    Bool,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum CellValue {
+    Empty,
+    Text(String),
+    Int(i64),
+    Float(f64),
+    Bool(bool),
+}
+
+impl CellValue {
+    /// Parses user input for a column of the given kind; empty input clears the cell.
+    pub fn parse(kind: ColumnKind, input: &str) -> Result<Self, String> {
+        let input = input.trim();
+        if input.is_empty() {
+            return Ok(CellValue::Empty);
+        }
+        match kind {
+            ColumnKind::Text => Ok(CellValue::Text(input.to_string())),
+            ColumnKind::Int => input
+                .parse()
+                .map(CellValue::Int)
+                .map_err(|_| format!("`{input}` is not a whole number")),
+            ColumnKind::Float => input
+                .parse()
+                .map(CellValue::Float)
+                .map_err(|_| format!("`{input}` is not a number")),
+            ColumnKind::Bool => match input.to_lowercase().as_str() {
+                "true" | "yes" | "1" => Ok(CellValue::Bool(true)),
+                "false" | "no" | "0" => Ok(CellValue::Bool(false)),
+                _ => Err(format!("`{input}` is not true or false")),
+            },
+        }
+    }
+
+    /// Orders values of the same type naturally; empty cells sort first.
+    pub fn compare(&self, other: &Self) -> Ordering {
+        match (self, other) {
+            (CellValue::Text(a), CellValue::Text(b)) => a.to_lowercase().cmp(&b.to_lowercase()),
+            (CellValue::Int(a), CellValue::Int(b)) => a.cmp(b),
+            (CellValue::Float(a), CellValue::Float(b)) => a.total_cmp(b),
+            (CellValue::Int(a), CellValue::Float(b)) => (*a as f64).total_cmp(b),
+            (CellValue::Float(a), CellValue::Int(b)) => a.total_cmp(&(*b as f64)),
+            (CellValue::Bool(a), CellValue::Bool(b)) => a.cmp(b),
+            _ => self.rank().cmp(&other.rank()),
+        }
+    }
+
+    fn rank(&self) -> u8 {
+        match self {
+            CellValue::Empty => 0,
+            CellValue::Bool(_) => 1,
+            CellValue::Int(_) | CellValue::Float(_) => 2,
+            CellValue::Text(_) => 3,
+        }
+    }
+}
+
+impl fmt::Display for CellValue {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            CellValue::Empty => Ok(()),
+            CellValue::Text(text) => f.write_str(text),
+            CellValue::Int(value) => write!(f, "{value}"),
+            CellValue::Float(value) => write!(f, "{value}"),
+            CellValue::Bool(value) => write!(f, "{value}"),
+        }
+    }
+}
+
+impl From<&str> for CellValue {
+    fn from(value: &str) -> Self {
+        CellValue::Text(value.to_string())
+    }
+}
+
+impl From<String> for CellValue {
+    fn from(value: String) -> Self {
+        CellValue::Text(value)
+    }
+}
+
+impl From<i64> for CellValue {
+    fn from(value: i64) -> Self {
+        CellValue::Int(value)
+    }
+}
+
+impl From<f64> for CellValue {
+    fn from(value: f64) -> Self {
+        CellValue::Float(value)
+    }
+}
+
+impl From<bool> for CellValue {
+    fn from(value: bool) -> Self {
+        CellValue::Bool(value)
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct Column {
+    pub title: String,
+    pub kind: ColumnKind,
+    pub width: f32,
+    pub min_width: f32,
+    pub sortable: bool,
+    pub editable: bool,
+}
+
+impl Column {
+    pub fn new(title: impl Into<String>, kind: ColumnKind) -> Self {
+        Self {
+            title: title.into(),
+            kind,
+            width: 120.,
+            min_width: 40.,
+            sortable: true,
+            editable: false,
+        }
+    }
+
+    pub fn with_width(mut self, width: f32) -> Self {
+        self.width = width;
+        self
+    }
+
+    pub fn with_sortable(mut self, sortable: bool) -> Self {
+        self.sortable = sortable;
+        self
+    }
+
+    pub fn editable(mut self) -> Self {
+        self.editable = true;
+        self
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum SortOrder {
+    Ascending,
+    Descending,
+}
+
+/// Lives on the table's root entity.
+#[derive(Component)]
+pub struct DataTable {
+    columns: Vec<Column>,
+    rows: Arc<Vec<Vec<CellValue>>>,
+    /// Data rows in display order.
+    order: Arc<Vec<usize>>,
+    sort: Option<(usize, SortOrder)>,
+    selection_mode: SelectionMode,
+    list: Entity,
+}
+
+impl DataTable {
+    pub fn new(columns: Vec<Column>, rows: Vec<Vec<CellValue>>) -> Self {
+        let order = (0..rows.len()).collect();
+        Self {
+            columns,
+            rows: Arc::new(rows),
+            order: Arc::new(order),
+            sort: None,
+            selection_mode: SelectionMode::Multiple,
+            list: Entity::PLACEHOLDER,
+        }
+    }
+
+    pub fn with_selection_mode(mut self, mode: SelectionMode) -> Self {
+        self.selection_mode = mode;
+        self
+    }
+
+    pub fn columns(&self) -> &[Column] {
+        &self.columns
+    }
+
+    pub fn rows(&self) -> &[Vec<CellValue>] {
+        &self.rows
+    }
+
+    pub fn cell(&self, row: usize, column: usize) -> Option<&CellValue> {
+        self.rows.get(row)?.get(column)
+    }
+
+    pub fn set_cell(&mut self, row: usize, column: usize, value: CellValue) {
+        let rows = Arc::make_mut(&mut self.rows);
+        if let Some(cells) = rows.get_mut(row) {
+            if cells.len() <= column {
+                cells.resize(column + 1, CellValue::Empty);
+            }
+            cells[column] = value;
+        }
+        self.sort();
+    }
+
+    pub fn set_rows(&mut self, rows: Vec<Vec<CellValue>>) {
+        self.rows = Arc::new(rows);
+        self.sort();
+    }
+
+    pub fn push_row(&mut self, row: Vec<CellValue>) {
+        Arc::make_mut(&mut self.rows).push(row);
+        self.sort();
+    }
+
+    pub fn set_column_width(&mut self, column: usize, width: f32) {
+        if let Some(column) = self.columns.get_mut(column) {
+            column.width = width.max(column.min_width);
+        }
+    }
+
+    pub fn sorting(&self) -> Option<(usize, SortOrder)> {
+        self.sort
+    }
+
+    /// Sorts by `column`, keeping the current order of equal rows; `None` restores data order.
+    pub fn sort_by(&mut self, sort: Option<(usize, SortOrder)>) {
+        self.sort = sort;
+        self.sort();
+    }
+
+    fn sort(&mut self) {
+        let mut order: Vec<usize> = (0..self.rows.len()).collect();
+        if let Some((column, direction)) = self.sort {
+            let key = |row: &usize| self.rows[*row].get(column).unwrap_or(&CellValue::Empty);
+            order.sort_by(|a, b| {
+                let ordering = key(a).compare(key(b));
+                match direction {
+                    SortOrder::Ascending => ordering,
+                    SortOrder::Descending => ordering.reverse(),
+                }
+            });
+        }
+        self.order = Arc::new(order);
+    }
+
+    /// Data rows in the order they're displayed.
+    pub fn view_order(&self) -> &[usize] {
+        &self.order
+    }
+
+    /// Where data row `row` is displayed.
+    pub fn view_position(&self, row: usize) -> Option<usize> {
+        self.order.iter().position(|data_row| *data_row == row)
+    }
+
+    /// Keeps the same data rows selected in `list` after they moved from the `previous` order.
+    fn follow_order(&self, previous: &[usize], list: &mut VirtualList) {
+        let mut positions = vec![None; self.rows.len()];
+        for (position, row) in self.order.iter().enumerate() {
+            positions[*row] = Some(position);
+        }
+        list.selection.remap(|position| {
+            previous
+                .get(position)
+                .and_then(|row| positions.get(*row).copied().flatten())
+        });
+    }
+
+    /// The entity holding the table's [`VirtualList`].
+    pub fn list(&self) -> Entity {
+        self.list
+    }
+
+    /// Data rows selected in `list`, which must be this table's list.
+    pub fn selected_rows(&self, list: &VirtualList) -> Vec<usize> {
+        list.selection
+            .iter()
+            .filter_map(|position| self.order.get(position).copied())
+            .collect()
+    }
+
+    /// The table as displayed (sorted, all columns) in CSV.
+    pub fn to_csv(&self) -> String {
+        let mut csv = String::new();
+        let titles: Vec<&str> = self
+            .columns
+            .iter()
+            .map(|column| column.title.as_str())
+            .collect();
+        push_csv_line(&mut csv, titles);
+        for row in self.order.iter() {
+            let cells: Vec<String> = (0..self.columns.len())
+                .map(|column| {
+                    self.rows[*row]
+                        .get(column)
+                        .map(ToString::to_string)
+                        .unwrap_or_default()
+                })
+                .collect();
+            push_csv_line(&mut csv, cells.iter().map(String::as_str));
+        }
+        csv
+    }
+
+    pub fn export_csv(&self, path: impl AsRef<Path>) -> std::io::Result<()> {
+        std::fs::write(path, self.to_csv())
+    }
+
+    fn source(&self, table: Entity, theme: &Theme) -> TableSource {
+        TableSource {
+            table,
+            columns: self.columns.clone(),
+            rows: self.rows.clone(),
+            order: self.order.clone(),
+            font: theme.text_font(),
+            color: TextColor(theme.text),
+        }
+    }
+}
+
+fn push_csv_line<'a>(csv: &mut String, fields: impl IntoIterator<Item = &'a str>) {
+    for (index, field) in fields.into_iter().enumerate() {
+        if index > 0 {
+            csv.push(',');
+        }
+        if field.contains([',', '"', '\n', '\r']) {
+            csv.push('"');
+            csv.push_str(&field.replace('"', "\"\""));
+            csv.push('"');
+        } else {
+            csv.push_str(field);
+        }
+    }
+    csv.push('\n');
+}
+
+/// Sent when an inline edit is committed.
+#[derive(Event, Clone, Debug)]
+pub struct CellEdited {
+    pub table: Entity,
+    /// Where the row is displayed once the edit has been sorted in.
+    pub row: usize,
+    /// The edited row in [`DataTable::rows`].
+    pub data_row: usize,
+    pub column: usize,
+    pub value: CellValue,
+}
+
+/// A cell showing `row` (a data row, not a display position) and `column`.
+#[derive(Component, Clone, Copy, Debug)]
+pub struct TableCell {
+    pub table: Entity,
+    pub row: usize,
+    pub column: usize,
+}
+
+#[derive(Component)]
+struct HeaderCell {
+    table: Entity,
+    column: usize,
+}
+
+#[derive(Component)]
+struct HeaderLabel {
+    table: Entity,
+    column: usize,
+}
+
+#[derive(Component)]
+struct ResizeHandle {
+    table: Entity,
+    column: usize,
+}
+
+#[derive(Component)]
+struct CellEditor(TableCell);
+
+struct TableSource {
+    table: Entity,
+    columns: Vec<Column>,
+    rows: Arc<Vec<Vec<CellValue>>>,
+    order: Arc<Vec<usize>>,
+    font: TextFont,
+    color: TextColor,
+}
+
+impl ListSource for TableSource {
+    fn len(&self) -> usize {
+        self.order.len()
+    }
+
+    fn row_height(&self, _index: usize) -> f32 {
+        ROW_HEIGHT
+    }
+
+    fn build_row(&self, index: usize, row: &mut ChildBuilder) {
+        let data_row = self.order[index];
+        row.spawn(Node {
+            height: Val::Percent(100.),
+            ..default()
+        })
+        .with_children(|cells| {
+            for (column, definition) in self.columns.iter().enumerate() {
+                let value = self.rows[data_row].get(column).unwrap_or(&CellValue::Empty);
+                let numeric = matches!(definition.kind, ColumnKind::Int | ColumnKind::Float);
+                cells
+                    .spawn((
+                        Node {
+                            width: Val::Px(definition.width),
+                            height: Val::Percent(100.),
+                            flex_shrink: 0.,
+                            align_items: AlignItems::Center,
+                            justify_content: if numeric {
+                                JustifyContent::FlexEnd
+                            } else {
+                                JustifyContent::FlexStart
+                            },
+                            padding: UiRect::horizontal(Val::Px(6.)),
+                            overflow: Overflow::clip(),
+                            ..default()
+                        },
+                        // let presses through to the row so it still gets selected
+                        Interaction::None,
+                        FocusPolicy::Pass,
+                        TableCell {
+                            table: self.table,
+                            row: data_row,
+                            column,
+                        },
+                    ))
+                    .with_child((Text::new(value.to_string()), self.font.clone(), self.color));
+            }
+        });
+    }
+}
+
+pub struct DataTablePlugin;
+
+impl Plugin for DataTablePlugin {
+    fn build(&self, app: &mut App) {
+        if !app.is_plugin_added::<VirtualListPlugin>() {
+            app.add_plugins(VirtualListPlugin);
+        }
+        if !app.is_plugin_added::<TextInputPlugin>() {
+            app.add_plugins(TextInputPlugin);
+        }
+        app.add_event::<CellEdited>().add_systems(
+            Update,
+            (
+                click_headers,
+                resize_columns,
+                start_edits,
+                commit_edits,
+                cancel_edits,
+                sync_tables,
+            )
+                .chain()
+                .before(VirtualListSystem),
+        );
+    }
+}
+
+/// Spawns a data table inside `root` and returns the entity holding the [`DataTable`].
+pub fn spawn_data_table(
+    parent: &mut ChildBuilder,
+    mut table: DataTable,
+    root: impl Bundle,
+) -> Entity {
+    let mut root_commands = parent.spawn(root);
+    let entity = root_commands.id();
+    let columns = table.columns.clone();
+    let selection_mode = table.selection_mode;
+    let mut list = Entity::PLACEHOLDER;
+    root_commands.with_children(|parent| {
+        parent
+            .spawn(Node {
+                width: Val::Percent(100.),
+                height: Val::Percent(100.),
+                flex_direction: FlexDirection::Column,
+                ..default()
+            })
+            .with_children(|parent| {
+                parent
+                    .spawn(Node {
+                        height: Val::Px(HEADER_HEIGHT),
+                        flex_shrink: 0.,
+                        padding: UiRect::horizontal(Val::Px(10.)),
+                        overflow: Overflow::clip(),
+                        ..default()
+                    })
+                    .with_children(|header| {
+                        for (column, definition) in columns.iter().enumerate() {
+                            spawn_header_cell(header, entity, column, definition);
+                        }
+                    });
+
+                // filled in by `sync_tables` once the table is inserted
+                let source = TableSource {
+                    table: entity,
+                    columns: Vec::new(),
+                    rows: default(),
+                    order: default(),
+                    font: default(),
+                    color: default(),
+                };
+                list = spawn_virtual_list(
+                    parent,
+                    VirtualList::new(source).with_selection_mode(selection_mode),
+                    Node {
+                        width: Val::Percent(100.),
+                        flex_grow: 1.,
+                        min_height: Val::Px(0.),
+                        ..default()
+                    },
+                );
+            });
+    });
+    table.list = list;
+    root_commands.insert(table);
+    entity
+}
+
+fn spawn_header_cell(header: &mut ChildBuilder, table: Entity, column: usize, definition: &Column) {
+    header
+        .spawn((
+            Button,
+            Node {
+                width: Val::Px(definition.width),
+                height: Val::Percent(100.),
+                flex_shrink: 0.,
+                align_items: AlignItems::Center,
+                padding: UiRect::horizontal(Val::Px(6.)),
+                ..default()
+            },
+            BackgroundColor::default(),
+            HeaderCell { table, column },
+        ))
+        .with_children(|cell| {
+            cell.spawn((
+                Text::new(definition.title.clone()),
+                TextFont::default(),
+                TextColor::default(),
+                HeaderLabel { table, column },
+            ));
+            cell.spawn((
+                Node {
+                    position_type: PositionType::Absolute,
+                    right: Val::Px(0.),
+                    top: Val::Px(0.),
+                    bottom: Val::Px(0.),
+                    width: Val::Px(RESIZE_HANDLE_WIDTH),
+                    ..default()
+                },
+                // keep the header underneath from sorting while resizing
+                Interaction::None,
+                FocusPolicy::Block,
+                BackgroundColor::default(),
+                ResizeHandle { table, column },
+            ));
+        });
+}
+
+fn click_headers(
+    headers: Query<(&Interaction, &HeaderCell), Changed<Interaction>>,
+    mut tables: Query<&mut DataTable>,
+    mut lists: Query<&mut VirtualList>,
+) {
+    for (interaction, header) in &headers {
+        if *interaction != Interaction::Pressed {
+            continue;
+        }
+        let Ok(mut table) = tables.get_mut(header.table) else {
+            continue;
+        };
+        if !table.columns[header.column].sortable {
+            continue;
+        }
+        let order = match table.sort {
+            Some((column, SortOrder::Ascending)) if column == header.column => {
+                SortOrder::Descending
+            }
+            _ => SortOrder::Ascending,
+        };
+        let previous = table.order.clone();
+        table.sort_by(Some((header.column, order)));
+        // the selected rows moved
+        if let Ok(mut list) = lists.get_mut(table.list) {
+            table.follow_order(&previous, &mut list);
+        }
+    }
+}
+
+fn resize_columns(
+    mouse: Res<ButtonInput<MouseButton>>,
+    window_query: Query<&Window, With<PrimaryWindow>>,
+    handles: Query<(&Interaction, &ResizeHandle)>,
+    mut tables: Query<&mut DataTable>,
+    mut drag: Local<Option<(Entity, usize, f32, f32)>>,
+) {
+    let Some(cursor) = window_query
+        .get_single()
+        .ok()
+        .and_then(|window| window.cursor_position())
+    else {
+        return;
+    };
+
+    if mouse.just_pressed(MouseButton::Left) {
+        for (interaction, handle) in &handles {
+            if *interaction != Interaction::Pressed {
+                continue;
+            }
+            if let Ok(table) = tables.get(handle.table) {
+                let width = table.columns[handle.column].width;
+                *drag = Some((handle.table, handle.column, cursor.x, width));
+            }
+        }
+    }
+    if !mouse.pressed(MouseButton::Left) {
+        *drag = None;
+    }
+
+    let Some((entity, column, start, width)) = *drag else {
+        return;
+    };
+    let Ok(mut table) = tables.get_mut(entity) else {
+        return;
+    };
+    let width = (width + cursor.x - start).max(table.columns[column].min_width);
+    if table.columns[column].width != width {
+        table.set_column_width(column, width);
+    }
+}
+
+fn start_edits(
+    mut commands: Commands,
+    time: Res<Time>,
+    theme: Res<Theme>,
+    cells: Query<(Entity, &Interaction, &TableCell), Changed<Interaction>>,
+    tables: Query<&DataTable>,
+    editors: Query<Entity, With<CellEditor>>,
+    mut last_click: Local<Option<(Entity, f64)>>,
+) {
+    let now = time.elapsed_secs_f64();
+    for (entity, interaction, cell) in &cells {
+        if *interaction != Interaction::Pressed {
+            continue;
+        }
+        let double_click = matches!(
+            *last_click,
+            Some((previous, at)) if previous == entity && now - at < DOUBLE_CLICK_TIME
+        );
+        *last_click = Some((entity, now));
+        if !double_click {
+            continue;
+        }
+
+        let Ok(table) = tables.get(cell.table) else {
+            continue;
+        };
+        if !table.columns[cell.column].editable {
+            continue;
+        }
+        for editor in &editors {
+            commands.entity(editor).despawn_recursive();
+        }
+        let value = table
+            .cell(cell.row, cell.column)
+            .map(ToString::to_string)
+            .unwrap_or_default();
+        commands.entity(entity).with_child((
+            Node {
+                position_type: PositionType::Absolute,
+                left: Val::Px(0.),
+                right: Val::Px(0.),
+                top: Val::Px(0.),
+                bottom: Val::Px(0.),
+                border: UiRect::all(Val::Px(1.)),
+                padding: UiRect::horizontal(Val::Px(5.)),
+                align_items: AlignItems::Center,
+                ..default()
+            },
+            BorderColor(theme.accent),
+            BackgroundColor(theme.surface),
+            TextInput,
+            TextInputValue(value),
+            TextInputInactive(false),
+            TextInputSettings {
+                retain_on_submit: true,
+                ..default()
+            },
+            TextInputTextFont(theme.text_font()),
+            TextInputTextColor(TextColor(theme.text)),
+            CellEditor(*cell),
+        ));
+        *last_click = None;
+    }
+}
+
+fn commit_edits(
+    mut commands: Commands,
+    mut submits: EventReader<TextInputSubmitEvent>,
+    mut editors: Query<(&CellEditor, &mut BorderColor)>,
+    mut tables: Query<&mut DataTable>,
+    mut lists: Query<&mut VirtualList>,
+    mut edits: EventWriter<CellEdited>,
+) {
+    for submit in submits.read() {
+        let Ok((CellEditor(cell), mut border)) = editors.get_mut(submit.entity) else {
+            continue;
+        };
+        let Ok(mut table) = tables.get_mut(cell.table) else {
+            continue;
+        };
+        match CellValue::parse(table.columns[cell.column].kind, &submit.value) {
+            Ok(value) => {
+                let previous = table.order.clone();
+                table.set_cell(cell.row, cell.column, value.clone());
+                // a sorted table may have moved the row, and the selected ones with it
+                if let Ok(mut list) = lists.get_mut(table.list) {
+                    table.follow_order(&previous, &mut list);
+                }
+                commands.entity(submit.entity).despawn_recursive();
+                edits.send(CellEdited {
+                    table: cell.table,
+                    row: table.view_position(cell.row).unwrap_or(cell.row),
+                    data_row: cell.row,
+                    column: cell.column,
+                    value,
+                });
+            }
+            Err(err) => {
+                println!("cannot edit {}: {err}", table.columns[cell.column].title);
+                border.0 = INVALID_EDIT;
+            }
+        }
+    }
+}
+
+fn cancel_edits(
+    mut commands: Commands,
+    keys: Res<ButtonInput<KeyCode>>,
+    editors: Query<Entity, With<CellEditor>>,
+) {
+    if keys.just_pressed(KeyCode::Escape) {
+        for editor in &editors {
+            commands.entity(editor).despawn_recursive();
+        }
+    }
+}
+
+/// Rows are only rebuilt when the data, its order or the theme changed; resizing a column just
+/// resizes the cells that are built.
+#[allow(clippy::type_complexity, clippy::too_many_arguments)]
+fn sync_tables(
+    theme: Res<Theme>,
+    tables: Query<(Entity, Ref<DataTable>)>,
+    mut lists: Query<&mut VirtualList>,
+    mut headers: Query<(&HeaderCell, &Interaction, &mut Node, &mut BackgroundColor)>,
+    mut cells: Query<(&TableCell, &mut Node), Without<HeaderCell>>,
+    mut labels: Query<(&HeaderLabel, &mut Text, &mut TextFont, &mut TextColor)>,
+    mut handles: Query<(&ResizeHandle, &Interaction, &mut BackgroundColor), Without<HeaderCell>>,
+    mut synced: Local<HashMap<Entity, Arc<Vec<usize>>>>,
+) {
+    for (header, interaction, mut node, mut color) in &mut headers {
+        let Ok((_, table)) = tables.get(header.table) else {
+            continue;
+        };
+        let width = Val::Px(table.columns[header.column].width);
+        if node.width != width {
+            node.width = width;
+        }
+        color.set_if_neq(BackgroundColor(theme.surface_color(*interaction)));
+    }
+    for (cell, mut node) in &mut cells {
+        let Ok((_, table)) = tables.get(cell.table) else {
+            continue;
+        };
+        let width = Val::Px(table.columns[cell.column].width);
+        if node.width != width {
+            node.width = width;
+        }
+    }
+    for (handle, interaction, mut color) in &mut handles {
+        let background = if *interaction == Interaction::None {
+            Color::NONE
+        } else {
+            theme.border
+        };
+        if tables.contains(handle.table) {
+            color.set_if_neq(BackgroundColor(background));
+        }
+    }
+
+    synced.retain(|entity, _| tables.contains(*entity));
+    for (entity, table) in &tables {
+        if !table.is_changed() && !theme.is_changed() {
+            continue;
+        }
+        // every change to the rows sorts them again into a new order
+        let rows_changed = synced
+            .get(&entity)
+            .is_none_or(|order| !Arc::ptr_eq(order, &table.order));
+        if (rows_changed || theme.is_changed())
+            && let Ok(mut list) = lists.get_mut(table.list)
+        {
+            list.set_source(table.source(entity, &theme));
+            synced.insert(entity, table.order.clone());
+        }
+        for (label, mut text, mut font, mut color) in &mut labels {
+            if label.table != entity {
+                continue;
+            }
+            let column = &table.columns[label.column];
+            let arrow = match table.sort {
+                Some((sorted, SortOrder::Ascending)) if sorted == label.column => " ↑",
+                Some((sorted, SortOrder::Descending)) if sorted == label.column => " ↓",
+                _ => "",
+            };
+            text.0 = format!("{}{arrow}", column.title);
+            *font = theme.text_font();
+            color.0 = theme.text;
+        }
+    }
+}
@@ -0,0 +1,1139 @@
+//! Docking: a [`DockArea`] lays out panels as tab groups in nested split panes, the way IDEs do,
+//! and lets the user rearrange them. Dragging a panel's tab over a group shows where it would go:
+//! the group's left, right, top or bottom half splits the group, its center adds the panel as a
+//! tab. Dropping it anywhere else floats it as a panel that can be dragged around the area by its
+//! title bar, and docked again the same way.
+//!
+//! The arrangement is a [`DockLayout`], which a [`DockLayoutStore`] keeps in a file across runs:
+//!
+//! ```ignore
+//! let layout = store.layout().cloned().unwrap_or_else(|| DockLayout::new(
+//!     DockNode::split(SplitDirection::Horizontal, 0.25,
+//!         DockNode::tabs(["inspector"]),
+//!         DockNode::tabs(["editor", "console"]),
+//!     ),
+//! ));
+//! let panels = [
+//!     DockPanel::new("inspector", "Inspector", |panel: &mut ChildBuilder| { ... }),
+//!     ...
+//! ];
+//! spawn_dock_area(parent, DockArea::new(panels, layout), Node::default());
+//! // and on DockLayoutChanged: store.save(dock.layout())
+//! ```
+//!
+//! Each panel's content is spawned once and moved around as the layout changes, so it keeps its
+//! state.
+
+use crate::store::RonStore;
+use crate::theme::Theme;
+use crate::widgets::overlay::node_rect;
+use crate::widgets::split_pane::{
+    PaneSize, SplitChanged, SplitDirection, SplitPane, SplitPanePlugin, spawn_split_pane,
+};
+use crate::widgets::tabs::TabContent;
+use bevy::prelude::*;
+use bevy::ui::FocusPolicy;
+use bevy::window::PrimaryWindow;
+use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
+use std::sync::Arc;
+
+/// How far the cursor moves before pressing a tab or title bar turns into a drag.
+const DRAG_THRESHOLD: f32 = 6.;
+/// The share of a group, from each edge, that docks a dropped panel on that side.
+const EDGE_ZONE: f32 = 0.25;
+/// The share of a group around its center that adds a dropped panel as a tab.
+const CENTER_ZONE: f32 = 0.3;
+/// The smallest size of a docked pane in logical pixels.
+const MIN_PANE_SIZE: f32 = 80.;
+/// The size a panel gets when it's floated.
+const FLOATING_SIZE: Vec2 = Vec2::new(320., 220.);
+const TITLE_BAR_HEIGHT: f32 = 30.;
+
+/// Where a dropped panel goes relative to a group.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum DockZone {
+    Left,
+    Right,
+    Top,
+    Bottom,
+    /// As a tab of the group.
+    Center,
+}
+
+/// A node of the docked part of a layout; panels are referred to by their id.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub enum DockNode {
+    /// Panels shown one at a time, with a tab for each.
+    Tabs { panels: Vec<String>, active: usize },
+    Split {
+        direction: SplitDirection,
+        /// The share of the split given to `first`.
+        ratio: f32,
+        first: Box<DockNode>,
+        second: Box<DockNode>,
+    },
+}
+
+impl DockNode {
+    pub fn tabs(panels: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        Self::Tabs {
+            panels: panels.into_iter().map(Into::into).collect(),
+            active: 0,
+        }
+    }
+
+    pub fn split(direction: SplitDirection, ratio: f32, first: DockNode, second: DockNode) -> Self {
+        Self::Split {
+            direction,
+            ratio,
+            first: Box::new(first),
+            second: Box::new(second),
+        }
+    }
+
+    /// The ids of the panels below this node, in layout order.
+    pub fn panels(&self) -> Vec<&str> {
+        match self {
+            DockNode::Tabs { panels, .. } => panels.iter().map(String::as_str).collect(),
+            DockNode::Split { first, second, .. } => {
+                let mut panels = first.panels();
+                panels.extend(second.panels());
+                panels
+            }
+        }
+    }
+
+    /// The node at `path`, where 0 is a split's first child and 1 its second.
+    fn at_path_mut(&mut self, path: &[usize]) -> Option<&mut DockNode> {
+        let Some((index, rest)) = path.split_first() else {
+            return Some(self);
+        };
+        match self {
+            DockNode::Split { first, .. } if *index == 0 => first.at_path_mut(rest),
+            DockNode::Split { second, .. } if *index == 1 => second.at_path_mut(rest),
+            _ => None,
+        }
+    }
+
+    /// The tab group holding `panel`.
+    fn group_of_mut(&mut self, panel: &str) -> Option<&mut DockNode> {
+        match self {
+            DockNode::Tabs { panels, .. } => panels
+                .iter()
+                .any(|candidate| candidate == panel)
+                .then_some(self),
+            DockNode::Split { first, second, .. } => first
+                .group_of_mut(panel)
+                .or_else(|| second.group_of_mut(panel)),
+        }
+    }
+
+    fn first_group_mut(&mut self) -> &mut DockNode {
+        match self {
+            DockNode::Tabs { .. } => self,
+            DockNode::Split { first, .. } => first.first_group_mut(),
+        }
+    }
+
+    /// This node without `panel`; groups left empty disappear, and so do the splits around them.
+    fn without(self, panel: &str) -> Option<DockNode> {
+        match self {
+            DockNode::Tabs {
+                mut panels,
+                mut active,
+            } => {
+                if let Some(index) = panels.iter().position(|candidate| candidate == panel) {
+                    panels.remove(index);
+                    if index < active {
+                        active -= 1;
+                    }
+                }
+                if panels.is_empty() {
+                    return None;
+                }
+                active = active.min(panels.len() - 1);
+                Some(DockNode::Tabs { panels, active })
+            }
+            DockNode::Split {
+                direction,
+                ratio,
+                first,
+                second,
+            } => match (first.without(panel), second.without(panel)) {
+                (Some(first), Some(second)) => {
+                    Some(DockNode::split(direction, ratio, first, second))
+                }
+                (Some(only), None) | (None, Some(only)) => Some(only),
+                (None, None) => None,
+            },
+        }
+    }
+
+    /// Splits this node to put `panel` on the `zone` side of it, or adds it as a tab.
+    fn dock(&mut self, panel: &str, zone: DockZone) {
+        if zone == DockZone::Center
+            && let DockNode::Tabs { panels, active } = self
+        {
+            panels.push(panel.to_string());
+            *active = panels.len() - 1;
+            return;
+        }
+        let existing = std::mem::replace(self, DockNode::tabs(Vec::<String>::new()));
+        let new = DockNode::tabs([panel]);
+        *self = match zone {
+            DockZone::Left => DockNode::split(SplitDirection::Horizontal, 0.35, new, existing),
+            DockZone::Right => DockNode::split(SplitDirection::Horizontal, 0.65, existing, new),
+            DockZone::Top => DockNode::split(SplitDirection::Vertical, 0.35, new, existing),
+            DockZone::Bottom | DockZone::Center => {
+                DockNode::split(SplitDirection::Vertical, 0.65, existing, new)
+            }
+        };
+    }
+}
+
+/// A panel floating over the docked ones; its position is relative to the dock area, in logical
+/// pixels.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct FloatingPanel {
+    pub panel: String,
+    pub position: Vec2,
+    pub size: Vec2,
+}
+
+/// The arrangement of a dock area's panels. Floating panels are listed back to front.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct DockLayout {
+    pub root: Option<DockNode>,
+    pub floating: Vec<FloatingPanel>,
+}
+
+impl DockLayout {
+    pub fn new(root: DockNode) -> Self {
+        Self {
+            root: Some(root),
+            floating: Vec::new(),
+        }
+    }
+
+    /// The ids of every panel in the layout, docked ones first.
+    pub fn panels(&self) -> Vec<&str> {
+        let mut panels = self.root.as_ref().map(DockNode::panels).unwrap_or_default();
+        panels.extend(self.floating.iter().map(|floating| floating.panel.as_str()));
+        panels
+    }
+
+    pub fn is_floating(&self, panel: &str) -> bool {
+        self.floating.iter().any(|floating| floating.panel == panel)
+    }
+
+    /// Whether `panel` floats or is the active tab of its group.
+    pub fn is_shown(&self, panel: &str) -> bool {
+        fn shown(node: &DockNode, panel: &str) -> bool {
+            match node {
+                DockNode::Tabs { panels, active } => {
+                    panels.get(*active).is_some_and(|active| active == panel)
+                }
+                DockNode::Split { first, second, .. } => {
+                    shown(first, panel) || shown(second, panel)
+                }
+            }
+        }
+        self.is_floating(panel) || self.root.as_ref().is_some_and(|root| shown(root, panel))
+    }
+
+    /// Takes `panel` out of the layout; returns false if it wasn't there.
+    pub fn remove(&mut self, panel: &str) -> bool {
+        let found = self.panels().contains(&panel);
+        self.floating.retain(|floating| floating.panel != panel);
+        self.root = self.root.take().and_then(|root| root.without(panel));
+        found
+    }
+}
+
+/// The layout of a dock area last saved, to restore it with.
+#[derive(Resource, Default, Debug)]
+pub struct DockLayoutStore(RonStore<Option<DockLayout>>);
+
+impl DockLayoutStore {
+    /// A store saved to `path` by [`DockLayoutStore::save`]; see [`RonStore::load`].
+    pub fn load(path: impl Into<PathBuf>) -> Result<Self, String> {
+        RonStore::load(path).map(Self)
+    }
+
+    /// The saved layout, if one was saved.
+    pub fn layout(&self) -> Option<&DockLayout> {
+        self.0.get().as_ref()
+    }
+
+    pub fn save(&mut self, layout: &DockLayout) {
+        *self.0.get_mut() = Some(layout.clone());
+        if let Err(err) = self.0.save() {
+            println!("failed to save {err}");
+        }
+    }
+}
+
+/// A panel that can be docked: its id in layouts, the title on its tab and its content.
+#[derive(Clone)]
+pub struct DockPanel {
+    pub id: String,
+    pub title: String,
+    content: Arc<dyn TabContent>,
+    entity: Option<Entity>,
+}
+
+impl DockPanel {
+    pub fn new(id: impl Into<String>, title: impl Into<String>, content: impl TabContent) -> Self {
+        Self {
+            id: id.into(),
+            title: title.into(),
+            content: Arc::new(content),
+            entity: None,
+        }
+    }
+
+    /// The node holding the panel's content, once the dock area is built.
+    pub fn entity(&self) -> Option<Entity> {
+        self.entity
+    }
+}
+
+#[derive(Component)]
+pub struct DockArea {
+    panels: Vec<DockPanel>,
+    layout: DockLayout,
+    rebuild: bool,
+    /// The entities built from the layout, despawned when it's built again.
+    structure: Vec<Entity>,
+    hint: Option<Entity>,
+}
+
+impl DockArea {
+    /// A dock area with `panels` arranged by `layout`. Panels the layout doesn't mention are added
+    /// to its first group, and ids it has but no panel has are dropped, so a saved layout still
+    /// works after panels are added or removed.
+    pub fn new(panels: impl IntoIterator<Item = DockPanel>, layout: DockLayout) -> Self {
+        let mut dock = Self {
+            panels: panels.into_iter().collect(),
+            layout: DockLayout::default(),
+            rebuild: true,
+            structure: Vec::new(),
+            hint: None,
+        };
+        dock.set_layout(layout);
+        dock
+    }
+
+    pub fn panels(&self) -> &[DockPanel] {
+        &self.panels
+    }
+
+    pub fn panel(&self, id: &str) -> Option<&DockPanel> {
+        self.panels.iter().find(|panel| panel.id == id)
+    }
+
+    pub fn layout(&self) -> &DockLayout {
+        &self.layout
+    }
+
+    pub fn set_layout(&mut self, mut layout: DockLayout) {
+        let unknown: Vec<String> = layout
+            .panels()
+            .into_iter()
+            .filter(|id| self.panel(id).is_none())
+            .map(str::to_string)
+            .collect();
+        for id in unknown {
+            layout.remove(&id);
+        }
+        for panel in &self.panels {
+            if layout.panels().contains(&panel.id.as_str()) {
+                continue;
+            }
+            match &mut layout.root {
+                Some(root) => root.first_group_mut().dock(&panel.id, DockZone::Center),
+                None => layout.root = Some(DockNode::tabs([panel.id.clone()])),
+            }
+        }
+        self.layout = layout;
+        self.rebuild = true;
+    }
+
+    /// Shows `panel` in its tab group; returns false if it already was shown or isn't docked.
+    pub fn select(&mut self, panel: &str) -> bool {
+        let Some(DockNode::Tabs { panels, active }) = self
+            .layout
+            .root
+            .as_mut()
+            .and_then(|root| root.group_of_mut(panel))
+        else {
+            return false;
+        };
+        let Some(index) = panels.iter().position(|candidate| candidate == panel) else {
+            return false;
+        };
+        if *active == index {
+            return false;
+        }
+        *active = index;
+        self.rebuild = true;
+        true
+    }
+
+    /// Docks `panel` on the `zone` side of the group holding `target`, or of the whole docked
+    /// layout when `target` is `None`. Returns false if the panel or the target isn't there.
+    pub fn dock(&mut self, panel: &str, target: Option<&str>, zone: DockZone) -> bool {
+        if self.panel(panel).is_none() || target == Some(panel) {
+            return false;
+        }
+        let mut layout = self.layout.clone();
+        layout.remove(panel);
+        match (target, &mut layout.root) {
+            (None, None) => layout.root = Some(DockNode::tabs([panel])),
+            (None, Some(root)) => root.dock(panel, zone),
+            (Some(target), root) => {
+                let Some(group) = root.as_mut().and_then(|root| root.group_of_mut(target)) else {
+                    return false;
+                };
+                group.dock(panel, zone);
+            }
+        }
+        self.layout = layout;
+        self.rebuild = true;
+        true
+    }
+
+    /// Floats `panel` at `position` in the dock area, or moves it there if it already floats.
+    pub fn float(&mut self, panel: &str, position: Vec2) -> bool {
+        if self.panel(panel).is_none() {
+            return false;
+        }
+        if let Some(floating) = self
+            .layout
+            .floating
+            .iter_mut()
+            .find(|floating| floating.panel == panel)
+        {
+            floating.position = position;
+        } else {
+            self.layout.remove(panel);
+            self.layout.floating.push(FloatingPanel {
+                panel: panel.to_string(),
+                position,
+                size: FLOATING_SIZE,
+            });
+        }
+        self.rebuild = true;
+        true
+    }
+
+    /// Brings a floating panel in front of the others; returns false if it already was.
+    fn raise(&mut self, panel: &str) -> bool {
+        let Some(index) = self
+            .layout
+            .floating
+            .iter()
+            .position(|floating| floating.panel == panel)
+        else {
+            return false;
+        };
+        if index + 1 == self.layout.floating.len() {
+            return false;
+        }
+        let floating = self.layout.floating.remove(index);
+        self.layout.floating.push(floating);
+        self.rebuild = true;
+        true
+    }
+}
+
+/// Where a panel was dropped.
+#[derive(Clone, Debug, PartialEq)]
+pub enum PanelPlace {
+    /// On the `zone` side of the group holding `target`, or of the whole layout.
+    Docked {
+        target: Option<String>,
+        zone: DockZone,
+    },
+    Floating,
+}
+
+/// Sent when the user drops a panel somewhere new.
+#[derive(Event, Clone, Debug)]
+pub struct PanelMoved {
+    pub dock: Entity,
+    pub panel: String,
+    pub place: PanelPlace,
+}
+
+/// Sent when the user changes a dock area's layout in any way, e.g. to save it.
+#[derive(Event, Clone, Copy, Debug)]
+pub struct DockLayoutChanged {
+    pub dock: Entity,
+}
+
+#[derive(Component, Clone, Debug)]
+pub struct DockGroup {
+    pub dock: Entity,
+    pub panels: Vec<String>,
+}
+
+#[derive(Component, Clone, Debug)]
+#[require(Button)]
+pub struct DockTab {
+    pub dock: Entity,
+    pub panel: String,
+}
+
+#[derive(Component, Clone, Debug)]
+pub struct DockFloating {
+    pub dock: Entity,
+    pub panel: String,
+}
+
+#[derive(Component, Clone, Debug)]
+#[require(Button)]
+pub struct DockTitleBar {
+    pub dock: Entity,
+    pub panel: String,
+}
+
+/// The node a panel's content is spawned in.
+#[derive(Component, Clone, Debug)]
+pub struct DockPanelContent {
+    pub dock: Entity,
+    pub panel: String,
+}
+
+/// A split of a docked layout, at `path` in it.
+#[derive(Component, Clone, Debug)]
+struct DockSplit {
+    dock: Entity,
+    path: Vec<usize>,
+}
+
+#[derive(Component)]
+struct DockDropHint;
+
+struct PanelDrag {
+    dock: Entity,
+    panel: String,
+    floating: bool,
+    start: Vec2,
+    cursor: Vec2,
+    /// Where the title bar was grabbed, from the floating panel's corner.
+    grab: Vec2,
+    dragging: bool,
+    target: Option<(Option<String>, DockZone)>,
+}
+
+#[derive(Resource, Default)]
+struct DockDrag(Option<PanelDrag>);
+
+pub struct DockPlugin;
+
+impl Plugin for DockPlugin {
+    fn build(&self, app: &mut App) {
+        if !app.is_plugin_added::<SplitPanePlugin>() {
+            app.add_plugins(SplitPanePlugin);
+        }
+        app.init_resource::<Theme>()
+            .init_resource::<DockDrag>()
+            .add_event::<PanelMoved>()
+            .add_event::<DockLayoutChanged>()
+            .add_systems(
+                Update,
+                (
+                    press_panels,
+                    drag_panels,
+                    sync_ratios,
+                    build_docks,
+                    draw_tabs,
+                )
+                    .chain(),
+            );
+    }
+}
+
+/// Spawns a dock area laid out by `root` and returns its entity. It fills its parent unless
+/// `root` says otherwise.
+pub fn spawn_dock_area(parent: &mut ChildBuilder, dock: DockArea, root: impl Bundle) -> Entity {
+    let mut dock_commands = parent.spawn(Node {
+        width: Val::Percent(100.),
+        height: Val::Percent(100.),
+        ..default()
+    });
+    dock_commands.insert(root);
+    dock_commands.insert(dock);
+    dock_commands.id()
+}
+
+/// The zone of `rect` under `cursor`, if it's in one.
+fn zone_at(rect: Rect, cursor: Vec2) -> Option<DockZone> {
+    let relative = (cursor - rect.min) / rect.size();
+    let center = (0.5 - CENTER_ZONE / 2.)..=(0.5 + CENTER_ZONE / 2.);
+    if !Rect::new(0., 0., 1., 1.).contains(relative) {
+        None
+    } else if relative.x < EDGE_ZONE {
+        Some(DockZone::Left)
+    } else if relative.x > 1. - EDGE_ZONE {
+        Some(DockZone::Right)
+    } else if relative.y < EDGE_ZONE {
+        Some(DockZone::Top)
+    } else if relative.y > 1. - EDGE_ZONE {
+        Some(DockZone::Bottom)
+    } else if center.contains(&relative.x) && center.contains(&relative.y) {
+        Some(DockZone::Center)
+    } else {
+        None
+    }
+}
+
+/// The part of `rect` a panel dropped in `zone` would take.
+fn zone_rect(rect: Rect, zone: DockZone) -> Rect {
+    let half = rect.size() / 2.;
+    match zone {
+        DockZone::Left => Rect::from_corners(rect.min, rect.min + Vec2::new(half.x, rect.height())),
+        DockZone::Right => Rect::from_corners(rect.min + Vec2::new(half.x, 0.), rect.max),
+        DockZone::Top => Rect::from_corners(rect.min, rect.min + Vec2::new(rect.width(), half.y)),
+        DockZone::Bottom => Rect::from_corners(rect.min + Vec2::new(0., half.y), rect.max),
+        DockZone::Center => rect,
+    }
+}
+
+/// Pressing a tab shows its panel and pressing a title bar raises its panel; either may start a
+/// drag.
+#[allow(clippy::too_many_arguments)]
+fn press_panels(
+    mouse: Res<ButtonInput<MouseButton>>,
+    windows: Query<&Window, With<PrimaryWindow>>,
+    mut drag: ResMut<DockDrag>,
+    tabs: Query<(&Interaction, &DockTab), Changed<Interaction>>,
+    bars: Query<(&Interaction, &DockTitleBar), Changed<Interaction>>,
+    mut docks: Query<&mut DockArea>,
+    nodes: Query<(&ComputedNode, &GlobalTransform)>,
+    mut changed: EventWriter<DockLayoutChanged>,
+) {
+    let cursor = windows
+        .get_single()
+        .ok()
+        .and_then(Window::cursor_position)
+        .filter(|_| mouse.just_pressed(MouseButton::Left));
+    let pressed = tabs
+        .iter()
+        .map(|(interaction, tab)| (interaction, tab.dock, &tab.panel, false))
+        .chain(
+            bars.iter()
+                .map(|(interaction, bar)| (interaction, bar.dock, &bar.panel, true)),
+        );
+    for (interaction, entity, panel, floating) in pressed {
+        if *interaction != Interaction::Pressed {
+            continue;
+        }
+        let Ok(mut dock) = docks.get_mut(entity) else {
+            continue;
+        };
+        let rearranged = if floating {
+            dock.raise(panel)
+        } else {
+            dock.select(panel)
+        };
+        if rearranged {
+            changed.send(DockLayoutChanged { dock: entity });
+        }
+        let Some(cursor) = cursor else {
+            continue;
+        };
+        let corner = nodes
+            .get(entity)
+            .map(|(node, transform)| node_rect(node, transform).min)
+            .unwrap_or_default();
+        let grab = dock
+            .layout
+            .floating
+            .iter()
+            .find(|candidate| candidate.panel == *panel)
+            .map_or(Vec2::ZERO, |floating| cursor - corner - floating.position);
+        drag.0 = Some(PanelDrag {
+            dock: entity,
+            panel: panel.clone(),
+            floating,
+            start: cursor,
+            cursor,
+            grab,
+            dragging: false,
+            target: None,
+        });
+    }
+}
+
+#[allow(clippy::too_many_arguments, clippy::type_complexity)]
+fn drag_panels(
+    mouse: Res<ButtonInput<MouseButton>>,
+    windows: Query<&Window, With<PrimaryWindow>>,
+    mut drag: ResMut<DockDrag>,
+    mut docks: Query<&mut DockArea>,
+    groups: Query<(&DockGroup, &ComputedNode, &GlobalTransform)>,
+    floating: Query<(&DockFloating, &ComputedNode, &GlobalTransform)>,
+    nodes: Query<(&ComputedNode, &GlobalTransform)>,
+    mut windows_nodes: Query<(&DockFloating, &mut Node)>,
+    mut hints: Query<&mut Node, (With<DockDropHint>, Without<DockFloating>)>,
+    mut moved: EventWriter<PanelMoved>,
+    mut changed: EventWriter<DockLayoutChanged>,
+) {
+    let Some(state) = &mut drag.0 else {
+        return;
+    };
+    let dock_entity = state.dock;
+    let (Ok(mut dock), Ok(dock_rect)) = (
+        docks.get_mut(dock_entity),
+        nodes
+            .get(dock_entity)
+            .map(|(node, transform)| node_rect(node, transform)),
+    ) else {
+        drag.0 = None;
+        return;
+    };
+    let hint = dock
+        .hint
+        .filter(|hint| hints.contains(*hint))
+        .unwrap_or(Entity::PLACEHOLDER);
+
+    if !mouse.pressed(MouseButton::Left) {
+        if let Ok(mut hint) = hints.get_mut(hint) {
+            hint.display = Display::None;
+        }
+        let Some(state) = drag.0.take().filter(|state| state.dragging) else {
+            return;
+        };
+        let place = match state.target {
+            Some((target, zone)) => {
+                if !dock.dock(&state.panel, target.as_deref(), zone) {
+                    return;
+                }
+                PanelPlace::Docked { target, zone }
+            }
+            None if !state.floating => {
+                let position = state.cursor
+                    - dock_rect.min
+                    - Vec2::new(FLOATING_SIZE.x / 2., TITLE_BAR_HEIGHT / 2.);
+                dock.float(&state.panel, position.max(Vec2::ZERO));
+                PanelPlace::Floating
+            }
+            // a floating panel was only moved
+            None => {
+                changed.send(DockLayoutChanged { dock: dock_entity });
+                return;
+            }
+        };
+        moved.send(PanelMoved {
+            dock: dock_entity,
+            panel: state.panel,
+            place,
+        });
+        changed.send(DockLayoutChanged { dock: dock_entity });
+        return;
+    }
+
+    let Some(cursor) = windows.get_single().ok().and_then(Window::cursor_position) else {
+        return;
+    };
+    state.cursor = cursor;
+    if !state.dragging && cursor.distance(state.start) > DRAG_THRESHOLD {
+        state.dragging = true;
+    }
+    if !state.dragging {
+        return;
+    }
+
+    if state.floating {
+        let position = (cursor - dock_rect.min - state.grab).max(Vec2::ZERO);
+        if let Some(floating) = dock
+            .layout
+            .floating
+            .iter_mut()
+            .find(|floating| floating.panel == state.panel)
+        {
+            floating.position = position;
+        }
+        for (window, mut node) in &mut windows_nodes {
+            if window.dock == dock_entity && window.panel == state.panel {
+                node.left = Val::Px(position.x);
+                node.top = Val::Px(position.y);
+            }
+        }
+    }
+
+    // panels floating over the groups hide them
+    let over_floating = floating.iter().any(|(window, node, transform)| {
+        window.dock == dock_entity
+            && window.panel != state.panel
+            && node_rect(node, transform).contains(cursor)
+    });
+    let target = if over_floating {
+        None
+    } else if dock.layout.root.is_none() {
+        zone_at(dock_rect, cursor).map(|zone| (None, dock_rect, zone))
+    } else {
+        groups.iter().find_map(|(group, node, transform)| {
+            let rect = node_rect(node, transform);
+            if group.dock != dock_entity || !rect.contains(cursor) {
+                return None;
+            }
+            let target = group.panels.iter().find(|panel| **panel != state.panel)?;
+            zone_at(rect, cursor).map(|zone| (Some(target.clone()), rect, zone))
+        })
+    };
+    state.target = target
+        .as_ref()
+        .map(|(target, _, zone)| (target.clone(), *zone));
+    let Ok(mut hint) = hints.get_mut(hint) else {
+        return;
+    };
+    let Some((_, rect, zone)) = target else {
+        hint.display = Display::None;
+        return;
+    };
+    let rect = zone_rect(rect, zone);
+    hint.display = Display::Flex;
+    hint.left = Val::Px(rect.min.x - dock_rect.min.x);
+    hint.top = Val::Px(rect.min.y - dock_rect.min.y);
+    hint.width = Val::Px(rect.width());
+    hint.height = Val::Px(rect.height());
+}
+
+/// Keeps the ratios of docked splits in the layout as their dividers are dragged, and reports
+/// the change once the mouse is released.
+fn sync_ratios(
+    mouse: Res<ButtonInput<MouseButton>>,
+    mut resized: EventReader<SplitChanged>,
+    splits: Query<&DockSplit>,
+    mut docks: Query<&mut DockArea>,
+    mut changed: EventWriter<DockLayoutChanged>,
+    mut pending: Local<Vec<Entity>>,
+) {
+    for event in resized.read() {
+        let Ok(split) = splits.get(event.split) else {
+            continue;
+        };
+        let Ok(mut dock) = docks.get_mut(split.dock) else {
+            continue;
+        };
+        if let Some(DockNode::Split { ratio, .. }) = dock
+            .layout
+            .root
+            .as_mut()
+            .and_then(|root| root.at_path_mut(&split.path))
+        {
+            *ratio = event.ratio;
+            if !pending.contains(&split.dock) {
+                pending.push(split.dock);
+            }
+        }
+    }
+    if !mouse.pressed(MouseButton::Left) {
+        for dock in pending.drain(..) {
+            changed.send(DockLayoutChanged { dock });
+        }
+    }
+}
+
+/// Builds the nodes of dock areas whose layout changed, moving the panels' contents into them.
+fn build_docks(
+    mut commands: Commands,
+    theme: Res<Theme>,
+    mut docks: Query<(Entity, &mut DockArea)>,
+    mut contents: Query<&mut Node, With<DockPanelContent>>,
+) {
+    for (dock_entity, mut dock) in &mut docks {
+        if !dock.rebuild {
+            continue;
+        }
+        let dock = dock.as_mut();
+        dock.rebuild = false;
+        for panel in &mut dock.panels {
+            let display = if dock.layout.is_shown(&panel.id) {
+                Display::Flex
+            } else {
+                Display::None
+            };
+            match panel.entity {
+                Some(entity) => {
+                    if let Ok(mut node) = contents.get_mut(entity) {
+                        node.display = display;
+                    }
+                    // keep the content while the nodes around it are replaced
+                    commands.entity(entity).set_parent(dock_entity);
+                }
+                None => {
+                    let content = panel.content.clone();
+                    let entity = commands
+                        .spawn((
+                            Node {
+                                display,
+                                flex_grow: 1.,
+                                flex_direction: FlexDirection::Column,
+                                min_width: Val::Px(0.),
+                                min_height: Val::Px(0.),
+                                overflow: Overflow::clip(),
+                                ..default()
+                            },
+                            DockPanelContent {
+                                dock: dock_entity,
+                                panel: panel.id.clone(),
+                            },
+                        ))
+                        .with_children(|parent| content.build(parent))
+                        .id();
+                    commands.entity(dock_entity).add_child(entity);
+                    panel.entity = Some(entity);
+                }
+            }
+        }
+        for entity in dock.structure.drain(..) {
+            commands.entity(entity).despawn_recursive();
+        }
+
+        let mut structure = Vec::new();
+        let mut hint = Entity::PLACEHOLDER;
+        let area: &DockArea = dock;
+        commands.entity(dock_entity).with_children(|parent| {
+            if let Some(root) = &area.layout.root {
+                let root = parent
+                    .spawn(Node {
+                        width: Val::Percent(100.),
+                        height: Val::Percent(100.),
+                        ..default()
+                    })
+                    .with_children(|parent| {
+                        spawn_dock_node(parent, dock_entity, area, root, Vec::new(), &theme);
+                    })
+                    .id();
+                structure.push(root);
+            }
+            for floating in &area.layout.floating {
+                let window = spawn_floating(parent, dock_entity, area, floating, &theme);
+                structure.push(window);
+            }
+            hint = parent
+                .spawn((
+                    Node {
+                        display: Display::None,
+                        position_type: PositionType::Absolute,
+                        ..default()
+                    },
+                    BackgroundColor(theme.accent.with_alpha(0.25)),
+                    BorderColor(theme.accent),
+                    ZIndex(i32::MAX),
+                    FocusPolicy::Pass,
+                    DockDropHint,
+                ))
+                .id();
+            structure.push(hint);
+        });
+        dock.structure = structure;
+        dock.hint = Some(hint);
+    }
+}
+
+fn spawn_dock_node(
+    parent: &mut ChildBuilder,
+    dock_entity: Entity,
+    dock: &DockArea,
+    node: &DockNode,
+    path: Vec<usize>,
+    theme: &Theme,
+) {
+    match node {
+        DockNode::Split {
+            direction,
+            ratio,
+            first,
+            second,
+        } => {
+            let split = SplitPane::new(*direction, *ratio)
+                .with_first_size(PaneSize::new(MIN_PANE_SIZE, f32::INFINITY))
+                .with_second_size(PaneSize::new(MIN_PANE_SIZE, f32::INFINITY));
+            let child_path = |index: usize| {
+                let mut path = path.clone();
+                path.push(index);
+                path
+            };
+            let (first_path, second_path) = (child_path(0), child_path(1));
+            spawn_split_pane(
+                parent,
+                split,
+                DockSplit {
+                    dock: dock_entity,
+                    path,
+                },
+                theme,
+                |parent| spawn_dock_node(parent, dock_entity, dock, first, first_path, theme),
+                |parent| spawn_dock_node(parent, dock_entity, dock, second, second_path, theme),
+            );
+        }
+        DockNode::Tabs { panels, active } => {
+            let contents: Vec<Entity> = panels
+                .iter()
+                .filter_map(|panel| dock.panel(panel).and_then(DockPanel::entity))
+                .collect();
+            parent
+                .spawn((
+                    Node {
+                        width: Val::Percent(100.),
+                        height: Val::Percent(100.),
+                        flex_direction: FlexDirection::Column,
+                        border: UiRect::all(Val::Px(1.)),
+                        ..default()
+                    },
+                    BackgroundColor(theme.surface),
+                    BorderColor(theme.border),
+                    DockGroup {
+                        dock: dock_entity,
+                        panels: panels.clone(),
+                    },
+                ))
+                .with_children(|parent| {
+                    parent
+                        .spawn((
+                            Node {
+                                flex_shrink: 0.,
+                                height: Val::Px(TITLE_BAR_HEIGHT),
+                                overflow: Overflow::clip(),
+                                ..default()
+                            },
+                            BackgroundColor(theme.background),
+                        ))
+                        .with_children(|strip| {
+                            for (index, panel) in panels.iter().enumerate() {
+                                let title = dock
+                                    .panel(panel)
+                                    .map_or(panel.as_str(), |panel| panel.title.as_str());
+                                let color = if index == *active {
+                                    theme.text
+                                } else {
+                                    theme.text_muted
+                                };
+                                strip
+                                    .spawn((
+                                        Node {
+                                            align_items: AlignItems::Center,
+                                            padding: UiRect::horizontal(Val::Px(12.)),
+                                            ..default()
+                                        },
+                                        BackgroundColor(theme.background),
+                                        DockTab {
+                                            dock: dock_entity,
+                                            panel: panel.clone(),
+                                        },
+                                    ))
+                                    .with_child((
+                                        Text::new(title),
+                                        theme.text_font(),
+                                        TextColor(color),
+                                    ));
+                            }
+                        });
+                    parent
+                        .spawn(Node {
+                            flex_grow: 1.,
+                            flex_direction: FlexDirection::Column,
+                            min_height: Val::Px(0.),
+                            ..default()
+                        })
+                        .add_children(&contents);
+                });
+        }
+    }
+}
+
+fn spawn_floating(
+    parent: &mut ChildBuilder,
+    dock_entity: Entity,
+    dock: &DockArea,
+    floating: &FloatingPanel,
+    theme: &Theme,
+) -> Entity {
+    let panel = dock.panel(&floating.panel);
+    let title = panel.map_or(floating.panel.as_str(), |panel| panel.title.as_str());
+    let content = panel.and_then(DockPanel::entity);
+    parent
+        .spawn((
+            Node {
+                position_type: PositionType::Absolute,
+                left: Val::Px(floating.position.x),
+                top: Val::Px(floating.position.y),
+                width: Val::Px(floating.size.x),
+                height: Val::Px(floating.size.y),
+                flex_direction: FlexDirection::Column,
+                border: UiRect::all(Val::Px(1.)),
+                ..default()
+            },
+            BackgroundColor(theme.surface),
+            BorderColor(theme.accent),
+            BorderRadius::all(Val::Px(4.)),
+            // keep the panels underneath from being clicked through this one
+            FocusPolicy::Block,
+            Interaction::None,
+            DockFloating {
+                dock: dock_entity,
+                panel: floating.panel.clone(),
+            },
+        ))
+        .with_children(|parent| {
+            parent
+                .spawn((
+                    Node {
+                        flex_shrink: 0.,
+                        height: Val::Px(TITLE_BAR_HEIGHT),
+                        align_items: AlignItems::Center,
+                        padding: UiRect::horizontal(Val::Px(12.)),
+                        ..default()
+                    },
+                    BackgroundColor(theme.background),
+                    DockTitleBar {
+                        dock: dock_entity,
+                        panel: floating.panel.clone(),
+                    },
+                ))
+                .with_child((Text::new(title), theme.text_font(), TextColor(theme.text)));
+            let mut body = parent.spawn(Node {
+                flex_grow: 1.,
+                flex_direction: FlexDirection::Column,
+                min_height: Val::Px(0.),
+                ..default()
+            });
+            if let Some(content) = content {
+                body.add_child(content);
+            }
+        })
+        .id()
+}
+
+#[allow(clippy::type_complexity)]
+fn draw_tabs(
+    theme: Res<Theme>,
+    docks: Query<&DockArea>,
+    mut tabs: Query<(&Interaction, &DockTab, &mut BackgroundColor), Without<DockTitleBar>>,
+    mut bars: Query<(&Interaction, &mut BackgroundColor), With<DockTitleBar>>,
+) {
+    for (interaction, tab, mut color) in &mut tabs {
+        let active = docks
+            .get(tab.dock)
+            .is_ok_and(|dock| dock.layout.is_shown(&tab.panel));
+        let background = match interaction {
+            Interaction::None if active => theme.surface,
+            Interaction::None => theme.background,
+            _ => theme.surface_hovered,
+        };
+        color.set_if_neq(BackgroundColor(background));
+    }
+    for (interaction, mut color) in &mut bars {
+        let background = match interaction {
+            Interaction::None => theme.background,
+            _ => theme.surface_hovered,
+        };
+        color.set_if_neq(BackgroundColor(background));
+    }
+}
@@ -0,0 +1,766 @@
+//! Drag and drop between UI nodes. A [`Draggable`] carries a typed payload; pressing it and
+//! moving the mouse picks it up, and a preview follows the cursor until the button is released.
+//! [`DropTarget`]s accepting the payload are outlined while it's dragged, the one under the
+//! cursor more strongly; a target accepts payloads by type, and optionally only the values of that
+//! type passing a filter. The drag is reported with observer events triggered on the entities
+//! involved:
+//!
+//! - [`DragStart`] and, if it's released anywhere but on an accepting target or Escape is
+//!   pressed, [`DragCancel`] on the dragged entity;
+//! - [`DragOver`] on the target under the cursor every frame, [`DragLeave`] when the cursor
+//!   leaves it and [`Drop`] when the payload is dropped on it.
+//!
+//! ```ignore
+//! parent.spawn((card, Draggable::new(Task(7))));
+//! parent
+//!     .spawn((trash, DropTarget::accepting::<Task>()))
+//!     .observe(|trigger: Trigger<Drop>, mut commands: Commands| {
+//!         let task = trigger.payload.get::<Task>().unwrap();
+//!         commands.entity(trigger.source).despawn_recursive();
+//!     });
+//! ```
+//!
+//! [`ReorderableList`] is built on these: its children can be dragged to reorder them, or moved
+//! to another list of the same group.
+
+use crate::theme::Theme;
+use crate::widgets::overlay::node_rect;
+use bevy::ecs::component::ComponentId;
+use bevy::ecs::world::DeferredWorld;
+use bevy::prelude::*;
+use bevy::ui::FocusPolicy;
+use bevy::window::PrimaryWindow;
+use std::any::{Any, TypeId};
+use std::sync::Arc;
+
+/// How far the cursor moves before pressing a draggable turns into a drag.
+const DRAG_THRESHOLD: f32 = 5.;
+/// Thickness of the line showing where a dropped list item goes.
+const INSERT_MARKER_SIZE: f32 = 2.;
+
+/// The value a draggable carries, of any type; targets accept payloads by type.
+#[derive(Clone)]
+pub struct DragPayload {
+    value: Arc<dyn Any + Send + Sync>,
+    type_name: &'static str,
+}
+
+impl DragPayload {
+    pub fn new<T: Any + Send + Sync>(value: T) -> Self {
+        Self {
+            value: Arc::new(value),
+            type_name: std::any::type_name::<T>(),
+        }
+    }
+
+    pub fn is<T: Any>(&self) -> bool {
+        self.type_id() == TypeId::of::<T>()
+    }
+
+    pub fn get<T: Any>(&self) -> Option<&T> {
+        self.value.downcast_ref()
+    }
+
+    pub fn type_id(&self) -> TypeId {
+        self.value.as_ref().type_id()
+    }
+
+    pub fn type_name(&self) -> &'static str {
+        self.type_name
+    }
+}
+
+impl std::fmt::Debug for DragPayload {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "DragPayload({})", self.type_name)
+    }
+}
+
+/// Builds the contents of a drag preview.
+pub trait DragPreview: Send + Sync + 'static {
+    fn build(&self, preview: &mut ChildBuilder);
+}
+
+impl<F: Fn(&mut ChildBuilder) + Send + Sync + 'static> DragPreview for F {
+    fn build(&self, preview: &mut ChildBuilder) {
+        self(preview)
+    }
+}
+
+#[derive(Component, Clone)]
+#[require(Interaction)]
+pub struct Draggable {
+    pub payload: DragPayload,
+    /// Builds the preview following the cursor; by default it's a copy of the node's box and
+    /// first text.
+    preview: Option<Arc<dyn DragPreview>>,
+}
+
+impl Draggable {
+    pub fn new<T: Any + Send + Sync>(payload: T) -> Self {
+        Self {
+            payload: DragPayload::new(payload),
+            preview: None,
+        }
+    }
+
+    pub fn with_preview(mut self, preview: impl DragPreview) -> Self {
+        self.preview = Some(Arc::new(preview));
+        self
+    }
+}
+
+type PayloadFilter = Arc<dyn Fn(&DragPayload) -> bool + Send + Sync>;
+
+/// A payload type a target accepts, and the filter its values have to pass.
+#[derive(Clone)]
+struct Accepted {
+    type_id: TypeId,
+    type_name: &'static str,
+    filter: Option<PayloadFilter>,
+}
+
+/// A node payloads of the accepted types can be dropped on.
+#[derive(Component, Clone, Default)]
+pub struct DropTarget {
+    accepts: Vec<Accepted>,
+}
+
+impl DropTarget {
+    pub fn accepting<T: Any>() -> Self {
+        Self::default().and::<T>()
+    }
+
+    /// Accepts the payloads of type `T` for which `filter` is true.
+    pub fn accepting_if<T: Any>(filter: impl Fn(&T) -> bool + Send + Sync + 'static) -> Self {
+        Self::default().and_if(filter)
+    }
+
+    pub fn and<T: Any>(mut self) -> Self {
+        self.accepts.push(Accepted {
+            type_id: TypeId::of::<T>(),
+            type_name: std::any::type_name::<T>(),
+            filter: None,
+        });
+        self
+    }
+
+    pub fn and_if<T: Any>(mut self, filter: impl Fn(&T) -> bool + Send + Sync + 'static) -> Self {
+        self.accepts.push(Accepted {
+            type_id: TypeId::of::<T>(),
+            type_name: std::any::type_name::<T>(),
+            filter: Some(Arc::new(move |payload| {
+                payload.get::<T>().is_some_and(&filter)
+            })),
+        });
+        self
+    }
+
+    pub fn accepts(&self, payload: &DragPayload) -> bool {
+        self.accepts.iter().any(|accepted| {
+            accepted.type_id == payload.type_id()
+                && accepted
+                    .filter
+                    .as_ref()
+                    .is_none_or(|filter| filter(payload))
+        })
+    }
+}
+
+impl std::fmt::Debug for DropTarget {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("DropTarget")
+            .field(
+                &self
+                    .accepts
+                    .iter()
+                    .map(|accepted| accepted.type_name)
+                    .collect::<Vec<_>>(),
+            )
+            .finish()
+    }
+}
+
+/// Triggered on a draggable when it's picked up.
+#[derive(Event, Clone, Debug)]
+pub struct DragStart {
+    pub payload: DragPayload,
+}
+
+/// Triggered on the target under the cursor every frame while a payload it accepts is dragged
+/// over it. `position` is the cursor's, from the target's top left corner in logical pixels.
+#[derive(Event, Clone, Debug)]
+pub struct DragOver {
+    pub source: Entity,
+    pub payload: DragPayload,
+    pub position: Vec2,
+}
+
+/// Triggered on a target when the cursor leaves it during a drag, or the drag is cancelled over
+/// it.
+#[derive(Event, Clone, Debug)]
+pub struct DragLeave {
+    pub source: Entity,
+}
+
+/// Triggered on a target when a payload it accepts is dropped on it.
+#[derive(Event, Clone, Debug)]
+pub struct Drop {
+    pub source: Entity,
+    pub payload: DragPayload,
+    pub position: Vec2,
+}
+
+/// Triggered on a draggable dropped outside of any target accepting it, or when Escape is
+/// pressed during the drag.
+#[derive(Event, Clone, Debug)]
+pub struct DragCancel {
+    pub payload: DragPayload,
+}
+
+/// The node following the cursor during a drag.
+#[derive(Component)]
+pub struct DragPreviewNode;
+
+/// Marks the targets outlined by the drag, so their outline is removed afterwards.
+#[derive(Component)]
+struct DropHighlight;
+
+struct ActiveDrag {
+    source: Entity,
+    payload: DragPayload,
+    start: Vec2,
+    /// Where the draggable was grabbed, from its top left corner.
+    grab: Vec2,
+    dragging: bool,
+    preview: Option<Entity>,
+    over: Option<Entity>,
+}
+
+/// The drag in progress, if any.
+#[derive(Resource, Default)]
+pub struct DragState(Option<ActiveDrag>);
+
+impl DragState {
+    /// The entity being dragged, once the mouse has moved far enough to start the drag.
+    pub fn dragged(&self) -> Option<Entity> {
+        self.0
+            .as_ref()
+            .filter(|drag| drag.dragging)
+            .map(|drag| drag.source)
+    }
+
+    pub fn payload(&self) -> Option<&DragPayload> {
+        self.0
+            .as_ref()
+            .filter(|drag| drag.dragging)
+            .map(|drag| &drag.payload)
+    }
+}
+
+/// A node whose children can be dragged to reorder them, or to another list of the same group.
+/// Its [`DropTarget`] only accepts the items of its group.
+#[derive(Component, Clone, Debug)]
+#[component(on_insert = list_inserted)]
+#[require(DropTarget)]
+pub struct ReorderableList {
+    pub group: String,
+}
+
+impl ReorderableList {
+    pub fn new(group: impl Into<String>) -> Self {
+        Self {
+            group: group.into(),
+        }
+    }
+}
+
+fn list_inserted(mut world: DeferredWorld, list: Entity, _: ComponentId) {
+    let Some(group) = world
+        .get::<ReorderableList>(list)
+        .map(|list| list.group.clone())
+    else {
+        return;
+    };
+    if let Some(mut target) = world.get_mut::<DropTarget>(list) {
+        *target = DropTarget::accepting_if::<ListItem>(move |item| item.group == group);
+    }
+}
+
+/// The payload of the children of a [`ReorderableList`].
+#[derive(Clone, Debug)]
+pub struct ListItem {
+    pub group: String,
+}
+
+/// Sent when an item is dropped in a list; `from` and `to` are its positions before and after.
+#[derive(Event, Clone, Copy, Debug)]
+pub struct ListItemMoved {
+    pub item: Entity,
+    pub from_list: Entity,
+    pub from: usize,
+    pub to_list: Entity,
+    pub to: usize,
+}
+
+/// The list and position a dragged item would be dropped at, and the line showing it.
+#[derive(Resource, Default)]
+struct ListInsertion {
+    target: Option<(Entity, usize)>,
+    marker: Option<Entity>,
+}
+
+pub struct DragDropPlugin;
+
+impl Plugin for DragDropPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<Theme>()
+            .init_resource::<DragState>()
+            .init_resource::<ListInsertion>()
+            .add_event::<ListItemMoved>()
+            .add_systems(
+                Update,
+                (
+                    make_items_draggable,
+                    press_draggables,
+                    move_drags,
+                    highlight_targets,
+                )
+                    .chain(),
+            )
+            .add_observer(show_list_insertion)
+            .add_observer(hide_list_insertion)
+            .add_observer(drop_list_items)
+            .add_observer(cancel_list_insertion);
+    }
+}
+
+/// Picks up the innermost pressed draggable; the drag starts once the mouse moves.
+fn press_draggables(
+    mouse: Res<ButtonInput<MouseButton>>,
+    windows: Query<&Window, With<PrimaryWindow>>,
+    mut state: ResMut<DragState>,
+    draggables: Query<(
+        Entity,
+        &Interaction,
+        &Draggable,
+        &ComputedNode,
+        &GlobalTransform,
+    )>,
+) {
+    if !mouse.just_pressed(MouseButton::Left) || state.0.is_some() {
+        return;
+    }
+    let Some(cursor) = windows.get_single().ok().and_then(Window::cursor_position) else {
+        return;
+    };
+    let pressed = draggables
+        .iter()
+        .filter(|(_, interaction, ..)| **interaction == Interaction::Pressed)
+        .max_by_key(|(.., node, _)| node.stack_index());
+    if let Some((entity, _, draggable, node, transform)) = pressed {
+        state.0 = Some(ActiveDrag {
+            source: entity,
+            payload: draggable.payload.clone(),
+            start: cursor,
+            grab: cursor - node_rect(node, transform).min,
+            dragging: false,
+            preview: None,
+            over: None,
+        });
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+fn move_drags(
+    mut commands: Commands,
+    theme: Res<Theme>,
+    mouse: Res<ButtonInput<MouseButton>>,
+    keys: Res<ButtonInput<KeyCode>>,
+    windows: Query<&Window, With<PrimaryWindow>>,
+    mut state: ResMut<DragState>,
+    draggables: Query<(&Draggable, &ComputedNode, &GlobalTransform)>,
+    targets: Query<(Entity, &DropTarget, &ComputedNode, &GlobalTransform)>,
+    children: Query<&Children>,
+    texts: Query<(&Text, Option<&TextFont>)>,
+    mut previews: Query<&mut Node, With<DragPreviewNode>>,
+) {
+    let Some(drag) = &mut state.0 else {
+        return;
+    };
+    let cursor = windows.get_single().ok().and_then(Window::cursor_position);
+    let released = !mouse.pressed(MouseButton::Left);
+    let cancelled = keys.just_pressed(KeyCode::Escape) || !draggables.contains(drag.source);
+    if !drag.dragging {
+        if released || cancelled {
+            state.0 = None;
+            return;
+        }
+        if cursor.is_none_or(|cursor| cursor.distance(drag.start) <= DRAG_THRESHOLD) {
+            return;
+        }
+        drag.dragging = true;
+        drag.preview = draggables
+            .get(drag.source)
+            .ok()
+            .map(|(draggable, node, transform)| {
+                let size = node_rect(node, transform).size();
+                spawn_preview(
+                    &mut commands,
+                    &theme,
+                    drag.source,
+                    draggable,
+                    size,
+                    &children,
+                    &texts,
+                )
+            });
+        commands.trigger_targets(
+            DragStart {
+                payload: drag.payload.clone(),
+            },
+            drag.source,
+        );
+    }
+
+    // the accepting target under the cursor, innermost first
+    let over = cursor.and_then(|cursor| {
+        targets
+            .iter()
+            .filter(|(entity, target, node, transform)| {
+                *entity != drag.source
+                    && target.accepts(&drag.payload)
+                    && node_rect(node, transform).contains(cursor)
+            })
+            .max_by_key(|(.., node, _)| node.stack_index())
+            .map(|(entity, _, node, transform)| (entity, cursor - node_rect(node, transform).min))
+    });
+    // a drop on the target the cursor was over doesn't leave it
+    if let Some(previous) = drag.over
+        && (cancelled || over.is_none_or(|(entity, _)| entity != previous))
+    {
+        commands.trigger_targets(
+            DragLeave {
+                source: drag.source,
+            },
+            previous,
+        );
+    }
+
+    if released || cancelled {
+        let drag = state.0.take().expect("a drag is in progress");
+        if let Some(preview) = drag.preview {
+            commands.entity(preview).despawn_recursive();
+        }
+        match over.filter(|_| !cancelled) {
+            Some((target, position)) => commands.trigger_targets(
+                Drop {
+                    source: drag.source,
+                    payload: drag.payload,
+                    position,
+                },
+                target,
+            ),
+            None => commands.trigger_targets(
+                DragCancel {
+                    payload: drag.payload,
+                },
+                drag.source,
+            ),
+        }
+        return;
+    }
+
+    drag.over = over.map(|(entity, _)| entity);
+    if let Some((target, position)) = over {
+        commands.trigger_targets(
+            DragOver {
+                source: drag.source,
+                payload: drag.payload.clone(),
+                position,
+            },
+            target,
+        );
+    }
+    if let (Some(cursor), Some(mut preview)) = (
+        cursor,
+        drag.preview
+            .and_then(|preview| previews.get_mut(preview).ok()),
+    ) {
+        let corner = cursor - drag.grab;
+        preview.left = Val::Px(corner.x);
+        preview.top = Val::Px(corner.y);
+    }
+}
+
+/// Spawns the preview of a drag at the dragged node's place; [`move_drags`] moves it along.
+fn spawn_preview(
+    commands: &mut Commands,
+    theme: &Theme,
+    source: Entity,
+    draggable: &Draggable,
+    size: Vec2,
+    children: &Query<&Children>,
+    texts: &Query<(&Text, Option<&TextFont>)>,
+) -> Entity {
+    // spawned out of sight; it's moved under the cursor on the next frame
+    let mut node = Node {
+        position_type: PositionType::Absolute,
+        left: Val::Px(-size.x),
+        top: Val::Px(-size.y),
+        ..default()
+    };
+    let custom = draggable.preview.clone();
+    if custom.is_none() {
+        node.width = Val::Px(size.x);
+        node.height = Val::Px(size.y);
+        node.justify_content = JustifyContent::Center;
+        node.align_items = AlignItems::Center;
+        node.border = UiRect::all(Val::Px(1.));
+    }
+    let mut preview = commands.spawn((
+        node,
+        GlobalZIndex(i32::MAX),
+        // keep the targets underneath hovered
+        FocusPolicy::Pass,
+        DragPreviewNode,
+    ));
+    if let Some(custom) = custom {
+        preview.with_children(|parent| custom.build(parent));
+        return preview.id();
+    }
+    preview.insert((
+        BackgroundColor(theme.surface_hovered.with_alpha(0.85)),
+        BorderColor(theme.accent),
+        BorderRadius::all(Val::Px(4.)),
+    ));
+    let text = std::iter::once(source)
+        .chain(children.iter_descendants(source))
+        .find_map(|entity| texts.get(entity).ok());
+    if let Some((text, font)) = text {
+        let font = font.cloned().unwrap_or_else(|| theme.text_font());
+        preview.with_child((text.clone(), font, TextColor(theme.text)));
+    }
+    preview.id()
+}
+
+/// Outlines the targets accepting the dragged payload, and the one under the cursor more
+/// strongly.
+#[allow(clippy::type_complexity)]
+fn highlight_targets(
+    mut commands: Commands,
+    theme: Res<Theme>,
+    state: Res<DragState>,
+    targets: Query<(Entity, &DropTarget, Option<&Outline>, Has<DropHighlight>)>,
+) {
+    let drag = state.0.as_ref().filter(|drag| drag.dragging);
+    for (entity, target, outline, highlighted) in &targets {
+        let color = drag
+            .filter(|drag| target.accepts(&drag.payload) && drag.source != entity)
+            .map(|drag| {
+                if drag.over == Some(entity) {
+                    theme.accent
+                } else {
+                    theme.accent.with_alpha(0.35)
+                }
+            });
+        match color {
+            Some(color) if outline.is_none_or(|outline| outline.color != color) => {
+                commands
+                    .entity(entity)
+                    .insert((Outline::new(Val::Px(2.), Val::Px(0.), color), DropHighlight));
+            }
+            None if highlighted => {
+                commands.entity(entity).remove::<(Outline, DropHighlight)>();
+            }
+            _ => {}
+        }
+    }
+}
+
+/// The children of reorderable lists become draggable items of the list's group.
+fn make_items_draggable(
+    mut commands: Commands,
+    lists: Query<(&ReorderableList, &Children), Changed<Children>>,
+    draggables: Query<&Draggable>,
+) {
+    for (list, children) in &lists {
+        for child in children {
+            let is_item = draggables
+                .get(*child)
+                .is_ok_and(|draggable| draggable.payload.is::<ListItem>());
+            if !is_item {
+                commands.entity(*child).insert(Draggable::new(ListItem {
+                    group: list.group.clone(),
+                }));
+            }
+        }
+    }
+}
+
+/// Where in `list` an item dropped at `position` goes, counting the other items.
+#[allow(clippy::type_complexity)]
+fn insertion_index(
+    list: Entity,
+    item: Entity,
+    position: Vec2,
+    lists: &Query<
+        (&Node, &ComputedNode, &GlobalTransform, Option<&Children>),
+        With<ReorderableList>,
+    >,
+    items: &Query<(&ComputedNode, &GlobalTransform)>,
+) -> Option<(usize, Rect)> {
+    let (node, computed, transform, children) = lists.get(list).ok()?;
+    let list_rect = node_rect(computed, transform);
+    let cursor = list_rect.min + position;
+    let horizontal = matches!(
+        node.flex_direction,
+        FlexDirection::Row | FlexDirection::RowReverse
+    );
+    let main = |point: Vec2| if horizontal { point.x } else { point.y };
+    let rects: Vec<Rect> = children
+        .into_iter()
+        .flatten()
+        .filter(|child| **child != item)
+        .filter_map(|child| items.get(*child).ok())
+        .map(|(node, transform)| node_rect(node, transform))
+        .collect();
+    let index = rects
+        .iter()
+        .filter(|rect| main(rect.center()) < main(cursor))
+        .count();
+    // a thin line in the gap before the item at `index`, or after the last one
+    let at = match (rects.get(index), rects.last()) {
+        (Some(next), _) => main(next.min) - INSERT_MARKER_SIZE,
+        (None, Some(last)) => main(last.max),
+        (None, None) => main(list_rect.min),
+    };
+    let marker = if horizontal {
+        Rect::new(
+            at,
+            list_rect.min.y,
+            at + INSERT_MARKER_SIZE,
+            list_rect.max.y,
+        )
+    } else {
+        Rect::new(
+            list_rect.min.x,
+            at,
+            list_rect.max.x,
+            at + INSERT_MARKER_SIZE,
+        )
+    };
+    Some((index, marker))
+}
+
+#[allow(clippy::type_complexity, clippy::too_many_arguments)]
+fn show_list_insertion(
+    trigger: Trigger<DragOver>,
+    mut commands: Commands,
+    theme: Res<Theme>,
+    mut insertion: ResMut<ListInsertion>,
+    lists: Query<
+        (&Node, &ComputedNode, &GlobalTransform, Option<&Children>),
+        With<ReorderableList>,
+    >,
+    items: Query<(&ComputedNode, &GlobalTransform)>,
+    mut markers: Query<&mut Node, Without<ReorderableList>>,
+) {
+    let list = trigger.entity();
+    let event = trigger.event();
+    let Some((index, rect)) = insertion_index(list, event.source, event.position, &lists, &items)
+    else {
+        return;
+    };
+    insertion.target = Some((list, index));
+    let marker_node = Node {
+        position_type: PositionType::Absolute,
+        left: Val::Px(rect.min.x),
+        top: Val::Px(rect.min.y),
+        width: Val::Px(rect.width()),
+        height: Val::Px(rect.height()),
+        ..default()
+    };
+    match insertion
+        .marker
+        .and_then(|marker| markers.get_mut(marker).ok())
+    {
+        Some(mut node) => {
+            if *node != marker_node {
+                *node = marker_node;
+            }
+        }
+        None => {
+            let marker = commands
+                .spawn((
+                    marker_node,
+                    BackgroundColor(theme.accent),
+                    GlobalZIndex(i32::MAX - 1),
+                    FocusPolicy::Pass,
+                ))
+                .id();
+            insertion.marker = Some(marker);
+        }
+    }
+}
+
+fn clear_insertion(commands: &mut Commands, insertion: &mut ListInsertion) {
+    insertion.target = None;
+    if let Some(marker) = insertion.marker.take() {
+        commands.entity(marker).despawn_recursive();
+    }
+}
+
+fn hide_list_insertion(
+    trigger: Trigger<DragLeave>,
+    mut commands: Commands,
+    mut insertion: ResMut<ListInsertion>,
+) {
+    if insertion
+        .target
+        .is_some_and(|(list, _)| list == trigger.entity())
+    {
+        clear_insertion(&mut commands, &mut insertion);
+    }
+}
+
+fn cancel_list_insertion(
+    _trigger: Trigger<DragCancel>,
+    mut commands: Commands,
+    mut insertion: ResMut<ListInsertion>,
+) {
+    clear_insertion(&mut commands, &mut insertion);
+}
+
+fn drop_list_items(
+    trigger: Trigger<Drop>,
+    mut commands: Commands,
+    mut insertion: ResMut<ListInsertion>,
+    parents: Query<&Parent>,
+    children: Query<&Children>,
+    mut moved: EventWriter<ListItemMoved>,
+) {
+    let list = trigger.entity();
+    let item = trigger.source;
+    let target = insertion.target.filter(|(target, _)| *target == list);
+    clear_insertion(&mut commands, &mut insertion);
+    let Some((_, to)) = target else {
+        return;
+    };
+    let Ok(from_list) = parents.get(item).map(Parent::get) else {
+        return;
+    };
+    let from = children
+        .get(from_list)
+        .ok()
+        .and_then(|children| children.iter().position(|child| *child == item))
+        .unwrap_or_default();
+    commands.entity(list).insert_children(to, &[item]);
+    moved.send(ListItemMoved {
+        item,
+        from_list,
+        from,
+        to_list: list,
+        to,
+    });
+}
@@ -0,0 +1,798 @@
+//! Picking one of many options from a popup list. A [`Dropdown`] is a button showing the selected
+//! option; a [`ComboBox`] is a text input suggesting the options that contain what's typed.
+//!
+//! The popup is an [overlay](crate::widgets::overlay) anchored below its owner, or above it when
+//! there's no room below. While it's open the arrow keys move the highlight, Enter picks the
+//! highlighted option and Escape closes it. A focused dropdown also jumps to the first option
+//! starting with the letters typed.
+
+use crate::theme::Theme;
+use crate::widgets::focus::{Focus, FocusPlugin, Focusable};
+use crate::widgets::overlay::{
+    Overlay, OverlayLayer, OverlayPlugin, OverlaySystem, Placement, spawn_overlay,
+};
+use crate::widgets::scroll_view::{
+    ScrollIntoView, ScrollView, ScrollViewPlugin, ScrollViewSystem, spawn_scroll_view,
+};
+use bevy::input::ButtonState;
+use bevy::input::keyboard::{Key, KeyboardInput};
+use bevy::prelude::*;
+use bevy_simple_text_input::{
+    TextInput, TextInputInactive, TextInputPlaceholder, TextInputPlugin, TextInputSettings,
+    TextInputSubmitEvent, TextInputTextColor, TextInputTextFont, TextInputValue,
+};
+use std::f32::consts::FRAC_PI_4;
+
+const OPTION_HEIGHT: f32 = 28.;
+const POPUP_MAX_HEIGHT: f32 = 240.;
+/// Space between the owner and its popup.
+const POPUP_GAP: f32 = 2.;
+/// Seconds after which typed letters start a new search.
+const TYPE_AHEAD_TIMEOUT: f32 = 1.;
+
+#[derive(Component, Clone, Debug, Default)]
+#[require(Button, Focusable, PopupState)]
+pub struct Dropdown {
+    pub options: Vec<String>,
+    pub selected: Option<usize>,
+    /// Shown while nothing is selected.
+    pub placeholder: String,
+}
+
+impl Dropdown {
+    pub fn new(options: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        Self {
+            options: options.into_iter().map(Into::into).collect(),
+            selected: None,
+            placeholder: "Select…".to_string(),
+        }
+    }
+
+    pub fn with_selected(mut self, selected: usize) -> Self {
+        self.selected = Some(selected);
+        self
+    }
+
+    pub fn with_placeholder(mut self, placeholder: impl Into<String>) -> Self {
+        self.placeholder = placeholder.into();
+        self
+    }
+
+    pub fn selected_option(&self) -> Option<&str> {
+        self.selected
+            .and_then(|index| self.options.get(index))
+            .map(String::as_str)
+    }
+}
+
+/// A text input whose popup suggests the options containing the typed text (ignoring case). Any
+/// text can be submitted, not only an option.
+#[derive(Component, Clone, Debug, Default)]
+#[require(PopupState)]
+pub struct ComboBox {
+    pub options: Vec<String>,
+}
+
+impl ComboBox {
+    pub fn new(options: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        Self {
+            options: options.into_iter().map(Into::into).collect(),
+        }
+    }
+
+    /// Indices of the options containing `text`, in order.
+    pub fn matches(&self, text: &str) -> Vec<usize> {
+        let text = text.to_lowercase();
+        self.options
+            .iter()
+            .enumerate()
+            .filter(|(_, option)| option.to_lowercase().contains(&text))
+            .map(|(index, _)| index)
+            .collect()
+    }
+}
+
+/// Sent when the user picks an option of a dropdown.
+#[derive(Event, Clone, Copy, Debug)]
+pub struct DropdownChanged {
+    pub dropdown: Entity,
+    pub selected: usize,
+}
+
+/// Sent when the user picks a suggestion or submits the text of a combo box. `option` is the
+/// index of the option matching `value`, if any.
+#[derive(Event, Clone, Debug)]
+pub struct ComboBoxChanged {
+    pub combo_box: Entity,
+    pub value: String,
+    pub option: Option<usize>,
+}
+
+/// The open popup of a dropdown or combo box.
+#[derive(Component, Clone, Copy, Debug)]
+pub struct DropdownPopup {
+    pub owner: Entity,
+}
+
+/// One option in a popup; `index` points into the owner's options.
+#[derive(Component, Clone, Copy, Debug)]
+#[require(Button)]
+pub struct DropdownOption {
+    pub owner: Entity,
+    pub index: usize,
+}
+
+#[derive(Component, Default)]
+struct PopupState {
+    popup: Option<Entity>,
+    /// The options listed in the popup, by index.
+    shown: Vec<usize>,
+    /// Position in `shown` of the highlighted option.
+    highlighted: Option<usize>,
+    /// Letters typed for type-ahead, and when the last one was.
+    typed: String,
+    typed_at: f32,
+    /// Set when a pick changes a combo box's text, so the change doesn't reopen the popup.
+    picked: bool,
+}
+
+impl PopupState {
+    fn highlighted_option(&self) -> Option<usize> {
+        self.highlighted
+            .and_then(|position| self.shown.get(position))
+            .copied()
+    }
+}
+
+#[derive(Component)]
+struct DropdownLabel {
+    dropdown: Entity,
+}
+
+pub struct DropdownPlugin;
+
+impl Plugin for DropdownPlugin {
+    fn build(&self, app: &mut App) {
+        if !app.is_plugin_added::<FocusPlugin>() {
+            app.add_plugins(FocusPlugin);
+        }
+        if !app.is_plugin_added::<OverlayPlugin>() {
+            app.add_plugins(OverlayPlugin);
+        }
+        if !app.is_plugin_added::<ScrollViewPlugin>() {
+            app.add_plugins(ScrollViewPlugin);
+        }
+        if !app.is_plugin_added::<TextInputPlugin>() {
+            app.add_plugins(TextInputPlugin);
+        }
+        app.add_event::<DropdownChanged>()
+            .add_event::<ComboBoxChanged>()
+            .add_systems(
+                Update,
+                (
+                    toggle_dropdowns,
+                    dropdown_keys,
+                    filter_combo_boxes,
+                    combo_box_keys,
+                    submit_combo_boxes,
+                    click_options,
+                    hover_options,
+                    close_popups,
+                    draw_options,
+                    draw_dropdowns,
+                )
+                    .chain()
+                    .after(OverlaySystem)
+                    .before(ScrollViewSystem),
+            );
+    }
+}
+
+/// Spawns a dropdown laid out by `root` and returns its entity.
+pub fn spawn_dropdown(
+    parent: &mut ChildBuilder,
+    dropdown: Dropdown,
+    root: impl Bundle,
+    theme: &Theme,
+) -> Entity {
+    let mut dropdown_commands = parent.spawn((
+        Node {
+            min_width: Val::Px(160.),
+            justify_content: JustifyContent::SpaceBetween,
+            align_items: AlignItems::Center,
+            column_gap: Val::Px(12.),
+            padding: UiRect::axes(Val::Px(10.), Val::Px(6.)),
+            border: UiRect::all(Val::Px(1.)),
+            ..default()
+        },
+        BackgroundColor(theme.surface),
+        BorderColor(theme.border),
+        BorderRadius::all(Val::Px(4.)),
+    ));
+    dropdown_commands.insert(root);
+    let entity = dropdown_commands.id();
+    dropdown_commands.insert(dropdown).with_children(|parent| {
+        parent.spawn((
+            Text::default(),
+            theme.text_font(),
+            TextColor(theme.text),
+            DropdownLabel { dropdown: entity },
+        ));
+        // a chevron: the bottom right corner of a square, turned to point down
+        parent.spawn((
+            Node {
+                width: Val::Px(7.),
+                height: Val::Px(7.),
+                margin: UiRect::bottom(Val::Px(3.)),
+                border: UiRect {
+                    right: Val::Px(2.),
+                    bottom: Val::Px(2.),
+                    ..default()
+                },
+                ..default()
+            },
+            BorderColor(theme.text_muted),
+            Transform::from_rotation(Quat::from_rotation_z(FRAC_PI_4)),
+        ));
+    });
+    entity
+}
+
+/// Spawns a combo box laid out by `root` and returns its entity, which is also its text input.
+pub fn spawn_combo_box(
+    parent: &mut ChildBuilder,
+    combo_box: ComboBox,
+    root: impl Bundle,
+    placeholder: impl Into<String>,
+    theme: &Theme,
+) -> Entity {
+    let mut combo_commands = parent.spawn((
+        Node {
+            min_width: Val::Px(160.),
+            padding: UiRect::axes(Val::Px(10.), Val::Px(6.)),
+            border: UiRect::all(Val::Px(1.)),
+            ..default()
+        },
+        BackgroundColor(theme.surface),
+        BorderColor(theme.border),
+        BorderRadius::all(Val::Px(4.)),
+    ));
+    combo_commands.insert(root).insert((
+        combo_box,
+        TextInput,
+        TextInputValue(String::new()),
+        TextInputInactive(true),
+        TextInputSettings {
+            retain_on_submit: true,
+            ..default()
+        },
+        TextInputPlaceholder {
+            value: placeholder.into(),
+            ..default()
+        },
+        TextInputTextFont(theme.text_font()),
+        TextInputTextColor(TextColor(theme.text)),
+        Interaction::None,
+    ));
+    combo_commands.id()
+}
+
+fn open_popup(
+    commands: &mut Commands,
+    owner: Entity,
+    state: &mut PopupState,
+    options: &[String],
+    shown: Vec<usize>,
+    highlighted: Option<usize>,
+    theme: &Theme,
+) {
+    if let Some(popup) = state.popup.take() {
+        commands.entity(popup).despawn_recursive();
+    }
+    state.highlighted = highlighted;
+    state.shown = shown;
+    if state.shown.is_empty() {
+        return;
+    }
+    // the scroll view needs a definite height to scroll in
+    let height = (state.shown.len() as f32 * OPTION_HEIGHT).min(POPUP_MAX_HEIGHT) + 2.;
+    let rows: Vec<(usize, String)> = state
+        .shown
+        .iter()
+        .map(|index| (*index, options[*index].clone()))
+        .collect();
+    let highlighted = state.highlighted;
+    let theme = theme.clone();
+    let overlay = Overlay::new(OverlayLayer::Popup)
+        .anchored(owner, Placement::Bottom)
+        .with_gap(POPUP_GAP)
+        .match_anchor_width();
+    let root = (
+        Node {
+            position_type: PositionType::Absolute,
+            height: Val::Px(height),
+            border: UiRect::all(Val::Px(1.)),
+            flex_direction: FlexDirection::Column,
+            ..default()
+        },
+        BackgroundColor(theme.surface),
+        BorderColor(theme.border),
+        BorderRadius::all(Val::Px(4.)),
+        DropdownPopup { owner },
+    );
+    let popup = spawn_overlay(commands, overlay, root, |parent| {
+        spawn_scroll_view(
+            parent,
+            ScrollView::vertical().with_keyboard(false),
+            Node {
+                width: Val::Percent(100.),
+                height: Val::Percent(100.),
+                ..default()
+            },
+            |content| {
+                for (position, (index, label)) in rows.into_iter().enumerate() {
+                    let mut option = content.spawn((
+                        DropdownOption { owner, index },
+                        Node {
+                            width: Val::Percent(100.),
+                            height: Val::Px(OPTION_HEIGHT),
+                            flex_shrink: 0.,
+                            padding: UiRect::horizontal(Val::Px(10.)),
+                            align_items: AlignItems::Center,
+                            ..default()
+                        },
+                        BackgroundColor(theme.surface),
+                    ));
+                    option.with_child((Text::new(label), theme.text_font(), TextColor(theme.text)));
+                    if highlighted == Some(position) {
+                        option.insert(ScrollIntoView);
+                    }
+                }
+            },
+        );
+    });
+    state.popup = Some(popup);
+}
+
+fn close_popup(commands: &mut Commands, state: &mut PopupState) {
+    if let Some(popup) = state.popup.take() {
+        commands.entity(popup).despawn_recursive();
+    }
+    state.shown.clear();
+    state.highlighted = None;
+}
+
+/// Moves the highlight to `position` and scrolls it into view.
+fn highlight(
+    commands: &mut Commands,
+    owner: Entity,
+    state: &mut PopupState,
+    position: usize,
+    options: &Query<(Entity, &DropdownOption)>,
+) {
+    state.highlighted = Some(position);
+    let Some(index) = state.shown.get(position) else {
+        return;
+    };
+    if let Some((entity, _)) = options
+        .iter()
+        .find(|(_, option)| option.owner == owner && option.index == *index)
+    {
+        // the popup may have been replaced earlier this frame
+        commands.entity(entity).try_insert(ScrollIntoView);
+    }
+}
+
+/// Adds `typed` to the type-ahead search and returns the option it finds, starting at `current`.
+fn type_ahead(
+    state: &mut PopupState,
+    options: &[String],
+    current: Option<usize>,
+    typed: &str,
+    now: f32,
+) -> Option<usize> {
+    if now - state.typed_at > TYPE_AHEAD_TIMEOUT {
+        state.typed.clear();
+    }
+    state.typed_at = now;
+    state.typed.push_str(&typed.to_lowercase());
+
+    // a single letter moves on to the next option with it, so repeating it cycles
+    let start = match current {
+        Some(index) if state.typed.chars().count() == 1 => index + 1,
+        Some(index) => index,
+        None => 0,
+    };
+    let count = options.len();
+    (0..count)
+        .map(|offset| (start + offset) % count)
+        .find(|index| options[*index].to_lowercase().starts_with(&state.typed))
+}
+
+/// The highlight position after `key`, for the navigation keys.
+fn navigate(state: &PopupState, key: KeyCode) -> Option<usize> {
+    let last = state.shown.len().checked_sub(1)?;
+    let position = match (key, state.highlighted) {
+        (KeyCode::ArrowDown, Some(position)) => (position + 1).min(last),
+        (KeyCode::ArrowUp, Some(position)) => position.saturating_sub(1),
+        (KeyCode::ArrowDown, None) => 0,
+        (KeyCode::ArrowUp, None) => last,
+        (KeyCode::PageDown, Some(position)) => (position + page_size()).min(last),
+        (KeyCode::PageUp, Some(position)) => position.saturating_sub(page_size()),
+        (KeyCode::Home, _) => 0,
+        (KeyCode::End, _) => last,
+        _ => return None,
+    };
+    Some(position)
+}
+
+fn page_size() -> usize {
+    (POPUP_MAX_HEIGHT / OPTION_HEIGHT) as usize
+}
+
+fn pick_dropdown(
+    dropdown: &mut Dropdown,
+    entity: Entity,
+    index: usize,
+    changes: &mut EventWriter<DropdownChanged>,
+) {
+    if dropdown.selected != Some(index) {
+        dropdown.selected = Some(index);
+        changes.send(DropdownChanged {
+            dropdown: entity,
+            selected: index,
+        });
+    }
+}
+
+#[allow(clippy::type_complexity)]
+fn toggle_dropdowns(
+    mut commands: Commands,
+    theme: Res<Theme>,
+    mut focus: ResMut<Focus>,
+    mut dropdowns: Query<(Entity, &Interaction, &Dropdown, &mut PopupState), Changed<Interaction>>,
+) {
+    for (entity, interaction, dropdown, mut state) in &mut dropdowns {
+        if *interaction != Interaction::Pressed {
+            continue;
+        }
+        focus.0 = Some(entity);
+        if state.popup.is_some() {
+            close_popup(&mut commands, &mut state);
+        } else {
+            let shown = (0..dropdown.options.len()).collect();
+            open_popup(
+                &mut commands,
+                entity,
+                &mut state,
+                &dropdown.options,
+                shown,
+                dropdown.selected,
+                &theme,
+            );
+        }
+    }
+}
+
+/// Keyboard navigation and type-ahead for the focused dropdown.
+#[allow(clippy::too_many_arguments)]
+fn dropdown_keys(
+    mut commands: Commands,
+    time: Res<Time>,
+    theme: Res<Theme>,
+    focus: Res<Focus>,
+    mut keys: EventReader<KeyboardInput>,
+    mut dropdowns: Query<(&mut Dropdown, &mut PopupState)>,
+    options: Query<(Entity, &DropdownOption)>,
+    mut changes: EventWriter<DropdownChanged>,
+) {
+    let Some(entity) = focus.0.filter(|entity| dropdowns.contains(*entity)) else {
+        keys.clear();
+        return;
+    };
+    let Ok((mut dropdown, mut state)) = dropdowns.get_mut(entity) else {
+        return;
+    };
+    for key in keys.read() {
+        if key.state != ButtonState::Pressed {
+            continue;
+        }
+        let open = state.popup.is_some();
+        if let Some(position) = navigate(&state, key.key_code).filter(|_| open) {
+            highlight(&mut commands, entity, &mut state, position, &options);
+            continue;
+        }
+        match key.key_code {
+            KeyCode::Tab if open => close_popup(&mut commands, &mut state),
+            KeyCode::Enter | KeyCode::Space if open => {
+                if let Some(index) = state.highlighted_option() {
+                    pick_dropdown(&mut dropdown, entity, index, &mut changes);
+                }
+                close_popup(&mut commands, &mut state);
+            }
+            KeyCode::Enter | KeyCode::Space | KeyCode::ArrowDown | KeyCode::ArrowUp => {
+                let shown = (0..dropdown.options.len()).collect();
+                open_popup(
+                    &mut commands,
+                    entity,
+                    &mut state,
+                    &dropdown.options,
+                    shown,
+                    dropdown.selected,
+                    &theme,
+                );
+            }
+            _ => {
+                let Key::Character(typed) = &key.logical_key else {
+                    continue;
+                };
+                let current = if open {
+                    state.highlighted_option()
+                } else {
+                    dropdown.selected
+                };
+                let found = type_ahead(
+                    &mut state,
+                    &dropdown.options,
+                    current,
+                    typed,
+                    time.elapsed_secs(),
+                );
+                match found {
+                    // a dropdown's popup lists every option, so positions are indices
+                    Some(index) if open => {
+                        highlight(&mut commands, entity, &mut state, index, &options)
+                    }
+                    Some(index) => pick_dropdown(&mut dropdown, entity, index, &mut changes),
+                    None => {}
+                }
+            }
+        }
+    }
+}
+
+/// Refilters the suggestions of a combo box as its text changes.
+#[allow(clippy::type_complexity)]
+fn filter_combo_boxes(
+    mut commands: Commands,
+    theme: Res<Theme>,
+    mut combo_boxes: Query<
+        (
+            Entity,
+            &ComboBox,
+            &TextInputValue,
+            &TextInputInactive,
+            &mut PopupState,
+        ),
+        Changed<TextInputValue>,
+    >,
+) {
+    for (entity, combo_box, value, inactive, mut state) in &mut combo_boxes {
+        if std::mem::take(&mut state.picked) || inactive.0 {
+            continue;
+        }
+        if value.0.is_empty() {
+            close_popup(&mut commands, &mut state);
+            continue;
+        }
+        let shown = combo_box.matches(&value.0);
+        let highlighted = (!shown.is_empty()).then_some(0);
+        open_popup(
+            &mut commands,
+            entity,
+            &mut state,
+            &combo_box.options,
+            shown,
+            highlighted,
+            &theme,
+        );
+    }
+}
+
+fn combo_box_keys(
+    mut commands: Commands,
+    theme: Res<Theme>,
+    focus: Res<Focus>,
+    keys: Res<ButtonInput<KeyCode>>,
+    mut combo_boxes: Query<(&ComboBox, &TextInputValue, &mut PopupState)>,
+    options: Query<(Entity, &DropdownOption)>,
+) {
+    let Some(entity) = focus.0 else {
+        return;
+    };
+    let Ok((combo_box, value, mut state)) = combo_boxes.get_mut(entity) else {
+        return;
+    };
+    for key in keys.get_just_pressed() {
+        match key {
+            KeyCode::ArrowDown | KeyCode::ArrowUp if state.popup.is_none() => {
+                let shown = combo_box.matches(&value.0);
+                open_popup(
+                    &mut commands,
+                    entity,
+                    &mut state,
+                    &combo_box.options,
+                    shown,
+                    Some(0),
+                    &theme,
+                );
+            }
+            KeyCode::ArrowDown | KeyCode::ArrowUp | KeyCode::PageDown | KeyCode::PageUp => {
+                if let Some(position) = navigate(&state, *key) {
+                    highlight(&mut commands, entity, &mut state, position, &options);
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Enter picks the highlighted suggestion, or takes the text as typed.
+fn submit_combo_boxes(
+    mut commands: Commands,
+    mut submits: EventReader<TextInputSubmitEvent>,
+    mut combo_boxes: Query<(&ComboBox, &mut TextInputValue, &mut PopupState)>,
+    mut changes: EventWriter<ComboBoxChanged>,
+) {
+    for submit in submits.read() {
+        let Ok((combo_box, mut value, mut state)) = combo_boxes.get_mut(submit.entity) else {
+            continue;
+        };
+        let option = state.highlighted_option().or_else(|| {
+            combo_box
+                .options
+                .iter()
+                .position(|option| option.eq_ignore_ascii_case(&submit.value))
+        });
+        let text = option.map_or(submit.value.clone(), |index| {
+            combo_box.options[index].clone()
+        });
+        if value.0 != text {
+            value.0 = text.clone();
+            state.picked = true;
+        }
+        close_popup(&mut commands, &mut state);
+        changes.send(ComboBoxChanged {
+            combo_box: submit.entity,
+            value: text,
+            option,
+        });
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+fn click_options(
+    mut commands: Commands,
+    options: Query<(&Interaction, &DropdownOption), Changed<Interaction>>,
+    mut dropdowns: Query<&mut Dropdown>,
+    mut combo_boxes: Query<(&ComboBox, &mut TextInputValue)>,
+    mut states: Query<&mut PopupState>,
+    mut dropdown_changes: EventWriter<DropdownChanged>,
+    mut combo_changes: EventWriter<ComboBoxChanged>,
+) {
+    for (interaction, option) in &options {
+        if *interaction != Interaction::Pressed {
+            continue;
+        }
+        let Ok(mut state) = states.get_mut(option.owner) else {
+            continue;
+        };
+        if let Ok(mut dropdown) = dropdowns.get_mut(option.owner) {
+            pick_dropdown(
+                &mut dropdown,
+                option.owner,
+                option.index,
+                &mut dropdown_changes,
+            );
+        } else if let Ok((combo_box, mut value)) = combo_boxes.get_mut(option.owner) {
+            let text = combo_box.options[option.index].clone();
+            if value.0 != text {
+                value.0 = text.clone();
+                state.picked = true;
+            }
+            combo_changes.send(ComboBoxChanged {
+                combo_box: option.owner,
+                value: text,
+                option: Some(option.index),
+            });
+        }
+        close_popup(&mut commands, &mut state);
+    }
+}
+
+/// The option under the cursor becomes the highlighted one.
+fn hover_options(
+    options: Query<(&Interaction, &DropdownOption), Changed<Interaction>>,
+    mut states: Query<&mut PopupState>,
+) {
+    for (interaction, option) in &options {
+        if *interaction != Interaction::Hovered {
+            continue;
+        }
+        if let Ok(mut state) = states.get_mut(option.owner) {
+            state.highlighted = state.shown.iter().position(|index| *index == option.index);
+        }
+    }
+}
+
+/// Forgets popups the overlay layer dismissed, and closes them when their owner loses focus or is
+/// despawned.
+fn close_popups(
+    mut commands: Commands,
+    focus: Res<Focus>,
+    popups: Query<(Entity, &DropdownPopup)>,
+    mut owners: Query<(Entity, &mut PopupState)>,
+) {
+    for (entity, mut state) in &mut owners {
+        let Some(popup) = state.popup else {
+            continue;
+        };
+        if !popups.contains(popup) {
+            state.popup = None;
+            close_popup(&mut commands, &mut state);
+        } else if !focus.is(entity) {
+            close_popup(&mut commands, &mut state);
+        }
+    }
+    for (popup, DropdownPopup { owner }) in &popups {
+        if !owners.contains(*owner) {
+            commands.entity(popup).despawn_recursive();
+        }
+    }
+}
+
+fn draw_options(
+    theme: Res<Theme>,
+    states: Query<&PopupState>,
+    mut options: Query<(&DropdownOption, &Interaction, &mut BackgroundColor)>,
+) {
+    for (option, interaction, mut background) in &mut options {
+        let Ok(state) = states.get(option.owner) else {
+            continue;
+        };
+        let color = if state.highlighted_option() == Some(option.index) {
+            theme.selection
+        } else {
+            theme.surface_color(*interaction)
+        };
+        background.set_if_neq(BackgroundColor(color));
+    }
+}
+
+#[allow(clippy::type_complexity)]
+fn draw_dropdowns(
+    theme: Res<Theme>,
+    mut dropdowns: Query<(
+        &Dropdown,
+        &Interaction,
+        &PopupState,
+        &mut BackgroundColor,
+        &mut BorderColor,
+    )>,
+    mut labels: Query<(&DropdownLabel, &mut Text, &mut TextColor)>,
+) {
+    for (label, mut text, mut color) in &mut labels {
+        let Ok((dropdown, ..)) = dropdowns.get(label.dropdown) else {
+            continue;
+        };
+        let (shown, shown_color) = match dropdown.selected_option() {
+            Some(option) => (option, theme.text),
+            None => (dropdown.placeholder.as_str(), theme.text_muted),
+        };
+        if text.0 != shown {
+            text.0 = shown.to_string();
+        }
+        if color.0 != shown_color {
+            color.0 = shown_color;
+        }
+    }
+    for (_, interaction, state, mut background, mut border) in &mut dropdowns {
+        let (fill, edge) = if state.popup.is_some() {
+            (theme.surface_pressed, theme.accent)
+        } else {
+            (theme.surface_color(*interaction), theme.border)
+        };
+        background.set_if_neq(BackgroundColor(fill));
+        border.set_if_neq(BorderColor(edge));
+    }
+}
@@ -0,0 +1,178 @@
+//! Keyboard focus shared by the widgets. Clicking a [`Focusable`] node focuses it, Tab and
+//! Shift+Tab move focus through the focusable nodes and text inputs in reading order, and the
+//! focused node gets an outline. Widgets only react to keys while they hold [`Focus`].
+//!
+//! While a [`FocusTrap`] exists, Tab only moves through the nodes inside the most recently added
+//! one, so focus can't leave e.g. a modal dialog.
+
+use crate::theme::Theme;
+use bevy::prelude::*;
+use bevy_simple_text_input::{TextInput, TextInputInactive};
+
+#[derive(Component, Default)]
+pub struct Focusable;
+
+/// Keeps Tab navigation inside this node's descendants while it's the newest trap.
+#[derive(Component, Default)]
+pub struct FocusTrap;
+
+/// The existing focus traps, oldest first.
+#[derive(Resource, Default)]
+struct FocusTraps(Vec<Entity>);
+
+/// The focused entity, if any.
+#[derive(Resource, Default, Debug)]
+pub struct Focus(pub Option<Entity>);
+
+impl Focus {
+    pub fn is(&self, entity: Entity) -> bool {
+        self.0 == Some(entity)
+    }
+}
+
+/// The focus systems, including Tab navigation and focusing clicked nodes in `PreUpdate`.
+#[derive(SystemSet, Debug, Clone, PartialEq, Eq, Hash)]
+pub struct FocusSystem;
+
+pub struct FocusPlugin;
+
+impl Plugin for FocusPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<Theme>()
+            .init_resource::<Focus>()
+            .init_resource::<FocusTraps>()
+            .add_systems(
+                PreUpdate,
+                click_to_focus
+                    .after(bevy::ui::UiSystem::Focus)
+                    .in_set(FocusSystem),
+            )
+            .add_systems(
+                Update,
+                (
+                    follow_text_inputs,
+                    track_focus_traps,
+                    tab_navigation,
+                    sync_focus,
+                )
+                    .chain()
+                    .in_set(FocusSystem),
+            );
+    }
+}
+
+#[allow(clippy::type_complexity)]
+fn click_to_focus(
+    mut focus: ResMut<Focus>,
+    focusables: Query<(Entity, &Interaction), (With<Focusable>, Changed<Interaction>)>,
+) {
+    for (entity, interaction) in &focusables {
+        if *interaction == Interaction::Pressed && !focus.is(entity) {
+            focus.0 = Some(entity);
+        }
+    }
+}
+
+/// A text input activated by clicking it takes the focus.
+fn follow_text_inputs(
+    mut focus: ResMut<Focus>,
+    inputs: Query<(Entity, &TextInputInactive), Changed<TextInputInactive>>,
+) {
+    for (entity, inactive) in &inputs {
+        if !inactive.0 && !focus.is(entity) {
+            focus.0 = Some(entity);
+        }
+    }
+}
+
+fn track_focus_traps(
+    mut traps: ResMut<FocusTraps>,
+    added: Query<Entity, Added<FocusTrap>>,
+    mut removed: RemovedComponents<FocusTrap>,
+) {
+    for entity in removed.read() {
+        traps.0.retain(|trap| *trap != entity);
+    }
+    for entity in &added {
+        traps.0.push(entity);
+    }
+}
+
+#[allow(clippy::type_complexity)]
+fn tab_navigation(
+    keys: Res<ButtonInput<KeyCode>>,
+    mut focus: ResMut<Focus>,
+    traps: Res<FocusTraps>,
+    candidates: Query<
+        (Entity, &GlobalTransform, &InheritedVisibility),
+        Or<(With<Focusable>, With<TextInput>)>,
+    >,
+    parents: Query<&Parent>,
+) {
+    if !keys.just_pressed(KeyCode::Tab) {
+        return;
+    }
+    let trap = traps.0.last().copied();
+    let mut order: Vec<(Entity, Vec2)> = candidates
+        .iter()
+        .filter(|(_, _, visibility)| visibility.get())
+        .filter(|(entity, _, _)| {
+            trap.is_none_or(|trap| parents.iter_ancestors(*entity).any(|parent| parent == trap))
+        })
+        .map(|(entity, transform, _)| (entity, transform.translation().truncate()))
+        .collect();
+    if order.is_empty() {
+        return;
+    }
+    // reading order: top to bottom, then left to right
+    order.sort_by(|(_, a), (_, b)| a.y.total_cmp(&b.y).then(a.x.total_cmp(&b.x)));
+
+    let backwards = keys.any_pressed([KeyCode::ShiftLeft, KeyCode::ShiftRight]);
+    let current = focus
+        .0
+        .and_then(|focused| order.iter().position(|(entity, _)| *entity == focused));
+    let next = match (current, backwards) {
+        (None, false) => 0,
+        (None, true) => order.len() - 1,
+        (Some(index), false) => (index + 1) % order.len(),
+        (Some(index), true) => (index + order.len() - 1) % order.len(),
+    };
+    focus.0 = Some(order[next].0);
+}
+
+/// Drops focus from despawned entities, keeps text inputs in sync with the focus and draws the
+/// focus outline.
+fn sync_focus(
+    mut commands: Commands,
+    theme: Res<Theme>,
+    mut focus: ResMut<Focus>,
+    exists: Query<()>,
+    mut inputs: Query<(Entity, &mut TextInputInactive)>,
+    outlined: Query<Entity, (With<Focusable>, With<Outline>)>,
+    mut previous: Local<Option<Entity>>,
+) {
+    if focus.0.is_some_and(|entity| exists.get(entity).is_err()) {
+        focus.0 = None;
+    }
+    if *previous == focus.0 && !theme.is_changed() {
+        return;
+    }
+    *previous = focus.0;
+
+    for (entity, mut inactive) in &mut inputs {
+        let should_be_inactive = !focus.is(entity);
+        if inactive.0 != should_be_inactive {
+            inactive.0 = should_be_inactive;
+        }
+    }
+    for entity in &outlined {
+        if !focus.is(entity) {
+            commands.entity(entity).remove::<Outline>();
+        }
+    }
+    if let Some(entity) = focus.0.filter(|entity| !inputs.contains(*entity)) {
+        commands
+            .entity(entity)
+            .insert(Outline::new(Val::Px(2.), Val::Px(2.), theme.accent));
+    }
+}
@@ -0,0 +1,714 @@
+//! Menus: a [`MenuBar`] of dropdown menus, and [`ContextMenu`]s opened by a right click on the
+//! node they're added to. Menus hold items, separators and submenus; items can be checkable or
+//! disabled, and show the shortcut the [`Keymap`] binds to their action.
+//!
+//! ```ignore
+//! let file = Menu::new("File")
+//!     .item(MenuItem::new("Save", "file.save"))
+//!     .separator()
+//!     .submenu(Menu::new("Export").item(MenuItem::new("PNG", "file.export_png")));
+//! spawn_menu_bar(parent, MenuBar::new([file]), Node::default(), &theme);
+//! commands.entity(canvas).insert(ContextMenu::new(Menu::new("Canvas").item(...)));
+//! ```
+//!
+//! Clicking a bar title opens its menu, and while one is open, hovering another title switches to
+//! it. Hovering a submenu opens it to the side. Picking an item sends [`MenuItemSelected`] and
+//! closes the menus; a press outside them or Escape closes them too, Escape one level at a time.
+
+use crate::keymap::{Keymap, KeymapPlugin};
+use crate::theme::Theme;
+use crate::widgets::overlay::{
+    Overlay, OverlayLayer, OverlayPlugin, OverlaySystem, Placement, spawn_overlay,
+};
+use bevy::prelude::*;
+use bevy::window::PrimaryWindow;
+use std::f32::consts::FRAC_PI_4;
+
+/// Space between a menu's border and its rows.
+const PANEL_PADDING: f32 = 4.;
+
+#[derive(Clone, Debug)]
+pub struct MenuItem {
+    pub label: String,
+    /// Reported by [`MenuItemSelected`] and looked up in the [`Keymap`] for the shortcut hint.
+    pub action: String,
+    pub enabled: bool,
+    /// `Some` for checkable items.
+    pub checked: Option<bool>,
+}
+
+impl MenuItem {
+    pub fn new(label: impl Into<String>, action: impl Into<String>) -> Self {
+        Self {
+            label: label.into(),
+            action: action.into(),
+            enabled: true,
+            checked: None,
+        }
+    }
+
+    pub fn disabled(mut self) -> Self {
+        self.enabled = false;
+        self
+    }
+
+    /// Makes the item checkable; picking it flips the check.
+    pub fn checkable(mut self, checked: bool) -> Self {
+        self.checked = Some(checked);
+        self
+    }
+}
+
+#[derive(Clone, Debug)]
+pub enum MenuEntry {
+    Item(MenuItem),
+    Submenu(Menu),
+    Separator,
+}
+
+#[derive(Clone, Debug)]
+pub struct Menu {
+    pub title: String,
+    pub entries: Vec<MenuEntry>,
+}
+
+impl Menu {
+    pub fn new(title: impl Into<String>) -> Self {
+        Self {
+            title: title.into(),
+            entries: Vec::new(),
+        }
+    }
+
+    pub fn item(mut self, item: MenuItem) -> Self {
+        self.entries.push(MenuEntry::Item(item));
+        self
+    }
+
+    pub fn separator(mut self) -> Self {
+        self.entries.push(MenuEntry::Separator);
+        self
+    }
+
+    pub fn submenu(mut self, menu: Menu) -> Self {
+        self.entries.push(MenuEntry::Submenu(menu));
+        self
+    }
+
+    /// The item with `action`, in this menu or its submenus.
+    pub fn find_item(&self, action: &str) -> Option<&MenuItem> {
+        self.entries.iter().find_map(|entry| match entry {
+            MenuEntry::Item(item) if item.action == action => Some(item),
+            MenuEntry::Submenu(menu) => menu.find_item(action),
+            _ => None,
+        })
+    }
+
+    pub fn find_item_mut(&mut self, action: &str) -> Option<&mut MenuItem> {
+        self.entries.iter_mut().find_map(|entry| match entry {
+            MenuEntry::Item(item) if item.action == action => Some(item),
+            MenuEntry::Submenu(menu) => menu.find_item_mut(action),
+            _ => None,
+        })
+    }
+
+    /// The submenu reached by following the entry indices in `path`.
+    fn at_path(&self, path: &[usize]) -> Option<&Menu> {
+        match path.split_first() {
+            None => Some(self),
+            Some((index, rest)) => match self.entries.get(*index) {
+                Some(MenuEntry::Submenu(menu)) => menu.at_path(rest),
+                _ => None,
+            },
+        }
+    }
+
+    fn entry_at_path_mut(&mut self, path: &[usize]) -> Option<&mut MenuEntry> {
+        let (last, submenus) = path.split_last()?;
+        let mut menu = self;
+        for index in submenus {
+            menu = match menu.entries.get_mut(*index) {
+                Some(MenuEntry::Submenu(submenu)) => submenu,
+                _ => return None,
+            };
+        }
+        menu.entries.get_mut(*last)
+    }
+}
+
+#[derive(Component, Clone, Debug)]
+pub struct MenuBar {
+    menus: Vec<Menu>,
+    /// The menu whose dropdown is open.
+    open: Option<usize>,
+}
+
+impl MenuBar {
+    pub fn new(menus: impl IntoIterator<Item = Menu>) -> Self {
+        Self {
+            menus: menus.into_iter().collect(),
+            open: None,
+        }
+    }
+
+    pub fn menus(&self) -> &[Menu] {
+        &self.menus
+    }
+
+    /// The item with `action` in any of the menus, e.g. to change it from code.
+    pub fn item_mut(&mut self, action: &str) -> Option<&mut MenuItem> {
+        self.menus
+            .iter_mut()
+            .find_map(|menu| menu.find_item_mut(action))
+    }
+
+    pub fn is_open(&self) -> bool {
+        self.open.is_some()
+    }
+}
+
+/// A menu opened at the cursor by a right click on this node.
+#[derive(Component, Clone, Debug)]
+#[require(Interaction)]
+pub struct ContextMenu {
+    pub menu: Menu,
+}
+
+impl ContextMenu {
+    pub fn new(menu: Menu) -> Self {
+        Self { menu }
+    }
+}
+
+/// Sent when a menu item is picked. `source` is the menu bar or the node of the context menu;
+/// `checked` is the new state of a checkable item.
+#[derive(Event, Clone, Debug)]
+pub struct MenuItemSelected {
+    pub source: Entity,
+    pub action: String,
+    pub checked: Option<bool>,
+}
+
+/// A title in a menu bar.
+#[derive(Component, Clone, Copy, Debug)]
+#[require(Button)]
+pub struct MenuBarButton {
+    pub bar: Entity,
+    pub index: usize,
+}
+
+/// An open menu or submenu. `path` leads from the source's menus to it: the index of the bar menu
+/// (0 for a context menu), then the entry index of each submenu.
+#[derive(Component, Clone, Debug)]
+pub struct MenuPanel {
+    pub source: Entity,
+    pub path: Vec<usize>,
+}
+
+/// An enabled item or submenu row of an open menu.
+#[derive(Component, Clone, Copy, Debug)]
+#[require(Button)]
+pub struct MenuRow {
+    pub panel: Entity,
+    pub entry: usize,
+}
+
+pub struct MenuPlugin;
+
+impl Plugin for MenuPlugin {
+    fn build(&self, app: &mut App) {
+        if !app.is_plugin_added::<OverlayPlugin>() {
+            app.add_plugins(OverlayPlugin);
+        }
+        if !app.is_plugin_added::<KeymapPlugin>() {
+            app.add_plugins(KeymapPlugin);
+        }
+        app.init_resource::<Theme>()
+            .add_event::<MenuItemSelected>()
+            .add_systems(
+                Update,
+                (
+                    track_bars,
+                    open_context_menus,
+                    press_bar_buttons,
+                    hover_rows,
+                    press_rows,
+                    draw_menus,
+                )
+                    .chain()
+                    .after(OverlaySystem),
+            );
+    }
+}
+
+/// Spawns a menu bar laid out by `root` and returns its entity.
+pub fn spawn_menu_bar(
+    parent: &mut ChildBuilder,
+    bar: MenuBar,
+    root: impl Bundle,
+    theme: &Theme,
+) -> Entity {
+    let mut bar_commands = parent.spawn((
+        Node {
+            flex_shrink: 0.,
+            padding: UiRect::horizontal(Val::Px(4.)),
+            border: UiRect::bottom(Val::Px(1.)),
+            ..default()
+        },
+        BackgroundColor(theme.surface),
+        BorderColor(theme.border),
+    ));
+    bar_commands.insert(root);
+    let entity = bar_commands.id();
+    bar_commands.with_children(|parent| {
+        for (index, menu) in bar.menus.iter().enumerate() {
+            parent
+                .spawn((
+                    Node {
+                        padding: UiRect::axes(Val::Px(10.), Val::Px(6.)),
+                        ..default()
+                    },
+                    BackgroundColor(Color::NONE),
+                    MenuBarButton { bar: entity, index },
+                ))
+                .with_child((
+                    Text::new(menu.title.clone()),
+                    theme.text_font(),
+                    TextColor(theme.text),
+                ));
+        }
+    });
+    bar_commands.insert(bar);
+    entity
+}
+
+/// Spawns the panel of `menu` as an overlay and returns it.
+fn open_panel(
+    commands: &mut Commands,
+    theme: &Theme,
+    keymap: &Keymap,
+    overlay: Overlay,
+    panel: MenuPanel,
+    menu: &Menu,
+) -> Entity {
+    let entity = spawn_overlay(
+        commands,
+        overlay,
+        (
+            Node {
+                flex_direction: FlexDirection::Column,
+                min_width: Val::Px(200.),
+                padding: UiRect::all(Val::Px(PANEL_PADDING)),
+                border: UiRect::all(Val::Px(1.)),
+                ..default()
+            },
+            BackgroundColor(theme.surface),
+            BorderColor(theme.border),
+            BorderRadius::all(Val::Px(4.)),
+        ),
+        |_| {},
+    );
+    commands
+        .entity(entity)
+        .insert(panel)
+        .with_children(|parent| {
+            for (index, entry) in menu.entries.iter().enumerate() {
+                match entry {
+                    MenuEntry::Separator => {
+                        parent.spawn((
+                            Node {
+                                height: Val::Px(1.),
+                                margin: UiRect::vertical(Val::Px(PANEL_PADDING)),
+                                ..default()
+                            },
+                            BackgroundColor(theme.border),
+                        ));
+                    }
+                    MenuEntry::Item(item) => {
+                        let hint = keymap.hint(&item.action);
+                        spawn_row(parent, entity, index, item, hint, theme);
+                    }
+                    MenuEntry::Submenu(submenu) => {
+                        spawn_submenu_row(parent, entity, index, &submenu.title, theme);
+                    }
+                }
+            }
+        });
+    entity
+}
+
+fn row_node() -> Node {
+    Node {
+        align_items: AlignItems::Center,
+        column_gap: Val::Px(8.),
+        padding: UiRect::axes(Val::Px(8.), Val::Px(5.)),
+        ..default()
+    }
+}
+
+/// The leading column of a row, holding the check mark of a checked item.
+fn spawn_check_column(parent: &mut ChildBuilder, checked: bool, theme: &Theme) {
+    parent
+        .spawn(Node {
+            width: Val::Px(14.),
+            justify_content: JustifyContent::Center,
+            ..default()
+        })
+        .with_children(|parent| {
+            if checked {
+                parent.spawn((
+                    Node {
+                        width: Val::Px(8.),
+                        height: Val::Px(8.),
+                        ..default()
+                    },
+                    BackgroundColor(theme.accent),
+                    BorderRadius::all(Val::Px(2.)),
+                ));
+            }
+        });
+}
+
+fn spawn_row(
+    parent: &mut ChildBuilder,
+    panel: Entity,
+    entry: usize,
+    item: &MenuItem,
+    hint: Option<String>,
+    theme: &Theme,
+) {
+    let color = if item.enabled {
+        theme.text
+    } else {
+        theme.text_muted
+    };
+    let mut row = parent.spawn((row_node(), BackgroundColor(Color::NONE)));
+    // disabled items aren't buttons, so they can't be hovered or pressed
+    if item.enabled {
+        row.insert(MenuRow { panel, entry });
+    }
+    row.with_children(|parent| {
+        spawn_check_column(parent, item.checked == Some(true), theme);
+        parent.spawn((
+            Node {
+                flex_grow: 1.,
+                ..default()
+            },
+            Text::new(item.label.clone()),
+            theme.text_font(),
+            TextColor(color),
+        ));
+        if let Some(hint) = hint {
+            parent.spawn((
+                Node {
+                    margin: UiRect::left(Val::Px(16.)),
+                    ..default()
+                },
+                Text::new(hint),
+                theme.text_font(),
+                TextColor(theme.text_muted),
+            ));
+        }
+    });
+}
+
+fn spawn_submenu_row(
+    parent: &mut ChildBuilder,
+    panel: Entity,
+    entry: usize,
+    title: &str,
+    theme: &Theme,
+) {
+    parent
+        .spawn((
+            row_node(),
+            BackgroundColor(Color::NONE),
+            MenuRow { panel, entry },
+        ))
+        .with_children(|parent| {
+            spawn_check_column(parent, false, theme);
+            parent.spawn((
+                Node {
+                    flex_grow: 1.,
+                    ..default()
+                },
+                Text::new(title),
+                theme.text_font(),
+                TextColor(theme.text),
+            ));
+            // a chevron: the bottom right corner of a square, turned to point right
+            parent.spawn((
+                Node {
+                    width: Val::Px(6.),
+                    height: Val::Px(6.),
+                    margin: UiRect::left(Val::Px(16.)),
+                    border: UiRect {
+                        right: Val::Px(2.),
+                        bottom: Val::Px(2.),
+                        ..default()
+                    },
+                    ..default()
+                },
+                BorderColor(theme.text_muted),
+                Transform::from_rotation(Quat::from_rotation_z(-FRAC_PI_4)),
+            ));
+        });
+}
+
+/// Closes the panels of `source` deeper than `depth` levels, e.g. all of them for 0.
+fn close_panels(
+    commands: &mut Commands,
+    panels: &Query<(Entity, &MenuPanel)>,
+    source: Entity,
+    depth: usize,
+) {
+    for (entity, panel) in panels {
+        if panel.source == source && panel.path.len() > depth {
+            commands.entity(entity).try_despawn_recursive();
+        }
+    }
+}
+
+/// The menus of a bar or context menu.
+fn source_menus<'a>(bar: Option<&'a MenuBar>, context: Option<&'a ContextMenu>) -> &'a [Menu] {
+    match (bar, context) {
+        (Some(bar), _) => &bar.menus,
+        (None, Some(context)) => std::slice::from_ref(&context.menu),
+        (None, None) => &[],
+    }
+}
+
+/// Forgets the open menu of bars whose dropdown was closed by a press outside or Escape.
+fn track_bars(mut bars: Query<(Entity, &mut MenuBar)>, panels: Query<(Entity, &MenuPanel)>) {
+    for (entity, mut bar) in &mut bars {
+        let open = panels
+            .iter()
+            .any(|(_, panel)| panel.source == entity && panel.path.len() == 1);
+        if bar.open.is_some() && !open {
+            bar.open = None;
+        }
+    }
+}
+
+/// A right click opens the context menu of the innermost hovered node that has one.
+#[allow(clippy::too_many_arguments)]
+fn open_context_menus(
+    mut commands: Commands,
+    mouse: Res<ButtonInput<MouseButton>>,
+    theme: Res<Theme>,
+    keymap: Res<Keymap>,
+    windows: Query<&Window, With<PrimaryWindow>>,
+    targets: Query<(Entity, &ContextMenu, &Interaction)>,
+    parents: Query<&Parent>,
+    panels: Query<(Entity, &MenuPanel)>,
+) {
+    if !mouse.just_pressed(MouseButton::Right) {
+        return;
+    }
+    let Some(cursor) = windows.get_single().ok().and_then(Window::cursor_position) else {
+        return;
+    };
+    let hovered: Vec<Entity> = targets
+        .iter()
+        .filter(|(_, _, interaction)| **interaction != Interaction::None)
+        .map(|(entity, _, _)| entity)
+        .collect();
+    let innermost = hovered.iter().copied().find(|candidate| {
+        !hovered.iter().any(|other| {
+            parents
+                .iter_ancestors(*other)
+                .any(|ancestor| ancestor == *candidate)
+        })
+    });
+    let Some((target, context, _)) = innermost.and_then(|entity| targets.get(entity).ok()) else {
+        return;
+    };
+    for (entity, _) in &panels {
+        commands.entity(entity).try_despawn_recursive();
+    }
+    open_panel(
+        &mut commands,
+        &theme,
+        &keymap,
+        Overlay::new(OverlayLayer::Popup).at_point(cursor, Placement::Bottom),
+        MenuPanel {
+            source: target,
+            path: vec![0],
+        },
+        &context.menu,
+    );
+}
+
+/// Clicking a title opens or closes its menu; hovering another title while one is open switches.
+#[allow(clippy::too_many_arguments)]
+fn press_bar_buttons(
+    mut commands: Commands,
+    theme: Res<Theme>,
+    keymap: Res<Keymap>,
+    buttons: Query<(Entity, &Interaction, &MenuBarButton), Changed<Interaction>>,
+    mut bars: Query<&mut MenuBar>,
+    panels: Query<(Entity, &MenuPanel)>,
+) {
+    for (entity, interaction, button) in &buttons {
+        let Ok(mut bar) = bars.get_mut(button.bar) else {
+            continue;
+        };
+        let open = match (interaction, bar.open) {
+            (Interaction::Pressed, Some(open)) if open == button.index => None,
+            (Interaction::Pressed, _) => Some(button.index),
+            (Interaction::Hovered, Some(open)) if open != button.index => Some(button.index),
+            _ => continue,
+        };
+        close_panels(&mut commands, &panels, button.bar, 0);
+        bar.open = open;
+        let Some(menu) = open.and_then(|index| bar.menus.get(index)) else {
+            continue;
+        };
+        open_panel(
+            &mut commands,
+            &theme,
+            &keymap,
+            Overlay::new(OverlayLayer::Popup).anchored(entity, Placement::Bottom),
+            MenuPanel {
+                source: button.bar,
+                path: vec![button.index],
+            },
+            menu,
+        );
+    }
+}
+
+/// Hovering a row closes the submenus opened from the other rows of its menu, and opens its own
+/// submenu.
+#[allow(clippy::too_many_arguments)]
+fn hover_rows(
+    mut commands: Commands,
+    theme: Res<Theme>,
+    keymap: Res<Keymap>,
+    rows: Query<(Entity, &Interaction, &MenuRow), Changed<Interaction>>,
+    panels: Query<(Entity, &MenuPanel)>,
+    sources: Query<(Option<&MenuBar>, Option<&ContextMenu>)>,
+) {
+    for (entity, interaction, row) in &rows {
+        if *interaction == Interaction::None {
+            continue;
+        }
+        let Ok((_, panel)) = panels.get(row.panel) else {
+            continue;
+        };
+        let mut path = panel.path.clone();
+        path.push(row.entry);
+        // the submenu of this row stays open
+        let already_open = panels
+            .iter()
+            .any(|(_, other)| other.source == panel.source && other.path == path);
+        if already_open {
+            continue;
+        }
+        close_panels(&mut commands, &panels, panel.source, panel.path.len());
+        let Ok((bar, context)) = sources.get(panel.source) else {
+            continue;
+        };
+        let menus = source_menus(bar, context);
+        let Some(submenu) = menus.get(path[0]).and_then(|menu| menu.at_path(&path[1..])) else {
+            continue;
+        };
+        // the submenu's first row lines up with this one
+        let overlay = Overlay::new(OverlayLayer::Popup)
+            .anchored(entity, Placement::Right)
+            .with_gap(PANEL_PADDING)
+            .with_offset(Vec2::new(0., -PANEL_PADDING - 1.));
+        let source = panel.source;
+        open_panel(
+            &mut commands,
+            &theme,
+            &keymap,
+            overlay,
+            MenuPanel { source, path },
+            submenu,
+        );
+    }
+}
+
+/// Picking an item flips its check, reports it and closes the menus.
+fn press_rows(
+    mut commands: Commands,
+    rows: Query<(&Interaction, &MenuRow), Changed<Interaction>>,
+    panels: Query<(Entity, &MenuPanel)>,
+    mut sources: Query<(Option<&mut MenuBar>, Option<&mut ContextMenu>)>,
+    mut selected: EventWriter<MenuItemSelected>,
+) {
+    for (interaction, row) in &rows {
+        if *interaction != Interaction::Pressed {
+            continue;
+        }
+        let Ok((_, panel)) = panels.get(row.panel) else {
+            continue;
+        };
+        let Ok((mut bar, mut context)) = sources.get_mut(panel.source) else {
+            continue;
+        };
+        let menu = match (&mut bar, &mut context) {
+            (Some(bar), _) => bar.menus.get_mut(panel.path[0]),
+            (None, Some(context)) => Some(&mut context.menu),
+            (None, None) => None,
+        };
+        let mut path = panel.path[1..].to_vec();
+        path.push(row.entry);
+        let Some(MenuEntry::Item(item)) = menu.and_then(|menu| menu.entry_at_path_mut(&path))
+        else {
+            // submenu rows open on hover
+            continue;
+        };
+        if let Some(checked) = &mut item.checked {
+            *checked = !*checked;
+        }
+        selected.send(MenuItemSelected {
+            source: panel.source,
+            action: item.action.clone(),
+            checked: item.checked,
+        });
+        if let Some(bar) = &mut bar {
+            bar.open = None;
+        }
+        close_panels(&mut commands, &panels, panel.source, 0);
+    }
+}
+
+#[allow(clippy::type_complexity)]
+fn draw_menus(
+    theme: Res<Theme>,
+    bars: Query<&MenuBar>,
+    panels: Query<&MenuPanel>,
+    mut buttons: Query<(&Interaction, &MenuBarButton, &mut BackgroundColor), Without<MenuRow>>,
+    mut rows: Query<(&Interaction, &MenuRow, &mut BackgroundColor)>,
+) {
+    for (interaction, button, mut color) in &mut buttons {
+        let open = bars
+            .get(button.bar)
+            .is_ok_and(|bar| bar.open == Some(button.index));
+        let background = if open || *interaction != Interaction::None {
+            theme.surface_hovered
+        } else {
+            Color::NONE
+        };
+        color.set_if_neq(BackgroundColor(background));
+    }
+    for (interaction, row, mut color) in &mut rows {
+        // a row stays lit while its submenu is open
+        let submenu_open = panels.get(row.panel).is_ok_and(|panel| {
+            panels.iter().any(|other| {
+                other.source == panel.source
+                    && other.path.len() == panel.path.len() + 1
+                    && other.path.starts_with(&panel.path)
+                    && other.path.last() == Some(&row.entry)
+            })
+        });
+        let background = if submenu_open || *interaction != Interaction::None {
+            theme.surface_hovered
+        } else {
+            Color::NONE
+        };
+        color.set_if_neq(BackgroundColor(background));
+    }
+}
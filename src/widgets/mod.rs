@@ -0,0 +1,27 @@
+//! Reusable widgets built from plain `Node`/`Text` entities; each one comes with its own plugin
+//! and takes its colors from the [`Theme`](crate::theme::Theme) resource.
+
+pub mod checkbox;
+pub mod collapsible;
+pub mod data_table;
+pub mod dock;
+pub mod drag_drop;
+pub mod dropdown;
+pub mod focus;
+pub mod menu;
+pub mod modal;
+pub mod overlay;
+pub mod progress;
+pub mod radio_group;
+pub mod scroll_view;
+pub mod slider;
+pub mod split_pane;
+pub mod tabs;
+pub mod toast;
+pub mod toggle_switch;
+pub mod tooltip;
+pub mod tree_view;
+pub mod virtual_list;
+
+/// Seconds between the clicks of a double-click, for the widgets that react to them.
+pub const DOUBLE_CLICK_TIME: f64 = 0.4;
@@ -0,0 +1,411 @@
+//! Modal dialogs: a card with a title, some content and a row of action buttons, drawn over a
+//! dimmed backdrop that blocks the UI underneath. Open one with [`spawn_modal`]; a modal can open
+//! another, and only the topmost one takes input.
+//!
+//! Opening a modal focuses its default action and Tab stays inside it. Enter picks the focused
+//! action, or the default one when no other widget of the modal has focus; Escape picks the cancel
+//! action. Closing a modal sends [`ModalClosed`] and gives the focus back to whatever had it
+//! before the modal opened.
+
+use crate::theme::Theme;
+use crate::widgets::focus::{Focus, FocusPlugin, FocusSystem, FocusTrap, Focusable};
+use crate::widgets::overlay::{
+    DismissReason, Overlay, OverlayDismissed, OverlayLayer, OverlayPlugin, OverlayStack,
+    OverlaySystem, spawn_overlay,
+};
+use bevy::prelude::*;
+use bevy_simple_text_input::TextInput;
+
+#[derive(Component, Clone, Debug)]
+pub struct Modal {
+    pub title: String,
+    /// Button labels, left to right.
+    pub actions: Vec<String>,
+    /// Focused when the modal opens and picked by Enter.
+    pub default_action: Option<usize>,
+    /// Picked by Escape; without one, Escape closes the modal with no action.
+    pub cancel_action: Option<usize>,
+}
+
+impl Modal {
+    pub fn new(
+        title: impl Into<String>,
+        actions: impl IntoIterator<Item = impl Into<String>>,
+    ) -> Self {
+        Self {
+            title: title.into(),
+            actions: actions.into_iter().map(Into::into).collect(),
+            default_action: None,
+            cancel_action: None,
+        }
+    }
+
+    pub fn with_default(mut self, action: usize) -> Self {
+        self.default_action = Some(action);
+        self
+    }
+
+    pub fn with_cancel(mut self, action: usize) -> Self {
+        self.cancel_action = Some(action);
+        self
+    }
+}
+
+/// Sent when a modal closes, just before it's despawned. `action` is the index of the picked
+/// action, or `None` when Escape closed a modal without a cancel action.
+#[derive(Event, Clone, Copy, Debug)]
+pub struct ModalClosed {
+    pub modal: Entity,
+    pub action: Option<usize>,
+}
+
+/// One of the buttons at the bottom of a modal.
+#[derive(Component, Clone, Copy, Debug)]
+#[require(Button, Focusable)]
+pub struct ModalAction {
+    pub modal: Entity,
+    pub index: usize,
+}
+
+#[derive(Debug)]
+struct ModalEntry {
+    modal: Entity,
+    cancel_action: Option<usize>,
+    /// What had the focus when the modal opened.
+    restore_focus: Option<Entity>,
+}
+
+/// The open modals, bottom to top.
+#[derive(Resource, Default, Debug)]
+pub struct ModalStack {
+    entries: Vec<ModalEntry>,
+}
+
+impl ModalStack {
+    pub fn top(&self) -> Option<Entity> {
+        self.entries.last().map(|entry| entry.modal)
+    }
+
+    pub fn contains(&self, modal: Entity) -> bool {
+        self.entries.iter().any(|entry| entry.modal == modal)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Takes `modal` off the stack. Returns where the focus goes back to when `modal` was the
+    /// topmost one; otherwise the modal above it inherits that.
+    fn remove(&mut self, modal: Entity) -> Option<Option<Entity>> {
+        let position = self.entries.iter().position(|entry| entry.modal == modal)?;
+        let entry = self.entries.remove(position);
+        match self.entries.get_mut(position) {
+            Some(above) => {
+                above.restore_focus = entry.restore_focus;
+                None
+            }
+            None => Some(entry.restore_focus),
+        }
+    }
+}
+
+pub struct ModalPlugin;
+
+impl Plugin for ModalPlugin {
+    fn build(&self, app: &mut App) {
+        if !app.is_plugin_added::<FocusPlugin>() {
+            app.add_plugins(FocusPlugin);
+        }
+        if !app.is_plugin_added::<OverlayPlugin>() {
+            app.add_plugins(OverlayPlugin);
+        }
+        app.init_resource::<ModalStack>()
+            .add_event::<ModalClosed>()
+            .add_systems(
+                Update,
+                (
+                    cancel_modals,
+                    track_modals,
+                    modal_keys,
+                    click_actions,
+                    draw_actions,
+                )
+                    .chain()
+                    .after(OverlaySystem)
+                    .before(FocusSystem),
+            );
+    }
+}
+
+/// Spawns a modal with `root` on its backdrop (e.g. a [`Name`]) and `content` between its title
+/// and its actions, and returns its entity. Despawning it closes it without [`ModalClosed`].
+pub fn spawn_modal(
+    commands: &mut Commands,
+    modal: Modal,
+    root: impl Bundle,
+    theme: &Theme,
+    content: impl FnOnce(&mut ChildBuilder),
+) -> Entity {
+    // the overlay layer handles Escape, so a popup opened from the modal closes first
+    let overlay = Overlay::new(OverlayLayer::Popup).with_dismiss(false, true);
+    let entity = spawn_overlay(
+        commands,
+        overlay,
+        (
+            Node {
+                position_type: PositionType::Absolute,
+                width: Val::Percent(100.),
+                height: Val::Percent(100.),
+                justify_content: JustifyContent::Center,
+                align_items: AlignItems::Center,
+                ..default()
+            },
+            BackgroundColor(theme.backdrop),
+        ),
+        |_| {},
+    );
+    let title = modal.title.clone();
+    let actions = modal.actions.clone();
+    let default_action = modal.default_action;
+    commands
+        .entity(entity)
+        .insert(root)
+        .insert((modal, FocusTrap))
+        .with_children(|parent| {
+            parent
+                .spawn((
+                    Node {
+                        flex_direction: FlexDirection::Column,
+                        row_gap: Val::Px(16.),
+                        min_width: Val::Px(320.),
+                        max_width: Val::Percent(80.),
+                        padding: UiRect::all(Val::Px(20.)),
+                        border: UiRect::all(Val::Px(1.)),
+                        ..default()
+                    },
+                    BackgroundColor(theme.surface),
+                    BorderColor(theme.border),
+                    BorderRadius::all(Val::Px(8.)),
+                ))
+                .with_children(|card| {
+                    card.spawn((
+                        Text::new(title),
+                        TextFont {
+                            font_size: theme.font_size * 1.4,
+                            ..default()
+                        },
+                        TextColor(theme.text),
+                    ));
+                    card.spawn(Node {
+                        flex_direction: FlexDirection::Column,
+                        row_gap: Val::Px(8.),
+                        ..default()
+                    })
+                    .with_children(content);
+                    card.spawn(Node {
+                        justify_content: JustifyContent::End,
+                        column_gap: Val::Px(10.),
+                        ..default()
+                    })
+                    .with_children(|row| {
+                        for (index, label) in actions.into_iter().enumerate() {
+                            row.spawn((
+                                Node {
+                                    min_width: Val::Px(90.),
+                                    justify_content: JustifyContent::Center,
+                                    padding: UiRect::axes(Val::Px(14.), Val::Px(8.)),
+                                    ..default()
+                                },
+                                BackgroundColor(action_color(
+                                    theme,
+                                    Interaction::None,
+                                    default_action == Some(index),
+                                )),
+                                BorderRadius::all(Val::Px(4.)),
+                                ModalAction {
+                                    modal: entity,
+                                    index,
+                                },
+                            ))
+                            .with_child((
+                                Text::new(label),
+                                theme.text_font(),
+                                TextColor(theme.text),
+                            ));
+                        }
+                    });
+                });
+        });
+    entity
+}
+
+/// The default action stands out while it's not hovered or pressed.
+fn action_color(theme: &Theme, interaction: Interaction, default: bool) -> Color {
+    if default && interaction == Interaction::None {
+        theme.accent
+    } else {
+        theme.surface_color(interaction)
+    }
+}
+
+/// Takes a modal off the stack, hands the focus back and reports the picked action.
+fn close_modal(
+    stack: &mut ModalStack,
+    focus: &mut Focus,
+    modal: Entity,
+    action: Option<usize>,
+    closed: &mut EventWriter<ModalClosed>,
+) {
+    if let Some(restore_focus) = stack.remove(modal) {
+        focus.0 = restore_focus;
+    }
+    closed.send(ModalClosed { modal, action });
+}
+
+/// Escape dismissed the modal through the overlay layer: that's its cancel action.
+fn cancel_modals(
+    mut stack: ResMut<ModalStack>,
+    mut focus: ResMut<Focus>,
+    mut dismissed: EventReader<OverlayDismissed>,
+    mut closed: EventWriter<ModalClosed>,
+) {
+    for event in dismissed.read() {
+        if event.reason != DismissReason::Escape {
+            continue;
+        }
+        let Some(entry) = stack
+            .entries
+            .iter()
+            .find(|entry| entry.modal == event.overlay)
+        else {
+            continue;
+        };
+        let cancel_action = entry.cancel_action;
+        close_modal(
+            &mut stack,
+            &mut focus,
+            event.overlay,
+            cancel_action,
+            &mut closed,
+        );
+    }
+}
+
+/// Pushes new modals, focusing their default action, and forgets the ones despawned directly.
+fn track_modals(
+    mut stack: ResMut<ModalStack>,
+    mut focus: ResMut<Focus>,
+    added: Query<(Entity, &Modal), Added<Modal>>,
+    actions: Query<(Entity, &ModalAction)>,
+    mut removed: RemovedComponents<Modal>,
+) {
+    for entity in removed.read() {
+        if let Some(restore_focus) = stack.remove(entity) {
+            focus.0 = restore_focus;
+        }
+    }
+    for (entity, modal) in &added {
+        stack.entries.push(ModalEntry {
+            modal: entity,
+            cancel_action: modal.cancel_action,
+            restore_focus: focus.0,
+        });
+        let first = modal.default_action.unwrap_or(0);
+        focus.0 = actions
+            .iter()
+            .find(|(_, action)| action.modal == entity && action.index == first)
+            .map(|(action, _)| action);
+    }
+}
+
+/// Enter on the topmost modal picks the focused action, or the default one.
+#[allow(clippy::too_many_arguments, clippy::type_complexity)]
+fn modal_keys(
+    mut commands: Commands,
+    keys: Res<ButtonInput<KeyCode>>,
+    mut stack: ResMut<ModalStack>,
+    mut focus: ResMut<Focus>,
+    overlay_stack: Res<OverlayStack>,
+    overlays: Query<&Overlay>,
+    modals: Query<Ref<Modal>>,
+    actions: Query<&ModalAction>,
+    widgets: Query<(), Or<(With<Focusable>, With<TextInput>)>>,
+    mut closed: EventWriter<ModalClosed>,
+) {
+    if !keys.any_just_pressed([KeyCode::Enter, KeyCode::NumpadEnter]) {
+        return;
+    }
+    let Some(top) = stack.top() else {
+        return;
+    };
+    let Ok(modal) = modals.get(top) else {
+        return;
+    };
+    // a modal opened by this very key press waits for the next one
+    if modal.is_added() {
+        return;
+    }
+    // a popup opened from the modal, like a dropdown's list, takes Enter itself
+    let covered = overlay_stack
+        .iter()
+        .rev()
+        .take_while(|overlay| *overlay != top)
+        .any(|overlay| {
+            overlays
+                .get(overlay)
+                .is_ok_and(|overlay| overlay.layer == OverlayLayer::Popup)
+        });
+    if covered {
+        return;
+    }
+    let action = match focus.0 {
+        Some(entity) => match actions.get(entity) {
+            Ok(action) if action.modal == top => Some(action.index),
+            // text inputs and the other widgets use Enter themselves
+            _ if widgets.contains(entity) => return,
+            _ => modal.default_action,
+        },
+        None => modal.default_action,
+    };
+    let Some(action) = action else {
+        return;
+    };
+    close_modal(&mut stack, &mut focus, top, Some(action), &mut closed);
+    commands.entity(top).despawn_recursive();
+}
+
+fn click_actions(
+    mut commands: Commands,
+    mut stack: ResMut<ModalStack>,
+    mut focus: ResMut<Focus>,
+    actions: Query<(&Interaction, &ModalAction), Changed<Interaction>>,
+    mut closed: EventWriter<ModalClosed>,
+) {
+    for (interaction, action) in &actions {
+        // the stack check also keeps a modal from closing twice in one frame
+        if *interaction != Interaction::Pressed || !stack.contains(action.modal) {
+            continue;
+        }
+        close_modal(
+            &mut stack,
+            &mut focus,
+            action.modal,
+            Some(action.index),
+            &mut closed,
+        );
+        commands.entity(action.modal).despawn_recursive();
+    }
+}
+
+fn draw_actions(
+    theme: Res<Theme>,
+    modals: Query<&Modal>,
+    mut actions: Query<(&Interaction, &ModalAction, &mut BackgroundColor), Changed<Interaction>>,
+) {
+    for (interaction, action, mut color) in &mut actions {
+        let default = modals
+            .get(action.modal)
+            .is_ok_and(|modal| modal.default_action == Some(action.index));
+        color.0 = action_color(&theme, *interaction, default);
+    }
+}
@@ -0,0 +1,423 @@
+//! Overlays: root nodes drawn above the rest of the UI, such as popups, menus, modals, toasts
+//! and tooltips. Spawn one with [`spawn_overlay`]; despawning it closes it.
+//!
+//! An overlay can be anchored to a node or a point. It's placed on the requested side of the
+//! anchor, moved to the opposite side when it doesn't fit in the window there, and then kept
+//! inside the window. Overlays stack in the order they were opened. A press outside an overlay (and
+//! outside its anchor and the overlays above it) dismisses it, and Escape dismisses the topmost
+//! one; both can be turned off per overlay.
+
+use bevy::prelude::*;
+use bevy::ui::{FocusPolicy, UiSystem};
+use bevy::window::PrimaryWindow;
+
+/// Z index of the lowest overlay of each layer.
+const POPUP_Z: i32 = 1000;
+const TOAST_Z: i32 = 2000;
+const TOOLTIP_Z: i32 = 3000;
+
+/// Which band of z indices an overlay is drawn in.
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub enum OverlayLayer {
+    /// Popups, menus and modals, stacked in the order they open.
+    #[default]
+    Popup,
+    /// Notifications, above popups.
+    Toast,
+    /// Above everything else.
+    Tooltip,
+}
+
+impl OverlayLayer {
+    fn base_z(self) -> i32 {
+        match self {
+            OverlayLayer::Popup => POPUP_Z,
+            OverlayLayer::Toast => TOAST_Z,
+            OverlayLayer::Tooltip => TOOLTIP_Z,
+        }
+    }
+}
+
+/// What an overlay is positioned against.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum OverlayAnchor {
+    Node(Entity),
+    /// A point in logical window coordinates, e.g. where a context menu was opened.
+    Point(Vec2),
+}
+
+/// The side of the anchor an overlay goes on.
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub enum Placement {
+    Top,
+    #[default]
+    Bottom,
+    Left,
+    Right,
+}
+
+impl Placement {
+    fn opposite(self) -> Self {
+        match self {
+            Placement::Top => Placement::Bottom,
+            Placement::Bottom => Placement::Top,
+            Placement::Left => Placement::Right,
+            Placement::Right => Placement::Left,
+        }
+    }
+}
+
+/// How an overlay lines up with its anchor across the placement side: `Start` lines up the left
+/// (or top) edges.
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub enum OverlayAlign {
+    #[default]
+    Start,
+    Center,
+    End,
+}
+
+#[derive(Component, Clone, Debug)]
+pub struct Overlay {
+    pub layer: OverlayLayer,
+    pub anchor: Option<OverlayAnchor>,
+    pub placement: Placement,
+    pub align: OverlayAlign,
+    /// Distance from the anchor, on whichever side the overlay ends up.
+    pub gap: f32,
+    /// Extra shift in logical pixels, applied after placement.
+    pub offset: Vec2,
+    /// Makes the overlay as wide as its anchor node.
+    pub match_anchor_width: bool,
+    pub dismiss_on_click_outside: bool,
+    pub dismiss_on_escape: bool,
+}
+
+impl Default for Overlay {
+    fn default() -> Self {
+        Self::new(OverlayLayer::Popup)
+    }
+}
+
+impl Overlay {
+    pub fn new(layer: OverlayLayer) -> Self {
+        Self {
+            layer,
+            anchor: None,
+            placement: Placement::Bottom,
+            align: OverlayAlign::Start,
+            gap: 0.,
+            offset: Vec2::ZERO,
+            match_anchor_width: false,
+            dismiss_on_click_outside: true,
+            dismiss_on_escape: true,
+        }
+    }
+
+    pub fn anchored(mut self, node: Entity, placement: Placement) -> Self {
+        self.anchor = Some(OverlayAnchor::Node(node));
+        self.placement = placement;
+        self
+    }
+
+    pub fn at_point(mut self, point: Vec2, placement: Placement) -> Self {
+        self.anchor = Some(OverlayAnchor::Point(point));
+        self.placement = placement;
+        self
+    }
+
+    pub fn with_align(mut self, align: OverlayAlign) -> Self {
+        self.align = align;
+        self
+    }
+
+    pub fn with_gap(mut self, gap: f32) -> Self {
+        self.gap = gap;
+        self
+    }
+
+    pub fn with_offset(mut self, offset: Vec2) -> Self {
+        self.offset = offset;
+        self
+    }
+
+    pub fn match_anchor_width(mut self) -> Self {
+        self.match_anchor_width = true;
+        self
+    }
+
+    pub fn with_dismiss(mut self, click_outside: bool, escape: bool) -> Self {
+        self.dismiss_on_click_outside = click_outside;
+        self.dismiss_on_escape = escape;
+        self
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum DismissReason {
+    ClickOutside,
+    Escape,
+}
+
+/// Sent when an overlay is dismissed, just before it's despawned.
+#[derive(Event, Clone, Copy, Debug)]
+pub struct OverlayDismissed {
+    pub overlay: Entity,
+    pub reason: DismissReason,
+}
+
+/// The open overlays, bottom to top.
+#[derive(Resource, Default, Debug)]
+pub struct OverlayStack {
+    entries: Vec<Entity>,
+}
+
+impl OverlayStack {
+    pub fn iter(&self) -> impl DoubleEndedIterator<Item = Entity> + '_ {
+        self.entries.iter().copied()
+    }
+
+    pub fn top(&self) -> Option<Entity> {
+        self.entries.last().copied()
+    }
+
+    pub fn contains(&self, overlay: Entity) -> bool {
+        self.entries.contains(&overlay)
+    }
+}
+
+/// Whether the overlay has been placed since it was spawned; it stays hidden until then.
+#[derive(Component, Default)]
+struct Placed(bool);
+
+/// Stacking and dismissal; anchored overlays are placed in [`PostUpdate`] before layout.
+#[derive(SystemSet, Debug, Clone, PartialEq, Eq, Hash)]
+pub struct OverlaySystem;
+
+pub struct OverlayPlugin;
+
+impl Plugin for OverlayPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<OverlayStack>()
+            .add_event::<OverlayDismissed>()
+            .add_systems(
+                Update,
+                (track_overlays, dismiss_overlays, stack_overlays)
+                    .chain()
+                    .in_set(OverlaySystem),
+            )
+            .add_systems(PostUpdate, place_overlays.before(UiSystem::Layout));
+    }
+}
+
+/// Spawns an overlay as a new UI root, with `root` for its node and look and `content` for its
+/// children, and returns its entity.
+pub fn spawn_overlay(
+    commands: &mut Commands,
+    overlay: Overlay,
+    root: impl Bundle,
+    content: impl FnOnce(&mut ChildBuilder),
+) -> Entity {
+    let anchored = overlay.anchor.is_some();
+    let mut overlay_commands = commands.spawn((
+        Node {
+            position_type: PositionType::Absolute,
+            ..default()
+        },
+        GlobalZIndex(overlay.layer.base_z()),
+        Interaction::None,
+        FocusPolicy::Block,
+    ));
+    overlay_commands
+        .insert(root)
+        .insert((overlay, Placed(!anchored)));
+    if anchored {
+        // until it's been placed next to its anchor
+        overlay_commands.insert(Visibility::Hidden);
+    }
+    overlay_commands.with_children(content).id()
+}
+
+fn track_overlays(
+    mut stack: ResMut<OverlayStack>,
+    added: Query<Entity, Added<Overlay>>,
+    mut removed: RemovedComponents<Overlay>,
+) {
+    for entity in removed.read() {
+        stack.entries.retain(|overlay| *overlay != entity);
+    }
+    for entity in &added {
+        stack.entries.push(entity);
+    }
+}
+
+/// The rectangle of a laid out node in logical window coordinates.
+pub(crate) fn node_rect(computed: &ComputedNode, transform: &GlobalTransform) -> Rect {
+    let scale = computed.inverse_scale_factor();
+    Rect::from_center_size(
+        transform.translation().truncate() * scale,
+        computed.size() * scale,
+    )
+}
+
+#[allow(clippy::too_many_arguments)]
+fn dismiss_overlays(
+    mut commands: Commands,
+    mouse: Res<ButtonInput<MouseButton>>,
+    keys: Res<ButtonInput<KeyCode>>,
+    mut stack: ResMut<OverlayStack>,
+    windows: Query<&Window, With<PrimaryWindow>>,
+    overlays: Query<&Overlay>,
+    nodes: Query<(&ComputedNode, &GlobalTransform)>,
+    mut dismissed: EventWriter<OverlayDismissed>,
+) {
+    let mut dismiss = Vec::new();
+    if keys.just_pressed(KeyCode::Escape)
+        && let Some(top) = stack.iter().rev().find(|entity| {
+            overlays
+                .get(*entity)
+                .is_ok_and(|overlay| overlay.dismiss_on_escape)
+        })
+    {
+        dismiss.push((top, DismissReason::Escape));
+    }
+
+    let cursor = windows.get_single().ok().and_then(Window::cursor_position);
+    if mouse.just_pressed(MouseButton::Left)
+        && let Some(cursor) = cursor
+    {
+        let contains = |entity: Entity| {
+            nodes
+                .get(entity)
+                .is_ok_and(|(computed, transform)| node_rect(computed, transform).contains(cursor))
+        };
+        // walking down from the top: a press inside an overlay protects the ones below it
+        for entity in stack.iter().rev() {
+            let Ok(overlay) = overlays.get(entity) else {
+                continue;
+            };
+            if contains(entity) {
+                break;
+            }
+            let on_anchor = match overlay.anchor {
+                Some(OverlayAnchor::Node(anchor)) => contains(anchor),
+                _ => false,
+            };
+            if overlay.dismiss_on_click_outside && !on_anchor {
+                dismiss.push((entity, DismissReason::ClickOutside));
+            }
+        }
+    }
+
+    for (overlay, reason) in dismiss {
+        if stack.contains(overlay) {
+            stack.entries.retain(|entity| *entity != overlay);
+            dismissed.send(OverlayDismissed { overlay, reason });
+            commands.entity(overlay).despawn_recursive();
+        }
+    }
+}
+
+fn stack_overlays(stack: Res<OverlayStack>, mut overlays: Query<(&Overlay, &mut GlobalZIndex)>) {
+    if !stack.is_changed() {
+        return;
+    }
+    for (position, entity) in stack.iter().enumerate() {
+        if let Ok((overlay, mut z_index)) = overlays.get_mut(entity) {
+            z_index.set_if_neq(GlobalZIndex(overlay.layer.base_z() + position as i32));
+        }
+    }
+}
+
+/// Where an overlay of `size` goes next to `anchor`, flipped and shifted to stay inside `bounds`.
+fn place(overlay: &Overlay, anchor: Rect, size: Vec2, bounds: Rect) -> Vec2 {
+    let position_on = |placement: Placement| {
+        let along = match placement {
+            Placement::Top => anchor.min.y - overlay.gap - size.y,
+            Placement::Bottom => anchor.max.y + overlay.gap,
+            Placement::Left => anchor.min.x - overlay.gap - size.x,
+            Placement::Right => anchor.max.x + overlay.gap,
+        };
+        let (start, end, length) = match placement {
+            Placement::Top | Placement::Bottom => (anchor.min.x, anchor.max.x, size.x),
+            Placement::Left | Placement::Right => (anchor.min.y, anchor.max.y, size.y),
+        };
+        let across = match overlay.align {
+            OverlayAlign::Start => start,
+            OverlayAlign::Center => (start + end - length) / 2.,
+            OverlayAlign::End => end - length,
+        };
+        match placement {
+            Placement::Top | Placement::Bottom => Vec2::new(across, along),
+            Placement::Left | Placement::Right => Vec2::new(along, across),
+        }
+    };
+    let fits = |position: Vec2| match overlay.placement {
+        Placement::Top | Placement::Bottom => {
+            position.y >= bounds.min.y && position.y + size.y <= bounds.max.y
+        }
+        Placement::Left | Placement::Right => {
+            position.x >= bounds.min.x && position.x + size.x <= bounds.max.x
+        }
+    };
+
+    let mut position = position_on(overlay.placement);
+    if !fits(position) {
+        let flipped = position_on(overlay.placement.opposite());
+        if fits(flipped) {
+            position = flipped;
+        }
+    }
+    position += overlay.offset;
+    let max = (bounds.max - size).max(bounds.min);
+    position.clamp(bounds.min, max)
+}
+
+#[allow(clippy::type_complexity)]
+fn place_overlays(
+    windows: Query<&Window, With<PrimaryWindow>>,
+    anchors: Query<(&ComputedNode, &GlobalTransform), Without<Overlay>>,
+    mut overlays: Query<(
+        &Overlay,
+        &ComputedNode,
+        &mut Node,
+        &mut Placed,
+        &mut Visibility,
+    )>,
+) {
+    let Ok(window) = windows.get_single() else {
+        return;
+    };
+    let bounds = Rect::new(0., 0., window.width(), window.height());
+    for (overlay, computed, mut node, mut placed, mut visibility) in &mut overlays {
+        let anchor = match overlay.anchor {
+            Some(OverlayAnchor::Node(entity)) => match anchors.get(entity) {
+                Ok((anchor, transform)) => node_rect(anchor, transform),
+                Err(_) => continue,
+            },
+            Some(OverlayAnchor::Point(point)) => Rect::from_center_size(point, Vec2::ZERO),
+            None => continue,
+        };
+        if overlay.match_anchor_width {
+            let width = Val::Px(anchor.width());
+            if node.width != width {
+                node.width = width;
+            }
+        }
+        // the size is only known once the overlay has been laid out
+        let size = computed.size() * computed.inverse_scale_factor();
+        if size == Vec2::ZERO {
+            continue;
+        }
+        let position = place(overlay, anchor, size, bounds);
+        let (left, top) = (Val::Px(position.x), Val::Px(position.y));
+        if node.left != left || node.top != top {
+            node.left = left;
+            node.top = top;
+        }
+        if !placed.0 {
+            placed.0 = true;
+            *visibility = Visibility::Inherited;
+        }
+    }
+}
@@ -0,0 +1,400 @@
+//! Progress feedback for long-running work: a [`ProgressBar`] that fills up as the work advances,
+//! or sweeps a stripe across its track while there's nothing to measure, and a [`Spinner`]. Both
+//! take their size from the root `Node` they're spawned with, so they fit anywhere, including
+//! inside a button next to its label, and they never block clicks.
+//!
+//! ```ignore
+//! let bar = spawn_progress_bar(parent, ProgressBar::new(0.).with_label(ProgressLabel::Percent),
+//!     Node { width: Val::Px(200.), height: Val::Px(16.), ..default() }, &theme);
+//! // later
+//! bars.get_mut(bar)?.set_value(0.5);
+//! ```
+
+use crate::theme::Theme;
+use accesskit::Role;
+use bevy::a11y::AccessibilityNode;
+use bevy::prelude::*;
+use bevy::window::RequestRedraw;
+use std::f32::consts::TAU;
+
+/// Width of the indeterminate stripe, in percent of the track.
+const STRIPE_WIDTH: f32 = 35.;
+/// Seconds the stripe takes to cross the track.
+const SWEEP_TIME: f32 = 1.4;
+/// Number of dots around a spinner.
+const SPINNER_DOTS: usize = 8;
+/// Diameter of a spinner dot, in percent of the spinner.
+const DOT_SIZE: f32 = 22.;
+
+/// What's written over a progress bar.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub enum ProgressLabel {
+    #[default]
+    None,
+    /// The value as a percentage, e.g. "42%"; nothing while indeterminate.
+    Percent,
+    Text(String),
+}
+
+#[derive(Component, Clone, Debug, PartialEq)]
+pub struct ProgressBar {
+    value: Option<f32>,
+    pub label: ProgressLabel,
+    /// Color of the fill, the theme's accent color if `None`.
+    pub color: Option<Color>,
+}
+
+impl ProgressBar {
+    /// A determinate bar filled to `value`, from 0 to 1.
+    pub fn new(value: f32) -> Self {
+        Self {
+            value: Some(value.clamp(0., 1.)),
+            label: ProgressLabel::None,
+            color: None,
+        }
+    }
+
+    /// A bar with a stripe sweeping across it, for work of unknown length.
+    pub fn indeterminate() -> Self {
+        Self {
+            value: None,
+            label: ProgressLabel::None,
+            color: None,
+        }
+    }
+
+    pub fn with_label(mut self, label: ProgressLabel) -> Self {
+        self.label = label;
+        self
+    }
+
+    pub fn with_color(mut self, color: Color) -> Self {
+        self.color = Some(color);
+        self
+    }
+
+    /// The value from 0 to 1, or `None` while indeterminate.
+    pub fn value(&self) -> Option<f32> {
+        self.value
+    }
+
+    /// Sets the value, clamped to 0..=1, making the bar determinate.
+    pub fn set_value(&mut self, value: f32) {
+        self.value = Some(value.clamp(0., 1.));
+    }
+
+    pub fn set_indeterminate(&mut self) {
+        self.value = None;
+    }
+
+    pub fn is_indeterminate(&self) -> bool {
+        self.value.is_none()
+    }
+
+    fn label_text(&self) -> String {
+        match (&self.label, self.value) {
+            (ProgressLabel::Percent, Some(value)) => format!("{:.0}%", value * 100.),
+            (ProgressLabel::Text(text), _) => text.clone(),
+            _ => String::new(),
+        }
+    }
+}
+
+#[derive(Component)]
+struct ProgressParts {
+    fill: Entity,
+    label: Entity,
+}
+
+/// A ring of dots going round, for work of unknown length.
+#[derive(Component, Clone, Debug, PartialEq)]
+pub struct Spinner {
+    /// Color of the dots, the theme's accent color if `None`.
+    pub color: Option<Color>,
+    /// Turns per second.
+    pub speed: f32,
+}
+
+impl Default for Spinner {
+    fn default() -> Self {
+        Self {
+            color: None,
+            speed: 1.,
+        }
+    }
+}
+
+impl Spinner {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_color(mut self, color: Color) -> Self {
+        self.color = Some(color);
+        self
+    }
+
+    pub fn with_speed(mut self, speed: f32) -> Self {
+        self.speed = speed;
+        self
+    }
+}
+
+#[derive(Component)]
+struct SpinnerParts {
+    wheel: Entity,
+    dots: Vec<Entity>,
+}
+
+pub struct ProgressPlugin;
+
+impl Plugin for ProgressPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<Theme>().add_systems(
+            Update,
+            (
+                draw_progress_bars,
+                sweep_progress_bars,
+                color_spinners,
+                spin_spinners,
+            ),
+        );
+    }
+}
+
+/// Spawns a progress bar filling `root`'s node, 8 pixels high unless `root` says otherwise, and
+/// returns its entity.
+pub fn spawn_progress_bar(
+    parent: &mut ChildBuilder,
+    bar: ProgressBar,
+    root: impl Bundle,
+    theme: &Theme,
+) -> Entity {
+    let mut accessible = accesskit::Node::new(Role::ProgressIndicator);
+    accessible.set_min_numeric_value(0.);
+    accessible.set_max_numeric_value(100.);
+    let mut bar_commands = parent.spawn(Node {
+        width: Val::Percent(100.),
+        height: Val::Px(8.),
+        ..default()
+    });
+    bar_commands.insert(root);
+    let mut fill = Entity::PLACEHOLDER;
+    let mut label = Entity::PLACEHOLDER;
+    bar_commands.with_children(|parent| {
+        parent
+            .spawn((
+                Node {
+                    width: Val::Percent(100.),
+                    height: Val::Percent(100.),
+                    overflow: Overflow::clip(),
+                    ..default()
+                },
+                BackgroundColor(theme.surface),
+                BorderRadius::MAX,
+            ))
+            .with_children(|parent| {
+                fill = parent
+                    .spawn((
+                        Node {
+                            position_type: PositionType::Absolute,
+                            height: Val::Percent(100.),
+                            ..default()
+                        },
+                        BackgroundColor(bar.color.unwrap_or(theme.accent)),
+                        BorderRadius::MAX,
+                    ))
+                    .id();
+                parent
+                    .spawn(Node {
+                        position_type: PositionType::Absolute,
+                        width: Val::Percent(100.),
+                        height: Val::Percent(100.),
+                        justify_content: JustifyContent::Center,
+                        align_items: AlignItems::Center,
+                        ..default()
+                    })
+                    .with_children(|parent| {
+                        label = parent
+                            .spawn((
+                                Text::new(bar.label_text()),
+                                TextFont {
+                                    font_size: theme.font_size * 0.8,
+                                    ..default()
+                                },
+                                TextColor(theme.text),
+                                TextLayout::new_with_no_wrap(),
+                            ))
+                            .id();
+                    });
+            });
+    });
+    bar_commands.insert((
+        bar,
+        ProgressParts { fill, label },
+        AccessibilityNode::from(accessible),
+    ));
+    bar_commands.id()
+}
+
+/// Spawns a spinner filling `root`'s node, 24 pixels square unless `root` says otherwise, and
+/// returns its entity. Keep the node square, or the ring turns into an ellipse.
+pub fn spawn_spinner(
+    parent: &mut ChildBuilder,
+    spinner: Spinner,
+    root: impl Bundle,
+    theme: &Theme,
+) -> Entity {
+    let mut accessible = accesskit::Node::new(Role::ProgressIndicator);
+    accessible.set_label("Loading");
+    let mut spinner_commands = parent.spawn(Node {
+        width: Val::Px(24.),
+        height: Val::Px(24.),
+        flex_shrink: 0.,
+        ..default()
+    });
+    spinner_commands.insert(root);
+    let mut wheel = Entity::PLACEHOLDER;
+    let mut dots = Vec::with_capacity(SPINNER_DOTS);
+    spinner_commands.with_children(|parent| {
+        wheel = parent
+            .spawn(Node {
+                width: Val::Percent(100.),
+                height: Val::Percent(100.),
+                ..default()
+            })
+            .with_children(|parent| {
+                // the dots sit on a circle touching the edges, clockwise from the top
+                let radius = 50. - DOT_SIZE / 2.;
+                for index in 0..SPINNER_DOTS {
+                    let angle = index as f32 / SPINNER_DOTS as f32 * TAU;
+                    let dot = parent.spawn((
+                        Node {
+                            position_type: PositionType::Absolute,
+                            left: Val::Percent(50. + radius * angle.sin() - DOT_SIZE / 2.),
+                            top: Val::Percent(50. - radius * angle.cos() - DOT_SIZE / 2.),
+                            width: Val::Percent(DOT_SIZE),
+                            height: Val::Percent(DOT_SIZE),
+                            ..default()
+                        },
+                        BackgroundColor(dot_color(&spinner, index, theme)),
+                        BorderRadius::MAX,
+                    ));
+                    dots.push(dot.id());
+                }
+            })
+            .id();
+    });
+    spinner_commands.insert((
+        spinner,
+        SpinnerParts { wheel, dots },
+        AccessibilityNode::from(accessible),
+    ));
+    spinner_commands.id()
+}
+
+/// The leading dot is opaque and the ones behind it fade out.
+fn dot_color(spinner: &Spinner, index: usize, theme: &Theme) -> Color {
+    let alpha = (index + 1) as f32 / SPINNER_DOTS as f32;
+    let color = spinner.color.unwrap_or(theme.accent);
+    color.with_alpha(color.alpha() * alpha)
+}
+
+fn draw_progress_bars(
+    theme: Res<Theme>,
+    mut bars: Query<(&ProgressBar, &ProgressParts, &mut AccessibilityNode), Changed<ProgressBar>>,
+    mut fills: Query<(&mut Node, &mut BackgroundColor)>,
+    mut labels: Query<&mut Text>,
+) {
+    for (bar, parts, mut accessible) in &mut bars {
+        if let Ok((mut node, mut background)) = fills.get_mut(parts.fill) {
+            background.0 = bar.color.unwrap_or(theme.accent);
+            match bar.value {
+                Some(value) => {
+                    node.left = Val::Px(0.);
+                    node.width = Val::Percent(value * 100.);
+                }
+                // positioned by sweep_progress_bars
+                None => node.width = Val::Percent(STRIPE_WIDTH),
+            }
+        }
+        if let Ok(mut text) = labels.get_mut(parts.label) {
+            let label = bar.label_text();
+            if text.0 != label {
+                text.0 = label;
+            }
+        }
+        match bar.value {
+            Some(value) => accessible.set_numeric_value(f64::from(value) * 100.),
+            None => accessible.clear_numeric_value(),
+        }
+    }
+}
+
+fn sweep_progress_bars(
+    time: Res<Time>,
+    bars: Query<(&ProgressBar, &ProgressParts)>,
+    mut fills: Query<&mut Node>,
+    mut redraw: EventWriter<RequestRedraw>,
+) {
+    let phase = (time.elapsed_secs() / SWEEP_TIME).fract();
+    // eased so the stripe speeds up in the middle of the track, from fully left to fully right
+    let eased = phase * phase * (3. - 2. * phase);
+    let left = -STRIPE_WIDTH + eased * (100. + STRIPE_WIDTH);
+    let mut sweeping = false;
+    for (bar, parts) in &bars {
+        if !bar.is_indeterminate() {
+            continue;
+        }
+        if let Ok(mut node) = fills.get_mut(parts.fill) {
+            node.left = Val::Percent(left);
+        }
+        sweeping = true;
+    }
+    if sweeping {
+        redraw.send(RequestRedraw);
+    }
+}
+
+fn color_spinners(
+    theme: Res<Theme>,
+    spinners: Query<(&Spinner, &SpinnerParts), Changed<Spinner>>,
+    mut dots: Query<&mut BackgroundColor>,
+) {
+    for (spinner, parts) in &spinners {
+        for (index, dot) in parts.dots.iter().enumerate() {
+            if let Ok(mut background) = dots.get_mut(*dot) {
+                background.0 = dot_color(spinner, index, &theme);
+            }
+        }
+    }
+}
+
+/// Turns the wheels a dot at a time, so the opaque dot steps round the ring.
+fn spin_spinners(
+    time: Res<Time>,
+    spinners: Query<(&Spinner, &SpinnerParts, &InheritedVisibility)>,
+    mut wheels: Query<&mut Transform>,
+    mut redraw: EventWriter<RequestRedraw>,
+) {
+    let mut spinning = false;
+    for (spinner, parts, visibility) in &spinners {
+        if !visibility.get() {
+            continue;
+        }
+        let step = (time.elapsed_secs() * spinner.speed * SPINNER_DOTS as f32).floor();
+        // the last dot is the opaque one, one step before the top; UI y points down, so a
+        // positive angle turns clockwise
+        let angle = (step + 1.) / SPINNER_DOTS as f32 * TAU;
+        if let Ok(mut transform) = wheels.get_mut(parts.wheel) {
+            let rotation = Quat::from_rotation_z(angle);
+            if transform.rotation != rotation {
+                transform.rotation = rotation;
+            }
+        }
+        spinning = true;
+    }
+    if spinning {
+        redraw.send(RequestRedraw);
+    }
+}
@@ -0,0 +1,243 @@
+//! Mutually exclusive options. Clicking an option selects it and focuses the group; while the group
+//! is focused the arrow keys move the selection.
+
+use crate::theme::Theme;
+use crate::widgets::focus::{Focus, FocusPlugin, Focusable};
+use bevy::prelude::*;
+
+const CIRCLE_SIZE: f32 = 18.;
+const DOT_SIZE: f32 = 8.;
+
+#[derive(Component, Clone, Debug, Default)]
+#[require(Focusable)]
+pub struct RadioGroup {
+    pub options: Vec<String>,
+    pub selected: Option<usize>,
+}
+
+impl RadioGroup {
+    pub fn new(options: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        Self {
+            options: options.into_iter().map(Into::into).collect(),
+            selected: None,
+        }
+    }
+
+    pub fn with_selected(mut self, selected: usize) -> Self {
+        self.selected = Some(selected);
+        self
+    }
+
+    pub fn selected_option(&self) -> Option<&str> {
+        self.selected
+            .and_then(|index| self.options.get(index))
+            .map(String::as_str)
+    }
+}
+
+/// Sent when the user selects an option.
+#[derive(Event, Clone, Copy, Debug)]
+pub struct RadioChanged {
+    pub group: Entity,
+    pub selected: usize,
+}
+
+/// One clickable option of a group.
+#[derive(Component, Clone, Copy, Debug)]
+#[require(Button)]
+pub struct RadioOption {
+    pub group: Entity,
+    pub index: usize,
+}
+
+#[derive(Component)]
+struct RadioCircle {
+    option: Entity,
+}
+
+#[derive(Component)]
+struct RadioDot {
+    group: Entity,
+    index: usize,
+}
+
+pub struct RadioGroupPlugin;
+
+impl Plugin for RadioGroupPlugin {
+    fn build(&self, app: &mut App) {
+        if !app.is_plugin_added::<FocusPlugin>() {
+            app.add_plugins(FocusPlugin);
+        }
+        app.add_event::<RadioChanged>().add_systems(
+            Update,
+            (click_options, arrow_keys, draw_radio_groups).chain(),
+        );
+    }
+}
+
+/// Spawns a radio group laid out by `root` (a column or a row) and returns the group entity.
+pub fn spawn_radio_group(
+    parent: &mut ChildBuilder,
+    group: RadioGroup,
+    root: impl Bundle,
+    theme: &Theme,
+) -> Entity {
+    let options = group.options.clone();
+    let mut group_commands = parent.spawn((root, group, BorderRadius::all(Val::Px(4.))));
+    let entity = group_commands.id();
+    group_commands.with_children(|parent| {
+        for (index, label) in options.into_iter().enumerate() {
+            let mut option = parent.spawn((
+                RadioOption {
+                    group: entity,
+                    index,
+                },
+                Node {
+                    align_items: AlignItems::Center,
+                    column_gap: Val::Px(8.),
+                    padding: UiRect::all(Val::Px(2.)),
+                    ..default()
+                },
+            ));
+            let option_entity = option.id();
+            option.with_children(|parent| {
+                parent
+                    .spawn((
+                        Node {
+                            width: Val::Px(CIRCLE_SIZE),
+                            height: Val::Px(CIRCLE_SIZE),
+                            border: UiRect::all(Val::Px(2.)),
+                            justify_content: JustifyContent::Center,
+                            align_items: AlignItems::Center,
+                            ..default()
+                        },
+                        BorderColor(theme.border),
+                        BorderRadius::MAX,
+                        BackgroundColor(theme.surface),
+                        RadioCircle {
+                            option: option_entity,
+                        },
+                    ))
+                    .with_child((
+                        Node {
+                            width: Val::Px(DOT_SIZE),
+                            height: Val::Px(DOT_SIZE),
+                            display: Display::None,
+                            ..default()
+                        },
+                        BackgroundColor(theme.accent),
+                        BorderRadius::MAX,
+                        RadioDot {
+                            group: entity,
+                            index,
+                        },
+                    ));
+                parent.spawn((Text::new(label), theme.text_font(), TextColor(theme.text)));
+            });
+        }
+    });
+    entity
+}
+
+fn select(
+    group: &mut RadioGroup,
+    entity: Entity,
+    index: usize,
+    changes: &mut EventWriter<RadioChanged>,
+) {
+    if group.selected != Some(index) {
+        group.selected = Some(index);
+        changes.send(RadioChanged {
+            group: entity,
+            selected: index,
+        });
+    }
+}
+
+fn click_options(
+    mut focus: ResMut<Focus>,
+    options: Query<(&Interaction, &RadioOption), Changed<Interaction>>,
+    mut groups: Query<&mut RadioGroup>,
+    mut changes: EventWriter<RadioChanged>,
+) {
+    for (interaction, option) in &options {
+        if *interaction != Interaction::Pressed {
+            continue;
+        }
+        if let Ok(mut group) = groups.get_mut(option.group) {
+            select(&mut group, option.group, option.index, &mut changes);
+            focus.0 = Some(option.group);
+        }
+    }
+}
+
+fn arrow_keys(
+    keys: Res<ButtonInput<KeyCode>>,
+    focus: Res<Focus>,
+    mut groups: Query<&mut RadioGroup>,
+    mut changes: EventWriter<RadioChanged>,
+) {
+    let Some(entity) = focus.0 else {
+        return;
+    };
+    let Ok(mut group) = groups.get_mut(entity) else {
+        return;
+    };
+    let count = group.options.len();
+    if count == 0 {
+        return;
+    }
+    for key in keys.get_just_pressed() {
+        let index = match (key, group.selected) {
+            (KeyCode::ArrowUp | KeyCode::ArrowLeft, Some(index)) => (index + count - 1) % count,
+            (KeyCode::ArrowDown | KeyCode::ArrowRight, Some(index)) => (index + 1) % count,
+            (KeyCode::Space, Some(index)) => index,
+            (
+                KeyCode::ArrowUp
+                | KeyCode::ArrowLeft
+                | KeyCode::ArrowDown
+                | KeyCode::ArrowRight
+                | KeyCode::Space,
+                None,
+            ) => 0,
+            _ => continue,
+        };
+        select(&mut group, entity, index, &mut changes);
+    }
+}
+
+fn draw_radio_groups(
+    theme: Res<Theme>,
+    groups: Query<&RadioGroup>,
+    options: Query<(&RadioOption, &Interaction)>,
+    mut circles: Query<(&RadioCircle, &mut BackgroundColor, &mut BorderColor)>,
+    mut dots: Query<(&RadioDot, &mut Node)>,
+) {
+    for (circle, mut background, mut border) in &mut circles {
+        let Ok((option, interaction)) = options.get(circle.option) else {
+            continue;
+        };
+        let selected = groups
+            .get(option.group)
+            .is_ok_and(|group| group.selected == Some(option.index));
+        background.set_if_neq(BackgroundColor(theme.surface_color(*interaction)));
+        border.set_if_neq(BorderColor(if selected {
+            theme.accent
+        } else {
+            theme.border
+        }));
+    }
+    for (dot, mut node) in &mut dots {
+        let selected = groups
+            .get(dot.group)
+            .is_ok_and(|group| group.selected == Some(dot.index));
+        let display = if selected {
+            Display::Flex
+        } else {
+            Display::None
+        };
+        if node.display != display {
+            node.display = display;
+        }
+    }
+}
@@ -0,0 +1,757 @@
+//! Scrollable container. Content taller or wider than the view scrolls with the mouse wheel
+//! (shift for horizontal), by dragging it, with the arrow/page/home/end keys while hovered and with
+//! the themed scrollbars. Dragged content keeps moving after release when `kinetic` is set.
+//!
+//! ```ignore
+//! let viewport = spawn_scroll_view(parent, ScrollView::vertical(), Node::default(), |content| {
+//!     content.spawn(Text::new("a long list"));
+//! });
+//! ```
+//!
+//! Insert [`ScrollIntoView`] on a descendant to scroll it into view; focused text inputs are
+//! scrolled into view automatically.
+
+use crate::theme::Theme;
+use bevy::input::mouse::{MouseScrollUnit, MouseWheel};
+use bevy::prelude::*;
+use bevy::ui::{FocusPolicy, RelativeCursorPosition};
+use bevy::window::PrimaryWindow;
+use bevy_simple_text_input::TextInputInactive;
+
+/// Pixels scrolled per wheel line and per arrow key press.
+const LINE_HEIGHT: f32 = 20.;
+/// Cursor travel before a press on the content turns into a drag.
+const DRAG_THRESHOLD: f32 = 4.;
+/// Exponential decay of kinetic scrolling, per second.
+const FRICTION: f32 = 5.;
+/// Kinetic scrolling stops below this speed, in pixels per second.
+const MIN_VELOCITY: f32 = 5.;
+const MIN_THUMB_PERCENT: f32 = 10.;
+
+/// The scrolling viewport; its [`ScrollPosition`] is the scroll offset.
+#[derive(Component, Clone, Copy, Debug)]
+#[require(ScrollPosition, ScrollMetrics, ScrollMotion, RelativeCursorPosition)]
+pub struct ScrollView {
+    pub horizontal: bool,
+    pub vertical: bool,
+    pub kinetic: bool,
+    /// Whether the arrow/page/home/end keys scroll the view while it's hovered.
+    pub keyboard: bool,
+}
+
+impl ScrollView {
+    pub fn vertical() -> Self {
+        Self {
+            horizontal: false,
+            vertical: true,
+            kinetic: false,
+            keyboard: true,
+        }
+    }
+
+    pub fn horizontal() -> Self {
+        Self {
+            horizontal: true,
+            vertical: false,
+            kinetic: false,
+            keyboard: true,
+        }
+    }
+
+    pub fn both() -> Self {
+        Self {
+            horizontal: true,
+            vertical: true,
+            kinetic: false,
+            keyboard: true,
+        }
+    }
+
+    pub fn with_kinetic(mut self, kinetic: bool) -> Self {
+        self.kinetic = kinetic;
+        self
+    }
+
+    pub fn with_keyboard(mut self, keyboard: bool) -> Self {
+        self.keyboard = keyboard;
+        self
+    }
+
+    fn clamp(&self, offset: Vec2, metrics: &ScrollMetrics) -> Vec2 {
+        let max = metrics.max_offset();
+        Vec2::new(
+            if self.horizontal {
+                offset.x.clamp(0., max.x)
+            } else {
+                0.
+            },
+            if self.vertical {
+                offset.y.clamp(0., max.y)
+            } else {
+                0.
+            },
+        )
+    }
+}
+
+/// Viewport and content size in logical pixels, as of the last layout.
+#[derive(Component, Clone, Copy, Default, Debug)]
+pub struct ScrollMetrics {
+    pub viewport: Vec2,
+    pub content: Vec2,
+}
+
+impl ScrollMetrics {
+    pub fn max_offset(&self) -> Vec2 {
+        (self.content - self.viewport).max(Vec2::ZERO)
+    }
+}
+
+/// Sent whenever a scroll view's offset changes.
+#[derive(Event, Clone, Copy, Debug)]
+pub struct ScrollPositionChanged {
+    pub view: Entity,
+    pub offset: Vec2,
+}
+
+/// Scrolls the nearest enclosing scroll view so this entity is visible, then removes itself.
+#[derive(Component)]
+pub struct ScrollIntoView;
+
+/// The node holding the scrolled children.
+#[derive(Component)]
+pub struct ScrollContent {
+    pub view: Entity,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum ScrollAxis {
+    Horizontal,
+    Vertical,
+}
+
+impl ScrollAxis {
+    fn of(self, value: Vec2) -> f32 {
+        match self {
+            ScrollAxis::Horizontal => value.x,
+            ScrollAxis::Vertical => value.y,
+        }
+    }
+
+    fn with(self, mut value: Vec2, component: f32) -> Vec2 {
+        match self {
+            ScrollAxis::Horizontal => value.x = component,
+            ScrollAxis::Vertical => value.y = component,
+        }
+        value
+    }
+}
+
+#[derive(Component)]
+pub struct ScrollbarTrack {
+    pub view: Entity,
+    pub axis: ScrollAxis,
+}
+
+#[derive(Component)]
+pub struct ScrollbarThumb {
+    pub view: Entity,
+    pub axis: ScrollAxis,
+    /// Cursor and offset along the axis when the drag started.
+    drag: Option<(f32, f32)>,
+}
+
+#[derive(Component, Default)]
+struct ScrollMotion {
+    velocity: Vec2,
+    drag: Option<ContentDrag>,
+}
+
+struct ContentDrag {
+    cursor: Vec2,
+    offset: Vec2,
+    dragging: bool,
+}
+
+/// The scroll view systems; widgets built on top of scroll views run after it.
+#[derive(SystemSet, Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ScrollViewSystem;
+
+pub struct ScrollViewPlugin;
+
+impl Plugin for ScrollViewPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<Theme>()
+            .add_event::<ScrollPositionChanged>()
+            .add_systems(
+                Update,
+                (
+                    measure,
+                    wheel_scroll,
+                    keyboard_scroll,
+                    drag_content,
+                    drag_thumbs,
+                    click_tracks,
+                    kinetic_scroll,
+                    scroll_into_view,
+                    update_scrollbars,
+                    send_changes,
+                )
+                    .chain()
+                    .in_set(ScrollViewSystem),
+            );
+    }
+}
+
+/// Spawns a scroll view and returns its viewport. `root` is the outer node, which sizes the view;
+/// children spawned by `content` are laid out in a column at their natural size.
+pub fn spawn_scroll_view(
+    parent: &mut ChildBuilder,
+    view: ScrollView,
+    root: impl Bundle,
+    content: impl FnOnce(&mut ChildBuilder),
+) -> Entity {
+    let mut viewport = Entity::PLACEHOLDER;
+    parent.spawn(root).with_children(|root| {
+        let mut viewport_commands = root.spawn((
+            Node {
+                width: Val::Percent(100.),
+                height: Val::Percent(100.),
+                flex_direction: FlexDirection::Column,
+                align_items: AlignItems::FlexStart,
+                overflow: Overflow {
+                    x: if view.horizontal {
+                        OverflowAxis::Scroll
+                    } else {
+                        OverflowAxis::Clip
+                    },
+                    y: if view.vertical {
+                        OverflowAxis::Scroll
+                    } else {
+                        OverflowAxis::Clip
+                    },
+                },
+                ..default()
+            },
+            view,
+        ));
+        viewport = viewport_commands.id();
+        viewport_commands.with_children(|parent| {
+            // the content node doesn't shrink, so children keep their natural size
+            parent
+                .spawn((
+                    Node {
+                        flex_direction: FlexDirection::Column,
+                        flex_shrink: 0.,
+                        min_width: Val::Percent(100.),
+                        ..default()
+                    },
+                    ScrollContent { view: viewport },
+                ))
+                .with_children(content);
+        });
+
+        for (enabled, axis) in [
+            (view.vertical, ScrollAxis::Vertical),
+            (view.horizontal, ScrollAxis::Horizontal),
+        ] {
+            if enabled {
+                spawn_scrollbar(root, viewport, axis);
+            }
+        }
+    });
+    viewport
+}
+
+fn spawn_scrollbar(root: &mut ChildBuilder, view: Entity, axis: ScrollAxis) {
+    let track = match axis {
+        ScrollAxis::Vertical => Node {
+            position_type: PositionType::Absolute,
+            right: Val::Px(0.),
+            top: Val::Px(0.),
+            bottom: Val::Px(0.),
+            ..default()
+        },
+        ScrollAxis::Horizontal => Node {
+            position_type: PositionType::Absolute,
+            left: Val::Px(0.),
+            right: Val::Px(0.),
+            bottom: Val::Px(0.),
+            ..default()
+        },
+    };
+    let thumb = match axis {
+        ScrollAxis::Vertical => Node {
+            position_type: PositionType::Absolute,
+            width: Val::Percent(100.),
+            ..default()
+        },
+        ScrollAxis::Horizontal => Node {
+            position_type: PositionType::Absolute,
+            height: Val::Percent(100.),
+            ..default()
+        },
+    };
+    root.spawn((
+        track,
+        Interaction::None,
+        FocusPolicy::Block,
+        BackgroundColor::default(),
+        ScrollbarTrack { view, axis },
+    ))
+    .with_child((
+        thumb,
+        Interaction::None,
+        FocusPolicy::Block,
+        BackgroundColor::default(),
+        BorderRadius::MAX,
+        ScrollbarThumb {
+            view,
+            axis,
+            drag: None,
+        },
+    ));
+}
+
+fn offset_of(position: &ScrollPosition) -> Vec2 {
+    Vec2::new(position.offset_x, position.offset_y)
+}
+
+fn set_offset(position: &mut Mut<ScrollPosition>, offset: Vec2) {
+    if offset_of(position) != offset {
+        position.offset_x = offset.x;
+        position.offset_y = offset.y;
+    }
+}
+
+/// The topmost hovered scroll view, so nested views take the input before their parents.
+fn hovered_view<'a>(
+    views: impl Iterator<Item = (Entity, &'a RelativeCursorPosition, &'a ComputedNode)>,
+) -> Option<Entity> {
+    views
+        .filter(|(_, cursor, _)| cursor.mouse_over())
+        .max_by_key(|(_, _, node)| node.stack_index())
+        .map(|(entity, _, _)| entity)
+}
+
+fn measure(
+    contents: Query<(&ScrollContent, &ComputedNode)>,
+    mut views: Query<(&ComputedNode, &mut ScrollMetrics)>,
+) {
+    for (content, content_node) in &contents {
+        let Ok((node, mut metrics)) = views.get_mut(content.view) else {
+            continue;
+        };
+        let scale = node.inverse_scale_factor();
+        let padding = node.padding();
+        let padding = Vec2::new(padding.left + padding.right, padding.top + padding.bottom);
+        let measured = ScrollMetrics {
+            viewport: node.size() * scale,
+            content: (content_node.size() + padding) * scale,
+        };
+        if metrics.viewport != measured.viewport || metrics.content != measured.content {
+            *metrics = measured;
+        }
+    }
+}
+
+type ViewQuery<'a> = (
+    Entity,
+    &'a ScrollView,
+    &'a ScrollMetrics,
+    &'a RelativeCursorPosition,
+    &'a ComputedNode,
+    &'a mut ScrollPosition,
+    &'a mut ScrollMotion,
+);
+
+fn wheel_scroll(
+    mut wheel: EventReader<MouseWheel>,
+    keys: Res<ButtonInput<KeyCode>>,
+    mut views: Query<ViewQuery>,
+) {
+    let mut delta = Vec2::ZERO;
+    for event in wheel.read() {
+        let scale = match event.unit {
+            MouseScrollUnit::Line => LINE_HEIGHT,
+            MouseScrollUnit::Pixel => 1.,
+        };
+        delta += Vec2::new(event.x, event.y) * scale;
+    }
+    if delta == Vec2::ZERO {
+        return;
+    }
+    if keys.any_pressed([KeyCode::ShiftLeft, KeyCode::ShiftRight]) {
+        delta = Vec2::new(delta.y, delta.x);
+    }
+
+    let Some(target) = hovered_view(views.iter().map(|(e, _, _, c, n, _, _)| (e, c, n))) else {
+        return;
+    };
+    if let Ok((_, view, metrics, _, _, mut position, mut motion)) = views.get_mut(target) {
+        motion.velocity = Vec2::ZERO;
+        let offset = view.clamp(offset_of(&position) - delta, metrics);
+        set_offset(&mut position, offset);
+    }
+}
+
+fn keyboard_scroll(
+    keys: Res<ButtonInput<KeyCode>>,
+    text_inputs: Query<&TextInputInactive>,
+    mut views: Query<ViewQuery>,
+) {
+    // arrow keys belong to the focused text input
+    if text_inputs.iter().any(|inactive| !inactive.0) {
+        return;
+    }
+    let Some(target) = hovered_view(views.iter().map(|(e, _, _, c, n, _, _)| (e, c, n))) else {
+        return;
+    };
+    let Ok((_, view, metrics, _, _, mut position, mut motion)) = views.get_mut(target) else {
+        return;
+    };
+    if !view.keyboard {
+        return;
+    }
+
+    let page = metrics.viewport * 0.9;
+    let mut offset = offset_of(&position);
+    for key in keys.get_just_pressed() {
+        match key {
+            KeyCode::ArrowUp => offset.y -= LINE_HEIGHT * 2.,
+            KeyCode::ArrowDown => offset.y += LINE_HEIGHT * 2.,
+            KeyCode::ArrowLeft => offset.x -= LINE_HEIGHT * 2.,
+            KeyCode::ArrowRight => offset.x += LINE_HEIGHT * 2.,
+            KeyCode::PageUp => offset.y -= page.y,
+            KeyCode::PageDown => offset.y += page.y,
+            KeyCode::Home => offset = Vec2::ZERO,
+            KeyCode::End => offset = metrics.max_offset(),
+            _ => continue,
+        }
+        motion.velocity = Vec2::ZERO;
+    }
+    set_offset(&mut position, view.clamp(offset, metrics));
+}
+
+#[allow(clippy::type_complexity)]
+fn drag_content(
+    mouse: Res<ButtonInput<MouseButton>>,
+    window_query: Query<&Window, With<PrimaryWindow>>,
+    time: Res<Time>,
+    scrollbars: Query<&Interaction, Or<(With<ScrollbarThumb>, With<ScrollbarTrack>)>>,
+    mut views: Query<ViewQuery>,
+) {
+    let Some(cursor) = window_query
+        .get_single()
+        .ok()
+        .and_then(|window| window.cursor_position())
+    else {
+        return;
+    };
+
+    if mouse.just_pressed(MouseButton::Left)
+        && scrollbars
+            .iter()
+            .all(|interaction| *interaction == Interaction::None)
+    {
+        let target = hovered_view(views.iter().map(|(e, _, _, c, n, _, _)| (e, c, n)));
+        if let Some(Ok((_, _, _, _, _, position, mut motion))) = target.map(|t| views.get_mut(t)) {
+            motion.velocity = Vec2::ZERO;
+            motion.drag = Some(ContentDrag {
+                cursor,
+                offset: offset_of(&position),
+                dragging: false,
+            });
+        }
+    }
+
+    for (_, view, metrics, _, _, mut position, mut motion) in &mut views {
+        let Some(drag) = &mut motion.drag else {
+            continue;
+        };
+        if !mouse.pressed(MouseButton::Left) {
+            motion.drag = None;
+            if !view.kinetic {
+                motion.velocity = Vec2::ZERO;
+            }
+            continue;
+        }
+
+        let delta = cursor - drag.cursor;
+        if !drag.dragging && delta.length() > DRAG_THRESHOLD {
+            drag.dragging = true;
+        }
+        if !drag.dragging {
+            continue;
+        }
+        let previous = offset_of(&position);
+        let offset = view.clamp(drag.offset - delta, metrics);
+        if time.delta_secs() > 0. {
+            motion.velocity = (offset - previous) / time.delta_secs();
+        }
+        set_offset(&mut position, offset);
+    }
+}
+
+fn drag_thumbs(
+    mouse: Res<ButtonInput<MouseButton>>,
+    window_query: Query<&Window, With<PrimaryWindow>>,
+    mut thumbs: Query<(&Interaction, &mut ScrollbarThumb)>,
+    mut views: Query<(&ScrollView, &ScrollMetrics, &mut ScrollPosition)>,
+) {
+    let cursor = window_query
+        .get_single()
+        .ok()
+        .and_then(|window| window.cursor_position());
+
+    for (interaction, mut thumb) in &mut thumbs {
+        let Ok((view, metrics, mut position)) = views.get_mut(thumb.view) else {
+            continue;
+        };
+        let Some(cursor) = cursor else {
+            continue;
+        };
+        let axis = thumb.axis;
+        if mouse.just_pressed(MouseButton::Left) && *interaction == Interaction::Pressed {
+            thumb.drag = Some((axis.of(cursor), axis.of(offset_of(&position))));
+        }
+        if !mouse.pressed(MouseButton::Left) {
+            thumb.drag = None;
+        }
+        let Some((start_cursor, start_offset)) = thumb.drag else {
+            continue;
+        };
+
+        // the thumb covers viewport/content of the track, so cursor travel scales up
+        let ratio = axis.of(metrics.content) / axis.of(metrics.viewport).max(1.);
+        let along = start_offset + (axis.of(cursor) - start_cursor) * ratio;
+        let offset = view.clamp(axis.with(offset_of(&position), along), metrics);
+        set_offset(&mut position, offset);
+    }
+}
+
+fn click_tracks(
+    window_query: Query<&Window, With<PrimaryWindow>>,
+    tracks: Query<
+        (
+            &Interaction,
+            &ScrollbarTrack,
+            &ComputedNode,
+            &GlobalTransform,
+        ),
+        Changed<Interaction>,
+    >,
+    mut views: Query<(&ScrollView, &ScrollMetrics, &mut ScrollPosition)>,
+) {
+    let Some(cursor) = window_query
+        .get_single()
+        .ok()
+        .and_then(|window| window.cursor_position())
+    else {
+        return;
+    };
+
+    for (interaction, track, node, transform) in &tracks {
+        if *interaction != Interaction::Pressed {
+            continue;
+        }
+        let Ok((view, metrics, mut position)) = views.get_mut(track.view) else {
+            continue;
+        };
+        let axis = track.axis;
+        let scale = node.inverse_scale_factor();
+        let size = axis.of(node.size() * scale);
+        let start = axis.of(transform.translation().truncate() * scale) - size / 2.;
+        let clicked = (axis.of(cursor) - start) / size.max(1.);
+
+        // page towards the click, like most scrollbars do
+        let content = axis.of(metrics.content).max(1.);
+        let offset = axis.of(offset_of(&position));
+        let page = axis.of(metrics.viewport) * 0.9;
+        let along = if clicked < offset / content {
+            offset - page
+        } else {
+            offset + page
+        };
+        let offset = view.clamp(axis.with(offset_of(&position), along), metrics);
+        set_offset(&mut position, offset);
+    }
+}
+
+fn kinetic_scroll(
+    time: Res<Time>,
+    mut views: Query<(
+        &ScrollView,
+        &ScrollMetrics,
+        &mut ScrollPosition,
+        &mut ScrollMotion,
+    )>,
+) {
+    let delta = time.delta_secs();
+    for (view, metrics, mut position, mut motion) in &mut views {
+        if !view.kinetic || motion.drag.is_some() || motion.velocity == Vec2::ZERO {
+            continue;
+        }
+        if motion.velocity.length() < MIN_VELOCITY {
+            motion.velocity = Vec2::ZERO;
+            continue;
+        }
+        let wanted = offset_of(&position) + motion.velocity * delta;
+        let offset = view.clamp(wanted, metrics);
+        // stop at the edges instead of pushing against them
+        if offset.x != wanted.x {
+            motion.velocity.x = 0.;
+        }
+        if offset.y != wanted.y {
+            motion.velocity.y = 0.;
+        }
+        motion.velocity *= (-FRICTION * delta).exp();
+        set_offset(&mut position, offset);
+    }
+}
+
+fn scroll_into_view(
+    mut commands: Commands,
+    requested: Query<Entity, With<ScrollIntoView>>,
+    focused: Query<(Entity, &TextInputInactive), Changed<TextInputInactive>>,
+    parents: Query<&Parent>,
+    nodes: Query<(&ComputedNode, &GlobalTransform)>,
+    mut views: Query<(&ScrollView, &ScrollMetrics, &mut ScrollPosition)>,
+) {
+    let focused = focused
+        .iter()
+        .filter(|(_, inactive)| !inactive.0)
+        .map(|(entity, _)| entity);
+    for target in requested.iter().chain(focused) {
+        let Some(viewport) = parents
+            .iter_ancestors(target)
+            .find(|ancestor| views.contains(*ancestor))
+        else {
+            commands.entity(target).remove::<ScrollIntoView>();
+            continue;
+        };
+        let (Ok((target_node, target_transform)), Ok((view_node, view_transform))) =
+            (nodes.get(target), nodes.get(viewport))
+        else {
+            continue;
+        };
+        // not laid out yet, try again next frame
+        if target_node.size() == Vec2::ZERO {
+            continue;
+        }
+        commands.entity(target).remove::<ScrollIntoView>();
+
+        let Ok((view, metrics, mut position)) = views.get_mut(viewport) else {
+            continue;
+        };
+        let scale = view_node.inverse_scale_factor();
+        let view_min = (view_transform.translation().truncate() - view_node.size() / 2.) * scale;
+        let min = (target_transform.translation().truncate() - target_node.size() / 2.) * scale
+            - view_min;
+        let max = min + target_node.size() * scale;
+
+        let mut offset = offset_of(&position);
+        for axis in [ScrollAxis::Horizontal, ScrollAxis::Vertical] {
+            let visible = axis.of(metrics.viewport);
+            let along = axis.of(offset);
+            let along = if axis.of(min) < 0. {
+                along + axis.of(min)
+            } else if axis.of(max) > visible {
+                along + axis.of(max) - visible
+            } else {
+                along
+            };
+            offset = axis.with(offset, along);
+        }
+        set_offset(&mut position, view.clamp(offset, metrics));
+    }
+}
+
+#[allow(clippy::type_complexity)]
+fn update_scrollbars(
+    theme: Res<Theme>,
+    views: Query<(&ScrollMetrics, &ScrollPosition)>,
+    mut tracks: Query<(&ScrollbarTrack, &mut Node, &mut BackgroundColor), Without<ScrollbarThumb>>,
+    mut thumbs: Query<
+        (
+            &ScrollbarThumb,
+            &Interaction,
+            &mut Node,
+            &mut BackgroundColor,
+        ),
+        Without<ScrollbarTrack>,
+    >,
+) {
+    for (track, mut node, mut color) in &mut tracks {
+        let Ok((metrics, _)) = views.get(track.view) else {
+            continue;
+        };
+        let overflowing = track.axis.of(metrics.content) > track.axis.of(metrics.viewport) + 0.5;
+        let display = if overflowing {
+            Display::Flex
+        } else {
+            Display::None
+        };
+        let thickness = Val::Px(theme.scrollbar_size);
+        let current = match track.axis {
+            ScrollAxis::Vertical => node.width,
+            ScrollAxis::Horizontal => node.height,
+        };
+        if node.display != display || current != thickness {
+            node.display = display;
+            match track.axis {
+                ScrollAxis::Vertical => node.width = thickness,
+                ScrollAxis::Horizontal => node.height = thickness,
+            }
+        }
+        color.set_if_neq(BackgroundColor(theme.scrollbar_track));
+    }
+
+    for (thumb, interaction, mut node, mut color) in &mut thumbs {
+        let Ok((metrics, position)) = views.get(thumb.view) else {
+            continue;
+        };
+        let axis = thumb.axis;
+        let content = axis.of(metrics.content).max(1.);
+        let length = (axis.of(metrics.viewport) / content * 100.).clamp(MIN_THUMB_PERCENT, 100.);
+        let max = axis.of(metrics.max_offset());
+        let start = if max > 0. {
+            axis.of(offset_of(position)) / max * (100. - length)
+        } else {
+            0.
+        };
+        let (length, start) = (Val::Percent(length), Val::Percent(start));
+        match axis {
+            ScrollAxis::Vertical if node.height != length || node.top != start => {
+                node.height = length;
+                node.top = start;
+            }
+            ScrollAxis::Horizontal if node.width != length || node.left != start => {
+                node.width = length;
+                node.left = start;
+            }
+            _ => {}
+        }
+
+        let active = thumb.drag.is_some() || *interaction != Interaction::None;
+        color.set_if_neq(BackgroundColor(if active {
+            theme.scrollbar_thumb_hovered
+        } else {
+            theme.scrollbar_thumb
+        }));
+    }
+}
+
+fn send_changes(
+    views: Query<(Entity, Ref<ScrollPosition>), With<ScrollView>>,
+    mut changes: EventWriter<ScrollPositionChanged>,
+) {
+    for (view, position) in &views {
+        if position.is_changed() && !position.is_added() {
+            changes.send(ScrollPositionChanged {
+                view,
+                offset: offset_of(&position),
+            });
+        }
+    }
+}
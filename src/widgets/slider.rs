@@ -0,0 +1,742 @@
+//! Sliders for picking a value, or a range with [`RangeSlider`], between a minimum and a maximum.
+//! Dragging a thumb moves it, pressing the track jumps the nearest thumb there, and a focused thumb
+//! follows the arrow keys (Page Up/Down for bigger steps, Home/End for the ends).
+//!
+//! [`SliderChanged`] is sent on every change while dragging; [`SliderCommitted`] once the drag is
+//! released, or right away for key presses, for work that shouldn't run on every pixel.
+
+use crate::theme::Theme;
+use crate::widgets::focus::{Focus, FocusPlugin, Focusable};
+use bevy::prelude::*;
+use bevy::ui::{FocusPolicy, RelativeCursorPosition};
+
+const THUMB_SIZE: f32 = 18.;
+const TRACK_THICKNESS: f32 = 4.;
+const TICK_LENGTH: f32 = 10.;
+/// Key steps taken by Page Up and Page Down.
+const PAGE_STEPS: f32 = 10.;
+/// Fraction of the range an arrow key moves a continuous slider.
+const CONTINUOUS_KEY_STEP: f32 = 0.01;
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub enum SliderOrientation {
+    #[default]
+    Horizontal,
+    /// Minimum at the bottom.
+    Vertical,
+}
+
+/// What a slider can be set to and how it's drawn, shared by [`Slider`] and [`RangeSlider`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct SliderSettings {
+    pub min: f32,
+    pub max: f32,
+    /// Values snap to `min + n * step`; 0 allows any value.
+    pub step: f32,
+    pub orientation: SliderOrientation,
+    /// Distance between tick marks, if there are any.
+    pub ticks: Option<f32>,
+    /// Whether the current value is shown next to the slider.
+    pub show_value: bool,
+}
+
+impl Default for SliderSettings {
+    fn default() -> Self {
+        Self::new(0., 1.)
+    }
+}
+
+impl SliderSettings {
+    pub fn new(min: f32, max: f32) -> Self {
+        Self {
+            min,
+            max,
+            step: 0.,
+            orientation: SliderOrientation::Horizontal,
+            ticks: None,
+            show_value: false,
+        }
+    }
+
+    pub fn with_step(mut self, step: f32) -> Self {
+        self.step = step;
+        self
+    }
+
+    pub fn vertical(mut self) -> Self {
+        self.orientation = SliderOrientation::Vertical;
+        self
+    }
+
+    pub fn with_ticks(mut self, every: f32) -> Self {
+        self.ticks = Some(every);
+        self
+    }
+
+    pub fn with_value_label(mut self) -> Self {
+        self.show_value = true;
+        self
+    }
+
+    /// The lowest and highest value; `min` and `max` given the wrong way round are swapped.
+    fn bounds(&self) -> (f32, f32) {
+        if self.max < self.min {
+            (self.max, self.min)
+        } else {
+            (self.min, self.max)
+        }
+    }
+
+    /// `value` clamped to the range and snapped to the step.
+    pub fn snap(&self, value: f32) -> f32 {
+        let (low, high) = self.bounds();
+        // not `clamp`, which panics on a NaN bound
+        let value = value.max(low).min(high);
+        if self.step <= 0. {
+            return value;
+        }
+        let snapped = low + ((value - low) / self.step).round() * self.step;
+        snapped.min(high)
+    }
+
+    /// Where `value` sits along the track, from 0 to 1.
+    pub fn fraction(&self, value: f32) -> f32 {
+        let (low, high) = self.bounds();
+        if high <= low {
+            return 0.;
+        }
+        ((value - low) / (high - low)).clamp(0., 1.)
+    }
+
+    /// The snapped value at `fraction` of the way along the track.
+    pub fn value_at(&self, fraction: f32) -> f32 {
+        let (low, high) = self.bounds();
+        self.snap(low + fraction.clamp(0., 1.) * (high - low))
+    }
+
+    /// `value` with as many decimals as the step needs.
+    pub fn format(&self, value: f32) -> String {
+        let decimals = if self.step <= 0. {
+            2
+        } else {
+            (0..3)
+                .find(|decimals| {
+                    let scaled = self.step * 10f32.powi(*decimals);
+                    (scaled - scaled.round()).abs() < 1e-3
+                })
+                .unwrap_or(3) as usize
+        };
+        format!("{value:.decimals$}")
+    }
+
+    fn key_step(&self) -> f32 {
+        if self.step > 0. {
+            self.step
+        } else {
+            let (low, high) = self.bounds();
+            (high - low) * CONTINUOUS_KEY_STEP
+        }
+    }
+
+    fn tick_values(&self) -> Vec<f32> {
+        let Some(every) = self.ticks.filter(|every| *every > 0.) else {
+            return Vec::new();
+        };
+        let (low, high) = self.bounds();
+        let count = ((high - low) / every).floor().max(0.) as usize;
+        (0..=count)
+            .map(|index| low + index as f32 * every)
+            .collect()
+    }
+}
+
+#[derive(Component, Clone, Debug, Default)]
+pub struct Slider {
+    pub settings: SliderSettings,
+    pub value: f32,
+}
+
+impl Slider {
+    pub fn new(settings: SliderSettings, value: f32) -> Self {
+        let value = settings.snap(value);
+        Self { settings, value }
+    }
+}
+
+/// A slider with two thumbs picking `low..=high`; the thumbs can meet but not cross.
+#[derive(Component, Clone, Debug, Default)]
+pub struct RangeSlider {
+    pub settings: SliderSettings,
+    pub low: f32,
+    pub high: f32,
+}
+
+impl RangeSlider {
+    pub fn new(settings: SliderSettings, low: f32, high: f32) -> Self {
+        let low = settings.snap(low);
+        let high = settings.snap(high).max(low);
+        Self {
+            settings,
+            low,
+            high,
+        }
+    }
+}
+
+/// Sent whenever the user moves a thumb. `thumb` is 0 for a [`Slider`], and 0 (low) or 1 (high)
+/// for a [`RangeSlider`].
+#[derive(Event, Clone, Copy, Debug)]
+pub struct SliderChanged {
+    pub slider: Entity,
+    pub thumb: usize,
+    pub value: f32,
+}
+
+/// Sent when the user lets go of a thumb they moved, or moves it with a key.
+#[derive(Event, Clone, Copy, Debug)]
+pub struct SliderCommitted {
+    pub slider: Entity,
+    pub thumb: usize,
+    pub value: f32,
+}
+
+/// A draggable, focusable thumb.
+#[derive(Component, Clone, Copy, Debug)]
+#[require(Interaction, Focusable)]
+pub struct SliderThumb {
+    pub slider: Entity,
+    pub index: usize,
+}
+
+/// The part of the slider the thumbs move along; pressing it jumps the nearest thumb.
+#[derive(Component)]
+struct SliderArea {
+    slider: Entity,
+}
+
+#[derive(Component)]
+struct SliderParts {
+    area: Entity,
+    fill: Entity,
+    thumbs: Vec<Entity>,
+    label: Option<Entity>,
+}
+
+#[derive(Component, Default)]
+struct SliderDrag {
+    thumb: Option<usize>,
+    /// Offset between the cursor and the thumb center, as a fraction of the track.
+    grab: f32,
+    start: f32,
+}
+
+/// What the slider systems need from [`Slider`] and [`RangeSlider`].
+trait SliderValues: Component {
+    fn settings(&self) -> &SliderSettings;
+    fn values(&self) -> Vec<f32>;
+    fn set(&mut self, thumb: usize, value: f32);
+
+    /// `value` snapped and kept between the neighbouring thumbs.
+    fn allowed(&self, thumb: usize, value: f32) -> f32 {
+        let values = self.values();
+        let low = thumb
+            .checked_sub(1)
+            .and_then(|index| values.get(index))
+            .copied()
+            .unwrap_or(f32::MIN);
+        let high = values.get(thumb + 1).copied().unwrap_or(f32::MAX);
+        self.settings().snap(value).clamp(low, high)
+    }
+
+    /// The thumb that pressing the track at `fraction` should move.
+    fn nearest(&self, fraction: f32) -> usize {
+        let settings = self.settings();
+        let values = self.values();
+        let distance = |index: usize| (settings.fraction(values[index]) - fraction).abs();
+        match values.as_slice() {
+            // thumbs on top of each other: pick the one on the side of the press
+            [low, high] if low == high => usize::from(fraction > settings.fraction(*low)),
+            _ => (0..values.len())
+                .min_by(|a, b| distance(*a).total_cmp(&distance(*b)))
+                .unwrap_or(0),
+        }
+    }
+
+    fn label(&self) -> String {
+        let settings = self.settings();
+        self.values()
+            .into_iter()
+            .map(|value| settings.format(value))
+            .collect::<Vec<_>>()
+            .join(" – ")
+    }
+}
+
+impl SliderValues for Slider {
+    fn settings(&self) -> &SliderSettings {
+        &self.settings
+    }
+
+    fn values(&self) -> Vec<f32> {
+        vec![self.value]
+    }
+
+    fn set(&mut self, _thumb: usize, value: f32) {
+        self.value = value;
+    }
+}
+
+impl SliderValues for RangeSlider {
+    fn settings(&self) -> &SliderSettings {
+        &self.settings
+    }
+
+    fn values(&self) -> Vec<f32> {
+        vec![self.low, self.high]
+    }
+
+    fn set(&mut self, thumb: usize, value: f32) {
+        if thumb == 0 {
+            self.low = value;
+        } else {
+            self.high = value;
+        }
+    }
+}
+
+pub struct SliderPlugin;
+
+impl Plugin for SliderPlugin {
+    fn build(&self, app: &mut App) {
+        if !app.is_plugin_added::<FocusPlugin>() {
+            app.add_plugins(FocusPlugin);
+        }
+        app.add_event::<SliderChanged>()
+            .add_event::<SliderCommitted>()
+            .add_systems(
+                Update,
+                (
+                    (
+                        press_sliders::<Slider>,
+                        drag_thumbs::<Slider>,
+                        arrow_keys::<Slider>,
+                        draw_sliders::<Slider>,
+                    )
+                        .chain(),
+                    (
+                        press_sliders::<RangeSlider>,
+                        drag_thumbs::<RangeSlider>,
+                        arrow_keys::<RangeSlider>,
+                        draw_sliders::<RangeSlider>,
+                    )
+                        .chain(),
+                    color_thumbs,
+                ),
+            );
+    }
+}
+
+/// Spawns a slider laid out by `root` and returns the slider entity. A horizontal slider fills the
+/// width of `root` and a vertical one its height, so `root` should set that size.
+pub fn spawn_slider(
+    parent: &mut ChildBuilder,
+    slider: Slider,
+    root: impl Bundle,
+    theme: &Theme,
+) -> Entity {
+    spawn_parts(parent, slider.settings.clone(), 1, (root, slider), theme)
+}
+
+/// Spawns a range slider laid out by `root` and returns the slider entity.
+pub fn spawn_range_slider(
+    parent: &mut ChildBuilder,
+    slider: RangeSlider,
+    root: impl Bundle,
+    theme: &Theme,
+) -> Entity {
+    spawn_parts(parent, slider.settings.clone(), 2, (root, slider), theme)
+}
+
+fn spawn_parts(
+    parent: &mut ChildBuilder,
+    settings: SliderSettings,
+    thumb_count: usize,
+    root: impl Bundle,
+    theme: &Theme,
+) -> Entity {
+    let vertical = settings.orientation == SliderOrientation::Vertical;
+    let mut slider_commands = parent.spawn((root, SliderDrag::default()));
+    let slider = slider_commands.id();
+    let mut area = Entity::PLACEHOLDER;
+    let mut fill = Entity::PLACEHOLDER;
+    let mut thumbs = Vec::new();
+    let mut label = None;
+    slider_commands.with_children(|parent| {
+        parent
+            .spawn(Node {
+                width: Val::Percent(100.),
+                height: Val::Percent(100.),
+                flex_direction: if vertical {
+                    FlexDirection::Column
+                } else {
+                    FlexDirection::Row
+                },
+                align_items: AlignItems::Center,
+                column_gap: Val::Px(12.),
+                row_gap: Val::Px(8.),
+                ..default()
+            })
+            .with_children(|parent| {
+                // the thumbs hang over the ends of the track by half their size
+                let (size, margin) = if vertical {
+                    (
+                        (Val::Px(THUMB_SIZE), Val::Auto),
+                        UiRect::vertical(Val::Px(THUMB_SIZE / 2.)),
+                    )
+                } else {
+                    (
+                        (Val::Auto, Val::Px(THUMB_SIZE)),
+                        UiRect::horizontal(Val::Px(THUMB_SIZE / 2.)),
+                    )
+                };
+                area = parent
+                    .spawn((
+                        Node {
+                            width: size.0,
+                            height: size.1,
+                            flex_grow: 1.,
+                            margin,
+                            ..default()
+                        },
+                        Interaction::None,
+                        FocusPolicy::Block,
+                        RelativeCursorPosition::default(),
+                        SliderArea { slider },
+                    ))
+                    .with_children(|parent| {
+                        let across = (THUMB_SIZE - TRACK_THICKNESS) / 2.;
+                        parent.spawn((
+                            along_track(vertical, 0., 1., across, TRACK_THICKNESS),
+                            BackgroundColor(theme.surface_hovered),
+                            BorderRadius::MAX,
+                        ));
+                        for tick in settings.tick_values() {
+                            let mut node = along_track(
+                                vertical,
+                                settings.fraction(tick),
+                                0.,
+                                (THUMB_SIZE - TICK_LENGTH) / 2.,
+                                TICK_LENGTH,
+                            );
+                            // a 2px mark centered on the value
+                            if vertical {
+                                node.height = Val::Px(2.);
+                                node.margin.bottom = Val::Px(-1.);
+                            } else {
+                                node.width = Val::Px(2.);
+                                node.margin.left = Val::Px(-1.);
+                            }
+                            parent.spawn((node, BackgroundColor(theme.border)));
+                        }
+                        fill = parent
+                            .spawn((
+                                along_track(vertical, 0., 0., across, TRACK_THICKNESS),
+                                BackgroundColor(theme.accent),
+                                BorderRadius::MAX,
+                            ))
+                            .id();
+                        for index in 0..thumb_count {
+                            let thumb = parent
+                                .spawn((
+                                    Node {
+                                        position_type: PositionType::Absolute,
+                                        width: Val::Px(THUMB_SIZE),
+                                        height: Val::Px(THUMB_SIZE),
+                                        margin: if vertical {
+                                            UiRect::bottom(Val::Px(-THUMB_SIZE / 2.))
+                                        } else {
+                                            UiRect::left(Val::Px(-THUMB_SIZE / 2.))
+                                        },
+                                        border: UiRect::all(Val::Px(2.)),
+                                        ..default()
+                                    },
+                                    FocusPolicy::Block,
+                                    BackgroundColor(theme.text),
+                                    BorderColor(theme.accent),
+                                    BorderRadius::MAX,
+                                    SliderThumb { slider, index },
+                                ))
+                                .id();
+                            thumbs.push(thumb);
+                        }
+                    })
+                    .id();
+                if settings.show_value {
+                    label = Some(
+                        parent
+                            .spawn((
+                                Text::default(),
+                                theme.text_font(),
+                                TextColor(theme.text),
+                                TextLayout::new_with_justify(JustifyText::Right),
+                                Node {
+                                    min_width: Val::Px(48.),
+                                    ..default()
+                                },
+                            ))
+                            .id(),
+                    );
+                }
+            });
+    });
+    slider_commands.insert(SliderParts {
+        area,
+        fill,
+        thumbs,
+        label,
+    });
+    slider
+}
+
+/// An absolutely positioned node covering `start..start + length` of the track (as fractions),
+/// `offset` pixels from the edge of the area and `thickness` pixels across.
+fn along_track(vertical: bool, start: f32, length: f32, offset: f32, thickness: f32) -> Node {
+    if vertical {
+        Node {
+            position_type: PositionType::Absolute,
+            bottom: Val::Percent(start * 100.),
+            height: Val::Percent(length * 100.),
+            left: Val::Px(offset),
+            width: Val::Px(thickness),
+            ..default()
+        }
+    } else {
+        Node {
+            position_type: PositionType::Absolute,
+            left: Val::Percent(start * 100.),
+            width: Val::Percent(length * 100.),
+            top: Val::Px(offset),
+            height: Val::Px(thickness),
+            ..default()
+        }
+    }
+}
+
+/// How far along the track the cursor is, from 0 to 1.
+fn cursor_fraction(settings: &SliderSettings, cursor: &RelativeCursorPosition) -> Option<f32> {
+    let position = cursor.normalized?;
+    let fraction = match settings.orientation {
+        SliderOrientation::Horizontal => position.x,
+        SliderOrientation::Vertical => 1. - position.y,
+    };
+    Some(fraction.clamp(0., 1.))
+}
+
+/// Moves `thumb` as close to `value` as it may go and reports whether it moved.
+fn move_thumb<T: SliderValues>(
+    slider: &mut Mut<T>,
+    entity: Entity,
+    thumb: usize,
+    value: f32,
+    changes: &mut EventWriter<SliderChanged>,
+) -> bool {
+    let value = slider.allowed(thumb, value);
+    if slider.values()[thumb] == value {
+        return false;
+    }
+    slider.set(thumb, value);
+    changes.send(SliderChanged {
+        slider: entity,
+        thumb,
+        value,
+    });
+    true
+}
+
+/// Starts dragging the pressed thumb, or jumps the nearest one to where the track was pressed.
+#[allow(clippy::type_complexity)]
+fn press_sliders<T: SliderValues>(
+    mut focus: ResMut<Focus>,
+    thumbs: Query<(Entity, &Interaction, &SliderThumb), Changed<Interaction>>,
+    areas: Query<(&Interaction, &SliderArea, &RelativeCursorPosition), Changed<Interaction>>,
+    cursors: Query<&RelativeCursorPosition>,
+    mut sliders: Query<(&mut T, &mut SliderDrag, &SliderParts)>,
+    mut changes: EventWriter<SliderChanged>,
+) {
+    for (entity, interaction, thumb) in &thumbs {
+        if *interaction != Interaction::Pressed {
+            continue;
+        }
+        let Ok((slider, mut drag, parts)) = sliders.get_mut(thumb.slider) else {
+            continue;
+        };
+        let value = slider.values()[thumb.index];
+        let grab = cursors
+            .get(parts.area)
+            .ok()
+            .and_then(|cursor| cursor_fraction(slider.settings(), cursor))
+            .map_or(0., |fraction| fraction - slider.settings().fraction(value));
+        *drag = SliderDrag {
+            thumb: Some(thumb.index),
+            grab,
+            start: value,
+        };
+        focus.0 = Some(entity);
+    }
+    for (interaction, area, cursor) in &areas {
+        if *interaction != Interaction::Pressed {
+            continue;
+        }
+        let Ok((mut slider, mut drag, parts)) = sliders.get_mut(area.slider) else {
+            continue;
+        };
+        let Some(fraction) = cursor_fraction(slider.settings(), cursor) else {
+            continue;
+        };
+        let index = slider.nearest(fraction);
+        let start = slider.values()[index];
+        let value = slider.settings().value_at(fraction);
+        move_thumb(&mut slider, area.slider, index, value, &mut changes);
+        *drag = SliderDrag {
+            thumb: Some(index),
+            grab: 0.,
+            start,
+        };
+        focus.0 = Some(parts.thumbs[index]);
+    }
+}
+
+/// Moves dragged thumbs with the cursor and commits the value when the mouse is released.
+fn drag_thumbs<T: SliderValues>(
+    mouse: Res<ButtonInput<MouseButton>>,
+    cursors: Query<&RelativeCursorPosition>,
+    mut sliders: Query<(Entity, &mut T, &mut SliderDrag, &SliderParts)>,
+    mut changes: EventWriter<SliderChanged>,
+    mut commits: EventWriter<SliderCommitted>,
+) {
+    for (entity, mut slider, mut drag, parts) in &mut sliders {
+        let Some(thumb) = drag.thumb else {
+            continue;
+        };
+        if !mouse.pressed(MouseButton::Left) {
+            drag.thumb = None;
+            let value = slider.values()[thumb];
+            if value != drag.start {
+                commits.send(SliderCommitted {
+                    slider: entity,
+                    thumb,
+                    value,
+                });
+            }
+            continue;
+        }
+        let Some(fraction) = cursors
+            .get(parts.area)
+            .ok()
+            .and_then(|cursor| cursor_fraction(slider.settings(), cursor))
+        else {
+            continue;
+        };
+        let value = slider.settings().value_at(fraction - drag.grab);
+        move_thumb(&mut slider, entity, thumb, value, &mut changes);
+    }
+}
+
+fn arrow_keys<T: SliderValues>(
+    keys: Res<ButtonInput<KeyCode>>,
+    focus: Res<Focus>,
+    thumbs: Query<&SliderThumb>,
+    mut sliders: Query<&mut T>,
+    mut changes: EventWriter<SliderChanged>,
+    mut commits: EventWriter<SliderCommitted>,
+) {
+    let Some(thumb) = focus.0.and_then(|entity| thumbs.get(entity).ok()) else {
+        return;
+    };
+    let Ok(mut slider) = sliders.get_mut(thumb.slider) else {
+        return;
+    };
+    for key in keys.get_just_pressed() {
+        let settings = slider.settings();
+        let value = slider.values()[thumb.index];
+        let step = settings.key_step();
+        let target = match key {
+            KeyCode::ArrowLeft | KeyCode::ArrowDown => value - step,
+            KeyCode::ArrowRight | KeyCode::ArrowUp => value + step,
+            KeyCode::PageDown => value - step * PAGE_STEPS,
+            KeyCode::PageUp => value + step * PAGE_STEPS,
+            // the track's ends, low to high like the arrows, also for a range given the wrong way round
+            KeyCode::Home => settings.value_at(0.),
+            KeyCode::End => settings.value_at(1.),
+            _ => continue,
+        };
+        if move_thumb(&mut slider, thumb.slider, thumb.index, target, &mut changes) {
+            commits.send(SliderCommitted {
+                slider: thumb.slider,
+                thumb: thumb.index,
+                value: slider.values()[thumb.index],
+            });
+        }
+    }
+}
+
+/// Places the thumbs and the fill, and updates the value label.
+fn draw_sliders<T: SliderValues>(
+    sliders: Query<(&T, &SliderParts), Changed<T>>,
+    mut nodes: Query<&mut Node>,
+    mut texts: Query<&mut Text>,
+) {
+    for (slider, parts) in &sliders {
+        let settings = slider.settings();
+        let vertical = settings.orientation == SliderOrientation::Vertical;
+        let fractions: Vec<f32> = slider
+            .values()
+            .into_iter()
+            .map(|value| settings.fraction(value))
+            .collect();
+        for (thumb, fraction) in parts.thumbs.iter().zip(&fractions) {
+            if let Ok(mut node) = nodes.get_mut(*thumb) {
+                if vertical {
+                    node.bottom = Val::Percent(fraction * 100.);
+                } else {
+                    node.left = Val::Percent(fraction * 100.);
+                }
+            }
+        }
+        // a single thumb fills from the minimum, two fill between them
+        let (start, end) = match fractions.as_slice() {
+            [value] => (0., *value),
+            [low, high] => (*low, *high),
+            _ => (0., 0.),
+        };
+        if let Ok(mut node) = nodes.get_mut(parts.fill) {
+            if vertical {
+                node.bottom = Val::Percent(start * 100.);
+                node.height = Val::Percent((end - start) * 100.);
+            } else {
+                node.left = Val::Percent(start * 100.);
+                node.width = Val::Percent((end - start) * 100.);
+            }
+        }
+        if let Some(mut text) = parts.label.and_then(|label| texts.get_mut(label).ok()) {
+            text.0 = slider.label();
+        }
+    }
+}
+
+#[allow(clippy::type_complexity)]
+fn color_thumbs(
+    theme: Res<Theme>,
+    mut thumbs: Query<
+        (&Interaction, &mut BackgroundColor),
+        (With<SliderThumb>, Changed<Interaction>),
+    >,
+) {
+    for (interaction, mut background) in &mut thumbs {
+        background.0 = match interaction {
+            Interaction::None => theme.text,
+            Interaction::Hovered => theme.text.mix(&theme.accent, 0.3),
+            Interaction::Pressed => theme.text.mix(&theme.accent, 0.6),
+        };
+    }
+}
@@ -0,0 +1,497 @@
+//! Split panes: two panes side by side or stacked, with a divider between them that can be
+//! dragged to resize them. The first pane is sized as a percentage of the split, like the nodes of
+//! `hierarchy_layout`, and the second one takes the rest, so the split follows the window.
+//! Double-clicking the divider collapses the collapsible pane and double-clicking it again brings
+//! it back. Splits are nested by spawning one inside a pane.
+//!
+//! ```ignore
+//! let split = SplitPane::horizontal(0.25)
+//!     .with_first_size(PaneSize::new(120., 400.))
+//!     .with_persist_key("sidebar");
+//! spawn_split_pane(parent, split, Node::default(), &theme, |sidebar| {
+//!     sidebar.spawn(Text::new("files"));
+//! }, |editor| {
+//!     editor.spawn(Text::new("editor"));
+//! });
+//! ```
+//!
+//! Splits with a persist key keep their ratio in [`SplitStore`], so they come back the same when
+//! they're spawned again, and across runs when the store was loaded from a file.
+
+use crate::store::RonStore;
+use crate::theme::Theme;
+use crate::widgets::DOUBLE_CLICK_TIME;
+use bevy::prelude::*;
+use bevy::ui::FocusPolicy;
+use bevy::window::PrimaryWindow;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::path::PathBuf;
+
+/// Thickness of the divider in logical pixels.
+pub const DIVIDER_SIZE: f32 = 6.;
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum SplitDirection {
+    /// The panes are side by side, with a vertical divider.
+    #[default]
+    Horizontal,
+    /// The panes are stacked, with a horizontal divider.
+    Vertical,
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum SplitSide {
+    #[default]
+    First,
+    Second,
+}
+
+/// The smallest and largest size of a pane in logical pixels along the split.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct PaneSize {
+    pub min: f32,
+    pub max: f32,
+}
+
+impl PaneSize {
+    pub fn new(min: f32, max: f32) -> Self {
+        Self { min, max }
+    }
+}
+
+impl Default for PaneSize {
+    fn default() -> Self {
+        Self {
+            min: 0.,
+            max: f32::INFINITY,
+        }
+    }
+}
+
+#[derive(Component, Clone, Debug)]
+pub struct SplitPane {
+    pub direction: SplitDirection,
+    /// The share of the split given to the first pane, from 0 to 1.
+    ratio: f32,
+    pub first_size: PaneSize,
+    pub second_size: PaneSize,
+    /// The pane a double-click on the divider collapses.
+    pub collapsible: SplitSide,
+    collapsed: bool,
+    /// Where the ratio is recorded in [`SplitStore`].
+    pub persist_key: Option<String>,
+}
+
+impl SplitPane {
+    pub fn horizontal(ratio: f32) -> Self {
+        Self::new(SplitDirection::Horizontal, ratio)
+    }
+
+    pub fn vertical(ratio: f32) -> Self {
+        Self::new(SplitDirection::Vertical, ratio)
+    }
+
+    pub fn new(direction: SplitDirection, ratio: f32) -> Self {
+        Self {
+            direction,
+            ratio: ratio.clamp(0., 1.),
+            first_size: PaneSize::default(),
+            second_size: PaneSize::default(),
+            collapsible: SplitSide::First,
+            collapsed: false,
+            persist_key: None,
+        }
+    }
+
+    pub fn with_first_size(mut self, size: PaneSize) -> Self {
+        self.first_size = size;
+        self
+    }
+
+    pub fn with_second_size(mut self, size: PaneSize) -> Self {
+        self.second_size = size;
+        self
+    }
+
+    pub fn with_collapsible(mut self, side: SplitSide) -> Self {
+        self.collapsible = side;
+        self
+    }
+
+    pub fn with_persist_key(mut self, key: impl Into<String>) -> Self {
+        self.persist_key = Some(key.into());
+        self
+    }
+
+    /// The share of the split the first pane gets when not collapsed. The pane sizes may keep
+    /// the panes from showing exactly this share.
+    pub fn ratio(&self) -> f32 {
+        self.ratio
+    }
+
+    pub fn set_ratio(&mut self, ratio: f32) {
+        self.ratio = ratio.clamp(0., 1.);
+    }
+
+    pub fn is_collapsed(&self) -> bool {
+        self.collapsed
+    }
+
+    pub fn set_collapsed(&mut self, collapsed: bool) {
+        self.collapsed = collapsed;
+    }
+
+    /// The size of the first pane in a split `length` logical pixels long, divider included.
+    pub fn first_length(&self, length: f32) -> f32 {
+        let available = (length - DIVIDER_SIZE).max(0.);
+        if self.collapsed {
+            return match self.collapsible {
+                SplitSide::First => 0.,
+                SplitSide::Second => available,
+            };
+        }
+        let max = self.first_size.max.min(available - self.second_size.min);
+        let min = self.first_size.min.max(available - self.second_size.max);
+        // the first pane's minimum wins when the split is too small for both
+        (self.ratio * length).min(max).max(min).clamp(0., available)
+    }
+}
+
+/// Sent when a split's ratio changes by dragging its divider, or when it collapses or expands.
+#[derive(Event, Clone, Copy, Debug)]
+pub struct SplitChanged {
+    pub split: Entity,
+    pub ratio: f32,
+    pub collapsed: bool,
+}
+
+#[derive(Component, Clone, Copy, Debug)]
+#[require(Interaction)]
+pub struct SplitDivider {
+    pub split: Entity,
+}
+
+#[derive(Component, Clone, Copy, Debug)]
+pub struct SplitPanel {
+    pub split: Entity,
+    pub side: SplitSide,
+}
+
+/// The ratios and collapsed state of persisted splits, keyed by their persist key.
+#[derive(Resource, Default, Debug)]
+pub struct SplitStore(RonStore<BTreeMap<String, (f32, bool)>>);
+
+impl SplitStore {
+    /// A store saved to `path` on every change; see [`RonStore::load`].
+    pub fn load(path: impl Into<PathBuf>) -> Result<Self, String> {
+        RonStore::load(path).map(Self)
+    }
+
+    /// The ratio and collapsed state last recorded for `key`.
+    pub fn split(&self, key: &str) -> Option<(f32, bool)> {
+        self.0.get().get(key).copied()
+    }
+
+    fn set_split(&mut self, key: &str, ratio: f32, collapsed: bool) {
+        self.0.get_mut().insert(key.to_string(), (ratio, collapsed));
+    }
+
+    fn save(&self) {
+        if let Err(err) = self.0.save() {
+            println!("failed to save {err}");
+        }
+    }
+}
+
+/// A divider being dragged: the split, the cursor position along the split when it started and
+/// the first pane's size then.
+#[derive(Resource, Default)]
+struct DividerDrag(Option<(Entity, f32, f32)>);
+
+/// The divider last pressed and when, for telling double-clicks. A press that turns into a drag
+/// is forgotten, so it doesn't make a double-click with the next click.
+#[derive(Resource, Default)]
+struct DividerClick(Option<(Entity, f64)>);
+
+pub struct SplitPanePlugin;
+
+impl Plugin for SplitPanePlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<Theme>()
+            .init_resource::<SplitStore>()
+            .init_resource::<DividerDrag>()
+            .init_resource::<DividerClick>()
+            .add_event::<SplitChanged>()
+            .add_systems(
+                Update,
+                (
+                    restore_splits,
+                    collapse_splits,
+                    drag_dividers,
+                    size_panels,
+                    draw_dividers,
+                )
+                    .chain(),
+            );
+    }
+}
+
+/// Spawns a split laid out by `root`, with the children spawned by `first` and `second` in its
+/// panes, and returns its entity. The split fills its parent unless `root` says otherwise.
+pub fn spawn_split_pane(
+    parent: &mut ChildBuilder,
+    split: SplitPane,
+    root: impl Bundle,
+    theme: &Theme,
+    first: impl FnOnce(&mut ChildBuilder),
+    second: impl FnOnce(&mut ChildBuilder),
+) -> Entity {
+    let horizontal = split.direction == SplitDirection::Horizontal;
+    let mut split_commands = parent.spawn(Node {
+        width: Val::Percent(100.),
+        height: Val::Percent(100.),
+        flex_direction: if horizontal {
+            FlexDirection::Row
+        } else {
+            FlexDirection::Column
+        },
+        ..default()
+    });
+    split_commands.insert(root);
+    let entity = split_commands.id();
+    let pane = |grow: f32| Node {
+        flex_direction: FlexDirection::Column,
+        flex_grow: grow,
+        flex_shrink: 1.,
+        flex_basis: if grow > 0. {
+            Val::Px(0.)
+        } else {
+            Val::Percent(split.ratio * 100.)
+        },
+        min_width: Val::Px(0.),
+        min_height: Val::Px(0.),
+        overflow: Overflow::clip(),
+        ..default()
+    };
+    let first_node = pane(0.);
+    let second_node = pane(1.);
+    split_commands.with_children(|parent| {
+        parent
+            .spawn((
+                first_node,
+                SplitPanel {
+                    split: entity,
+                    side: SplitSide::First,
+                },
+            ))
+            .with_children(first);
+        parent.spawn((
+            Node {
+                width: if horizontal {
+                    Val::Px(DIVIDER_SIZE)
+                } else {
+                    Val::Auto
+                },
+                height: if horizontal {
+                    Val::Auto
+                } else {
+                    Val::Px(DIVIDER_SIZE)
+                },
+                flex_shrink: 0.,
+                ..default()
+            },
+            BackgroundColor(theme.border),
+            FocusPolicy::Block,
+            SplitDivider { split: entity },
+        ));
+        parent
+            .spawn((
+                second_node,
+                SplitPanel {
+                    split: entity,
+                    side: SplitSide::Second,
+                },
+            ))
+            .with_children(second);
+    });
+    split_commands.insert(split);
+    entity
+}
+
+/// The length of a split along its direction in logical pixels.
+fn split_length(split: &SplitPane, node: &ComputedNode) -> f32 {
+    let size = node.size() * node.inverse_scale_factor();
+    match split.direction {
+        SplitDirection::Horizontal => size.x,
+        SplitDirection::Vertical => size.y,
+    }
+}
+
+/// New persisted splits come back as they were last left.
+fn restore_splits(store: Res<SplitStore>, mut splits: Query<&mut SplitPane, Added<SplitPane>>) {
+    for mut split in &mut splits {
+        let Some((ratio, collapsed)) = split
+            .persist_key
+            .as_deref()
+            .and_then(|key| store.split(key))
+        else {
+            continue;
+        };
+        split.set_ratio(ratio);
+        split.collapsed = collapsed;
+    }
+}
+
+fn collapse_splits(
+    time: Res<Time>,
+    mut store: ResMut<SplitStore>,
+    dividers: Query<(Entity, &Interaction, &SplitDivider), Changed<Interaction>>,
+    mut splits: Query<&mut SplitPane>,
+    mut changed: EventWriter<SplitChanged>,
+    mut last_click: ResMut<DividerClick>,
+) {
+    let now = time.elapsed_secs_f64();
+    for (entity, interaction, divider) in &dividers {
+        if *interaction != Interaction::Pressed {
+            continue;
+        }
+        let double_click = matches!(
+            last_click.0,
+            Some((previous, at)) if previous == entity && now - at < DOUBLE_CLICK_TIME
+        );
+        // a third click starts a new double-click
+        last_click.0 = if double_click {
+            None
+        } else {
+            Some((entity, now))
+        };
+        if !double_click {
+            continue;
+        }
+        let Ok(mut split) = splits.get_mut(divider.split) else {
+            continue;
+        };
+        split.collapsed = !split.collapsed;
+        changed.send(SplitChanged {
+            split: divider.split,
+            ratio: split.ratio,
+            collapsed: split.collapsed,
+        });
+        if let Some(key) = &split.persist_key {
+            store.set_split(key, split.ratio, split.collapsed);
+            store.save();
+        }
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+fn drag_dividers(
+    mouse: Res<ButtonInput<MouseButton>>,
+    windows: Query<&Window, With<PrimaryWindow>>,
+    mut store: ResMut<SplitStore>,
+    mut drag: ResMut<DividerDrag>,
+    mut last_click: ResMut<DividerClick>,
+    dividers: Query<(&Interaction, &SplitDivider)>,
+    mut splits: Query<(&mut SplitPane, &ComputedNode)>,
+    mut changed: EventWriter<SplitChanged>,
+) {
+    let cursor = windows.get_single().ok().and_then(Window::cursor_position);
+    let along = |split: &SplitPane, cursor: Vec2| match split.direction {
+        SplitDirection::Horizontal => cursor.x,
+        SplitDirection::Vertical => cursor.y,
+    };
+    if mouse.just_pressed(MouseButton::Left)
+        && let Some(cursor) = cursor
+    {
+        for (interaction, divider) in &dividers {
+            if *interaction != Interaction::Pressed {
+                continue;
+            }
+            if let Ok((split, node)) = splits.get(divider.split) {
+                let start = split.first_length(split_length(split, node));
+                drag.0 = Some((divider.split, along(split, cursor), start));
+            }
+        }
+    }
+    let Some((entity, start, length)) = drag.0 else {
+        return;
+    };
+    let Ok((mut split, node)) = splits.get_mut(entity) else {
+        drag.0 = None;
+        return;
+    };
+    if !mouse.pressed(MouseButton::Left) {
+        drag.0 = None;
+        if let Some(key) = &split.persist_key {
+            store.set_split(key, split.ratio, split.collapsed);
+            store.save();
+        }
+        return;
+    }
+    let Some(cursor) = cursor else {
+        return;
+    };
+    let total = split_length(&split, node);
+    let moved = along(&split, cursor) - start;
+    if total <= 0. || moved == 0. {
+        return;
+    }
+    last_click.0 = None;
+    // dragging a collapsed split opens it from where the divider is
+    let mut resized = split.clone();
+    resized.collapsed = false;
+    resized.set_ratio((length + moved) / total);
+    let ratio = resized.first_length(total) / total;
+    if ratio != split.ratio || split.collapsed {
+        split.set_ratio(ratio);
+        split.collapsed = false;
+        changed.send(SplitChanged {
+            split: entity,
+            ratio,
+            collapsed: false,
+        });
+    }
+}
+
+/// Sizes the first pane of every split from its ratio and pane sizes; the second pane takes the
+/// rest.
+fn size_panels(
+    splits: Query<(&SplitPane, &ComputedNode)>,
+    mut panels: Query<(&SplitPanel, &mut Node)>,
+) {
+    for (panel, mut node) in &mut panels {
+        if panel.side != SplitSide::First {
+            continue;
+        }
+        let Ok((split, computed)) = splits.get(panel.split) else {
+            continue;
+        };
+        let total = split_length(split, computed);
+        let ratio = if total > 0. {
+            split.first_length(total) / total
+        } else {
+            split.ratio
+        };
+        let basis = Val::Percent(ratio * 100.);
+        if node.flex_basis != basis {
+            node.flex_basis = basis;
+        }
+    }
+}
+
+fn draw_dividers(
+    theme: Res<Theme>,
+    drag: Res<DividerDrag>,
+    mut dividers: Query<(&Interaction, &SplitDivider, &mut BackgroundColor)>,
+) {
+    for (interaction, divider, mut color) in &mut dividers {
+        let dragging = drag.0.is_some_and(|(split, ..)| split == divider.split);
+        let background = if dragging || *interaction != Interaction::None {
+            theme.accent
+        } else {
+            theme.border
+        };
+        color.set_if_neq(BackgroundColor(background));
+    }
+}
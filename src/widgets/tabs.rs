@@ -0,0 +1,671 @@
+//! Tabs: a strip of tab buttons above a content panel. A tab's panel is only spawned the first
+//! time the tab is shown; when another tab is shown it's hidden, or despawned for tabs that don't
+//! keep it alive, and rebuilt on its next activation.
+//!
+//! ```ignore
+//! spawn_tabs(parent, Tabs::new([
+//!     Tab::new("Log", |panel: &mut ChildBuilder| { panel.spawn(Text::new("...")); }),
+//!     Tab::new("Notes", build_notes).closable(),
+//! ]), Node::default(), &theme);
+//! ```
+//!
+//! Tabs can be closed with their × button and reordered by dragging them along the strip. When
+//! they don't fit, the strip scrolls with the mouse wheel and keeps the active tab in view. The
+//! arrow keys, Home and End move between tabs while one has the keyboard focus.
+
+use crate::theme::Theme;
+use crate::widgets::focus::{Focus, FocusPlugin, Focusable};
+use bevy::input::mouse::{MouseScrollUnit, MouseWheel};
+use bevy::prelude::*;
+use bevy::ui::RelativeCursorPosition;
+use bevy::window::PrimaryWindow;
+use std::sync::Arc;
+
+/// Pixels the strip scrolls per wheel line.
+const LINE_WIDTH: f32 = 20.;
+/// Cursor travel before a press on a tab turns into a drag.
+const DRAG_THRESHOLD: f32 = 4.;
+
+/// Builds the content of a tab's panel; implemented by closures taking the panel's
+/// [`ChildBuilder`].
+pub trait TabContent: Send + Sync + 'static {
+    fn build(&self, panel: &mut ChildBuilder);
+}
+
+impl<F: Fn(&mut ChildBuilder) + Send + Sync + 'static> TabContent for F {
+    fn build(&self, panel: &mut ChildBuilder) {
+        self(panel)
+    }
+}
+
+#[derive(Clone)]
+pub struct Tab {
+    pub title: String,
+    /// Whether the tab has a close button.
+    pub closable: bool,
+    /// Whether the panel is kept (hidden) while another tab is shown, rather than despawned.
+    pub keep_alive: bool,
+    content: Arc<dyn TabContent>,
+    button: Entity,
+    panel: Option<Entity>,
+}
+
+impl Tab {
+    pub fn new(title: impl Into<String>, content: impl TabContent) -> Self {
+        Self {
+            title: title.into(),
+            closable: false,
+            keep_alive: true,
+            content: Arc::new(content),
+            button: Entity::PLACEHOLDER,
+            panel: None,
+        }
+    }
+
+    pub fn closable(mut self) -> Self {
+        self.closable = true;
+        self
+    }
+
+    /// Despawns the panel whenever another tab is shown.
+    pub fn despawn_when_hidden(mut self) -> Self {
+        self.keep_alive = false;
+        self
+    }
+}
+
+#[derive(Component)]
+#[require(TabDrag)]
+pub struct Tabs {
+    tabs: Vec<Tab>,
+    active: Option<usize>,
+    /// The button of the tab whose panel is shown.
+    shown: Option<Entity>,
+    strip: Entity,
+    panels: Entity,
+}
+
+impl Tabs {
+    pub fn new(tabs: impl IntoIterator<Item = Tab>) -> Self {
+        let tabs: Vec<Tab> = tabs.into_iter().collect();
+        Self {
+            active: (!tabs.is_empty()).then_some(0),
+            tabs,
+            shown: None,
+            strip: Entity::PLACEHOLDER,
+            panels: Entity::PLACEHOLDER,
+        }
+    }
+
+    pub fn with_active(mut self, index: usize) -> Self {
+        self.select(index);
+        self
+    }
+
+    pub fn active(&self) -> Option<usize> {
+        self.active
+    }
+
+    /// Shows the tab at `index`, if there is one.
+    pub fn select(&mut self, index: usize) {
+        if index < self.tabs.len() {
+            self.active = Some(index);
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.tabs.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.tabs.is_empty()
+    }
+
+    pub fn title(&self, index: usize) -> Option<&str> {
+        self.tabs.get(index).map(|tab| tab.title.as_str())
+    }
+
+    /// The panel of the tab at `index`, if it's been spawned.
+    pub fn panel(&self, index: usize) -> Option<Entity> {
+        self.tabs.get(index).and_then(|tab| tab.panel)
+    }
+
+    fn position(&self, button: Entity) -> Option<usize> {
+        self.tabs.iter().position(|tab| tab.button == button)
+    }
+
+    fn remove(&mut self, index: usize) -> Tab {
+        let tab = self.tabs.remove(index);
+        self.active = match self.active {
+            _ if self.tabs.is_empty() => None,
+            Some(active) if active > index => Some(active - 1),
+            Some(active) if active == index => Some(index.min(self.tabs.len() - 1)),
+            active => active,
+        };
+        tab
+    }
+
+    fn move_tab(&mut self, from: usize, to: usize) {
+        let active = self.active.map(|active| self.tabs[active].button);
+        let tab = self.tabs.remove(from);
+        self.tabs.insert(to, tab);
+        self.active = active.and_then(|button| self.position(button));
+    }
+}
+
+/// Sent when another tab is shown.
+#[derive(Event, Clone, Copy, Debug)]
+pub struct TabChanged {
+    pub tabs: Entity,
+    pub index: usize,
+}
+
+/// Sent when a tab is closed with its close button.
+#[derive(Event, Clone, Debug)]
+pub struct TabClosed {
+    pub tabs: Entity,
+    pub title: String,
+}
+
+/// Sent when a tab is dragged to another position.
+#[derive(Event, Clone, Copy, Debug)]
+pub struct TabMoved {
+    pub tabs: Entity,
+    pub from: usize,
+    pub to: usize,
+}
+
+#[derive(Component, Clone, Copy, Debug)]
+#[require(Button, Focusable)]
+pub struct TabButton {
+    pub tabs: Entity,
+}
+
+#[derive(Component, Clone, Copy, Debug)]
+#[require(Button)]
+pub struct TabCloseButton {
+    pub tabs: Entity,
+    pub tab: Entity,
+}
+
+/// The scrolling row holding the tab buttons.
+#[derive(Component, Clone, Copy, Debug)]
+#[require(ScrollPosition, RelativeCursorPosition)]
+pub struct TabStrip {
+    pub tabs: Entity,
+}
+
+/// The content of one tab.
+#[derive(Component, Clone, Copy, Debug)]
+pub struct TabPanel {
+    pub tabs: Entity,
+}
+
+/// A tab being pressed or dragged.
+#[derive(Component, Default)]
+struct TabDrag(Option<PressedTab>);
+
+struct PressedTab {
+    button: Entity,
+    cursor: f32,
+    dragging: bool,
+}
+
+/// Scrolls the strip so this tab is fully visible once it's been laid out.
+#[derive(Component)]
+struct RevealTab;
+
+pub struct TabsPlugin;
+
+impl Plugin for TabsPlugin {
+    fn build(&self, app: &mut App) {
+        if !app.is_plugin_added::<FocusPlugin>() {
+            app.add_plugins(FocusPlugin);
+        }
+        app.add_event::<TabChanged>()
+            .add_event::<TabClosed>()
+            .add_event::<TabMoved>()
+            .add_systems(
+                Update,
+                (
+                    press_tabs,
+                    close_tabs,
+                    drag_tabs,
+                    tab_keys,
+                    scroll_strips,
+                    show_panels,
+                    reveal_tabs,
+                    draw_tabs,
+                )
+                    .chain(),
+            );
+    }
+}
+
+/// Spawns tabs laid out by `root` and returns their entity.
+pub fn spawn_tabs(
+    parent: &mut ChildBuilder,
+    mut tabs: Tabs,
+    root: impl Bundle,
+    theme: &Theme,
+) -> Entity {
+    let mut tabs_commands = parent.spawn(Node {
+        flex_direction: FlexDirection::Column,
+        ..default()
+    });
+    tabs_commands.insert(root);
+    let entity = tabs_commands.id();
+    tabs_commands.with_children(|parent| {
+        tabs.strip = parent
+            .spawn((
+                Node {
+                    flex_shrink: 0.,
+                    overflow: Overflow::scroll_x(),
+                    border: UiRect::bottom(Val::Px(1.)),
+                    ..default()
+                },
+                BorderColor(theme.border),
+                TabStrip { tabs: entity },
+            ))
+            .with_children(|strip| {
+                for tab in &mut tabs.tabs {
+                    tab.button = spawn_tab_button(strip, entity, tab, theme);
+                }
+            })
+            .id();
+        tabs.panels = parent
+            .spawn((
+                Node {
+                    flex_grow: 1.,
+                    flex_direction: FlexDirection::Column,
+                    ..default()
+                },
+                BackgroundColor(theme.surface),
+            ))
+            .id();
+    });
+    tabs_commands.insert(tabs);
+    entity
+}
+
+fn spawn_tab_button(strip: &mut ChildBuilder, tabs: Entity, tab: &Tab, theme: &Theme) -> Entity {
+    let mut button = strip.spawn((
+        Node {
+            flex_shrink: 0.,
+            align_items: AlignItems::Center,
+            column_gap: Val::Px(6.),
+            padding: UiRect::axes(Val::Px(14.), Val::Px(8.)),
+            border: UiRect::bottom(Val::Px(2.)),
+            ..default()
+        },
+        BackgroundColor(theme.background),
+        BorderColor(Color::NONE),
+        TabButton { tabs },
+    ));
+    let entity = button.id();
+    button.with_children(|button| {
+        button.spawn((
+            Text::new(tab.title.clone()),
+            theme.text_font(),
+            TextColor(theme.text),
+        ));
+        if tab.closable {
+            button
+                .spawn((
+                    Node {
+                        padding: UiRect::horizontal(Val::Px(4.)),
+                        ..default()
+                    },
+                    BackgroundColor(Color::NONE),
+                    BorderRadius::all(Val::Px(3.)),
+                    TabCloseButton { tabs, tab: entity },
+                ))
+                .with_child((
+                    Text::new("×"),
+                    theme.text_font(),
+                    TextColor(theme.text_muted),
+                ));
+        }
+    });
+    entity
+}
+
+/// A press on a tab shows it and may start a drag.
+fn press_tabs(
+    windows: Query<&Window, With<PrimaryWindow>>,
+    buttons: Query<(Entity, &Interaction, &TabButton), Changed<Interaction>>,
+    mut tabs_query: Query<(&mut Tabs, &mut TabDrag)>,
+) {
+    let cursor = windows.get_single().ok().and_then(Window::cursor_position);
+    for (entity, interaction, button) in &buttons {
+        if *interaction != Interaction::Pressed {
+            continue;
+        }
+        let Ok((mut tabs, mut drag)) = tabs_query.get_mut(button.tabs) else {
+            continue;
+        };
+        if let Some(index) = tabs.position(entity) {
+            tabs.select(index);
+        }
+        drag.0 = cursor.map(|cursor| PressedTab {
+            button: entity,
+            cursor: cursor.x,
+            dragging: false,
+        });
+    }
+}
+
+/// Closing the focused tab moves the focus to the tab shown instead.
+fn close_tabs(
+    mut commands: Commands,
+    mut focus: ResMut<Focus>,
+    closes: Query<(&Interaction, &TabCloseButton), Changed<Interaction>>,
+    mut tabs_query: Query<&mut Tabs>,
+    mut closed: EventWriter<TabClosed>,
+) {
+    for (interaction, close) in &closes {
+        if *interaction != Interaction::Pressed {
+            continue;
+        }
+        let Ok(mut tabs) = tabs_query.get_mut(close.tabs) else {
+            continue;
+        };
+        let Some(index) = tabs.position(close.tab) else {
+            continue;
+        };
+        let tab = tabs.remove(index);
+        if focus.is(tab.button) {
+            focus.0 = tabs.active.map(|active| tabs.tabs[active].button);
+        }
+        commands.entity(tab.button).despawn_recursive();
+        if let Some(panel) = tab.panel {
+            commands.entity(panel).despawn_recursive();
+        }
+        closed.send(TabClosed {
+            tabs: close.tabs,
+            title: tab.title,
+        });
+    }
+}
+
+/// Moves a dragged tab to the position of the tab under the cursor.
+fn drag_tabs(
+    mut commands: Commands,
+    mouse: Res<ButtonInput<MouseButton>>,
+    windows: Query<&Window, With<PrimaryWindow>>,
+    mut tabs_query: Query<(Entity, &mut Tabs, &mut TabDrag)>,
+    nodes: Query<(&ComputedNode, &GlobalTransform)>,
+    mut moved: EventWriter<TabMoved>,
+) {
+    let cursor = windows.get_single().ok().and_then(Window::cursor_position);
+    for (entity, mut tabs, mut drag) in &mut tabs_query {
+        let Some(pressed) = &mut drag.0 else {
+            continue;
+        };
+        let Some(cursor) = cursor.filter(|_| mouse.pressed(MouseButton::Left)) else {
+            drag.0 = None;
+            continue;
+        };
+        if !pressed.dragging && (cursor.x - pressed.cursor).abs() > DRAG_THRESHOLD {
+            pressed.dragging = true;
+        }
+        if !pressed.dragging {
+            continue;
+        }
+        let Some(from) = tabs.position(pressed.button) else {
+            drag.0 = None;
+            continue;
+        };
+        let under_cursor = tabs.tabs.iter().position(|tab| {
+            nodes.get(tab.button).is_ok_and(|(computed, transform)| {
+                let scale = computed.inverse_scale_factor();
+                let center = transform.translation().x * scale;
+                let half_width = computed.size().x * scale / 2.;
+                (cursor.x - center).abs() <= half_width
+            })
+        });
+        let Some(to) = under_cursor.filter(|to| *to != from) else {
+            continue;
+        };
+        tabs.move_tab(from, to);
+        let buttons: Vec<Entity> = tabs.tabs.iter().map(|tab| tab.button).collect();
+        commands.entity(tabs.strip).replace_children(&buttons);
+        moved.send(TabMoved {
+            tabs: entity,
+            from,
+            to,
+        });
+    }
+}
+
+/// The arrow keys, Home and End move between the tabs while one of them has focus.
+fn tab_keys(
+    keys: Res<ButtonInput<KeyCode>>,
+    mut focus: ResMut<Focus>,
+    buttons: Query<&TabButton>,
+    mut tabs_query: Query<&mut Tabs>,
+) {
+    let Some((focused, button)) = focus
+        .0
+        .and_then(|entity| buttons.get(entity).ok().map(|button| (entity, button)))
+    else {
+        return;
+    };
+    let Ok(mut tabs) = tabs_query.get_mut(button.tabs) else {
+        return;
+    };
+    let Some(current) = tabs.position(focused) else {
+        return;
+    };
+    let last = tabs.len() - 1;
+    let next = if keys.just_pressed(KeyCode::ArrowLeft) {
+        current.checked_sub(1).unwrap_or(last)
+    } else if keys.just_pressed(KeyCode::ArrowRight) {
+        if current == last { 0 } else { current + 1 }
+    } else if keys.just_pressed(KeyCode::Home) {
+        0
+    } else if keys.just_pressed(KeyCode::End) {
+        last
+    } else {
+        return;
+    };
+    tabs.select(next);
+    focus.0 = Some(tabs.tabs[next].button);
+}
+
+/// The distance the strip can scroll: the width of its tabs beyond its own.
+fn max_scroll(
+    strip: &ComputedNode,
+    children: &Children,
+    nodes: &Query<(&ComputedNode, &GlobalTransform)>,
+) -> f32 {
+    let content: f32 = children
+        .iter()
+        .filter_map(|child| nodes.get(*child).ok())
+        .map(|(computed, _)| computed.size().x)
+        .sum();
+    ((content - strip.size().x) * strip.inverse_scale_factor()).max(0.)
+}
+
+fn scroll_strips(
+    mut wheel: EventReader<MouseWheel>,
+    mut strips: Query<
+        (
+            &RelativeCursorPosition,
+            &ComputedNode,
+            &Children,
+            &mut ScrollPosition,
+        ),
+        With<TabStrip>,
+    >,
+    nodes: Query<(&ComputedNode, &GlobalTransform)>,
+) {
+    let delta: f32 = wheel
+        .read()
+        .map(|event| {
+            let lines = if event.x != 0. { event.x } else { event.y };
+            match event.unit {
+                MouseScrollUnit::Line => lines * LINE_WIDTH,
+                MouseScrollUnit::Pixel => lines,
+            }
+        })
+        .sum();
+    if delta == 0. {
+        return;
+    }
+    for (cursor, strip, children, mut position) in &mut strips {
+        if !cursor.mouse_over() {
+            continue;
+        }
+        let max = max_scroll(strip, children, &nodes);
+        position.offset_x = (position.offset_x - delta).clamp(0., max);
+    }
+}
+
+/// Shows the active tab's panel, spawning it the first time, and hides or despawns the one shown
+/// before.
+fn show_panels(
+    mut commands: Commands,
+    mut tabs_query: Query<(Entity, &mut Tabs), Changed<Tabs>>,
+    mut panels: Query<&mut Node, With<TabPanel>>,
+    mut changed: EventWriter<TabChanged>,
+) {
+    for (entity, mut tabs) in &mut tabs_query {
+        let active = tabs.active.map(|index| tabs.tabs[index].button);
+        if active == tabs.shown {
+            continue;
+        }
+        let panels_root = tabs.panels;
+        for tab in &mut tabs.tabs {
+            if Some(tab.button) == active {
+                match tab.panel {
+                    Some(panel) => {
+                        if let Ok(mut node) = panels.get_mut(panel) {
+                            node.display = Display::Flex;
+                        }
+                    }
+                    None => {
+                        commands.entity(panels_root).with_children(|parent| {
+                            let mut panel = parent.spawn((
+                                Node {
+                                    flex_grow: 1.,
+                                    flex_direction: FlexDirection::Column,
+                                    row_gap: Val::Px(8.),
+                                    padding: UiRect::all(Val::Px(12.)),
+                                    ..default()
+                                },
+                                TabPanel { tabs: entity },
+                            ));
+                            let content = tab.content.clone();
+                            panel.with_children(|panel| content.build(panel));
+                            tab.panel = Some(panel.id());
+                        });
+                    }
+                }
+                commands.entity(tab.button).insert(RevealTab);
+            } else if let Some(panel) = tab.panel {
+                if tab.keep_alive {
+                    if let Ok(mut node) = panels.get_mut(panel) {
+                        node.display = Display::None;
+                    }
+                } else {
+                    commands.entity(panel).despawn_recursive();
+                    tab.panel = None;
+                }
+            }
+        }
+        tabs.shown = active;
+        if let Some(index) = tabs.active {
+            changed.send(TabChanged {
+                tabs: entity,
+                index,
+            });
+        }
+    }
+}
+
+#[allow(clippy::type_complexity)]
+fn reveal_tabs(
+    mut commands: Commands,
+    buttons: Query<(Entity, &TabButton), With<RevealTab>>,
+    tabs_query: Query<&Tabs>,
+    mut strips: Query<(
+        &ComputedNode,
+        &GlobalTransform,
+        &Children,
+        &mut ScrollPosition,
+    )>,
+    nodes: Query<(&ComputedNode, &GlobalTransform)>,
+) {
+    for (entity, button) in &buttons {
+        let Ok(tabs) = tabs_query.get(button.tabs) else {
+            commands.entity(entity).remove::<RevealTab>();
+            continue;
+        };
+        let (Ok((tab, tab_transform)), Ok((strip, strip_transform, children, mut position))) =
+            (nodes.get(entity), strips.get_mut(tabs.strip))
+        else {
+            continue;
+        };
+        // wait for the layout
+        if tab.size().x == 0. || strip.size().x == 0. {
+            continue;
+        }
+        let scale = strip.inverse_scale_factor();
+        let rect = |computed: &ComputedNode, transform: &GlobalTransform| {
+            Rect::from_center_size(
+                transform.translation().truncate() * scale,
+                computed.size() * scale,
+            )
+        };
+        let tab_rect = rect(tab, tab_transform);
+        let strip_rect = rect(strip, strip_transform);
+        let mut offset = position.offset_x;
+        if tab_rect.min.x < strip_rect.min.x {
+            offset -= strip_rect.min.x - tab_rect.min.x;
+        } else if tab_rect.max.x > strip_rect.max.x {
+            offset += tab_rect.max.x - strip_rect.max.x;
+        }
+        let max = max_scroll(strip, children, &nodes);
+        position.offset_x = offset.clamp(0., max);
+        commands.entity(entity).remove::<RevealTab>();
+    }
+}
+
+#[allow(clippy::type_complexity)]
+fn draw_tabs(
+    theme: Res<Theme>,
+    tabs_query: Query<&Tabs>,
+    mut buttons: Query<
+        (
+            Entity,
+            &Interaction,
+            &TabButton,
+            &mut BackgroundColor,
+            &mut BorderColor,
+        ),
+        Without<TabCloseButton>,
+    >,
+    mut closes: Query<(&Interaction, &mut BackgroundColor), With<TabCloseButton>>,
+) {
+    for (entity, interaction, button, mut background, mut border) in &mut buttons {
+        let active = tabs_query.get(button.tabs).is_ok_and(|tabs| {
+            tabs.active
+                .is_some_and(|index| tabs.tabs[index].button == entity)
+        });
+        let (color, underline) = match (active, interaction) {
+            (true, _) => (theme.surface, theme.accent),
+            (false, Interaction::None) => (theme.background, Color::NONE),
+            (false, _) => (theme.surface_hovered, Color::NONE),
+        };
+        background.set_if_neq(BackgroundColor(color));
+        border.set_if_neq(BorderColor(underline));
+    }
+    for (interaction, mut background) in &mut closes {
+        let color = match interaction {
+            Interaction::None => Color::NONE,
+            _ => theme.surface_hovered,
+        };
+        background.set_if_neq(BackgroundColor(color));
+    }
+}
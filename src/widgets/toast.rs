@@ -0,0 +1,462 @@
+//! Toast notifications: short messages that slide in at a corner of the window, stack up and go
+//! away on their own. Queue one through the [`Toasts`] resource:
+//!
+//! ```ignore
+//! fn save(mut toasts: ResMut<Toasts>) {
+//!     toasts.push(Toast::success("Saved").with_action("Undo"));
+//! }
+//! ```
+//!
+//! A toast is dismissed after its timeout, which is paused while the pointer is on it, or with
+//! its close button. A toast with an action button sends [`ToastActionPressed`] when the button is
+//! pressed, and closes. Toasts beyond [`ToastSettings::max_visible`] wait for a free spot.
+
+use crate::theme::Theme;
+use crate::widgets::overlay::{Overlay, OverlayLayer, OverlayPlugin, spawn_overlay};
+use bevy::prelude::*;
+use bevy::ui::FocusPolicy;
+use bevy::window::RequestRedraw;
+use std::collections::VecDeque;
+
+/// Seconds a toast takes to slide in.
+const SLIDE_SECONDS: f32 = 0.25;
+/// Distance between the toasts and the window edges.
+const MARGIN: f32 = 16.;
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub enum ToastKind {
+    #[default]
+    Info,
+    Success,
+    Warning,
+    Error,
+}
+
+impl ToastKind {
+    fn color(self, theme: &Theme) -> Color {
+        match self {
+            ToastKind::Info => theme.accent,
+            ToastKind::Success => theme.success,
+            ToastKind::Warning => theme.warning,
+            ToastKind::Error => theme.error,
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct Toast {
+    pub kind: ToastKind,
+    pub message: String,
+    /// Seconds before the toast goes away; `None` uses [`ToastSettings::duration`].
+    pub duration: Option<f32>,
+    /// Label of the action button, if any.
+    pub action: Option<String>,
+}
+
+impl Toast {
+    pub fn new(kind: ToastKind, message: impl Into<String>) -> Self {
+        Self {
+            kind,
+            message: message.into(),
+            duration: None,
+            action: None,
+        }
+    }
+
+    pub fn info(message: impl Into<String>) -> Self {
+        Self::new(ToastKind::Info, message)
+    }
+
+    pub fn success(message: impl Into<String>) -> Self {
+        Self::new(ToastKind::Success, message)
+    }
+
+    pub fn warning(message: impl Into<String>) -> Self {
+        Self::new(ToastKind::Warning, message)
+    }
+
+    pub fn error(message: impl Into<String>) -> Self {
+        Self::new(ToastKind::Error, message)
+    }
+
+    pub fn with_duration(mut self, seconds: f32) -> Self {
+        self.duration = Some(seconds);
+        self
+    }
+
+    /// Keeps the toast until it's closed or dismissed.
+    pub fn persistent(self) -> Self {
+        self.with_duration(f32::INFINITY)
+    }
+
+    pub fn with_action(mut self, label: impl Into<String>) -> Self {
+        self.action = Some(label.into());
+        self
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub struct ToastId(u64);
+
+/// The notification queue.
+#[derive(Resource, Default, Debug)]
+pub struct Toasts {
+    next_id: u64,
+    queued: VecDeque<(ToastId, Toast)>,
+    dismissed: Vec<ToastId>,
+}
+
+impl Toasts {
+    /// Queues `toast`; it shows as soon as there's room for it.
+    pub fn push(&mut self, toast: Toast) -> ToastId {
+        let id = ToastId(self.next_id);
+        self.next_id += 1;
+        self.queued.push_back((id, toast));
+        id
+    }
+
+    /// Closes the toast, or drops it if it hasn't shown yet.
+    pub fn dismiss(&mut self, toast: ToastId) {
+        self.queued.retain(|(id, _)| *id != toast);
+        self.dismissed.push(toast);
+    }
+}
+
+/// The corner of the window the toasts stack in.
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub enum ToastCorner {
+    TopLeft,
+    TopRight,
+    BottomLeft,
+    #[default]
+    BottomRight,
+}
+
+#[derive(Resource, Clone, Debug)]
+pub struct ToastSettings {
+    pub corner: ToastCorner,
+    /// Seconds a toast stays, not counting the time the pointer rests on it.
+    pub duration: f32,
+    pub max_visible: usize,
+    pub width: f32,
+}
+
+impl Default for ToastSettings {
+    fn default() -> Self {
+        Self {
+            corner: ToastCorner::default(),
+            duration: 4.,
+            max_visible: 4,
+            width: 320.,
+        }
+    }
+}
+
+/// Sent when the action button of a toast is pressed.
+#[derive(Event, Clone, Copy, Debug)]
+pub struct ToastActionPressed {
+    pub toast: ToastId,
+}
+
+/// A shown toast.
+#[derive(Component, Debug)]
+#[require(Interaction)]
+pub struct ToastCard {
+    pub toast: ToastId,
+    /// Seconds left before it goes away.
+    remaining: f32,
+    /// Seconds since it was shown.
+    age: f32,
+}
+
+#[derive(Component, Clone, Copy, Debug)]
+#[require(Button)]
+pub struct ToastActionButton {
+    pub toast: ToastId,
+}
+
+#[derive(Component, Clone, Copy, Debug)]
+#[require(Button)]
+pub struct ToastCloseButton {
+    pub toast: ToastId,
+}
+
+/// The overlay the toasts are stacked in.
+#[derive(Component)]
+struct ToastRegion;
+
+pub struct ToastPlugin;
+
+impl Plugin for ToastPlugin {
+    fn build(&self, app: &mut App) {
+        if !app.is_plugin_added::<OverlayPlugin>() {
+            app.add_plugins(OverlayPlugin);
+        }
+        app.init_resource::<Theme>()
+            .init_resource::<Toasts>()
+            .init_resource::<ToastSettings>()
+            .add_event::<ToastActionPressed>()
+            .add_systems(
+                Update,
+                (
+                    place_region,
+                    show_toasts,
+                    press_toast_buttons,
+                    expire_toasts,
+                    draw_toast_buttons,
+                )
+                    .chain(),
+            );
+    }
+}
+
+fn region_node(settings: &ToastSettings) -> Node {
+    let inset = Val::Px(MARGIN);
+    let (top, bottom, left, right) = match settings.corner {
+        ToastCorner::TopLeft => (inset, Val::Auto, inset, Val::Auto),
+        ToastCorner::TopRight => (inset, Val::Auto, Val::Auto, inset),
+        ToastCorner::BottomLeft => (Val::Auto, inset, inset, Val::Auto),
+        ToastCorner::BottomRight => (Val::Auto, inset, Val::Auto, inset),
+    };
+    // the newest toast goes nearest the corner
+    let flex_direction = match settings.corner {
+        ToastCorner::TopLeft | ToastCorner::TopRight => FlexDirection::ColumnReverse,
+        ToastCorner::BottomLeft | ToastCorner::BottomRight => FlexDirection::Column,
+    };
+    Node {
+        position_type: PositionType::Absolute,
+        top,
+        bottom,
+        left,
+        right,
+        flex_direction,
+        row_gap: Val::Px(8.),
+        ..default()
+    }
+}
+
+/// Spawns the overlay holding the toasts, and moves it when the corner changes.
+fn place_region(
+    mut commands: Commands,
+    settings: Res<ToastSettings>,
+    mut regions: Query<&mut Node, With<ToastRegion>>,
+) {
+    if let Ok(mut node) = regions.get_single_mut() {
+        if settings.is_changed() {
+            *node = region_node(&settings);
+        }
+        return;
+    }
+    spawn_overlay(
+        &mut commands,
+        Overlay::new(OverlayLayer::Toast).with_dismiss(false, false),
+        (
+            region_node(&settings),
+            // only the toasts themselves catch the pointer
+            FocusPolicy::Pass,
+            ToastRegion,
+            Name::new("toasts"),
+        ),
+        |_| {},
+    );
+}
+
+fn show_toasts(
+    mut commands: Commands,
+    theme: Res<Theme>,
+    settings: Res<ToastSettings>,
+    mut toasts: ResMut<Toasts>,
+    regions: Query<Entity, With<ToastRegion>>,
+    cards: Query<(), With<ToastCard>>,
+) {
+    let Ok(region) = regions.get_single() else {
+        return;
+    };
+    let mut visible = cards.iter().count();
+    while visible < settings.max_visible {
+        let Some((id, toast)) = toasts.queued.pop_front() else {
+            break;
+        };
+        visible += 1;
+        commands.entity(region).with_children(|parent| {
+            spawn_card(parent, id, toast, &settings, &theme);
+        });
+    }
+}
+
+fn spawn_card(
+    parent: &mut ChildBuilder,
+    id: ToastId,
+    toast: Toast,
+    settings: &ToastSettings,
+    theme: &Theme,
+) {
+    let button_node = Node {
+        padding: UiRect::axes(Val::Px(8.), Val::Px(4.)),
+        ..default()
+    };
+    parent
+        .spawn((
+            Node {
+                width: Val::Px(settings.width),
+                align_items: AlignItems::Center,
+                column_gap: Val::Px(8.),
+                padding: UiRect::axes(Val::Px(12.), Val::Px(10.)),
+                border: UiRect::left(Val::Px(4.)),
+                left: Val::Px(slide_offset(0., settings)),
+                ..default()
+            },
+            BackgroundColor(theme.surface_hovered),
+            BorderColor(toast.kind.color(theme)),
+            BorderRadius::all(Val::Px(4.)),
+            FocusPolicy::Block,
+            ToastCard {
+                toast: id,
+                remaining: toast.duration.unwrap_or(settings.duration),
+                age: 0.,
+            },
+        ))
+        .with_children(|card| {
+            card.spawn((
+                Text::new(toast.message),
+                theme.text_font(),
+                TextColor(theme.text),
+                Node {
+                    flex_grow: 1.,
+                    ..default()
+                },
+            ));
+            if let Some(action) = toast.action {
+                card.spawn((
+                    button_node.clone(),
+                    BackgroundColor(theme.surface),
+                    BorderRadius::all(Val::Px(4.)),
+                    ToastActionButton { toast: id },
+                ))
+                .with_child((
+                    Text::new(action),
+                    theme.text_font(),
+                    TextColor(theme.accent),
+                ));
+            }
+            card.spawn((
+                button_node,
+                BackgroundColor(Color::NONE),
+                BorderRadius::all(Val::Px(4.)),
+                ToastCloseButton { toast: id },
+            ))
+            .with_child((
+                Text::new("×"),
+                theme.text_font(),
+                TextColor(theme.text_muted),
+            ));
+        });
+}
+
+/// How far a toast of `age` seconds still is from its place, towards the nearest window edge.
+fn slide_offset(age: f32, settings: &ToastSettings) -> f32 {
+    let t = (age / SLIDE_SECONDS).clamp(0., 1.);
+    let remaining = (1. - t).powi(3);
+    let distance = (settings.width + MARGIN) * remaining;
+    match settings.corner {
+        ToastCorner::TopLeft | ToastCorner::BottomLeft => -distance,
+        ToastCorner::TopRight | ToastCorner::BottomRight => distance,
+    }
+}
+
+fn press_toast_buttons(
+    mut toasts: ResMut<Toasts>,
+    actions: Query<(&Interaction, &ToastActionButton), Changed<Interaction>>,
+    closes: Query<(&Interaction, &ToastCloseButton), Changed<Interaction>>,
+    mut pressed: EventWriter<ToastActionPressed>,
+) {
+    for (interaction, button) in &actions {
+        if *interaction == Interaction::Pressed {
+            pressed.send(ToastActionPressed {
+                toast: button.toast,
+            });
+            toasts.dismissed.push(button.toast);
+        }
+    }
+    for (interaction, button) in &closes {
+        if *interaction == Interaction::Pressed {
+            toasts.dismissed.push(button.toast);
+        }
+    }
+}
+
+/// Slides toasts in, counts down their time unless the pointer is on them, and despawns the
+/// expired and dismissed ones.
+#[allow(clippy::too_many_arguments, clippy::type_complexity)]
+fn expire_toasts(
+    mut commands: Commands,
+    time: Res<Time>,
+    settings: Res<ToastSettings>,
+    mut toasts: ResMut<Toasts>,
+    mut cards: Query<(Entity, &mut ToastCard, &Interaction, &mut Node)>,
+    buttons: Query<
+        (
+            &Interaction,
+            Option<&ToastActionButton>,
+            Option<&ToastCloseButton>,
+        ),
+        Or<(With<ToastActionButton>, With<ToastCloseButton>)>,
+    >,
+    mut redraw: EventWriter<RequestRedraw>,
+) {
+    let dismissed = std::mem::take(&mut toasts.dismissed);
+    // buttons block the pointer from the card underneath them
+    let hovered_buttons: Vec<ToastId> = buttons
+        .iter()
+        .filter(|(interaction, _, _)| **interaction != Interaction::None)
+        .filter_map(|(_, action, close)| {
+            action
+                .map(|button| button.toast)
+                .or(close.map(|button| button.toast))
+        })
+        .collect();
+    let delta = time.delta_secs();
+    for (entity, mut card, interaction, mut node) in &mut cards {
+        let hovered = *interaction != Interaction::None || hovered_buttons.contains(&card.toast);
+        if !hovered {
+            card.remaining -= delta;
+        }
+        if card.remaining <= 0. || dismissed.contains(&card.toast) {
+            commands.entity(entity).despawn_recursive();
+            continue;
+        }
+        if card.age < SLIDE_SECONDS {
+            card.age += delta;
+            node.left = Val::Px(slide_offset(card.age, &settings));
+        }
+        // reactive apps only update on input, and toasts move and expire without any
+        redraw.send(RequestRedraw);
+    }
+}
+
+#[allow(clippy::type_complexity)]
+fn draw_toast_buttons(
+    theme: Res<Theme>,
+    mut actions: Query<
+        (&Interaction, &mut BackgroundColor),
+        (Changed<Interaction>, With<ToastActionButton>),
+    >,
+    mut closes: Query<
+        (&Interaction, &mut BackgroundColor),
+        (
+            Changed<Interaction>,
+            With<ToastCloseButton>,
+            Without<ToastActionButton>,
+        ),
+    >,
+) {
+    for (interaction, mut color) in &mut actions {
+        color.0 = theme.surface_color(*interaction);
+    }
+    for (interaction, mut color) in &mut closes {
+        color.0 = match interaction {
+            Interaction::None => Color::NONE,
+            _ => theme.surface_color(*interaction),
+        };
+    }
+}
@@ -0,0 +1,160 @@
+//! On/off switch whose thumb slides across the track. Clicking it, or pressing Space or Enter
+//! while it's focused, flips it.
+
+use crate::theme::Theme;
+use crate::widgets::focus::{Focus, FocusPlugin, Focusable};
+use bevy::prelude::*;
+
+const TRACK_WIDTH: f32 = 40.;
+const TRACK_HEIGHT: f32 = 22.;
+const THUMB_SIZE: f32 = 16.;
+const THUMB_MARGIN: f32 = 3.;
+/// Seconds the thumb takes to cross the track.
+const SLIDE_TIME: f32 = 0.12;
+
+#[derive(Component, Clone, Copy, Debug, Default)]
+#[require(Button, Focusable, SwitchSlide)]
+pub struct ToggleSwitch {
+    pub on: bool,
+}
+
+impl ToggleSwitch {
+    pub fn new(on: bool) -> Self {
+        Self { on }
+    }
+}
+
+/// Sent when the user flips a switch.
+#[derive(Event, Clone, Copy, Debug)]
+pub struct ToggleChanged {
+    pub switch: Entity,
+    pub on: bool,
+}
+
+/// How far the thumb is towards "on", from 0 to 1.
+#[derive(Component, Default)]
+struct SwitchSlide(f32);
+
+#[derive(Component)]
+struct SwitchParts {
+    track: Entity,
+    thumb: Entity,
+}
+
+pub struct ToggleSwitchPlugin;
+
+impl Plugin for ToggleSwitchPlugin {
+    fn build(&self, app: &mut App) {
+        if !app.is_plugin_added::<FocusPlugin>() {
+            app.add_plugins(FocusPlugin);
+        }
+        app.add_event::<ToggleChanged>()
+            .add_systems(Update, (flip_switches, slide_thumbs).chain());
+    }
+}
+
+/// Spawns a switch followed by its label and returns the switch entity.
+pub fn spawn_toggle_switch(
+    parent: &mut ChildBuilder,
+    switch: ToggleSwitch,
+    label: impl Into<String>,
+    theme: &Theme,
+) -> Entity {
+    let slide = if switch.on { 1. } else { 0. };
+    let mut track = Entity::PLACEHOLDER;
+    let mut thumb = Entity::PLACEHOLDER;
+    let mut switch_commands = parent.spawn((
+        switch,
+        SwitchSlide(slide),
+        Node {
+            align_items: AlignItems::Center,
+            column_gap: Val::Px(8.),
+            padding: UiRect::all(Val::Px(2.)),
+            ..default()
+        },
+        BorderRadius::all(Val::Px(4.)),
+    ));
+    switch_commands.with_children(|parent| {
+        track = parent
+            .spawn((
+                Node {
+                    width: Val::Px(TRACK_WIDTH),
+                    height: Val::Px(TRACK_HEIGHT),
+                    ..default()
+                },
+                BackgroundColor(theme.surface_hovered.mix(&theme.accent, slide)),
+                BorderRadius::MAX,
+            ))
+            .with_children(|parent| {
+                thumb = parent
+                    .spawn((
+                        Node {
+                            position_type: PositionType::Absolute,
+                            top: Val::Px(THUMB_MARGIN),
+                            left: Val::Px(thumb_left(slide)),
+                            width: Val::Px(THUMB_SIZE),
+                            height: Val::Px(THUMB_SIZE),
+                            ..default()
+                        },
+                        BackgroundColor(theme.text),
+                        BorderRadius::MAX,
+                    ))
+                    .id();
+            })
+            .id();
+        parent.spawn((Text::new(label), theme.text_font(), TextColor(theme.text)));
+    });
+    switch_commands.insert(SwitchParts { track, thumb });
+    switch_commands.id()
+}
+
+fn thumb_left(slide: f32) -> f32 {
+    THUMB_MARGIN + (TRACK_WIDTH - THUMB_SIZE - THUMB_MARGIN * 2.) * slide
+}
+
+fn flip_switches(
+    keys: Res<ButtonInput<KeyCode>>,
+    focus: Res<Focus>,
+    mut switches: Query<(Entity, Ref<Interaction>, &mut ToggleSwitch)>,
+    mut changes: EventWriter<ToggleChanged>,
+) {
+    for (entity, interaction, mut switch) in &mut switches {
+        let clicked = interaction.is_changed() && *interaction == Interaction::Pressed;
+        let pressed_key =
+            focus.is(entity) && keys.any_just_pressed([KeyCode::Space, KeyCode::Enter]);
+        if clicked || pressed_key {
+            switch.on = !switch.on;
+            changes.send(ToggleChanged {
+                switch: entity,
+                on: switch.on,
+            });
+        }
+    }
+}
+
+fn slide_thumbs(
+    time: Res<Time>,
+    theme: Res<Theme>,
+    mut switches: Query<(&ToggleSwitch, &mut SwitchSlide, &SwitchParts)>,
+    mut backgrounds: Query<&mut BackgroundColor>,
+    mut nodes: Query<&mut Node>,
+) {
+    let step = time.delta_secs() / SLIDE_TIME;
+    for (switch, mut slide, parts) in &mut switches {
+        let target = if switch.on { 1. } else { 0. };
+        if slide.0 == target && !theme.is_changed() {
+            continue;
+        }
+        slide.0 = if slide.0 < target {
+            (slide.0 + step).min(target)
+        } else {
+            (slide.0 - step).max(target)
+        };
+        if let Ok(mut thumb) = nodes.get_mut(parts.thumb) {
+            thumb.left = Val::Px(thumb_left(slide.0));
+        }
+        if let Ok(mut track) = backgrounds.get_mut(parts.track) {
+            track.0 = theme.surface_hovered.mix(&theme.accent, slide.0);
+        }
+    }
+}
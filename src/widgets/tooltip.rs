@@ -0,0 +1,261 @@
+//! Tooltips: a short description shown next to a node once the pointer has rested on it for a
+//! while. Add a [`Tooltip`] to any node; a press on the node or the pointer leaving it hides the
+//! tooltip again, and it stays hidden until the pointer comes back.
+//!
+//! The popup is an [overlay](crate::widgets::overlay) on the tooltip layer, so it's placed and
+//! flipped like the other anchored overlays. The tooltip's text is also set as the node's
+//! accessibility description.
+
+use crate::theme::Theme;
+use crate::widgets::overlay::{
+    Overlay, OverlayAlign, OverlayLayer, OverlayPlugin, Placement, spawn_overlay,
+};
+use accesskit::Role;
+use bevy::a11y::AccessibilityNode;
+use bevy::prelude::*;
+use bevy::ui::FocusPolicy;
+use bevy::window::RequestRedraw;
+
+/// Space between a tooltip and its node.
+const TOOLTIP_GAP: f32 = 6.;
+
+/// A run of tooltip text with its own color or font.
+#[derive(Clone, Debug)]
+pub struct TooltipSpan {
+    pub text: String,
+    /// Defaults to the theme's text color.
+    pub color: Option<Color>,
+    /// Defaults to Bevy's default font.
+    pub font: Option<Handle<Font>>,
+}
+
+impl TooltipSpan {
+    pub fn new(text: impl Into<String>) -> Self {
+        Self {
+            text: text.into(),
+            color: None,
+            font: None,
+        }
+    }
+
+    pub fn with_color(mut self, color: Color) -> Self {
+        self.color = Some(color);
+        self
+    }
+
+    pub fn with_font(mut self, font: Handle<Font>) -> Self {
+        self.font = Some(font);
+        self
+    }
+}
+
+#[derive(Component, Clone, Debug)]
+#[require(Interaction)]
+pub struct Tooltip {
+    pub spans: Vec<TooltipSpan>,
+    pub placement: Placement,
+    /// Seconds the pointer rests on the node before the tooltip shows; `None` uses
+    /// [`TooltipSettings::delay`].
+    pub delay: Option<f32>,
+}
+
+impl Tooltip {
+    pub fn new(text: impl Into<String>) -> Self {
+        Self::rich([TooltipSpan::new(text)])
+    }
+
+    /// A tooltip made of several spans, e.g. a highlighted shortcut after the description.
+    pub fn rich(spans: impl IntoIterator<Item = TooltipSpan>) -> Self {
+        Self {
+            spans: spans.into_iter().collect(),
+            placement: Placement::Top,
+            delay: None,
+        }
+    }
+
+    pub fn with_placement(mut self, placement: Placement) -> Self {
+        self.placement = placement;
+        self
+    }
+
+    pub fn with_delay(mut self, seconds: f32) -> Self {
+        self.delay = Some(seconds);
+        self
+    }
+
+    /// The text of all the spans, without their styling.
+    pub fn plain_text(&self) -> String {
+        self.spans.iter().map(|span| span.text.as_str()).collect()
+    }
+}
+
+#[derive(Resource, Clone, Debug)]
+pub struct TooltipSettings {
+    /// Seconds the pointer rests on a node before its tooltip shows.
+    pub delay: f32,
+}
+
+impl Default for TooltipSettings {
+    fn default() -> Self {
+        Self { delay: 0.5 }
+    }
+}
+
+/// The popup showing a tooltip.
+#[derive(Component, Clone, Copy, Debug)]
+pub struct TooltipPopup {
+    pub anchor: Entity,
+}
+
+/// The node under the pointer and what its tooltip is doing.
+#[derive(Resource, Default)]
+struct TooltipState {
+    hovered: Option<Entity>,
+    /// When the pointer came onto `hovered`.
+    since: f32,
+    popup: Option<Entity>,
+    /// Set by a press; the tooltip stays hidden until the pointer leaves.
+    suppressed: bool,
+}
+
+pub struct TooltipPlugin;
+
+impl Plugin for TooltipPlugin {
+    fn build(&self, app: &mut App) {
+        if !app.is_plugin_added::<OverlayPlugin>() {
+            app.add_plugins(OverlayPlugin);
+        }
+        app.init_resource::<Theme>()
+            .init_resource::<TooltipSettings>()
+            .init_resource::<TooltipState>()
+            .add_systems(Update, (show_tooltips, describe_tooltips));
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+fn show_tooltips(
+    mut commands: Commands,
+    time: Res<Time>,
+    theme: Res<Theme>,
+    settings: Res<TooltipSettings>,
+    mut state: ResMut<TooltipState>,
+    anchors: Query<(Entity, &Tooltip, &Interaction)>,
+    exists: Query<()>,
+    mut redraw: EventWriter<RequestRedraw>,
+) {
+    let now = time.elapsed_secs();
+    let hovered = anchors
+        .iter()
+        .find(|(_, _, interaction)| **interaction != Interaction::None);
+    let hovered_entity = hovered.map(|(entity, _, _)| entity);
+    if state.hovered != hovered_entity {
+        hide(&mut commands, &mut state);
+        state.hovered = hovered_entity;
+        state.since = now;
+        state.suppressed = false;
+    }
+    if state.popup.is_some_and(|popup| !exists.contains(popup)) {
+        state.popup = None;
+    }
+    let Some((anchor, tooltip, interaction)) = hovered else {
+        return;
+    };
+    if *interaction == Interaction::Pressed {
+        hide(&mut commands, &mut state);
+        state.suppressed = true;
+    }
+    if state.suppressed || state.popup.is_some() {
+        return;
+    }
+    let delay = tooltip.delay.unwrap_or(settings.delay);
+    if now - state.since < delay {
+        // reactive apps only update on input, and the pointer may not move again
+        redraw.send(RequestRedraw);
+        return;
+    }
+    let overlay = Overlay::new(OverlayLayer::Tooltip)
+        .anchored(anchor, tooltip.placement)
+        .with_align(OverlayAlign::Center)
+        .with_gap(TOOLTIP_GAP)
+        .with_dismiss(false, false);
+    let popup = spawn_overlay(
+        &mut commands,
+        overlay,
+        (
+            Node {
+                max_width: Val::Px(280.),
+                padding: UiRect::axes(Val::Px(8.), Val::Px(4.)),
+                border: UiRect::all(Val::Px(1.)),
+                ..default()
+            },
+            BackgroundColor(theme.surface_hovered),
+            BorderColor(theme.border),
+            BorderRadius::all(Val::Px(4.)),
+            // the pointer passes through to whatever is underneath
+            FocusPolicy::Pass,
+            TooltipPopup { anchor },
+        ),
+        |parent| {
+            let span_style = |span: &TooltipSpan| {
+                let mut font = theme.text_font();
+                if let Some(handle) = &span.font {
+                    font.font = handle.clone();
+                }
+                (font, TextColor(span.color.unwrap_or(theme.text)))
+            };
+            let mut spans = tooltip.spans.iter();
+            let first = spans.next();
+            parent
+                .spawn((
+                    Text::new(first.map_or("", |span| span.text.as_str())),
+                    first.map_or((theme.text_font(), TextColor(theme.text)), span_style),
+                ))
+                .with_children(|text| {
+                    for span in spans {
+                        text.spawn((TextSpan::new(span.text.clone()), span_style(span)));
+                    }
+                });
+        },
+    );
+    state.popup = Some(popup);
+}
+
+fn hide(commands: &mut Commands, state: &mut TooltipState) {
+    if let Some(popup) = state.popup.take() {
+        commands.entity(popup).try_despawn_recursive();
+    }
+}
+
+/// Sets the tooltip text as the accessibility description. Buttons get their accessibility node
+/// from Bevy a frame after they're spawned, so the description is set again once it's added.
+#[allow(clippy::type_complexity)]
+fn describe_tooltips(
+    mut commands: Commands,
+    mut anchors: Query<
+        (
+            Entity,
+            &Tooltip,
+            Option<&mut AccessibilityNode>,
+            Has<Button>,
+        ),
+        Or<(Changed<Tooltip>, Added<AccessibilityNode>)>,
+    >,
+) {
+    for (entity, tooltip, accessible, is_button) in &mut anchors {
+        let description = tooltip.plain_text();
+        match accessible {
+            Some(mut accessible) if accessible.description() != Some(description.as_str()) => {
+                accessible.set_description(description);
+            }
+            Some(_) => {}
+            None if !is_button => {
+                let mut node = accesskit::Node::new(Role::GenericContainer);
+                node.set_description(description);
+                commands
+                    .entity(entity)
+                    .insert(AccessibilityNode::from(node));
+            }
+            None => {}
+        }
+    }
+}
@@ -0,0 +1,655 @@
+//! Tree view: rows of [`TreeItem`]s indented under their parents, with a chevron to expand or
+//! collapse the items that have children. Only the rows of expanded items are spawned, and an
+//! item's children can be loaded lazily the first time it's expanded.
+//!
+//! ```ignore
+//! spawn_tree_view(parent, TreeView::new([
+//!     TreeItem::new(1, "src").with_children([TreeItem::new(2, "main.rs")]),
+//!     TreeItem::new(3, "target").with_lazy_children(list_target_dir),
+//! ]), Node::default(), &theme);
+//! ```
+//!
+//! Clicking a row selects it and focuses the tree; the chevron toggles it. While the tree has the
+//! focus, Up and Down move the selection, Right expands the selected item or moves to its first
+//! child, Left collapses it or moves to its parent, and Home and End jump to the first and last
+//! rows.
+//!
+//! [`TreeView::live`] mirrors the entity hierarchy instead, with an item per entity, named after
+//! its [`Name`] where it has one, for looking into a running app.
+
+use crate::theme::Theme;
+use crate::widgets::focus::{Focus, FocusPlugin, Focusable};
+use crate::widgets::scroll_view::{
+    ScrollIntoView, ScrollView, ScrollViewPlugin, ScrollViewSystem, spawn_scroll_view,
+};
+use bevy::prelude::*;
+use bevy::utils::HashSet;
+use std::f32::consts::FRAC_PI_4;
+use std::sync::Arc;
+
+pub const TREE_ROW_HEIGHT: f32 = 24.;
+/// Width of one level of indentation.
+const INDENT: f32 = 18.;
+/// Seconds between two looks at the entity hierarchy for live trees.
+const LIVE_REFRESH: f32 = 0.5;
+
+type LoadChildren = Arc<dyn Fn() -> Vec<TreeItem> + Send + Sync>;
+
+#[derive(Clone)]
+enum TreeChildren {
+    None,
+    Loaded(Vec<TreeItem>),
+    Lazy(LoadChildren),
+}
+
+/// An item of a [`TreeView`]. `id` identifies it across changes to the items, e.g. to keep it
+/// expanded or selected, so it must be unique within the tree.
+#[derive(Clone)]
+pub struct TreeItem {
+    pub id: u64,
+    pub label: String,
+    children: TreeChildren,
+}
+
+impl TreeItem {
+    pub fn new(id: u64, label: impl Into<String>) -> Self {
+        Self {
+            id,
+            label: label.into(),
+            children: TreeChildren::None,
+        }
+    }
+
+    pub fn with_children(mut self, children: impl IntoIterator<Item = TreeItem>) -> Self {
+        self.children = TreeChildren::Loaded(children.into_iter().collect());
+        self
+    }
+
+    /// Calls `load` for the children the first time the item is expanded.
+    pub fn with_lazy_children(
+        mut self,
+        load: impl Fn() -> Vec<TreeItem> + Send + Sync + 'static,
+    ) -> Self {
+        self.children = TreeChildren::Lazy(Arc::new(load));
+        self
+    }
+
+    /// Whether the item can be expanded. Lazy children count, even before they're loaded.
+    pub fn has_children(&self) -> bool {
+        match &self.children {
+            TreeChildren::None => false,
+            TreeChildren::Loaded(children) => !children.is_empty(),
+            TreeChildren::Lazy(_) => true,
+        }
+    }
+
+    /// The children loaded so far.
+    pub fn children(&self) -> &[TreeItem] {
+        match &self.children {
+            TreeChildren::Loaded(children) => children,
+            _ => &[],
+        }
+    }
+
+    fn load_children(&mut self) {
+        if let TreeChildren::Lazy(load) = &self.children {
+            self.children = TreeChildren::Loaded(load());
+        }
+    }
+}
+
+impl PartialEq for TreeItem {
+    fn eq(&self, other: &Self) -> bool {
+        let same_children = match (&self.children, &other.children) {
+            (TreeChildren::None, TreeChildren::None) => true,
+            (TreeChildren::Loaded(a), TreeChildren::Loaded(b)) => a == b,
+            (TreeChildren::Lazy(a), TreeChildren::Lazy(b)) => Arc::ptr_eq(a, b),
+            _ => false,
+        };
+        self.id == other.id && self.label == other.label && same_children
+    }
+}
+
+/// Where a tree's items come from.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum TreeMode {
+    Items,
+    /// Mirrors the hierarchy below an entity, or the whole world.
+    Live(Option<Entity>),
+}
+
+/// A shown row.
+#[derive(Clone, Debug)]
+struct FlatRow {
+    id: u64,
+    parent: Option<u64>,
+    depth: usize,
+    label: String,
+    has_children: bool,
+    expanded: bool,
+    entity: Entity,
+}
+
+#[derive(Component)]
+#[require(Focusable)]
+pub struct TreeView {
+    roots: Vec<TreeItem>,
+    expanded: HashSet<u64>,
+    selected: Option<u64>,
+    mode: TreeMode,
+    rows: Vec<FlatRow>,
+    content: Entity,
+    dirty: bool,
+}
+
+impl TreeView {
+    pub fn new(roots: impl IntoIterator<Item = TreeItem>) -> Self {
+        Self {
+            roots: roots.into_iter().collect(),
+            expanded: HashSet::default(),
+            selected: None,
+            mode: TreeMode::Items,
+            rows: Vec::new(),
+            content: Entity::PLACEHOLDER,
+            dirty: true,
+        }
+    }
+
+    /// A tree mirroring the whole entity hierarchy, refreshed while the app runs. Item ids are
+    /// the entities' [`Entity::to_bits`].
+    pub fn live() -> Self {
+        Self {
+            mode: TreeMode::Live(None),
+            ..Self::new([])
+        }
+    }
+
+    /// A live tree of `root` and its descendants.
+    pub fn live_from(root: Entity) -> Self {
+        Self {
+            mode: TreeMode::Live(Some(root)),
+            ..Self::new([])
+        }
+    }
+
+    pub fn with_expanded(mut self, ids: impl IntoIterator<Item = u64>) -> Self {
+        self.expanded.extend(ids);
+        self
+    }
+
+    pub fn roots(&self) -> &[TreeItem] {
+        &self.roots
+    }
+
+    /// Replaces the items, keeping the expanded and selected ones that still exist.
+    pub fn set_items(&mut self, roots: impl IntoIterator<Item = TreeItem>) {
+        self.roots = roots.into_iter().collect();
+        self.dirty = true;
+    }
+
+    pub fn selected(&self) -> Option<u64> {
+        self.selected
+    }
+
+    /// The selected entity of a live tree.
+    pub fn selected_entity(&self) -> Option<Entity> {
+        self.selected
+            .filter(|_| matches!(self.mode, TreeMode::Live(_)))
+            .map(Entity::from_bits)
+    }
+
+    pub fn select(&mut self, id: Option<u64>) {
+        self.selected = id;
+    }
+
+    pub fn is_expanded(&self, id: u64) -> bool {
+        self.expanded.contains(&id)
+    }
+
+    pub fn expand(&mut self, id: u64) {
+        self.dirty |= self.expanded.insert(id);
+    }
+
+    pub fn collapse(&mut self, id: u64) {
+        self.dirty |= self.expanded.remove(&id);
+    }
+
+    /// Expands a collapsed item and collapses an expanded one.
+    pub fn toggle(&mut self, id: u64) {
+        if self.is_expanded(id) {
+            self.collapse(id);
+        } else {
+            self.expand(id);
+        }
+    }
+
+    /// The number of shown rows.
+    pub fn visible_rows(&self) -> usize {
+        self.rows.len()
+    }
+
+    fn row(&self, id: u64) -> Option<(usize, &FlatRow)> {
+        self.rows.iter().enumerate().find(|(_, row)| row.id == id)
+    }
+
+    /// Lists the rows of the expanded items, loading lazy children on the way.
+    fn flatten(&mut self) -> Vec<FlatRow> {
+        fn visit(
+            items: &mut [TreeItem],
+            parent: Option<u64>,
+            depth: usize,
+            expanded: &HashSet<u64>,
+            rows: &mut Vec<FlatRow>,
+        ) {
+            for item in items {
+                let is_expanded = expanded.contains(&item.id) && item.has_children();
+                rows.push(FlatRow {
+                    id: item.id,
+                    parent,
+                    depth,
+                    label: item.label.clone(),
+                    has_children: item.has_children(),
+                    expanded: is_expanded,
+                    entity: Entity::PLACEHOLDER,
+                });
+                if is_expanded {
+                    item.load_children();
+                    if let TreeChildren::Loaded(children) = &mut item.children {
+                        visit(children, Some(item.id), depth + 1, expanded, rows);
+                    }
+                }
+            }
+        }
+        let mut rows = Vec::new();
+        visit(&mut self.roots, None, 0, &self.expanded, &mut rows);
+        rows
+    }
+}
+
+/// Sent when the selected row of a tree changes.
+#[derive(Event, Clone, Copy, Debug)]
+pub struct TreeSelectionChanged {
+    pub tree: Entity,
+    pub id: Option<u64>,
+}
+
+/// Sent when an item is expanded or collapsed from the UI.
+#[derive(Event, Clone, Copy, Debug)]
+pub struct TreeToggled {
+    pub tree: Entity,
+    pub id: u64,
+    pub expanded: bool,
+}
+
+#[derive(Component, Clone, Copy, Debug)]
+#[require(Button)]
+pub struct TreeRow {
+    pub tree: Entity,
+    pub id: u64,
+}
+
+/// The chevron expanding or collapsing a row.
+#[derive(Component, Clone, Copy, Debug)]
+#[require(Button)]
+pub struct TreeToggle {
+    pub tree: Entity,
+    pub id: u64,
+}
+
+pub struct TreeViewPlugin;
+
+impl Plugin for TreeViewPlugin {
+    fn build(&self, app: &mut App) {
+        if !app.is_plugin_added::<ScrollViewPlugin>() {
+            app.add_plugins(ScrollViewPlugin);
+        }
+        if !app.is_plugin_added::<FocusPlugin>() {
+            app.add_plugins(FocusPlugin);
+        }
+        app.add_event::<TreeSelectionChanged>()
+            .add_event::<TreeToggled>()
+            .add_systems(
+                Update,
+                (
+                    mirror_hierarchy,
+                    click_rows,
+                    tree_keys,
+                    build_rows,
+                    color_rows,
+                )
+                    .chain()
+                    .before(ScrollViewSystem),
+            );
+    }
+}
+
+/// Spawns a tree view inside a vertical scroll view sized by `root` and returns the viewport,
+/// which holds the [`TreeView`].
+pub fn spawn_tree_view(
+    parent: &mut ChildBuilder,
+    mut tree: TreeView,
+    root: impl Bundle,
+    theme: &Theme,
+) -> Entity {
+    let view = ScrollView::vertical().with_keyboard(false);
+    let mut content = Entity::PLACEHOLDER;
+    let viewport = spawn_scroll_view(parent, view, root, |parent| {
+        content = parent
+            .spawn(Node {
+                flex_direction: FlexDirection::Column,
+                min_width: Val::Percent(100.),
+                ..default()
+            })
+            .id();
+    });
+    tree.content = content;
+    let background = BackgroundColor(theme.surface);
+    parent.enqueue_command(move |world: &mut World| {
+        world.entity_mut(viewport).insert((tree, background));
+    });
+    viewport
+}
+
+/// The items for the hierarchy below `entity`. The rows of tree views aren't listed, which would
+/// change them on every refresh.
+fn mirror_item(
+    entity: Entity,
+    names: &Query<Option<&Name>>,
+    children: &Query<&Children>,
+    trees: &Query<(), With<TreeView>>,
+) -> TreeItem {
+    let label = match names.get(entity) {
+        Ok(Some(name)) => name.to_string(),
+        _ => entity.to_string(),
+    };
+    let item = TreeItem::new(entity.to_bits(), label);
+    if trees.contains(entity) {
+        return item;
+    }
+    match children.get(entity) {
+        Ok(children_of) => item.with_children(
+            children_of
+                .iter()
+                .map(|child| mirror_item(*child, names, children, trees)),
+        ),
+        Err(_) => item,
+    }
+}
+
+#[allow(clippy::type_complexity)]
+fn mirror_hierarchy(
+    time: Res<Time>,
+    mut since: Local<f32>,
+    mut trees: Query<&mut TreeView>,
+    top_level: Query<Entity, Without<Parent>>,
+    names: Query<Option<&Name>>,
+    children: Query<&Children>,
+    tree_views: Query<(), With<TreeView>>,
+) {
+    *since += time.delta_secs();
+    let refresh = *since >= LIVE_REFRESH;
+    for mut tree in &mut trees {
+        let TreeMode::Live(root) = tree.mode else {
+            continue;
+        };
+        // a new tree is filled in right away
+        if !refresh && !tree.rows.is_empty() {
+            continue;
+        }
+        let roots: Vec<TreeItem> = match root {
+            Some(root) if names.contains(root) => {
+                vec![mirror_item(root, &names, &children, &tree_views)]
+            }
+            Some(_) => Vec::new(),
+            None => {
+                let mut top_level: Vec<Entity> = top_level.iter().collect();
+                top_level.sort();
+                top_level
+                    .into_iter()
+                    .map(|entity| mirror_item(entity, &names, &children, &tree_views))
+                    .collect()
+            }
+        };
+        if tree.roots != roots {
+            tree.set_items(roots);
+        }
+    }
+    if refresh {
+        *since = 0.;
+    }
+}
+
+fn click_rows(
+    mut focus: ResMut<Focus>,
+    rows: Query<(&Interaction, &TreeRow), Changed<Interaction>>,
+    toggles: Query<(&Interaction, &TreeToggle), Changed<Interaction>>,
+    mut trees: Query<&mut TreeView>,
+    mut selection_changes: EventWriter<TreeSelectionChanged>,
+    mut toggled: EventWriter<TreeToggled>,
+) {
+    for (interaction, row) in &rows {
+        if *interaction != Interaction::Pressed {
+            continue;
+        }
+        let Ok(mut tree) = trees.get_mut(row.tree) else {
+            continue;
+        };
+        focus.0 = Some(row.tree);
+        if tree.selected != Some(row.id) {
+            tree.selected = Some(row.id);
+            selection_changes.send(TreeSelectionChanged {
+                tree: row.tree,
+                id: Some(row.id),
+            });
+        }
+    }
+    for (interaction, toggle) in &toggles {
+        if *interaction != Interaction::Pressed {
+            continue;
+        }
+        let Ok(mut tree) = trees.get_mut(toggle.tree) else {
+            continue;
+        };
+        focus.0 = Some(toggle.tree);
+        tree.toggle(toggle.id);
+        toggled.send(TreeToggled {
+            tree: toggle.tree,
+            id: toggle.id,
+            expanded: tree.is_expanded(toggle.id),
+        });
+    }
+}
+
+fn tree_keys(
+    mut commands: Commands,
+    keys: Res<ButtonInput<KeyCode>>,
+    focus: Res<Focus>,
+    mut trees: Query<(Entity, &mut TreeView)>,
+    mut selection_changes: EventWriter<TreeSelectionChanged>,
+    mut toggled: EventWriter<TreeToggled>,
+) {
+    let Some((entity, mut tree)) = focus.0.and_then(|entity| trees.get_mut(entity).ok()) else {
+        return;
+    };
+    if tree.rows.is_empty() {
+        return;
+    }
+    let last = tree.rows.len() - 1;
+    let current = tree.selected.and_then(|id| tree.row(id));
+    let index = current.map(|(index, _)| index);
+    let mut toggle = None;
+    let target = if keys.just_pressed(KeyCode::ArrowUp) {
+        index.map_or(0, |index| index.saturating_sub(1))
+    } else if keys.just_pressed(KeyCode::ArrowDown) {
+        index.map_or(0, |index| (index + 1).min(last))
+    } else if keys.just_pressed(KeyCode::Home) {
+        0
+    } else if keys.just_pressed(KeyCode::End) {
+        last
+    } else if keys.just_pressed(KeyCode::ArrowRight) {
+        match current {
+            Some((index, row)) if row.expanded => (index + 1).min(last),
+            Some((index, row)) => {
+                if row.has_children {
+                    toggle = Some(row.id);
+                }
+                index
+            }
+            None => 0,
+        }
+    } else if keys.just_pressed(KeyCode::ArrowLeft) {
+        match current {
+            Some((index, row)) if row.expanded => {
+                toggle = Some(row.id);
+                index
+            }
+            Some((index, row)) => row
+                .parent
+                .and_then(|parent| tree.row(parent))
+                .map_or(index, |(parent, _)| parent),
+            None => 0,
+        }
+    } else {
+        return;
+    };
+    if let Some(id) = toggle {
+        tree.toggle(id);
+        toggled.send(TreeToggled {
+            tree: entity,
+            id,
+            expanded: tree.is_expanded(id),
+        });
+    }
+    let row = &tree.rows[target];
+    let (id, row_entity) = (row.id, row.entity);
+    if tree.selected != Some(id) {
+        tree.selected = Some(id);
+        selection_changes.send(TreeSelectionChanged {
+            tree: entity,
+            id: Some(id),
+        });
+        commands.entity(row_entity).try_insert(ScrollIntoView);
+    }
+}
+
+/// Respawns the rows of the trees whose items or expanded items changed.
+fn build_rows(
+    mut commands: Commands,
+    theme: Res<Theme>,
+    mut trees: Query<(Entity, &mut TreeView)>,
+    mut selection_changes: EventWriter<TreeSelectionChanged>,
+) {
+    for (entity, mut tree) in &mut trees {
+        if !tree.dirty {
+            continue;
+        }
+        tree.dirty = false;
+        let mut rows = tree.flatten();
+        // the selection goes when its row is removed or collapsed away
+        if tree
+            .selected
+            .is_some_and(|id| !rows.iter().any(|row| row.id == id))
+        {
+            tree.selected = None;
+            selection_changes.send(TreeSelectionChanged {
+                tree: entity,
+                id: None,
+            });
+        }
+        commands.entity(tree.content).despawn_descendants();
+        commands.entity(tree.content).with_children(|content| {
+            for row in &mut rows {
+                row.entity = spawn_row(content, entity, row, &theme);
+            }
+        });
+        tree.rows = rows;
+    }
+}
+
+fn spawn_row(content: &mut ChildBuilder, tree: Entity, row: &FlatRow, theme: &Theme) -> Entity {
+    content
+        .spawn((
+            Node {
+                height: Val::Px(TREE_ROW_HEIGHT),
+                align_items: AlignItems::Center,
+                padding: UiRect::right(Val::Px(10.)),
+                ..default()
+            },
+            BackgroundColor(theme.surface),
+            TreeRow { tree, id: row.id },
+        ))
+        .with_children(|parent| {
+            // an indentation guide per level
+            for _ in 0..row.depth {
+                parent
+                    .spawn(Node {
+                        width: Val::Px(INDENT),
+                        height: Val::Percent(100.),
+                        justify_content: JustifyContent::Center,
+                        flex_shrink: 0.,
+                        ..default()
+                    })
+                    .with_child((
+                        Node {
+                            width: Val::Px(1.),
+                            height: Val::Percent(100.),
+                            ..default()
+                        },
+                        BackgroundColor(theme.border),
+                    ));
+            }
+            let mut toggle = parent.spawn(Node {
+                width: Val::Px(INDENT),
+                height: Val::Percent(100.),
+                justify_content: JustifyContent::Center,
+                align_items: AlignItems::Center,
+                flex_shrink: 0.,
+                ..default()
+            });
+            if row.has_children {
+                // a chevron: the bottom right corner of a square, pointing right or down
+                let angle = if row.expanded { FRAC_PI_4 } else { -FRAC_PI_4 };
+                toggle.insert(TreeToggle { tree, id: row.id }).with_child((
+                    Node {
+                        width: Val::Px(6.),
+                        height: Val::Px(6.),
+                        border: UiRect {
+                            right: Val::Px(2.),
+                            bottom: Val::Px(2.),
+                            ..default()
+                        },
+                        ..default()
+                    },
+                    BorderColor(theme.text_muted),
+                    Transform::from_rotation(Quat::from_rotation_z(angle)),
+                ));
+            }
+            parent.spawn((
+                Text::new(row.label.clone()),
+                theme.text_font(),
+                TextColor(theme.text),
+                TextLayout::new_with_no_wrap(),
+            ));
+        })
+        .id()
+}
+
+fn color_rows(
+    theme: Res<Theme>,
+    trees: Query<&TreeView>,
+    mut rows: Query<(&TreeRow, &Interaction, &mut BackgroundColor)>,
+) {
+    for (row, interaction, mut color) in &mut rows {
+        let Ok(tree) = trees.get(row.tree) else {
+            continue;
+        };
+        let background = if tree.selected == Some(row.id) {
+            theme.selection
+        } else if *interaction == Interaction::None {
+            theme.surface
+        } else {
+            theme.surface_hovered
+        };
+        color.set_if_neq(BackgroundColor(background));
+    }
+}
@@ -0,0 +1,511 @@
+//! Virtualized list. Rows come from a [`ListSource`]; only the rows in view plus a buffer exist as
+//! entities, and rows scrolled out of view are rebuilt for the rows scrolling in instead of being
+//! despawned. Rows may have different heights, which the source reports up front so the list can
+//! be laid out without spawning it.
+//!
+//! ```ignore
+//! spawn_virtual_list(parent, VirtualList::new(Items(100_000)), Node::default());
+//! ```
+//!
+//! Clicking a row selects it (shift extends and ctrl toggles in [`SelectionMode::Multiple`]); the
+//! arrow, page, home and end keys move the selection while the list is hovered.
+
+use crate::theme::Theme;
+use crate::widgets::scroll_view::{
+    ScrollMetrics, ScrollView, ScrollViewPlugin, ScrollViewSystem, spawn_scroll_view,
+};
+use bevy::prelude::*;
+use bevy::ui::RelativeCursorPosition;
+use bevy::utils::HashMap;
+use bevy_simple_text_input::TextInputInactive;
+use std::collections::BTreeSet;
+use std::ops::Range;
+
+pub const DEFAULT_ROW_HEIGHT: f32 = 24.;
+
+/// Rows shown by a [`VirtualList`].
+pub trait ListSource: Send + Sync + 'static {
+    fn len(&self) -> usize;
+
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Height of a row in logical pixels.
+    fn row_height(&self, _index: usize) -> f32 {
+        DEFAULT_ROW_HEIGHT
+    }
+
+    /// Spawns the content of a row. Called whenever a row entity is (re)used for `index`.
+    fn build_row(&self, index: usize, row: &mut ChildBuilder);
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub enum SelectionMode {
+    None,
+    #[default]
+    Single,
+    Multiple,
+}
+
+/// Selected row indices.
+#[derive(Clone, Debug, Default)]
+pub struct Selection {
+    pub mode: SelectionMode,
+    selected: BTreeSet<usize>,
+    anchor: Option<usize>,
+    current: Option<usize>,
+}
+
+impl Selection {
+    pub fn new(mode: SelectionMode) -> Self {
+        Self { mode, ..default() }
+    }
+
+    pub fn contains(&self, index: usize) -> bool {
+        self.selected.contains(&index)
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = usize> + '_ {
+        self.selected.iter().copied()
+    }
+
+    /// The most recently selected row.
+    pub fn current(&self) -> Option<usize> {
+        self.current
+    }
+
+    pub fn clear(&mut self) {
+        self.selected.clear();
+        self.anchor = None;
+        self.current = None;
+    }
+
+    /// Selects `index`. In [`SelectionMode::Multiple`], `extend` selects the range from the last
+    /// plain selection and `toggle` adds or removes the row. Returns whether anything changed.
+    pub fn select(&mut self, index: usize, extend: bool, toggle: bool) -> bool {
+        let before = (self.selected.clone(), self.current);
+        match self.mode {
+            SelectionMode::None => return false,
+            SelectionMode::Multiple if extend => {
+                let anchor = self.anchor.unwrap_or(index);
+                if !toggle {
+                    self.selected.clear();
+                }
+                self.selected.extend(anchor.min(index)..=anchor.max(index));
+            }
+            SelectionMode::Multiple if toggle => {
+                if !self.selected.remove(&index) {
+                    self.selected.insert(index);
+                }
+                self.anchor = Some(index);
+            }
+            _ => {
+                self.selected.clear();
+                self.selected.insert(index);
+                self.anchor = Some(index);
+            }
+        }
+        self.current = Some(index);
+        before != (self.selected.clone(), self.current)
+    }
+
+    /// Moves the selection along with rows that changed places; rows `position` returns `None`
+    /// for are dropped.
+    pub fn remap(&mut self, mut position: impl FnMut(usize) -> Option<usize>) {
+        self.selected = self
+            .selected
+            .iter()
+            .filter_map(|index| position(*index))
+            .collect();
+        self.anchor = self.anchor.and_then(&mut position);
+        self.current = self.current.and_then(&mut position);
+    }
+
+    /// Drops rows past the end of a list that shrank.
+    pub fn truncate(&mut self, len: usize) {
+        self.selected.retain(|index| *index < len);
+        self.anchor = self.anchor.filter(|index| *index < len);
+        self.current = self.current.filter(|index| *index < len);
+    }
+}
+
+/// Sent when the selection of a list changes, with the row that was clicked or moved to.
+#[derive(Event, Clone, Copy, Debug)]
+pub struct SelectionChanged {
+    pub list: Entity,
+    pub current: Option<usize>,
+}
+
+/// A row entity and the index it currently shows.
+#[derive(Component, Clone, Copy, Debug)]
+pub struct VirtualRow {
+    pub list: Entity,
+    pub index: usize,
+}
+
+/// Lives on the scroll view's viewport.
+#[derive(Component)]
+pub struct VirtualList {
+    source: Box<dyn ListSource>,
+    /// Rows kept alive above and below the visible ones.
+    pub buffer: usize,
+    pub selection: Selection,
+    /// Top of every row, plus the total height at the end.
+    offsets: Vec<f32>,
+    spacer: Entity,
+    rows: HashMap<usize, Entity>,
+    free: Vec<Entity>,
+    range: Range<usize>,
+    dirty: bool,
+}
+
+impl VirtualList {
+    pub fn new(source: impl ListSource) -> Self {
+        Self {
+            source: Box::new(source),
+            buffer: 5,
+            selection: default(),
+            offsets: vec![0.],
+            spacer: Entity::PLACEHOLDER,
+            rows: default(),
+            free: default(),
+            range: 0..0,
+            dirty: true,
+        }
+    }
+
+    pub fn with_buffer(mut self, buffer: usize) -> Self {
+        self.buffer = buffer;
+        self
+    }
+
+    pub fn with_selection_mode(mut self, mode: SelectionMode) -> Self {
+        self.selection.mode = mode;
+        self
+    }
+
+    pub fn source(&self) -> &dyn ListSource {
+        self.source.as_ref()
+    }
+
+    pub fn set_source(&mut self, source: impl ListSource) {
+        self.source = Box::new(source);
+        self.refresh();
+    }
+
+    /// Re-reads lengths and heights from the source and rebuilds the visible rows.
+    pub fn refresh(&mut self) {
+        self.dirty = true;
+    }
+
+    /// Top and bottom of a row within the list.
+    pub fn row_span(&self, index: usize) -> (f32, f32) {
+        (self.offsets[index], self.offsets[index + 1])
+    }
+
+    pub fn total_height(&self) -> f32 {
+        self.offsets.last().copied().unwrap_or_default()
+    }
+
+    /// The entity showing `index`, if it's currently built.
+    pub fn row_entity(&self, index: usize) -> Option<Entity> {
+        self.rows.get(&index).copied()
+    }
+
+    /// Indices of the built rows, including the buffer.
+    pub fn built_rows(&self) -> Range<usize> {
+        self.range.clone()
+    }
+
+    /// Row entities in use or waiting to be reused.
+    pub fn row_entities(&self) -> usize {
+        self.rows.len() + self.free.len()
+    }
+
+    fn measure(&mut self) {
+        let len = self.source.len();
+        self.offsets.clear();
+        self.offsets.reserve(len + 1);
+        let mut top = 0.;
+        self.offsets.push(top);
+        for index in 0..len {
+            top += self.source.row_height(index);
+            self.offsets.push(top);
+        }
+        self.selection.truncate(len);
+    }
+
+    fn range_for(&self, top: f32, height: f32) -> Range<usize> {
+        let len = self.offsets.len() - 1;
+        let starts = &self.offsets[..len];
+        let first = starts
+            .partition_point(|start| *start <= top)
+            .saturating_sub(1);
+        let end = starts.partition_point(|start| *start < top + height);
+        first.saturating_sub(self.buffer)..(end + self.buffer).min(len)
+    }
+}
+
+/// The virtual list systems, run after the scroll views so rows follow the current offset.
+#[derive(SystemSet, Debug, Clone, PartialEq, Eq, Hash)]
+pub struct VirtualListSystem;
+
+pub struct VirtualListPlugin;
+
+impl Plugin for VirtualListPlugin {
+    fn build(&self, app: &mut App) {
+        if !app.is_plugin_added::<ScrollViewPlugin>() {
+            app.add_plugins(ScrollViewPlugin);
+        }
+        app.add_event::<SelectionChanged>().add_systems(
+            Update,
+            (click_rows, keyboard_select, layout_rows, color_rows)
+                .chain()
+                .after(ScrollViewSystem)
+                .in_set(VirtualListSystem),
+        );
+    }
+}
+
+/// Spawns a virtual list inside a vertical scroll view sized by `root` and returns the viewport,
+/// which holds the [`VirtualList`].
+pub fn spawn_virtual_list(
+    parent: &mut ChildBuilder,
+    mut list: VirtualList,
+    root: impl Bundle,
+) -> Entity {
+    // the keys move the selection instead, unless there is none
+    let view = ScrollView::vertical()
+        .with_kinetic(true)
+        .with_keyboard(list.selection.mode == SelectionMode::None);
+    let mut spacer = Entity::PLACEHOLDER;
+    let viewport = spawn_scroll_view(parent, view, root, |content| {
+        spacer = content
+            .spawn(Node {
+                width: Val::Percent(100.),
+                ..default()
+            })
+            .id();
+    });
+    list.spacer = spacer;
+    parent.enqueue_command(move |world: &mut World| {
+        world.entity_mut(viewport).insert(list);
+    });
+    viewport
+}
+
+fn row_node(top: f32, height: f32) -> Node {
+    Node {
+        position_type: PositionType::Absolute,
+        left: Val::Px(0.),
+        right: Val::Px(0.),
+        top: Val::Px(top),
+        height: Val::Px(height),
+        flex_direction: FlexDirection::Column,
+        justify_content: JustifyContent::Center,
+        padding: UiRect::horizontal(Val::Px(10.)),
+        overflow: Overflow::clip(),
+        ..default()
+    }
+}
+
+fn click_rows(
+    keys: Res<ButtonInput<KeyCode>>,
+    rows: Query<(&Interaction, &VirtualRow), Changed<Interaction>>,
+    mut lists: Query<&mut VirtualList>,
+    mut changes: EventWriter<SelectionChanged>,
+) {
+    let extend = keys.any_pressed([KeyCode::ShiftLeft, KeyCode::ShiftRight]);
+    let toggle = keys.any_pressed([
+        KeyCode::ControlLeft,
+        KeyCode::ControlRight,
+        KeyCode::SuperLeft,
+        KeyCode::SuperRight,
+    ]);
+    for (interaction, row) in &rows {
+        if *interaction != Interaction::Pressed {
+            continue;
+        }
+        let Ok(mut list) = lists.get_mut(row.list) else {
+            continue;
+        };
+        if list.selection.select(row.index, extend, toggle) {
+            changes.send(SelectionChanged {
+                list: row.list,
+                current: Some(row.index),
+            });
+        }
+    }
+}
+
+fn keyboard_select(
+    keys: Res<ButtonInput<KeyCode>>,
+    text_inputs: Query<&TextInputInactive>,
+    mut lists: Query<(
+        Entity,
+        &mut VirtualList,
+        &RelativeCursorPosition,
+        &ScrollMetrics,
+        &mut ScrollPosition,
+    )>,
+    mut changes: EventWriter<SelectionChanged>,
+) {
+    if text_inputs.iter().any(|inactive| !inactive.0) {
+        return;
+    }
+    let extend = keys.any_pressed([KeyCode::ShiftLeft, KeyCode::ShiftRight]);
+
+    for (entity, mut list, cursor, metrics, mut position) in &mut lists {
+        // offsets are stale until the next layout
+        if list.dirty
+            || !cursor.mouse_over()
+            || list.selection.mode == SelectionMode::None
+            || list.source.is_empty()
+        {
+            continue;
+        }
+        let last = list.source.len() - 1;
+        let page = list.range.len().saturating_sub(list.buffer * 2).max(1);
+
+        let mut target = None;
+        for key in keys.get_just_pressed() {
+            let from = target.or(list.selection.current());
+            target = Some(match key {
+                KeyCode::ArrowUp => from.map_or(0, |index| index.saturating_sub(1)),
+                KeyCode::ArrowDown => from.map_or(0, |index| (index + 1).min(last)),
+                KeyCode::PageUp => from.map_or(0, |index| index.saturating_sub(page)),
+                KeyCode::PageDown => from.map_or(0, |index| (index + page).min(last)),
+                KeyCode::Home => 0,
+                KeyCode::End => last,
+                _ => continue,
+            });
+        }
+        let Some(index) = target else {
+            continue;
+        };
+        if list.selection.select(index, extend, false) {
+            changes.send(SelectionChanged {
+                list: entity,
+                current: Some(index),
+            });
+        }
+
+        // keep the selected row in view
+        let (top, bottom) = list.row_span(index);
+        let offset = if top < position.offset_y {
+            top
+        } else if bottom > position.offset_y + metrics.viewport.y {
+            bottom - metrics.viewport.y
+        } else {
+            position.offset_y
+        };
+        let offset = offset.clamp(0., metrics.max_offset().y);
+        if offset != position.offset_y {
+            position.offset_y = offset;
+        }
+    }
+}
+
+fn layout_rows(
+    mut commands: Commands,
+    mut lists: Query<(Entity, &mut VirtualList, &ScrollPosition, &ScrollMetrics)>,
+    mut nodes: Query<&mut Node>,
+    mut rows: Query<&mut VirtualRow>,
+) {
+    for (entity, mut list, position, metrics) in &mut lists {
+        let list = &mut *list;
+        let rebuild = list.dirty;
+        if rebuild {
+            list.dirty = false;
+            list.measure();
+            if let Ok(mut spacer) = nodes.get_mut(list.spacer) {
+                spacer.height = Val::Px(list.total_height());
+            }
+            list.free.extend(list.rows.drain().map(|(_, row)| row));
+        }
+
+        let range = list.range_for(position.offset_y, metrics.viewport.y);
+        if range == list.range && !rebuild {
+            continue;
+        }
+        list.range = range.clone();
+
+        let VirtualList {
+            rows: built, free, ..
+        } = &mut *list;
+        built.retain(|index, row| {
+            let keep = range.contains(index);
+            if !keep {
+                free.push(*row);
+            }
+            keep
+        });
+
+        for index in range {
+            if list.rows.contains_key(&index) {
+                continue;
+            }
+            let (top, bottom) = list.row_span(index);
+            let row = match list.free.pop() {
+                Some(row) => {
+                    if let Ok(mut node) = nodes.get_mut(row) {
+                        node.display = Display::Flex;
+                        node.top = Val::Px(top);
+                        node.height = Val::Px(bottom - top);
+                    }
+                    if let Ok(mut marker) = rows.get_mut(row) {
+                        marker.index = index;
+                    }
+                    commands.entity(row).despawn_descendants();
+                    row
+                }
+                None => {
+                    let row = commands
+                        .spawn((
+                            row_node(top, bottom - top),
+                            Button,
+                            BackgroundColor::default(),
+                            VirtualRow {
+                                list: entity,
+                                index,
+                            },
+                        ))
+                        .id();
+                    commands.entity(list.spacer).add_child(row);
+                    row
+                }
+            };
+            commands
+                .entity(row)
+                .with_children(|builder| list.source.build_row(index, builder));
+            list.rows.insert(index, row);
+        }
+
+        for row in &list.free {
+            if let Ok(mut node) = nodes.get_mut(*row) {
+                node.display = Display::None;
+            }
+        }
+    }
+}
+
+fn color_rows(
+    theme: Res<Theme>,
+    lists: Query<&VirtualList>,
+    mut rows: Query<(&VirtualRow, &Interaction, &mut BackgroundColor)>,
+) {
+    for (row, interaction, mut color) in &mut rows {
+        let Ok(list) = lists.get(row.list) else {
+            continue;
+        };
+        let background = if list.selection.contains(row.index) {
+            theme.selection
+        } else if *interaction == Interaction::None {
+            theme.surface
+        } else {
+            theme.surface_hovered
+        };
+        color.set_if_neq(BackgroundColor(background));
+    }
+}
@@ -0,0 +1,118 @@
+//! Registration checks, password hashes, the credential file and the login throttle's delays and
+//! lockout.
+
+use bevy_container::auth::{
+    AuthError, CredentialStore, LoginThrottle, hash_password, validate_registration,
+    verify_password,
+};
+
+#[test]
+fn registration_is_validated() {
+    assert_eq!(
+        validate_registration(" ", "horsebattery", "horsebattery"),
+        Err(AuthError::EmptyUsername)
+    );
+    assert_eq!(
+        validate_registration("alice", "short", "short"),
+        Err(AuthError::PasswordTooShort)
+    );
+    assert_eq!(
+        validate_registration("alice", "horsebattery", "horsebatterz"),
+        Err(AuthError::PasswordsDiffer)
+    );
+    assert_eq!(
+        validate_registration("alice", "horsebattery", "horsebattery"),
+        Ok(())
+    );
+}
+
+#[test]
+fn passwords_verify_against_their_hash() {
+    let hash = hash_password("horsebattery").unwrap();
+    assert!(hash.starts_with("$argon2id$"));
+    assert_eq!(verify_password(Some(&hash), "horsebattery"), Ok(()));
+    assert_eq!(
+        verify_password(Some(&hash), "wrong"),
+        Err(AuthError::InvalidCredentials)
+    );
+    // unknown users fail like a wrong password
+    assert_eq!(
+        verify_password(None, "horsebattery"),
+        Err(AuthError::InvalidCredentials)
+    );
+}
+
+#[test]
+fn credentials_load_again() {
+    let path = std::env::temp_dir().join("bevy_container_credentials.ron");
+    let _ = std::fs::remove_file(&path);
+    let mut store = CredentialStore::load(&path).unwrap();
+    store.add("alice", "hash".into()).unwrap();
+    assert_eq!(
+        store.add("alice", "other".into()),
+        Err(AuthError::UsernameTaken)
+    );
+
+    let loaded = CredentialStore::load(&path);
+    std::fs::remove_file(&path).unwrap();
+    let loaded = loaded.unwrap();
+    assert_eq!(loaded.hash("alice"), Some("hash"));
+    assert!(!loaded.contains("bob"));
+}
+
+#[test]
+fn unsaved_user_is_not_added() {
+    let path = std::env::temp_dir()
+        .join("bevy_container_missing_dir")
+        .join("credentials.ron");
+    let mut store = CredentialStore::load(&path).unwrap();
+    let added = store.add("alice", "hash".into());
+    assert!(matches!(added, Err(AuthError::Store(_))), "{added:?}");
+    assert!(!store.contains("alice"));
+}
+
+#[test]
+fn failures_delay_the_next_attempt() {
+    let mut throttle = LoginThrottle::new(3, 1., 30.);
+    assert_eq!(throttle.check("alice", 0.), Ok(()));
+    assert_eq!(throttle.attempts_left("alice"), 3);
+
+    throttle.fail("alice", 0.);
+    assert_eq!(throttle.attempts_left("alice"), 2);
+    assert_eq!(
+        throttle.check("alice", 0.5),
+        Err(AuthError::TooSoon { seconds: 0.5 })
+    );
+    assert_eq!(throttle.check("alice", 1.), Ok(()));
+    // other usernames aren't held up
+    assert_eq!(throttle.check("bob", 0.5), Ok(()));
+
+    // the delay doubles
+    throttle.fail("alice", 1.);
+    assert_eq!(
+        throttle.check("alice", 2.),
+        Err(AuthError::TooSoon { seconds: 1. })
+    );
+    assert_eq!(throttle.check("alice", 3.), Ok(()));
+}
+
+#[test]
+fn too_many_failures_lock_out() {
+    let mut throttle = LoginThrottle::new(3, 1., 30.);
+    throttle.fail("alice", 0.);
+    throttle.fail("alice", 1.);
+    throttle.fail("alice", 3.);
+    assert_eq!(throttle.attempts_left("alice"), 0);
+    assert_eq!(
+        throttle.check("alice", 13.),
+        Err(AuthError::LockedOut { seconds: 20. })
+    );
+    assert_eq!(throttle.check("alice", 33.), Ok(()));
+
+    // a failure after the lockout starts a new round
+    throttle.fail("alice", 33.);
+    assert_eq!(throttle.attempts_left("alice"), 2);
+    throttle.succeed("alice");
+    assert_eq!(throttle.attempts_left("alice"), 3);
+    assert_eq!(throttle.check("alice", 33.), Ok(()));
+}
@@ -0,0 +1,122 @@
+//! Parsing, ordering and sorting of table cells, and selections following rows that moved.
+
+use bevy_container::widgets::data_table::{CellValue, Column, ColumnKind, DataTable, SortOrder};
+use bevy_container::widgets::virtual_list::{Selection, SelectionMode};
+use std::cmp::Ordering;
+
+fn people() -> DataTable {
+    DataTable::new(
+        vec![
+            Column::new("Name", ColumnKind::Text),
+            Column::new("Age", ColumnKind::Int).editable(),
+        ],
+        vec![
+            vec!["Grace".into(), 45.into()],
+            vec!["ada".into(), 36.into()],
+            vec!["Linus".into(), CellValue::Empty],
+        ],
+    )
+}
+
+#[test]
+fn parse_by_column_kind() {
+    assert_eq!(
+        CellValue::parse(ColumnKind::Text, "  hi "),
+        Ok(CellValue::Text("hi".into()))
+    );
+    assert_eq!(
+        CellValue::parse(ColumnKind::Int, "-3"),
+        Ok(CellValue::Int(-3))
+    );
+    assert_eq!(
+        CellValue::parse(ColumnKind::Float, "2.5"),
+        Ok(CellValue::Float(2.5))
+    );
+    assert_eq!(
+        CellValue::parse(ColumnKind::Bool, "Yes"),
+        Ok(CellValue::Bool(true))
+    );
+    assert_eq!(
+        CellValue::parse(ColumnKind::Bool, "0"),
+        Ok(CellValue::Bool(false))
+    );
+    assert_eq!(CellValue::parse(ColumnKind::Int, " "), Ok(CellValue::Empty));
+}
+
+#[test]
+fn parse_rejects_the_wrong_kind() {
+    assert!(CellValue::parse(ColumnKind::Int, "2.5").is_err());
+    assert!(CellValue::parse(ColumnKind::Float, "two").is_err());
+    assert!(CellValue::parse(ColumnKind::Bool, "maybe").is_err());
+}
+
+#[test]
+fn compare_orders_naturally() {
+    let text = |value: &str| CellValue::Text(value.into());
+    assert_eq!(text("apple").compare(&text("Banana")), Ordering::Less);
+    assert_eq!(
+        CellValue::Int(10).compare(&CellValue::Int(9)),
+        Ordering::Greater
+    );
+    assert_eq!(
+        CellValue::Int(2).compare(&CellValue::Float(2.5)),
+        Ordering::Less
+    );
+    assert_eq!(
+        CellValue::Empty.compare(&CellValue::Int(-100)),
+        Ordering::Less
+    );
+    assert_eq!(
+        CellValue::Bool(true).compare(&CellValue::Int(0)),
+        Ordering::Less
+    );
+}
+
+#[test]
+fn sorting_keeps_the_data_order() {
+    let mut table = people();
+    table.sort_by(Some((0, SortOrder::Ascending)));
+    assert_eq!(table.view_order(), [1, 0, 2]);
+    table.sort_by(Some((1, SortOrder::Descending)));
+    assert_eq!(table.view_order(), [0, 1, 2]);
+    assert_eq!(table.view_position(2), Some(2));
+    table.sort_by(None);
+    assert_eq!(table.view_order(), [0, 1, 2]);
+    assert_eq!(table.cell(1, 0), Some(&CellValue::Text("ada".into())));
+}
+
+#[test]
+fn edits_are_sorted_in() {
+    let mut table = people();
+    table.sort_by(Some((1, SortOrder::Ascending)));
+    assert_eq!(table.view_order(), [2, 1, 0]);
+    table.set_cell(2, 1, CellValue::Int(50));
+    assert_eq!(table.view_order(), [1, 0, 2]);
+    assert_eq!(table.view_position(2), Some(2));
+}
+
+#[test]
+fn csv_follows_the_view() {
+    let mut table = people();
+    table.set_cell(0, 0, "Hopper, Grace".into());
+    table.sort_by(Some((1, SortOrder::Descending)));
+    assert_eq!(
+        table.to_csv(),
+        "Name,Age\n\"Hopper, Grace\",45\nada,36\nLinus,\n"
+    );
+}
+
+#[test]
+fn selection_follows_remapped_rows() {
+    let mut selection = Selection::new(SelectionMode::Multiple);
+    selection.select(0, false, false);
+    selection.select(2, false, true);
+    // row 0 moved to 1, row 2 is gone
+    selection.remap(|index| match index {
+        0 => Some(1),
+        1 => Some(0),
+        _ => None,
+    });
+    assert_eq!(selection.iter().collect::<Vec<_>>(), [1]);
+    assert_eq!(selection.current(), None);
+}
@@ -0,0 +1,197 @@
+//! The demos, driven through the automation API without a window, and each example's script run
+//! against its demo.
+
+use bevy::log::LogPlugin;
+use bevy::prelude::*;
+use bevy_container::automation::{
+    Automation, AutomationError, AutomationPlugin, Selector, find_all, headless_plugins,
+    load_script,
+};
+use bevy_container::demos::accordion::AccordionDemoPlugin;
+use bevy_container::demos::auth::AuthDemoPlugin;
+use bevy_container::demos::confirmation::{ConfirmationDemoPlugin, SelectedButton};
+use bevy_container::demos::counter::CounterDemoPlugin;
+use bevy_container::demos::docking::DockingDemoPlugin;
+use bevy_container::demos::drag_drop::DragDropDemoPlugin;
+use bevy_container::demos::dropdowns::DropdownsDemoPlugin;
+use bevy_container::demos::login::LoginDemoPlugin;
+use bevy_container::demos::menus::MenusDemoPlugin;
+use bevy_container::demos::modals::ModalsDemoPlugin;
+use bevy_container::demos::popovers::PopoversDemoPlugin;
+use bevy_container::demos::progress::ProgressDemoPlugin;
+use bevy_container::demos::sliders::SlidersDemoPlugin;
+use bevy_container::demos::split_panes::SplitPanesDemoPlugin;
+use bevy_container::demos::tabs::TabsDemoPlugin;
+use bevy_container::demos::tree_view::TreeViewDemoPlugin;
+use bevy_container::task::Loading;
+use std::path::Path;
+
+/// Frames to wait for anything that doesn't run in the background.
+const FRAMES: u32 = 10;
+
+fn demo_app(demo: impl Plugin) -> App {
+    let mut app = App::new();
+    // every test's app would try to install the global logger
+    app.add_plugins(headless_plugins().disable::<LogPlugin>())
+        .add_plugins(AutomationPlugin)
+        .add_plugins(demo);
+    app
+}
+
+fn run_script(ui: &mut Automation, name: &str) -> Result<(), AutomationError> {
+    let path = Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("scripts")
+        .join(name);
+    let steps = load_script(&path).unwrap_or_else(|err| panic!("{err}"));
+    ui.run_script(steps)
+}
+
+#[test]
+fn confirm_then_undo() -> Result<(), AutomationError> {
+    let mut app = demo_app(ConfirmationDemoPlugin);
+    let mut ui = Automation::new(&mut app);
+    let status = Selector::name("status_text");
+
+    ui.click(&Selector::label("Confirm"))?;
+    ui.wait_until_text(&status, "selected action is ...  Confirm", FRAMES)?;
+    assert!(matches!(
+        ui.world().resource::<SelectedButton>(),
+        SelectedButton::Confirm
+    ));
+    ui.wait_until("dialog closed", FRAMES, |world| {
+        find_all(world, &Selector::name("dialog")).is_empty()
+    })?;
+
+    ui.click(&Selector::label("Undo"))?;
+    ui.wait_until_text(&status, "selected action is ...  None", FRAMES)?;
+    Ok(())
+}
+
+#[test]
+fn escape_cancels() -> Result<(), AutomationError> {
+    let mut app = demo_app(ConfirmationDemoPlugin);
+    let mut ui = Automation::new(&mut app);
+
+    ui.find(&Selector::name("dialog"))?;
+    ui.press_key(KeyCode::Escape);
+    ui.wait_until_text(
+        &Selector::name("status_text"),
+        "selected action is ...  Cancel",
+        FRAMES,
+    )?;
+    ui.wait_until("toast shown", FRAMES, |world| {
+        !find_all(world, &Selector::label("Nothing was deleted")).is_empty()
+    })
+}
+
+#[test]
+fn confirmation_script() -> Result<(), AutomationError> {
+    let mut app = demo_app(ConfirmationDemoPlugin);
+    run_script(&mut Automation::new(&mut app), "confirmation_confirm.ron")
+}
+
+#[test]
+fn login_needs_a_name() -> Result<(), AutomationError> {
+    let mut app = demo_app(LoginDemoPlugin);
+    let mut ui = Automation::new(&mut app);
+
+    ui.click(&Selector::label("Submit"))?;
+    ui.wait_until("warning shown", FRAMES, |world| {
+        !find_all(world, &Selector::label("Enter a name to log in")).is_empty()
+    })?;
+    let world = ui.world();
+    let loading = world.query::<&Loading>().iter(world).count();
+    assert_eq!(loading, 0, "nothing should be checked without a name");
+    Ok(())
+}
+
+#[test]
+fn login_script() -> Result<(), AutomationError> {
+    let mut app = demo_app(LoginDemoPlugin);
+    run_script(&mut Automation::new(&mut app), "login.ron")
+}
+
+#[test]
+fn accordion_script() -> Result<(), AutomationError> {
+    let mut app = demo_app(AccordionDemoPlugin);
+    run_script(&mut Automation::new(&mut app), "accordion.ron")
+}
+
+#[test]
+fn auth_script() -> Result<(), AutomationError> {
+    // the demo's credential store has no file, so every run starts without accounts
+    let mut app = demo_app(AuthDemoPlugin);
+    run_script(&mut Automation::new(&mut app), "auth.ron")
+}
+
+#[test]
+fn counter_script() -> Result<(), AutomationError> {
+    let mut app = demo_app(CounterDemoPlugin);
+    run_script(&mut Automation::new(&mut app), "counter.ron")
+}
+
+#[test]
+fn docking_script() -> Result<(), AutomationError> {
+    let mut app = demo_app(DockingDemoPlugin);
+    run_script(&mut Automation::new(&mut app), "docking.ron")
+}
+
+#[test]
+fn drag_drop_script() -> Result<(), AutomationError> {
+    let mut app = demo_app(DragDropDemoPlugin);
+    run_script(&mut Automation::new(&mut app), "drag_drop.ron")
+}
+
+#[test]
+fn dropdowns_script() -> Result<(), AutomationError> {
+    let mut app = demo_app(DropdownsDemoPlugin);
+    run_script(&mut Automation::new(&mut app), "dropdown_select.ron")
+}
+
+#[test]
+fn menus_script() -> Result<(), AutomationError> {
+    let mut app = demo_app(MenusDemoPlugin);
+    run_script(&mut Automation::new(&mut app), "menus.ron")
+}
+
+#[test]
+fn modals_script() -> Result<(), AutomationError> {
+    let mut app = demo_app(ModalsDemoPlugin);
+    run_script(&mut Automation::new(&mut app), "modals.ron")
+}
+
+#[test]
+fn popovers_script() -> Result<(), AutomationError> {
+    let mut app = demo_app(PopoversDemoPlugin);
+    run_script(&mut Automation::new(&mut app), "popovers.ron")
+}
+
+#[test]
+fn progress_script() -> Result<(), AutomationError> {
+    let mut app = demo_app(ProgressDemoPlugin);
+    run_script(&mut Automation::new(&mut app), "progress.ron")
+}
+
+#[test]
+fn sliders_script() -> Result<(), AutomationError> {
+    let mut app = demo_app(SlidersDemoPlugin);
+    run_script(&mut Automation::new(&mut app), "slider_drag.ron")
+}
+
+#[test]
+fn split_panes_script() -> Result<(), AutomationError> {
+    let mut app = demo_app(SplitPanesDemoPlugin);
+    run_script(&mut Automation::new(&mut app), "split_panes.ron")
+}
+
+#[test]
+fn tabs_script() -> Result<(), AutomationError> {
+    let mut app = demo_app(TabsDemoPlugin);
+    run_script(&mut Automation::new(&mut app), "tabs.ron")
+}
+
+#[test]
+fn tree_view_script() -> Result<(), AutomationError> {
+    let mut app = demo_app(TreeViewDemoPlugin);
+    run_script(&mut Automation::new(&mut app), "tree_view.ron")
+}
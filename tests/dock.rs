@@ -0,0 +1,38 @@
+//! Dock layouts kept in a [`DockLayoutStore`] across runs.
+
+use bevy_container::widgets::dock::{DockLayout, DockLayoutStore, DockNode};
+use bevy_container::widgets::split_pane::SplitDirection;
+use std::path::PathBuf;
+
+fn temp_path(name: &str) -> PathBuf {
+    let path = std::env::temp_dir().join(format!("bevy_container_{name}.ron"));
+    let _ = std::fs::remove_file(&path);
+    path
+}
+
+#[test]
+fn saved_layout_loads_again() {
+    let path = temp_path("dock_round_trip");
+    let mut store = DockLayoutStore::load(&path).unwrap();
+    assert_eq!(store.layout(), None);
+    let layout = DockLayout::new(DockNode::split(
+        SplitDirection::Horizontal,
+        0.25,
+        DockNode::tabs(["inspector"]),
+        DockNode::tabs(["editor", "console"]),
+    ));
+    store.save(&layout);
+
+    let loaded = DockLayoutStore::load(&path);
+    std::fs::remove_file(&path).unwrap();
+    assert_eq!(loaded.unwrap().layout(), Some(&layout));
+}
+
+#[test]
+fn unreadable_layout_is_an_error() {
+    let path = temp_path("dock_corrupt");
+    std::fs::write(&path, "Some((root: Some(Tabs").unwrap();
+    let loaded = DockLayoutStore::load(&path);
+    std::fs::remove_file(&path).unwrap();
+    assert!(loaded.is_err());
+}
@@ -0,0 +1,114 @@
+//! Reorderable lists only take the items of their own group.
+
+use bevy::log::LogPlugin;
+use bevy::prelude::*;
+use bevy_container::automation::{
+    Automation, AutomationError, AutomationPlugin, Selector, headless_plugins,
+};
+use bevy_container::widgets::drag_drop::{
+    DragCancel, DragDropPlugin, DragPayload, DropTarget, ListItem, ListItemMoved, ReorderableList,
+};
+
+#[derive(Resource, Default)]
+struct Cancelled(u32);
+
+fn spawn_list(commands: &mut Commands, group: &str, name: &str, items: &[&str]) -> Entity {
+    commands
+        .spawn((
+            Node {
+                width: Val::Px(200.),
+                height: Val::Px(300.),
+                flex_direction: FlexDirection::Column,
+                ..default()
+            },
+            ReorderableList::new(group),
+            Name::new(name.to_string()),
+        ))
+        .with_children(|parent| {
+            for item in items {
+                parent.spawn((
+                    Node {
+                        height: Val::Px(40.),
+                        ..default()
+                    },
+                    Name::new(item.to_string()),
+                ));
+            }
+        })
+        .id()
+}
+
+fn setup(mut commands: Commands) {
+    commands.spawn(Camera2d);
+    let lists = [
+        spawn_list(&mut commands, "fruits", "fruits", &["apple", "pear"]),
+        spawn_list(&mut commands, "fruits", "basket", &[]),
+        spawn_list(&mut commands, "tools", "toolbox", &["hammer"]),
+    ];
+    commands
+        .spawn(Node {
+            column_gap: Val::Px(100.),
+            ..default()
+        })
+        .add_children(&lists);
+}
+
+fn list_app() -> App {
+    let mut app = App::new();
+    app.add_plugins(headless_plugins().disable::<LogPlugin>())
+        .add_plugins((AutomationPlugin, DragDropPlugin))
+        .init_resource::<Cancelled>()
+        .add_systems(Startup, setup)
+        .add_observer(|_: Trigger<DragCancel>, mut cancelled: ResMut<Cancelled>| {
+            cancelled.0 += 1;
+        });
+    app
+}
+
+fn items_of(ui: &mut Automation, list: &str) -> Result<Vec<String>, AutomationError> {
+    let list = ui.find(&Selector::name(list))?;
+    let world = ui.world();
+    Ok(world
+        .get::<Children>(list)
+        .into_iter()
+        .flatten()
+        .filter_map(|child| world.get::<Name>(*child))
+        .map(|name| name.to_string())
+        .collect())
+}
+
+#[test]
+fn lists_accept_their_group_only() -> Result<(), AutomationError> {
+    let mut app = list_app();
+    let mut ui = Automation::new(&mut app);
+    let fruit = DragPayload::new(ListItem {
+        group: "fruits".into(),
+    });
+    let basket = ui.find(&Selector::name("basket"))?;
+    let toolbox = ui.find(&Selector::name("toolbox"))?;
+    let accepts =
+        |ui: &mut Automation, list| ui.world().get::<DropTarget>(list).unwrap().accepts(&fruit);
+    assert!(accepts(&mut ui, basket));
+    assert!(!accepts(&mut ui, toolbox));
+    Ok(())
+}
+
+#[test]
+fn item_dropped_on_another_group_is_cancelled() -> Result<(), AutomationError> {
+    let mut app = list_app();
+    let mut ui = Automation::new(&mut app);
+
+    // into the toolbox, two lists to the right
+    ui.drag(&Selector::name("apple"), Vec2::new(600., 0.))?;
+    assert_eq!(ui.world().resource::<Cancelled>().0, 1);
+    assert_eq!(items_of(&mut ui, "fruits")?, ["apple", "pear"]);
+    assert_eq!(items_of(&mut ui, "toolbox")?, ["hammer"]);
+
+    // the basket next to it takes it
+    ui.drag(&Selector::name("apple"), Vec2::new(300., 0.))?;
+    assert_eq!(ui.world().resource::<Cancelled>().0, 1);
+    assert_eq!(items_of(&mut ui, "basket")?, ["apple"]);
+    let moved = ui.world().resource::<Events<ListItemMoved>>().len();
+    assert_eq!(moved, 1);
+    Ok(())
+}
@@ -0,0 +1,47 @@
+//! Key chords as shown in menu hints, rebinding actions, and matching the modifiers held down.
+
+use bevy::prelude::*;
+use bevy_container::keymap::{KeyChord, Keymap};
+
+#[test]
+fn hints_name_the_modifiers_and_key() {
+    let keymap = Keymap::default()
+        .with("file.save", KeyChord::new(KeyCode::KeyS).ctrl())
+        .with("edit.redo", KeyChord::new(KeyCode::KeyZ).ctrl().shift())
+        .with("view.tab", KeyChord::new(KeyCode::Digit1).alt())
+        .with("help", KeyChord::new(KeyCode::F1));
+    assert_eq!(keymap.hint("file.save").as_deref(), Some("Ctrl+S"));
+    assert_eq!(keymap.hint("edit.redo").as_deref(), Some("Ctrl+Shift+Z"));
+    assert_eq!(keymap.hint("view.tab").as_deref(), Some("Alt+1"));
+    assert_eq!(keymap.hint("help").as_deref(), Some("F1"));
+    assert_eq!(keymap.hint("file.quit"), None);
+}
+
+#[test]
+fn binding_again_replaces_the_chord() {
+    let mut keymap = Keymap::default().with("file.save", KeyChord::new(KeyCode::KeyS).ctrl());
+    keymap.bind("file.save", KeyChord::new(KeyCode::F2));
+    assert_eq!(keymap.chord("file.save"), Some(KeyChord::new(KeyCode::F2)));
+    keymap.unbind("file.save");
+    assert_eq!(keymap.chord("file.save"), None);
+}
+
+#[test]
+fn chords_need_exactly_their_modifiers() {
+    let save = KeyChord::new(KeyCode::KeyS).ctrl();
+    let mut keys = ButtonInput::<KeyCode>::default();
+    keys.press(KeyCode::KeyS);
+    assert!(!save.just_pressed(&keys));
+
+    // Command counts as Ctrl
+    keys.press(KeyCode::SuperLeft);
+    assert!(save.just_pressed(&keys));
+
+    keys.press(KeyCode::ShiftRight);
+    assert!(!save.just_pressed(&keys));
+
+    // held from an earlier frame isn't just pressed
+    keys.release(KeyCode::ShiftRight);
+    keys.clear();
+    assert!(!save.just_pressed(&keys));
+}
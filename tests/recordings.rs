@@ -0,0 +1,96 @@
+//! The sample recordings under `recordings/` have to load and replay, and a bad line has to be
+//! reported with its number.
+
+use bevy::log::LogPlugin;
+use bevy::prelude::*;
+use bevy_container::automation::{Automation, AutomationPlugin, headless_plugins};
+use bevy_container::demos::counter::{Counter, CounterDemoPlugin};
+use bevy_container::record::{
+    InputRecordPlugin, RecordedEvent, RecordedInput, Replay, load_recording, replay_finished,
+};
+use std::path::Path;
+
+#[test]
+fn recordings_load() {
+    let dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("recordings");
+    let mut recordings = 0;
+    for entry in std::fs::read_dir(&dir).unwrap() {
+        let path = entry.unwrap().path();
+        if path.extension().is_none_or(|extension| extension != "ron") {
+            continue;
+        }
+        let events = load_recording(&path).unwrap_or_else(|err| panic!("{err}"));
+        assert!(!events.is_empty(), "{} has no events", path.display());
+        assert!(
+            events.windows(2).all(|pair| pair[0].frame <= pair[1].frame),
+            "{} goes back in time",
+            path.display()
+        );
+        recordings += 1;
+    }
+    assert!(recordings > 0, "no recordings in {}", dir.display());
+}
+
+#[test]
+fn recorded_lines_round_trip() {
+    let path = std::env::temp_dir().join("bevy_container_round_trip.ron");
+    let events = vec![
+        RecordedEvent {
+            frame: 3,
+            input: RecordedInput::CursorMoved {
+                position: Vec2::new(10., 20.),
+            },
+        },
+        RecordedEvent {
+            frame: 4,
+            input: RecordedInput::MouseButton {
+                button: MouseButton::Left,
+                state: bevy::input::ButtonState::Pressed,
+            },
+        },
+    ];
+    let lines: Vec<String> = events
+        .iter()
+        .map(|event| ron::to_string(event).unwrap())
+        .collect();
+    // blank lines are skipped
+    std::fs::write(&path, lines.join("\n\n")).unwrap();
+    let loaded = load_recording(&path);
+    std::fs::remove_file(&path).unwrap();
+    assert_eq!(loaded, Ok(events));
+}
+
+#[test]
+fn bad_line_is_reported() {
+    let path = std::env::temp_dir().join("bevy_container_bad_line.ron");
+    std::fs::write(
+        &path,
+        "(frame:1,input:CursorLeft)\n(frame:2,input:Teleport)\n",
+    )
+    .unwrap();
+    let loaded = load_recording(&path);
+    std::fs::remove_file(&path).unwrap();
+    let err = loaded.unwrap_err();
+    assert!(err.contains("bad_line.ron:2:"), "{err}");
+}
+
+#[test]
+fn counter_session_replays() {
+    let path = Path::new(env!("CARGO_MANIFEST_DIR")).join("recordings/counter_session.ron");
+    let events = load_recording(&path).unwrap_or_else(|err| panic!("{err}"));
+    let mut app = App::new();
+    app.add_plugins(headless_plugins().disable::<LogPlugin>())
+        .add_plugins((AutomationPlugin, InputRecordPlugin, CounterDemoPlugin))
+        .insert_resource(Replay::new(events));
+    let mut ui = Automation::new(&mut app);
+
+    // two clicks on "+" by frame 25
+    ui.update(25);
+    assert_eq!(ui.world().resource::<Counter>().0, 2);
+    ui.wait_until("replay finished", 60, |world| {
+        world.run_system_cached(replay_finished).unwrap()
+    })
+    .unwrap();
+    // and two on "-", back where the snapshots expect it
+    assert_eq!(ui.world().resource::<Counter>().0, 0);
+}
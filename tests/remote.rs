@@ -0,0 +1,126 @@
+//! The counter and login examples started with `--remote` can be inspected and driven through BRP.
+//! Each test starts its example on a free port, so they don't get in each other's way.
+
+use bevy_container::remote::call_on;
+use serde_json::{Value, json};
+use std::net::TcpListener;
+use std::process::{Child, Command};
+use std::time::{Duration, Instant};
+
+/// Kills the example when the test ends, passed or not.
+struct Running(Child);
+
+impl Drop for Running {
+    fn drop(&mut self) {
+        let _ = self.0.kill();
+        let _ = self.0.wait();
+    }
+}
+
+/// A port nothing listens on, for the example to serve on.
+fn free_port() -> u16 {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    listener.local_addr().unwrap().port()
+}
+
+/// Starts `exe` headless, serving on a free port, and returns the port once it answers.
+fn start(exe: &str) -> (Running, u16) {
+    let port = free_port();
+    let running = Running(
+        Command::new(exe)
+            .args(["--remote", "--headless", "--remote-port", &port.to_string()])
+            .spawn()
+            .unwrap(),
+    );
+    // the http server takes a moment to come up
+    call_until(port, "ui/list_widgets", None, |widgets| {
+        widgets
+            .as_array()
+            .is_some_and(|widgets| !widgets.is_empty())
+    })
+    .unwrap();
+    (running, port)
+}
+
+/// Calls `method` until `done` accepts the result, for up to ten seconds.
+fn call_until(
+    port: u16,
+    method: &str,
+    params: Option<Value>,
+    done: impl Fn(&Value) -> bool,
+) -> Result<Value, String> {
+    let start = Instant::now();
+    loop {
+        let result = call_on(port, method, params.clone());
+        match &result {
+            Ok(value) if done(value) => return result,
+            _ if start.elapsed() > Duration::from_secs(10) => {
+                return Err(format!("{method} gave {result:?}"));
+            }
+            _ => std::thread::sleep(Duration::from_millis(100)),
+        }
+    }
+}
+
+fn named(name: &str) -> Value {
+    json!({"selector": {"Name": name}})
+}
+
+#[test]
+fn counter_is_clicked_remotely() {
+    let (_counter, port) = start(env!("CARGO_BIN_EXE_counter"));
+
+    let widgets = call_on(port, "ui/list_widgets", None).unwrap();
+    let increment = widgets
+        .as_array()
+        .unwrap()
+        .iter()
+        .find(|widget| widget["name"] == "increment_button")
+        .expect("increment_button is listed");
+    assert_eq!(increment["role"], "Button");
+    assert_eq!(increment["text"], "+");
+    assert_eq!(
+        call_on(port, "ui/get_text", Some(named("counter_text"))).unwrap(),
+        "0"
+    );
+
+    call_on(port, "ui/click", Some(named("increment_button"))).unwrap();
+    call_until(port, "counter/get", None, |count| count == 1).unwrap();
+    // the widget can be addressed by entity too
+    let entity = json!({"entity": increment["entity"]});
+    call_on(port, "ui/click", Some(entity)).unwrap();
+    call_until(port, "counter/get", None, |count| count == 2).unwrap();
+    call_until(port, "ui/get_text", Some(named("counter_text")), |text| {
+        text == "2"
+    })
+    .unwrap();
+
+    let dump = call_on(port, "ui/dump", None).unwrap();
+    assert!(
+        dump.as_str()
+            .is_some_and(|dump| dump.contains("counter_display")),
+        "{dump}"
+    );
+    let missing = call_on(port, "ui/click", Some(named("reset_button")));
+    assert!(missing.is_err(), "{missing:?}");
+}
+
+#[test]
+fn login_form_is_filled_remotely() {
+    let (_login, port) = start(env!("CARGO_BIN_EXE_simple_text_input"));
+
+    let username = named("username");
+    let mut params = username.clone();
+    params["text"] = json!("alice");
+    call_on(port, "ui/set_text", Some(params)).unwrap();
+    assert_eq!(
+        call_on(port, "ui/get_text", Some(username)).unwrap(),
+        "alice"
+    );
+
+    // a node showing no text can't be given any
+    let mut params = named("submit_button");
+    params["text"] = json!("Go");
+    let result = call_on(port, "ui/set_text", Some(params));
+    assert!(result.is_err(), "{result:?}");
+}
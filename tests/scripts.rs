@@ -0,0 +1,21 @@
+//! Every automation script under `scripts/` has to load, or the example running it would exit
+//! before the first step.
+
+use bevy_container::automation::load_script;
+use std::path::Path;
+
+#[test]
+fn scripts_load() {
+    let dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("scripts");
+    let mut scripts = 0;
+    for entry in std::fs::read_dir(&dir).unwrap() {
+        let path = entry.unwrap().path();
+        if path.extension().is_none_or(|extension| extension != "ron") {
+            continue;
+        }
+        let steps = load_script(&path).unwrap_or_else(|err| panic!("{err}"));
+        assert!(!steps.is_empty(), "{} has no steps", path.display());
+        scripts += 1;
+    }
+    assert!(scripts > 0, "no scripts in {}", dir.display());
+}
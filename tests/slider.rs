@@ -0,0 +1,125 @@
+//! Snapping and track positions of [`SliderSettings`], including ranges given the wrong way round
+//! or with no width at all, and the keys moving a focused thumb.
+
+use bevy::log::LogPlugin;
+use bevy::prelude::*;
+use bevy_container::automation::{
+    Automation, AutomationError, AutomationPlugin, Selector, headless_plugins,
+};
+use bevy_container::theme::Theme;
+use bevy_container::widgets::focus::Focus;
+use bevy_container::widgets::slider::{
+    Slider, SliderPlugin, SliderSettings, SliderThumb, spawn_slider,
+};
+
+#[test]
+fn snap_clamps_to_the_range() {
+    let settings = SliderSettings::new(0., 10.);
+    assert_eq!(settings.snap(-5.), 0.);
+    assert_eq!(settings.snap(4.3), 4.3);
+    assert_eq!(settings.snap(12.), 10.);
+}
+
+#[test]
+fn snap_rounds_to_the_step_from_min() {
+    let settings = SliderSettings::new(1., 10.).with_step(2.);
+    assert_eq!(settings.snap(1.9), 1.);
+    assert_eq!(settings.snap(2.1), 3.);
+    assert_eq!(settings.snap(8.2), 9.);
+    // the last step would overshoot max
+    assert_eq!(settings.snap(10.), 10.);
+}
+
+#[test]
+fn inverted_range_is_swapped() {
+    let settings = SliderSettings::new(10., 0.).with_step(5.);
+    assert_eq!(settings.snap(-1.), 0.);
+    assert_eq!(settings.snap(3.), 5.);
+    assert_eq!(settings.snap(11.), 10.);
+    assert_eq!(settings.fraction(2.5), 0.25);
+    assert_eq!(settings.value_at(1.), 10.);
+}
+
+#[test]
+fn equal_bounds_pin_the_value() {
+    let settings = SliderSettings::new(3., 3.).with_step(1.);
+    assert_eq!(settings.snap(0.), 3.);
+    assert_eq!(settings.snap(7.), 3.);
+    assert_eq!(settings.fraction(3.), 0.);
+    assert_eq!(settings.value_at(0.5), 3.);
+}
+
+#[test]
+fn fraction_and_value_at_agree() {
+    let settings = SliderSettings::new(-50., 50.);
+    assert_eq!(settings.fraction(-50.), 0.);
+    assert_eq!(settings.fraction(0.), 0.5);
+    assert_eq!(settings.fraction(80.), 1.);
+    assert_eq!(settings.value_at(0.25), -25.);
+    assert_eq!(settings.value_at(2.), 50.);
+}
+
+#[test]
+fn format_uses_the_step_decimals() {
+    assert_eq!(SliderSettings::new(0., 1.).format(0.5), "0.50");
+    assert_eq!(
+        SliderSettings::new(0., 100.).with_step(5.).format(35.),
+        "35"
+    );
+    assert_eq!(
+        SliderSettings::new(0., 1.).with_step(0.1).format(0.3),
+        "0.3"
+    );
+}
+
+fn slider_app(settings: SliderSettings, value: f32) -> App {
+    let mut app = App::new();
+    app.add_plugins(headless_plugins().disable::<LogPlugin>())
+        .add_plugins((AutomationPlugin, SliderPlugin))
+        .add_systems(Startup, move |mut commands: Commands, theme: Res<Theme>| {
+            commands.spawn(Camera2d);
+            let slider = Slider::new(settings.clone(), value);
+            commands
+                .spawn(Node {
+                    width: Val::Px(300.),
+                    ..default()
+                })
+                .with_children(|parent| {
+                    let root = Node {
+                        width: Val::Percent(100.),
+                        height: Val::Px(28.),
+                        ..default()
+                    };
+                    spawn_slider(parent, slider, (root, Name::new("slider")), &theme);
+                });
+        });
+    app
+}
+
+fn value(ui: &mut Automation) -> Result<f32, AutomationError> {
+    let slider = ui.find(&Selector::name("slider"))?;
+    Ok(ui.world().get::<Slider>(slider).unwrap().value)
+}
+
+#[test]
+fn home_and_end_go_to_the_track_ends() -> Result<(), AutomationError> {
+    for settings in [
+        SliderSettings::new(0., 10.).with_step(2.),
+        SliderSettings::new(10., 0.).with_step(2.),
+    ] {
+        let mut app = slider_app(settings, 4.);
+        let mut ui = Automation::new(&mut app);
+        let thumb = ui.find(&Selector::marker::<SliderThumb>())?;
+        ui.world().resource_mut::<Focus>().0 = Some(thumb);
+
+        ui.press_key(KeyCode::ArrowLeft);
+        assert_eq!(value(&mut ui)?, 2.);
+        ui.press_key(KeyCode::Home);
+        assert_eq!(value(&mut ui)?, 0.);
+        ui.press_key(KeyCode::End);
+        assert_eq!(value(&mut ui)?, 10.);
+        ui.press_key(KeyCode::ArrowRight);
+        assert_eq!(value(&mut ui)?, 10.);
+    }
+    Ok(())
+}
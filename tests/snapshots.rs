@@ -0,0 +1,77 @@
+//! The examples with committed baselines lay out the same as their snapshots, checked headless.
+
+use std::process::Command;
+
+/// Runs `exe` with `--snapshot --headless` and fails with its output on a mismatch.
+fn compare(exe: &str) {
+    let output = Command::new(exe)
+        .args(["--snapshot", "--headless"])
+        .output()
+        .unwrap_or_else(|err| panic!("{exe}: {err}"));
+    assert!(
+        output.status.success(),
+        "{exe} exited with {}\n{}",
+        output.status,
+        String::from_utf8_lossy(&output.stdout)
+    );
+}
+
+#[test]
+fn button_example() {
+    compare(env!("CARGO_BIN_EXE_button_example"));
+}
+
+#[test]
+fn confirmation_example() {
+    compare(env!("CARGO_BIN_EXE_confirmation_example"));
+}
+
+#[test]
+fn counter() {
+    compare(env!("CARGO_BIN_EXE_counter"));
+}
+
+#[test]
+fn counter_after_replay() {
+    let output = Command::new(env!("CARGO_BIN_EXE_counter"))
+        .args(["--replay", "recordings/counter_session.ron"])
+        .args(["--snapshot", "--headless"])
+        .current_dir(env!("CARGO_MANIFEST_DIR"))
+        .output()
+        .unwrap();
+    assert!(
+        output.status.success(),
+        "{}",
+        String::from_utf8_lossy(&output.stdout)
+    );
+}
+
+#[test]
+fn flex_playground() {
+    compare(env!("CARGO_BIN_EXE_flex_playground"));
+}
+
+#[test]
+fn hierarchy_layout() {
+    compare(env!("CARGO_BIN_EXE_hierarchy_layout"));
+}
+
+#[test]
+fn root_container() {
+    compare(env!("CARGO_BIN_EXE_root_container"));
+}
+
+#[test]
+fn scroll_view() {
+    compare(env!("CARGO_BIN_EXE_scroll_view"));
+}
+
+#[test]
+fn settings_form() {
+    compare(env!("CARGO_BIN_EXE_settings_form"));
+}
+
+#[test]
+fn simple_text_input() {
+    compare(env!("CARGO_BIN_EXE_simple_text_input"));
+}
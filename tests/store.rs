@@ -0,0 +1,44 @@
+//! [`RonStore`] round trips, and only starts over when its file is missing.
+
+use bevy_container::store::RonStore;
+use std::collections::BTreeMap;
+use std::path::PathBuf;
+
+fn temp_path(name: &str) -> PathBuf {
+    let path = std::env::temp_dir().join(format!("bevy_container_{name}.ron"));
+    let _ = std::fs::remove_file(&path);
+    path
+}
+
+#[test]
+fn saved_values_load_again() {
+    let path = temp_path("store_round_trip");
+    let mut store = RonStore::<BTreeMap<String, f32>>::load(&path).unwrap();
+    assert!(store.get().is_empty());
+    store.get_mut().insert("sidebar".into(), 0.25);
+    store.save().unwrap();
+
+    let loaded = RonStore::<BTreeMap<String, f32>>::load(&path).unwrap();
+    std::fs::remove_file(&path).unwrap();
+    assert_eq!(loaded.get().get("sidebar"), Some(&0.25));
+    assert_eq!(loaded.path(), Some(path.as_path()));
+}
+
+#[test]
+fn corrupt_file_is_kept() {
+    let path = temp_path("store_corrupt");
+    std::fs::write(&path, "{\"sidebar\": 0.25").unwrap();
+    let loaded = RonStore::<BTreeMap<String, f32>>::load(&path);
+    let contents = std::fs::read_to_string(&path).unwrap();
+    std::fs::remove_file(&path).unwrap();
+    assert!(loaded.is_err());
+    assert_eq!(contents, "{\"sidebar\": 0.25");
+}
+
+#[test]
+fn default_store_has_no_file() {
+    let mut store = RonStore::<BTreeMap<String, bool>>::default();
+    store.get_mut().insert("open".into(), true);
+    assert_eq!(store.save(), Ok(()));
+    assert_eq!(store.path(), None);
+}
@@ -0,0 +1,52 @@
+//! Tasks only start for owners that are still there, and [`delay`] waits without blocking.
+
+use bevy::prelude::*;
+use bevy::tasks::{AsyncComputeTaskPool, TaskPool, block_on};
+use bevy_container::task::{Loading, SpawnTaskExt, delay};
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::{Duration, Instant};
+
+fn spawn_flagging_task(world: &mut World, owner: Entity) -> Arc<AtomicBool> {
+    AsyncComputeTaskPool::get_or_init(TaskPool::default);
+    let ran = Arc::new(AtomicBool::new(false));
+    let flag = ran.clone();
+    world.commands().spawn_task(owner, async move {
+        flag.store(true, Ordering::SeqCst);
+        Ok(())
+    });
+    world.flush();
+    ran
+}
+
+#[test]
+fn task_runs_for_its_owner() {
+    let mut world = World::new();
+    let owner = world.spawn_empty().id();
+    let ran = spawn_flagging_task(&mut world, owner);
+    assert_eq!(world.get::<Loading>(owner).map(Loading::tasks), Some(1));
+    let start = Instant::now();
+    while !ran.load(Ordering::SeqCst) {
+        assert!(start.elapsed() < Duration::from_secs(5), "task never ran");
+        std::thread::sleep(Duration::from_millis(5));
+    }
+}
+
+#[test]
+fn despawned_owner_starts_nothing() {
+    let mut world = World::new();
+    let owner = world.spawn_empty().id();
+    world.despawn(owner);
+    let ran = spawn_flagging_task(&mut world, owner);
+    std::thread::sleep(Duration::from_millis(100));
+    assert!(!ran.load(Ordering::SeqCst));
+}
+
+#[test]
+fn delay_waits_its_duration() {
+    let start = Instant::now();
+    block_on(delay(Duration::from_millis(50)));
+    assert!(start.elapsed() >= Duration::from_millis(50));
+    // a delay that's over is ready at once
+    block_on(delay(Duration::ZERO));
+}